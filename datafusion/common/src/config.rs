@@ -234,6 +234,11 @@ config_namespace! {
         /// the parquet file
         pub pruning: bool, default = true
 
+        /// If true, the parquet reader reads the split block bloom filters (if present)
+        /// of columns compared with `=` or `IN` in the query's predicate, and skips row
+        /// groups whose bloom filters prove that no row can match
+        pub bloom_filter_enabled: bool, default = false
+
         /// If true, the parquet reader skip the optional embedded metadata that may be in
         /// the file Schema. This setting can help avoid schema conflicts when querying
         /// multiple parquet files with schemas containing compatible types but different metadata
@@ -321,6 +326,20 @@ config_namespace! {
         /// The maximum estimated size in bytes for one input side of a HashJoin
         /// will be collected into a single partition
         pub hash_join_single_partition_threshold: usize, default = 1024 * 1024
+
+        /// When set to true, the physical plan optimizer will reorder connected inner
+        /// hash joins based on the cardinalities estimated from the input statistics
+        pub enable_join_reordering: bool, default = false
+
+        /// The maximum number of join inputs for which the join reordering will search
+        /// all join orders. Above it, the join order is chosen greedily. As the search
+        /// takes time exponential in the number of inputs, values above 12 are lowered to 12
+        pub join_reordering_dp_threshold: usize, default = 10
+
+        /// When set to true, the physical plan optimizer will push the bounds of the
+        /// join keys of the build side of hash joins down to the scans and filters of
+        /// their probe side, once the build side is collected
+        pub enable_dynamic_filter_pushdown: bool, default = false
     }
 }
 
@@ -38,8 +38,13 @@ use crate::arrow::datatypes::Schema;
 use crate::arrow::datatypes::SchemaRef;
 use crate::arrow::record_batch::RecordBatch;
 use crate::arrow::util::pretty;
+use crate::datasource::file_format::csv::CsvFormat;
+use crate::datasource::file_format::file_type::FileType;
+use crate::datasource::file_format::json::JsonFormat;
+use crate::datasource::file_format::FileFormat;
 use crate::datasource::{provider_as_source, MemTable, TableProvider};
 use crate::error::Result;
+use crate::execution::options::{CsvWriteOptions, NdJsonWriteOptions};
 use crate::execution::{
     context::{SessionState, TaskContext},
     FunctionRegistry,
@@ -48,7 +53,9 @@ use crate::logical_expr::{
     col, utils::find_window_exprs, Expr, JoinType, LogicalPlan, LogicalPlanBuilder,
     Partitioning, TableType,
 };
-use crate::physical_plan::file_format::{plan_to_csv, plan_to_json, plan_to_parquet};
+use crate::physical_plan::file_format::{
+    plan_to_csv, plan_to_json, plan_to_parquet, FileSinkExec, ParquetWriterOptions,
+};
 use crate::physical_plan::SendableRecordBatchStream;
 use crate::physical_plan::{collect, collect_partitioned};
 use crate::physical_plan::{execute_stream, execute_stream_partitioned, ExecutionPlan};
@@ -924,6 +931,48 @@ impl DataFrame {
         plan_to_csv(task_ctx, plan, path).await
     }
 
+    /// Write a `DataFrame` as CSV files to the directory `path`, laying
+    /// out the written files according to `options`.
+    ///
+    /// `path` is either a local directory, which is created if it does
+    /// not exist, or the URL of a directory in a registered
+    /// [`ObjectStore`](object_store::ObjectStore). One file is written
+    /// per output partition, and per value of the `partition_by` columns
+    /// in Hive style `col=value` directories.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::datasource::file_format::file_type::FileCompressionType;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let options = CsvWriteOptions::new()
+    ///     .file_compression_type(FileCompressionType::GZIP)
+    ///     .partition_by(vec!["a".to_string()]);
+    /// # let dir = tempfile::tempdir()?;
+    /// # let path = dir.path().join("out");
+    /// # let path = path.to_str().unwrap();
+    /// df.write_csv_with_options(path, options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_csv_with_options(
+        self,
+        path: &str,
+        options: CsvWriteOptions,
+    ) -> Result<()> {
+        let file_extension = FileType::CSV
+            .get_ext_with_compression(options.file_compression_type.clone())?;
+        let format = CsvFormat::default()
+            .with_has_header(options.has_header)
+            .with_delimiter(options.delimiter)
+            .with_file_compression_type(options.file_compression_type);
+        self.write_files(path, Arc::new(format), file_extension, options.partition_by)
+            .await
+    }
+
     /// Write a `DataFrame` to a Parquet file.
     pub async fn write_parquet(
         self,
@@ -935,6 +984,38 @@ impl DataFrame {
         plan_to_parquet(task_ctx, plan, path, writer_properties).await
     }
 
+    /// Write a `DataFrame` to a Parquet file, laying out the written
+    /// files according to `options`.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::physical_plan::file_format::ParquetWriterOptions;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let options = ParquetWriterOptions::new()
+    ///     .with_max_row_group_size(1024)
+    ///     .with_bloom_filter_column("a");
+    /// # let dir = tempfile::tempdir()?;
+    /// # let path = dir.path().join("out");
+    /// # let path = path.to_str().unwrap();
+    /// df.write_parquet_with_options(path, options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_parquet_with_options(
+        self,
+        path: &str,
+        options: ParquetWriterOptions,
+    ) -> Result<()> {
+        let plan = self.session_state.create_physical_plan(&self.plan).await?;
+        let writer_properties = options.writer_properties(plan.schema().as_ref())?;
+        let task_ctx = Arc::new(self.task_ctx());
+        plan_to_parquet(task_ctx, plan, path, Some(writer_properties)).await
+    }
+
     /// Executes a query and writes the results to a partitioned JSON file.
     pub async fn write_json(self, path: impl AsRef<str>) -> Result<()> {
         let plan = self.session_state.create_physical_plan(&self.plan).await?;
@@ -942,6 +1023,51 @@ impl DataFrame {
         plan_to_json(task_ctx, plan, path).await
     }
 
+    /// Write a `DataFrame` as newline delimited JSON files to the
+    /// directory `path`, laying out the written files according to
+    /// `options`.
+    ///
+    /// See [`Self::write_csv_with_options`] for how the files are laid out.
+    pub async fn write_json_with_options(
+        self,
+        path: &str,
+        options: NdJsonWriteOptions,
+    ) -> Result<()> {
+        let file_extension = FileType::JSON
+            .get_ext_with_compression(options.file_compression_type.clone())?;
+        let format = JsonFormat::default()
+            .with_file_compression_type(options.file_compression_type);
+        self.write_files(path, Arc::new(format), file_extension, options.partition_by)
+            .await
+    }
+
+    /// Write a `DataFrame` as files of `format` to the directory `path`
+    async fn write_files(
+        self,
+        path: &str,
+        format: Arc<dyn FileFormat>,
+        file_extension: String,
+        partition_by: Vec<String>,
+    ) -> Result<()> {
+        let plan = self.session_state.create_physical_plan(&self.plan).await?;
+        // a trailing `/` makes FileSinkExec write a directory
+        let path = if path.ends_with('/') {
+            path.to_string()
+        } else {
+            format!("{path}/")
+        };
+        let plan = Arc::new(FileSinkExec::try_new(
+            plan,
+            path,
+            format,
+            file_extension,
+            partition_by,
+        )?);
+        let task_ctx = Arc::new(self.task_ctx());
+        collect(plan, task_ctx).await?;
+        Ok(())
+    }
+
     /// Add an additional column to the DataFrame.
     ///
     /// ```
@@ -98,15 +98,55 @@ pub trait TableProvider: Sync + Send {
         None
     }
 
-    /// Insert into this table
+    /// Return an [`ExecutionPlan`] to insert the output of `input` into
+    /// this table, if supported.
+    ///
+    /// The returned plan should produce a single row with the number of
+    /// rows inserted, in a `UInt64` column named `count`. See
+    /// [`InsertExec`](crate::physical_plan::insert::InsertExec) for a
+    /// plan writing to a [`DataSink`](crate::physical_plan::insert::DataSink).
     async fn insert_into(
         &self,
         _state: &SessionState,
-        _input: &LogicalPlan,
-    ) -> Result<()> {
+        _input: Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
         let msg = "Insertion not implemented for this table".to_owned();
         Err(DataFusionError::NotImplemented(msg))
     }
+
+    /// Return an [`ExecutionPlan`] to delete the rows of this table
+    /// matching all `filters`, if supported.
+    ///
+    /// The columns of `filters` are unqualified. As for
+    /// [`Self::insert_into`], the returned plan should produce a single
+    /// row with the number of deleted rows, in a `UInt64` column named
+    /// `count`.
+    async fn delete_from(
+        &self,
+        _state: &SessionState,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let msg = "Deletion not implemented for this table".to_owned();
+        Err(DataFusionError::NotImplemented(msg))
+    }
+
+    /// Return an [`ExecutionPlan`] to update the rows of this table
+    /// matching all `filters`, if supported.
+    ///
+    /// `assignments` contains the name of each updated column and the
+    /// expression computing its new value from the current values of
+    /// the row. The columns of all expressions are unqualified. The
+    /// returned plan should produce a single row with the number of
+    /// updated rows, in a `UInt64` column named `count`.
+    async fn update(
+        &self,
+        _state: &SessionState,
+        _assignments: Vec<(String, Expr)>,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let msg = "Update not implemented for this table".to_owned();
+        Err(DataFusionError::NotImplemented(msg))
+    }
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
@@ -0,0 +1,296 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Apache Arrow IPC file format abstractions
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ipc::convert::fb_to_schema;
+use arrow::ipc::root_as_footer;
+use async_trait::async_trait;
+use bytes::Bytes;
+use datafusion_physical_expr::PhysicalExpr;
+use object_store::{ObjectMeta, ObjectStore};
+
+use super::FileFormat;
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::SessionState;
+use crate::physical_plan::file_format::{ArrowExec, FileScanConfig};
+use crate::physical_plan::ExecutionPlan;
+use crate::physical_plan::Statistics;
+
+/// The default file extension of arrow files
+pub const DEFAULT_ARROW_EXTENSION: &str = ".arrow";
+
+/// Magic bytes at the start and end of an Arrow IPC file
+const ARROW_MAGIC: [u8; 6] = [b'A', b'R', b'R', b'O', b'W', b'1'];
+
+/// Size of the trailer of an Arrow IPC file: footer length followed by the magic
+const TRAILER_LEN: usize = 4 + ARROW_MAGIC.len();
+
+/// Arrow IPC (Feather v2) `FileFormat` implementation.
+#[derive(Default, Debug)]
+pub struct ArrowFormat;
+
+#[async_trait]
+impl FileFormat for ArrowFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let mut schemas = vec![];
+        for object in objects {
+            let footer = fetch_footer(store.as_ref(), object).await?;
+            let schema = root_as_footer(&footer)
+                .ok()
+                .and_then(|footer| footer.schema())
+                .map(fb_to_schema)
+                .ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Unable to read schema from Arrow IPC file {}",
+                        object.location
+                    ))
+                })?;
+            schemas.push(schema);
+        }
+        let merged_schema = Schema::try_merge(schemas)?;
+        Ok(Arc::new(merged_schema))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        _store: &Arc<dyn ObjectStore>,
+        _table_schema: SchemaRef,
+        _object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        Ok(Statistics::default())
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        conf: FileScanConfig,
+        _filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let exec = ArrowExec::new(conf);
+        Ok(Arc::new(exec))
+    }
+}
+
+/// Fetches the flatbuffer encoded footer of an Arrow IPC file without
+/// reading the rest of the file.
+pub(crate) async fn fetch_footer(
+    store: &dyn ObjectStore,
+    object: &ObjectMeta,
+) -> Result<Bytes> {
+    let invalid = || {
+        DataFusionError::Execution(format!(
+            "{} is not a valid Arrow IPC file",
+            object.location
+        ))
+    };
+
+    if object.size < ARROW_MAGIC.len() + TRAILER_LEN {
+        return Err(invalid());
+    }
+
+    let trailer = store
+        .get_range(&object.location, object.size - TRAILER_LEN..object.size)
+        .await?;
+    if trailer[4..] != ARROW_MAGIC {
+        return Err(invalid());
+    }
+
+    let footer_len = i32::from_le_bytes(trailer[..4].try_into().unwrap());
+    let footer_len = usize::try_from(footer_len).map_err(|_| invalid())?;
+    let footer_end = object.size - TRAILER_LEN;
+    if footer_len > footer_end {
+        return Err(invalid());
+    }
+
+    Ok(store
+        .get_range(&object.location, footer_end - footer_len..footer_end)
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::test_util::scan_format;
+    use crate::physical_plan::collect;
+    use crate::prelude::SessionContext;
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use arrow::ipc::writer::FileWriter;
+    use arrow::record_batch::RecordBatch;
+    use datafusion_common::cast::{as_int32_array, as_string_array};
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    /// Writes an Arrow IPC file with `num_batches` batches of two rows each
+    fn write_arrow_file(dir: &TempDir, name: &str, num_batches: usize) -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let file = File::create(dir.path().join(name))?;
+        let mut writer = FileWriter::try_new(file, &schema)?;
+        for i in 0..num_batches as i32 {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(vec![2 * i, 2 * i + 1])),
+                    Arc::new(StringArray::from(vec![Some("a"), None])),
+                ],
+            )?;
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_all_batches() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        write_arrow_file(&tmp_dir, "test.arrow", 3)?;
+
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let exec = scan_format(
+            &state,
+            &ArrowFormat {},
+            tmp_dir.path().to_str().unwrap(),
+            "test.arrow",
+            None,
+            None,
+        )
+        .await?;
+
+        let x: Vec<String> = exec
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+            .collect();
+        assert_eq!(vec!["id: Int32", "name: Utf8"], x);
+
+        let batches = collect(exec, task_ctx).await?;
+        assert_eq!(3, batches.len());
+        let ids: Vec<i32> = batches
+            .iter()
+            .flat_map(|b| as_int32_array(b.column(0)).unwrap().values().to_vec())
+            .collect();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], ids);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_with_projection_and_limit() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        write_arrow_file(&tmp_dir, "test.arrow", 3)?;
+
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let exec = scan_format(
+            &state,
+            &ArrowFormat {},
+            tmp_dir.path().to_str().unwrap(),
+            "test.arrow",
+            Some(vec![1]),
+            Some(3),
+        )
+        .await?;
+
+        let batches = collect(exec, task_ctx).await?;
+        assert_eq!(2, batches.len());
+        assert_eq!(1, batches[0].num_columns());
+        assert_eq!(2, batches[0].num_rows());
+        assert_eq!(1, batches[1].num_rows());
+        let names = as_string_array(batches[0].column(0))?;
+        assert_eq!("a", names.value(0));
+        assert!(names.is_null(1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_external_table_stored_as_arrow() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        write_arrow_file(&tmp_dir, "part-0.arrow", 1)?;
+        write_arrow_file(&tmp_dir, "part-1.arrow", 2)?;
+
+        let ctx = SessionContext::new();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS ARROW LOCATION '{}'",
+            tmp_dir.path().to_str().unwrap()
+        ))
+        .await?
+        .collect()
+        .await?;
+
+        let batches = ctx
+            .sql("SELECT name, count(*) AS cnt, sum(id) AS total FROM t GROUP BY name ORDER BY name")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+------+-----+-------+",
+            "| name | cnt | total |",
+            "+------+-----+-------+",
+            "| a    | 3   | 2     |",
+            "|      | 3   | 5     |",
+            "+------+-----+-------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn infer_schema_rejects_non_arrow_file() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        std::fs::write(tmp_dir.path().join("test.arrow"), "not an arrow file")?;
+
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let err = scan_format(
+            &state,
+            &ArrowFormat {},
+            tmp_dir.path().to_str().unwrap(),
+            "test.arrow",
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("is not a valid Arrow IPC file"));
+
+        Ok(())
+    }
+}
@@ -20,9 +20,13 @@
 use std::any::Any;
 
 use std::collections::HashSet;
+use std::io::Read;
 use std::sync::Arc;
 
+use arrow::csv::reader::Format;
+use arrow::csv::WriterBuilder;
 use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
 use arrow::{self, datatypes::SchemaRef};
 use async_trait::async_trait;
 use bytes::{Buf, Bytes};
@@ -33,12 +37,12 @@ use datafusion_physical_expr::PhysicalExpr;
 use futures::{pin_mut, Stream, StreamExt, TryStreamExt};
 use object_store::{delimited::newline_delimited_stream, ObjectMeta, ObjectStore};
 
-use super::FileFormat;
+use super::{BatchSerializer, FileFormat};
 use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
 use crate::error::Result;
 use crate::execution::context::SessionState;
-use crate::physical_plan::file_format::{CsvExec, FileScanConfig};
+use crate::physical_plan::file_format::{CommentFilter, CsvExec, FileScanConfig};
 use crate::physical_plan::ExecutionPlan;
 use crate::physical_plan::Statistics;
 
@@ -49,6 +53,11 @@ pub const DEFAULT_CSV_EXTENSION: &str = ".csv";
 pub struct CsvFormat {
     has_header: bool,
     delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    comment: Option<u8>,
+    null_value: Option<String>,
+    column_types: Vec<(String, DataType)>,
     schema_infer_max_rec: Option<usize>,
     file_compression_type: FileCompressionType,
 }
@@ -59,6 +68,11 @@ impl Default for CsvFormat {
             schema_infer_max_rec: Some(DEFAULT_SCHEMA_INFER_MAX_RECORD),
             has_header: true,
             delimiter: b',',
+            quote: b'"',
+            escape: None,
+            comment: None,
+            null_value: None,
+            column_types: vec![],
             file_compression_type: FileCompressionType::UNCOMPRESSED,
         }
     }
@@ -105,6 +119,63 @@ impl CsvFormat {
     pub fn delimiter(&self) -> u8 {
         self.delimiter
     }
+
+    /// The quote character.
+    /// - default to '"'
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// The quote character.
+    pub fn quote(&self) -> u8 {
+        self.quote
+    }
+
+    /// The escape character within quoted values. If `None`, quotes are
+    /// escaped by doubling them.
+    /// - default to None
+    pub fn with_escape(mut self, escape: Option<u8>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// The escape character.
+    pub fn escape(&self) -> Option<u8> {
+        self.escape
+    }
+
+    /// Lines starting with the comment character are skipped.
+    /// - default to None
+    pub fn with_comment(mut self, comment: Option<u8>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// The comment character.
+    pub fn comment(&self) -> Option<u8> {
+        self.comment
+    }
+
+    /// Values equal to `null_value` are read as nulls, in addition to
+    /// empty values.
+    /// - default to None
+    pub fn with_null_value(mut self, null_value: Option<String>) -> Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// The string read as null.
+    pub fn null_value(&self) -> Option<&str> {
+        self.null_value.as_deref()
+    }
+
+    /// Override the types inferred for the named columns
+    /// - default to no overrides
+    pub fn with_column_types(mut self, column_types: Vec<(String, DataType)>) -> Self {
+        self.column_types = column_types;
+        self
+    }
 }
 
 #[async_trait]
@@ -137,7 +208,7 @@ impl FileFormat for CsvFormat {
         }
 
         let merged_schema = Schema::try_merge(schemas)?;
-        Ok(Arc::new(merged_schema))
+        Ok(Arc::new(self.override_column_types(merged_schema)?))
     }
 
     async fn infer_stats(
@@ -161,9 +232,56 @@ impl FileFormat for CsvFormat {
             self.has_header,
             self.delimiter,
             self.file_compression_type.to_owned(),
-        );
+        )
+        .with_quote(self.quote)
+        .with_escape(self.escape)
+        .with_comment(self.comment)
+        .with_null_value(self.null_value.clone());
         Ok(Arc::new(exec))
     }
+
+    fn create_serializer(&self, schema: SchemaRef) -> Result<Box<dyn BatchSerializer>> {
+        Ok(Box::new(CsvSerializer {
+            schema,
+            has_header: self.has_header,
+            delimiter: self.delimiter,
+            header_written: false,
+        }))
+    }
+
+    fn file_compression_type(&self) -> FileCompressionType {
+        self.file_compression_type.to_owned()
+    }
+}
+
+/// Encodes record batches as CSV, writing the header (if any) before
+/// the first batch, or at the end of a file without batches
+struct CsvSerializer {
+    schema: SchemaRef,
+    has_header: bool,
+    delimiter: u8,
+    header_written: bool,
+}
+
+impl BatchSerializer for CsvSerializer {
+    fn serialize(&mut self, batch: &RecordBatch) -> Result<Bytes> {
+        let mut buffer = Vec::with_capacity(4096);
+        let mut writer = WriterBuilder::new()
+            .has_headers(self.has_header && !self.header_written)
+            .with_delimiter(self.delimiter)
+            .build(&mut buffer);
+        writer.write(batch)?;
+        drop(writer);
+        self.header_written = true;
+        Ok(Bytes::from(buffer))
+    }
+
+    fn finish(&mut self) -> Result<Bytes> {
+        if self.has_header && !self.header_written {
+            return self.serialize(&RecordBatch::new_empty(self.schema.clone()));
+        }
+        Ok(Bytes::new())
+    }
 }
 
 /// Return a newline delimited stream from the specified file on
@@ -205,14 +323,22 @@ impl CsvFormat {
         pin_mut!(stream);
 
         while let Some(chunk) = stream.next().await.transpose()? {
+            let mut data = vec![];
+            self.file_compression_type
+                .convert_read(chunk.reader())?
+                .read_to_end(&mut data)?;
+            let data = self.prepare_for_inference(data);
+
+            let mut format = Format::default()
+                // only consider header for first chunk
+                .with_header(self.has_header && first_chunk)
+                .with_delimiter(self.delimiter)
+                .with_quote(self.quote);
+            if let Some(escape) = self.escape {
+                format = format.with_escape(escape);
+            }
             let (Schema { fields, .. }, records_read) =
-                arrow::csv::reader::infer_reader_schema(
-                    self.file_compression_type.convert_read(chunk.reader())?,
-                    self.delimiter,
-                    Some(records_to_read),
-                    // only consider header for first chunk
-                    self.has_header && first_chunk,
-                )?;
+                format.infer_schema(data.as_slice(), Some(records_to_read))?;
             records_to_read -= records_read;
             total_records_read += records_read;
 
@@ -257,6 +383,93 @@ impl CsvFormat {
         let schema = build_schema_helper(column_names, &column_type_possibilities);
         Ok((schema, total_records_read))
     }
+
+    /// Removes the comment lines of `data` and blanks the values equal
+    /// to the null value, which the arrow schema inference would
+    /// otherwise consider to be strings
+    fn prepare_for_inference(&self, mut data: Vec<u8>) -> Vec<u8> {
+        if let Some(comment) = self.comment {
+            let len =
+                CommentFilter::new(comment, self.delimiter, self.quote, self.escape)
+                    .filter(&mut data);
+            data.truncate(len);
+        }
+        match &self.null_value {
+            Some(null_value) => blank_values(
+                &data,
+                null_value.as_bytes(),
+                self.delimiter,
+                self.quote,
+                self.escape,
+            ),
+            None => data,
+        }
+    }
+
+    /// Replaces the types of the columns in `column_types`
+    fn override_column_types(&self, schema: Schema) -> Result<Schema> {
+        if self.column_types.is_empty() {
+            return Ok(schema);
+        }
+        let mut fields = schema.fields().clone();
+        for (name, data_type) in &self.column_types {
+            let idx = schema.index_of(name).map_err(|_| {
+                DataFusionError::Plan(format!(
+                    "Column '{name}' with an overridden type not found in the CSV schema"
+                ))
+            })?;
+            fields[idx] = Field::new(name, data_type.clone(), true);
+        }
+        Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+    }
+}
+
+/// Returns a copy of the CSV `data` in which the unquoted values equal to
+/// `value` are replaced by empty values
+fn blank_values(
+    data: &[u8],
+    value: &[u8],
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+) -> Vec<u8> {
+    let is_value_end = |i: usize| {
+        i == data.len() || matches!(data[i], b'\n' | b'\r') || data[i] == delimiter
+    };
+
+    let mut output = Vec::with_capacity(data.len());
+    let mut field_start = true;
+    let mut quoted = false;
+    let mut quote_closed = false;
+    let mut i = 0;
+    while i < data.len() {
+        if field_start && data[i..].starts_with(value) && is_value_end(i + value.len()) {
+            i += value.len();
+            field_start = false;
+            continue;
+        }
+
+        let b = data[i];
+        output.push(b);
+        i += 1;
+        if quoted {
+            if Some(b) == escape && i < data.len() {
+                output.push(data[i]);
+                i += 1;
+            } else if b == quote {
+                quoted = false;
+                quote_closed = true;
+            }
+            field_start = false;
+        } else {
+            // quotes only start a quoted value at the start of a field, or
+            // reopen it when doubled
+            quoted = b == quote && (field_start || quote_closed);
+            quote_closed = false;
+            field_start = b == delimiter || b == b'\n';
+        }
+    }
+    output
 }
 
 fn build_schema_helper(names: Vec<String>, types: &[HashSet<DataType>]) -> Schema {
@@ -297,7 +510,7 @@ mod tests {
     use super::*;
     use crate::datasource::file_format::test_util::VariableStream;
     use crate::physical_plan::collect;
-    use crate::prelude::{SessionConfig, SessionContext};
+    use crate::prelude::{CsvReadOptions, SessionConfig, SessionContext};
     use bytes::Bytes;
     use chrono::DateTime;
     use datafusion_common::cast::as_string_array;
@@ -461,6 +674,186 @@ mod tests {
         Ok(())
     }
 
+    /// CSV data with `'` quotes, `\` escapes, `#` comments and `NA` nulls
+    const CUSTOM_CSV: &str = r"# leading comment
+id,name,score
+1,'a, \'quoted\' name',1.5
+# comment, with a delimiter
+2,NA,NA
+NA,'c',3
+";
+
+    #[test]
+    fn blank_null_values() {
+        let data = b"NA,'NA',NAN\nx,NA\n'a,NA',NA";
+        let blanked = blank_values(data, b"NA", b',', b'\'', None);
+        assert_eq!(
+            ",'NA',NAN\nx,\n'a,NA',",
+            std::str::from_utf8(&blanked).unwrap()
+        );
+
+        // quotes within values don't start quoted values
+        let data = b"x'y,NA\n'a'',NA',NA";
+        let blanked = blank_values(data, b"NA", b',', b'\'', None);
+        assert_eq!("x'y,\n'a'',NA',", std::str::from_utf8(&blanked).unwrap());
+    }
+
+    #[tokio::test]
+    async fn read_csv_with_parsing_options() -> Result<()> {
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("custom.csv");
+        std::fs::write(&path, CUSTOM_CSV)?;
+
+        let ctx = SessionContext::new();
+        let options = CsvReadOptions::new()
+            .quote(b'\'')
+            .escape(b'\\')
+            .comment(b'#')
+            .null_value("NA")
+            .column_types(vec![("score".to_string(), DataType::Float32)]);
+        ctx.register_csv("t", path.to_str().unwrap(), options)
+            .await?;
+
+        let df = ctx.sql("SELECT * FROM t ORDER BY id").await?;
+        let fields: Vec<_> = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+            .collect();
+        assert_eq!(vec!["id: Int64", "name: Utf8", "score: Float32"], fields);
+
+        let expected = vec![
+            "+----+------------------+-------+",
+            "| id | name             | score |",
+            "+----+------------------+-------+",
+            "| 1  | a, 'quoted' name | 1.5   |",
+            "| 2  |                  |       |",
+            "|    | c                | 3.0   |",
+            "+----+------------------+-------+",
+        ];
+        crate::assert_batches_eq!(expected, &df.collect().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_csv_with_empty_values_and_quotes_within_fields() -> Result<()> {
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("data.csv");
+        // the quote within the third value does not start a quoted value,
+        // so the following line is a comment
+        std::fs::write(&path, "id,name\n1,\n2,NA\n3,it's\n# comment\n4,x\n")?;
+
+        for (null_value, expected) in [
+            (None, vec!["+----+", "| id |", "+----+", "| 1  |", "+----+"]),
+            (
+                Some("NA"),
+                vec!["+----+", "| id |", "+----+", "| 1  |", "| 2  |", "+----+"],
+            ),
+        ] {
+            let ctx = SessionContext::new();
+            let mut options = CsvReadOptions::new().quote(b'\'').comment(b'#');
+            if let Some(null_value) = null_value {
+                options = options.null_value(null_value);
+            }
+            ctx.register_csv("t", path.to_str().unwrap(), options)
+                .await?;
+
+            // empty values are read as nulls, whether a null value is set
+            // or not
+            let batches = ctx
+                .sql("SELECT id FROM t WHERE name IS NULL ORDER BY id")
+                .await?
+                .collect()
+                .await?;
+            crate::assert_batches_eq!(expected, &batches);
+
+            let batches = ctx.sql("SELECT count(*) FROM t").await?.collect().await?;
+            let expected = vec![
+                "+-----------------+",
+                "| COUNT(UInt8(1)) |",
+                "+-----------------+",
+                "| 4               |",
+                "+-----------------+",
+            ];
+            crate::assert_batches_eq!(expected, &batches);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_external_table_with_csv_options() -> Result<()> {
+        let tmp_dir = tempfile::TempDir::new()?;
+        std::fs::write(tmp_dir.path().join("custom.csv"), CUSTOM_CSV)?;
+        let location = tmp_dir.path().to_str().unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.sql(&format!(
+            r"CREATE EXTERNAL TABLE t STORED AS CSV WITH HEADER ROW
+              OPTIONS ('QUOTE' '''', 'escape' '\', 'comment' '#', 'null_value' 'NA')
+              LOCATION '{location}'"
+        ))
+        .await?;
+
+        let batches = ctx
+            .sql("SELECT count(id), count(name), sum(score) FROM t")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+-------------+---------------+--------------+",
+            "| COUNT(t.id) | COUNT(t.name) | SUM(t.score) |",
+            "+-------------+---------------+--------------+",
+            "| 2           | 2             | 4.5          |",
+            "+-------------+---------------+--------------+",
+        ];
+        crate::assert_batches_eq!(expected, &batches);
+
+        let err = ctx
+            .sql(&format!(
+                "CREATE EXTERNAL TABLE t2 STORED AS CSV OPTIONS ('quote' 'ab') LOCATION '{location}'"
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            "Error during planning: CSV option 'quote' must be a single character, got 'ab'",
+            err.to_string()
+        );
+
+        let err = ctx
+            .sql(&format!(
+                "CREATE EXTERNAL TABLE t2 STORED AS CSV OPTIONS ('quoting' 'x') LOCATION '{location}'"
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            "Error during planning: Unsupported CSV option 'quoting', expected one of 'quote', 'escape', 'comment' or 'null_value'",
+            err.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn override_unknown_column_type() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let root = format!("{}/csv", crate::test_util::arrow_test_data());
+        let format = CsvFormat::default()
+            .with_column_types(vec![("unknown".to_string(), DataType::Int8)]);
+        let err =
+            scan_format(&state, &format, &root, "aggregate_test_100.csv", None, None)
+                .await
+                .unwrap_err();
+        assert_eq!(
+            "Error during planning: Column 'unknown' with an overridden type not found in the CSV schema",
+            err.to_string()
+        );
+
+        Ok(())
+    }
+
     async fn get_exec(
         state: &SessionState,
         file_name: &str,
@@ -26,14 +26,16 @@ use arrow::datatypes::Schema;
 use arrow::datatypes::SchemaRef;
 use arrow::json::reader::infer_json_schema_from_iterator;
 use arrow::json::reader::ValueIter;
+use arrow::json::LineDelimitedWriter;
+use arrow::record_batch::RecordBatch;
 use async_trait::async_trait;
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 
 use datafusion_physical_expr::PhysicalExpr;
 use object_store::{GetResult, ObjectMeta, ObjectStore};
 
-use super::FileFormat;
 use super::FileScanConfig;
+use super::{BatchSerializer, FileFormat};
 use crate::datasource::file_format::file_type::FileCompressionType;
 use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
 use crate::error::Result;
@@ -148,6 +150,32 @@ impl FileFormat for JsonFormat {
         let exec = NdJsonExec::new(conf, self.file_compression_type.to_owned());
         Ok(Arc::new(exec))
     }
+
+    fn create_serializer(&self, _schema: SchemaRef) -> Result<Box<dyn BatchSerializer>> {
+        Ok(Box::new(JsonSerializer {}))
+    }
+
+    fn file_compression_type(&self) -> FileCompressionType {
+        self.file_compression_type.to_owned()
+    }
+}
+
+/// Encodes record batches as newline delimited JSON
+struct JsonSerializer {}
+
+impl BatchSerializer for JsonSerializer {
+    fn serialize(&mut self, batch: &RecordBatch) -> Result<Bytes> {
+        let mut buffer = Vec::with_capacity(4096);
+        let mut writer = LineDelimitedWriter::new(&mut buffer);
+        writer.write(batch.clone())?;
+        writer.finish()?;
+        drop(writer);
+        Ok(Bytes::from(buffer))
+    }
+
+    fn finish(&mut self) -> Result<Bytes> {
+        Ok(Bytes::new())
+    }
 }
 
 #[cfg(test)]
@@ -20,24 +20,29 @@
 /// Default max records to scan to infer the schema
 pub const DEFAULT_SCHEMA_INFER_MAX_RECORD: usize = 1000;
 
+pub mod arrow;
 pub mod avro;
 pub mod csv;
 pub mod file_type;
 pub mod json;
 pub mod options;
 pub mod parquet;
+pub(crate) mod write;
 
 use std::any::Any;
 use std::fmt;
 use std::sync::Arc;
 
 use crate::arrow::datatypes::SchemaRef;
-use crate::error::Result;
+use crate::arrow::record_batch::RecordBatch;
+use crate::datasource::file_format::file_type::FileCompressionType;
+use crate::error::{DataFusionError, Result};
 use crate::physical_plan::file_format::FileScanConfig;
 use crate::physical_plan::{ExecutionPlan, Statistics};
 
 use crate::execution::context::SessionState;
 use async_trait::async_trait;
+use bytes::Bytes;
 use datafusion_physical_expr::PhysicalExpr;
 use object_store::{ObjectMeta, ObjectStore};
 
@@ -86,6 +91,31 @@ pub trait FileFormat: Send + Sync + fmt::Debug {
         conf: FileScanConfig,
         filters: Option<&Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn ExecutionPlan>>;
+
+    /// Create a [`BatchSerializer`] that encodes record batches of `schema`
+    /// into a new file of this format. This is used to write to tables
+    /// of this format, e.g. with `INSERT INTO`.
+    fn create_serializer(&self, _schema: SchemaRef) -> Result<Box<dyn BatchSerializer>> {
+        Err(DataFusionError::NotImplemented(
+            "Writing files is not implemented for this file format".to_string(),
+        ))
+    }
+
+    /// The compression applied to the files written with the
+    /// [`BatchSerializer`] of this format
+    fn file_compression_type(&self) -> FileCompressionType {
+        FileCompressionType::UNCOMPRESSED
+    }
+}
+
+/// Encodes record batches into the contents of a single file, see
+/// [`FileFormat::create_serializer`]
+pub trait BatchSerializer: Send {
+    /// Encode `batch`, returning the bytes to append to the file
+    fn serialize(&mut self, batch: &RecordBatch) -> Result<Bytes>;
+
+    /// Complete the file, returning any remaining bytes, such as a footer
+    fn finish(&mut self) -> Result<Bytes>;
 }
 
 #[cfg(test)]
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! User facing options for the file formats readers
+//! User facing options for the file formats readers and writers
 
 use std::sync::Arc;
 
@@ -54,6 +54,16 @@ pub struct CsvReadOptions<'a> {
     pub has_header: bool,
     /// An optional column delimiter. Defaults to `b','`.
     pub delimiter: u8,
+    /// An optional quote character. Defaults to `b'"'`.
+    pub quote: u8,
+    /// An optional escape character within quoted values.
+    pub escape: Option<u8>,
+    /// An optional character starting the comment lines to skip.
+    pub comment: Option<u8>,
+    /// An optional string read as null, in addition to empty values.
+    pub null_value: Option<String>,
+    /// Types overriding the inferred types of the named columns.
+    pub column_types: Vec<(String, DataType)>,
     /// An optional schema representing the CSV files. If None, CSV reader will try to infer it
     /// based on data in file.
     pub schema: Option<&'a Schema>,
@@ -84,6 +94,11 @@ impl<'a> CsvReadOptions<'a> {
             schema: None,
             schema_infer_max_records: DEFAULT_SCHEMA_INFER_MAX_RECORD,
             delimiter: b',',
+            quote: b'"',
+            escape: None,
+            comment: None,
+            null_value: None,
+            column_types: vec![],
             file_extension: DEFAULT_CSV_EXTENSION,
             table_partition_cols: vec![],
             file_compression_type: FileCompressionType::UNCOMPRESSED,
@@ -109,6 +124,36 @@ impl<'a> CsvReadOptions<'a> {
         self
     }
 
+    /// Specify quote to use for CSV read
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Specify escape character to use for CSV read
+    pub fn escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Specify the character starting the comment lines to skip
+    pub fn comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Specify the string read as null
+    pub fn null_value(mut self, null_value: impl Into<String>) -> Self {
+        self.null_value = Some(null_value.into());
+        self
+    }
+
+    /// Specify types overriding the inferred types of the named columns
+    pub fn column_types(mut self, column_types: Vec<(String, DataType)>) -> Self {
+        self.column_types = column_types;
+        self
+    }
+
     /// Specify the file extension for CSV file selection
     pub fn file_extension(mut self, file_extension: &'a str) -> Self {
         self.file_extension = file_extension;
@@ -342,6 +387,108 @@ impl<'a> NdJsonReadOptions<'a> {
     }
 }
 
+/// Options that control the writing of CSV files with
+/// [`DataFrame::write_csv_with_options`](crate::dataframe::DataFrame::write_csv_with_options).
+#[derive(Clone, Debug)]
+pub struct CsvWriteOptions {
+    /// Should a header row be written to each file? Defaults to `true`.
+    pub has_header: bool,
+    /// Column delimiter. Defaults to `b','`.
+    pub delimiter: u8,
+    /// Compression of the written files
+    pub file_compression_type: FileCompressionType,
+    /// Columns used to write Hive style `col=value` partition directories.
+    /// These columns are not written to the files.
+    pub partition_by: Vec<String>,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvWriteOptions {
+    /// Create a CSV write option with default presets
+    pub fn new() -> Self {
+        Self {
+            has_header: true,
+            delimiter: b',',
+            file_compression_type: FileCompressionType::UNCOMPRESSED,
+            partition_by: vec![],
+        }
+    }
+
+    /// Configure has_header setting
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Specify delimiter to use for CSV write
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Configure file compression type
+    pub fn file_compression_type(
+        mut self,
+        file_compression_type: FileCompressionType,
+    ) -> Self {
+        self.file_compression_type = file_compression_type;
+        self
+    }
+
+    /// Specify the columns to partition the output by
+    pub fn partition_by(mut self, partition_by: Vec<String>) -> Self {
+        self.partition_by = partition_by;
+        self
+    }
+}
+
+/// Options that control the writing of newline delimited JSON files with
+/// [`DataFrame::write_json_with_options`](crate::dataframe::DataFrame::write_json_with_options).
+#[derive(Clone, Debug)]
+pub struct NdJsonWriteOptions {
+    /// Compression of the written files
+    pub file_compression_type: FileCompressionType,
+    /// Columns used to write Hive style `col=value` partition directories.
+    /// These columns are not written to the files.
+    pub partition_by: Vec<String>,
+}
+
+impl Default for NdJsonWriteOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NdJsonWriteOptions {
+    /// Create a JSON write option with default presets
+    pub fn new() -> Self {
+        Self {
+            file_compression_type: FileCompressionType::UNCOMPRESSED,
+            partition_by: vec![],
+        }
+    }
+
+    /// Configure file compression type
+    pub fn file_compression_type(
+        mut self,
+        file_compression_type: FileCompressionType,
+    ) -> Self {
+        self.file_compression_type = file_compression_type;
+        self
+    }
+
+    /// Specify the columns to partition the output by
+    pub fn partition_by(mut self, partition_by: Vec<String>) -> Self {
+        self.partition_by = partition_by;
+        self
+    }
+}
+
 #[async_trait]
 /// ['ReadOptions'] is implemented by Options like ['CsvReadOptions'] that control the reading of respective files/sources.
 pub trait ReadOptions<'a> {
@@ -388,6 +535,11 @@ impl ReadOptions<'_> for CsvReadOptions<'_> {
         let file_format = CsvFormat::default()
             .with_has_header(self.has_header)
             .with_delimiter(self.delimiter)
+            .with_quote(self.quote)
+            .with_escape(self.escape)
+            .with_comment(self.comment)
+            .with_null_value(self.null_value.clone())
+            .with_column_types(self.column_types.clone())
             .with_schema_infer_max_rec(Some(self.schema_infer_max_records))
             .with_file_compression_type(self.file_compression_type.to_owned());
 
@@ -18,23 +18,26 @@
 //! Parquet format abstractions
 
 use std::any::Any;
+use std::io::Write;
 use std::sync::Arc;
 
 use arrow::datatypes::Schema;
 use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
 use async_trait::async_trait;
-use bytes::{BufMut, BytesMut};
+use bytes::{BufMut, Bytes, BytesMut};
 use datafusion_common::DataFusionError;
 use datafusion_physical_expr::PhysicalExpr;
 use hashbrown::HashMap;
 use object_store::{ObjectMeta, ObjectStore};
-use parquet::arrow::parquet_to_arrow_schema;
+use parking_lot::Mutex;
+use parquet::arrow::{parquet_to_arrow_schema, ArrowWriter};
 use parquet::file::footer::{decode_footer, decode_metadata};
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::statistics::Statistics as ParquetStatistics;
 
-use super::FileFormat;
 use super::FileScanConfig;
+use super::{BatchSerializer, FileFormat};
 use crate::arrow::array::{
     BooleanArray, Float32Array, Float64Array, Int32Array, Int64Array,
 };
@@ -45,7 +48,9 @@ use crate::datasource::{create_max_min_accs, get_col_stats};
 use crate::error::Result;
 use crate::execution::context::SessionState;
 use crate::physical_plan::expressions::{MaxAccumulator, MinAccumulator};
-use crate::physical_plan::file_format::{ParquetExec, SchemaAdapter};
+use crate::physical_plan::file_format::{
+    ParquetExec, ParquetWriterOptions, SchemaAdapter, SortOrderValidator,
+};
 use crate::physical_plan::{Accumulator, ExecutionPlan, Statistics};
 
 /// The default file extension of parquet files
@@ -66,6 +71,8 @@ pub struct ParquetFormat {
     metadata_size_hint: Option<usize>,
     /// Override the global setting for `skip_metadata`
     skip_metadata: Option<bool>,
+    /// Options for files written with this format
+    writer_options: ParquetWriterOptions,
 }
 
 impl ParquetFormat {
@@ -74,6 +81,32 @@ impl ParquetFormat {
         Self::default()
     }
 
+    /// Fetch the metadata of `object`, from the file metadata cache of the
+    /// runtime if enabled
+    async fn fetch_metadata(
+        &self,
+        state: &SessionState,
+        store: &dyn ObjectStore,
+        object: &ObjectMeta,
+    ) -> Result<Arc<ParquetMetaData>> {
+        let cache = state.runtime_env().cache_manager.file_metadata_cache();
+        let cached = cache
+            .as_ref()
+            .and_then(|cache| cache.get(object))
+            .and_then(|metadata| metadata.downcast::<ParquetMetaData>().ok());
+        if let Some(metadata) = cached {
+            return Ok(metadata);
+        }
+
+        let metadata = Arc::new(
+            fetch_parquet_metadata(store, object, self.metadata_size_hint).await?,
+        );
+        if let Some(cache) = cache {
+            cache.put(object, metadata.clone());
+        }
+        Ok(metadata)
+    }
+
     /// Activate statistics based row group level pruning
     /// - If `None`, defaults to value on `config_options`
     pub fn with_enable_pruning(mut self, enable: Option<bool>) -> Self {
@@ -120,6 +153,18 @@ impl ParquetFormat {
         self.skip_metadata
             .unwrap_or(config_options.execution.parquet.skip_metadata)
     }
+
+    /// Set the options used when writing files with this format,
+    /// for example by `INSERT INTO` or `COPY ... TO`
+    pub fn with_writer_options(mut self, writer_options: ParquetWriterOptions) -> Self {
+        self.writer_options = writer_options;
+        self
+    }
+
+    /// Return the options used when writing files with this format
+    pub fn writer_options(&self) -> &ParquetWriterOptions {
+        &self.writer_options
+    }
 }
 
 /// Clears all metadata (Schema level and field level) on an iterator
@@ -153,9 +198,8 @@ impl FileFormat for ParquetFormat {
     ) -> Result<SchemaRef> {
         let mut schemas = Vec::with_capacity(objects.len());
         for object in objects {
-            let schema =
-                fetch_schema(store.as_ref(), object, self.metadata_size_hint).await?;
-            schemas.push(schema)
+            let metadata = self.fetch_metadata(state, store.as_ref(), object).await?;
+            schemas.push(parquet_metadata_to_schema(&metadata)?)
         }
 
         let schema = if self.skip_metadata(state.config_options()) {
@@ -169,19 +213,13 @@ impl FileFormat for ParquetFormat {
 
     async fn infer_stats(
         &self,
-        _state: &SessionState,
+        state: &SessionState,
         store: &Arc<dyn ObjectStore>,
         table_schema: SchemaRef,
         object: &ObjectMeta,
     ) -> Result<Statistics> {
-        let stats = fetch_statistics(
-            store.as_ref(),
-            table_schema,
-            object,
-            self.metadata_size_hint,
-        )
-        .await?;
-        Ok(stats)
+        let metadata = self.fetch_metadata(state, store.as_ref(), object).await?;
+        statistics_from_parquet_meta(&metadata, table_schema)
     }
 
     async fn create_physical_plan(
@@ -204,6 +242,71 @@ impl FileFormat for ParquetFormat {
             self.metadata_size_hint(state.config_options()),
         )))
     }
+
+    fn create_serializer(&self, schema: SchemaRef) -> Result<Box<dyn BatchSerializer>> {
+        let buffer = SharedBuffer::default();
+        let props = self.writer_options.writer_properties(&schema)?;
+        let validator = SortOrderValidator::try_new(&schema, &props)?;
+        let writer = ArrowWriter::try_new(buffer.clone(), schema, Some(props))?;
+        Ok(Box::new(ParquetSerializer {
+            buffer,
+            writer: Some(writer),
+            validator,
+        }))
+    }
+}
+
+/// Encodes record batches as a parquet file. Row groups are buffered by
+/// the [`ArrowWriter`], so bytes are only returned once a row group is
+/// complete, or the file is finished.
+struct ParquetSerializer {
+    buffer: SharedBuffer,
+    writer: Option<ArrowWriter<SharedBuffer>>,
+    validator: Option<SortOrderValidator>,
+}
+
+impl BatchSerializer for ParquetSerializer {
+    fn serialize(&mut self, batch: &RecordBatch) -> Result<Bytes> {
+        let writer = self.writer.as_mut().ok_or_else(|| {
+            DataFusionError::Internal("Parquet file already finished".to_string())
+        })?;
+        if let Some(validator) = self.validator.as_mut() {
+            validator.validate(batch)?;
+        }
+        writer.write(batch)?;
+        Ok(self.buffer.take())
+    }
+
+    fn finish(&mut self) -> Result<Bytes> {
+        if let Some(writer) = self.writer.take() {
+            writer.close()?;
+        }
+        Ok(self.buffer.take())
+    }
+}
+
+/// A [`Write`] implementation appending to a buffer shared with the
+/// [`ParquetSerializer`], so the written bytes can be taken out of it
+#[derive(Debug, Clone, Default)]
+struct SharedBuffer {
+    inner: Arc<Mutex<Vec<u8>>>,
+}
+
+impl SharedBuffer {
+    fn take(&self) -> Bytes {
+        Bytes::from(std::mem::take(&mut *self.inner.lock()))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 fn summarize_min_max(
@@ -437,13 +540,8 @@ pub async fn fetch_parquet_metadata(
     }
 }
 
-/// Read and parse the schema of the Parquet file at location `path`
-async fn fetch_schema(
-    store: &dyn ObjectStore,
-    file: &ObjectMeta,
-    metadata_size_hint: Option<usize>,
-) -> Result<Schema> {
-    let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
+/// Convert the schema of parquet metadata to an arrow schema
+fn parquet_metadata_to_schema(metadata: &ParquetMetaData) -> Result<Schema> {
     let file_metadata = metadata.file_metadata();
     let schema = parquet_to_arrow_schema(
         file_metadata.schema_descr(),
@@ -452,14 +550,11 @@ async fn fetch_schema(
     Ok(schema)
 }
 
-/// Read and parse the statistics of the Parquet file at location `path`
-async fn fetch_statistics(
-    store: &dyn ObjectStore,
+/// Compute the statistics of a parquet file for `table_schema` from its metadata
+fn statistics_from_parquet_meta(
+    metadata: &ParquetMetaData,
     table_schema: SchemaRef,
-    file: &ObjectMeta,
-    metadata_size_hint: Option<usize>,
 ) -> Result<Statistics> {
-    let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
     let file_metadata = metadata.file_metadata();
 
     let file_schema = parquet_to_arrow_schema(
@@ -620,6 +715,9 @@ mod tests {
     use super::*;
 
     use crate::datasource::file_format::parquet::test_util::store_parquet;
+    use crate::execution::cache::cache_unit::DefaultFileMetadataCache;
+    use crate::execution::cache::{CacheManagerConfig, FileMetadataCache};
+    use crate::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use crate::physical_plan::file_format::get_scan_files;
     use crate::physical_plan::metrics::MetricValue;
     use crate::prelude::{SessionConfig, SessionContext};
@@ -645,6 +743,17 @@ mod tests {
     use tokio::fs::File;
     use tokio::io::AsyncWrite;
 
+    /// Read and parse the statistics of the Parquet file at location `path`
+    async fn fetch_statistics(
+        store: &dyn ObjectStore,
+        table_schema: SchemaRef,
+        file: &ObjectMeta,
+        metadata_size_hint: Option<usize>,
+    ) -> Result<Statistics> {
+        let metadata = fetch_parquet_metadata(store, file, metadata_size_hint).await?;
+        statistics_from_parquet_meta(&metadata, table_schema)
+    }
+
     #[tokio::test]
     async fn read_merged_batches() -> Result<()> {
         let c1: ArrayRef =
@@ -874,6 +983,41 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn fetch_metadata_from_cache() -> Result<()> {
+        let c1: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("Foo"), None, Some("bar")]));
+        let batch = RecordBatch::try_from_iter(vec![("c1", c1)]).unwrap();
+        let (meta, _files) = store_parquet(vec![batch], false).await?;
+
+        let store = Arc::new(RequestCountingObjectStore::new(Arc::new(
+            LocalFileSystem::new(),
+        )));
+        let cache: FileMetadataCache = Arc::new(DefaultFileMetadataCache::new(10));
+        let cache_config =
+            CacheManagerConfig::default().with_file_metadata_cache(Some(cache));
+        let runtime =
+            RuntimeEnv::new(RuntimeConfig::new().with_cache_manager(cache_config))?;
+        let session =
+            SessionContext::with_config_rt(SessionConfig::new(), Arc::new(runtime));
+        let ctx = session.state();
+
+        // the file size as hint fetches the metadata in a single request
+        let format = ParquetFormat::default().with_metadata_size_hint(Some(meta[0].size));
+        let schema = format.infer_schema(&ctx, &store.upcast(), &meta).await?;
+        assert_eq!(store.request_count(), 1);
+
+        // the metadata is read from the cache
+        format.infer_schema(&ctx, &store.upcast(), &meta).await?;
+        let stats = format
+            .infer_stats(&ctx, &store.upcast(), schema, &meta[0])
+            .await?;
+        assert_eq!(stats.num_rows, Some(3));
+        assert_eq!(store.request_count(), 1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn read_small_batches() -> Result<()> {
         let config = SessionConfig::new().with_batch_size(2);
@@ -0,0 +1,505 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for writing streams of record batches as files of a
+//! [`FileFormat`] to an [`ObjectStore`]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{Array, UInt32Array};
+use arrow::compute::take;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow::util::display::array_value_to_string;
+use async_trait::async_trait;
+use futures::StreamExt;
+use object_store::path::Path;
+use object_store::{MultipartId, ObjectStore};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use uuid::Uuid;
+
+use super::{BatchSerializer, FileFormat};
+use crate::error::{DataFusionError, Result};
+use crate::execution::cache::ListFilesCache;
+use crate::execution::context::TaskContext;
+use crate::physical_plan::common::AbortOnDropSingle;
+use crate::physical_plan::insert::DataSink;
+use crate::physical_plan::SendableRecordBatchStream;
+
+/// Directory name used for null partition values, as in Hive
+pub(crate) const DEFAULT_PARTITION_VALUE: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Describes where and how files are written by [`write_files`]
+#[derive(Clone)]
+pub(crate) struct FileWriteConfig {
+    /// The object store to write to
+    pub store: Arc<dyn ObjectStore>,
+    /// The directory the files are written to
+    pub base_path: Path,
+    /// The format of the written files
+    pub format: Arc<dyn FileFormat>,
+    /// The extension of the written files, including the leading `.`
+    pub file_extension: String,
+    /// Columns of the input used to create Hive style `col=value`
+    /// directories. These columns are not written to the files.
+    pub partition_cols: Vec<String>,
+    /// The cache of listings of the runtime, whose listings of the
+    /// directories of written files are removed once they are written
+    pub list_files_cache: Option<ListFilesCache>,
+}
+
+impl std::fmt::Debug for FileWriteConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileWriteConfig")
+            .field("store", &self.store)
+            .field("base_path", &self.base_path)
+            .field("format", &self.format)
+            .field("file_extension", &self.file_extension)
+            .field("partition_cols", &self.partition_cols)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileWriteConfig {
+    /// Removes the cached listings of all the directories containing
+    /// `path`, so that later scans see the file written to `path`
+    fn invalidate_listings(&self, path: &Path) {
+        if let Some(cache) = &self.list_files_cache {
+            let parts: Vec<_> = path.parts().collect();
+            for len in 0..parts.len() {
+                cache.remove(&Path::from_iter(parts[..len].iter().cloned()));
+            }
+        }
+    }
+}
+
+/// Writes each of `streams` as new files, returning the number of rows
+/// written.
+///
+/// Each stream writes its own file(s), with names unique to this
+/// write, so existing files in `base_path` are never overwritten. If
+/// any stream fails, the files written by the other streams are
+/// deleted, so that a failed write leaves no files behind.
+pub(crate) async fn write_files(
+    streams: Vec<SendableRecordBatchStream>,
+    config: FileWriteConfig,
+) -> Result<u64> {
+    let write_id = Uuid::new_v4().simple().to_string();
+
+    let mut tasks = vec![];
+    for (partition, stream) in streams.into_iter().enumerate() {
+        let config = config.clone();
+        let file_name = format!("{write_id}_{partition}{}", config.file_extension);
+        let handle = tokio::task::spawn(async move {
+            write_partition(stream, &config, file_name, false).await
+        });
+        tasks.push(AbortOnDropSingle::new(handle));
+    }
+
+    let mut row_count = 0;
+    let mut paths = vec![];
+    let mut error = None;
+    for result in futures::future::join_all(tasks).await {
+        match result.map_err(|e| DataFusionError::Execution(format!("{e}"))) {
+            Ok(Ok((rows, written))) => {
+                row_count += rows;
+                paths.extend(written);
+            }
+            Ok(Err(e)) | Err(e) => {
+                error.get_or_insert(e);
+            }
+        }
+    }
+
+    match error {
+        Some(e) => {
+            delete_files(&config.store, &paths).await;
+            Err(e)
+        }
+        None => {
+            for path in &paths {
+                config.invalidate_listings(path);
+            }
+            Ok(row_count)
+        }
+    }
+}
+
+/// A [`DataSink`] writing new files, used to insert into a `ListingTable`
+#[derive(Debug)]
+pub(crate) struct FileSink {
+    config: FileWriteConfig,
+}
+
+impl FileSink {
+    pub(crate) fn new(config: FileWriteConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl std::fmt::Display for FileSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FileSink(path={})", self.config.base_path)
+    }
+}
+
+#[async_trait]
+impl DataSink for FileSink {
+    async fn write_all(
+        &self,
+        data: Vec<SendableRecordBatchStream>,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        write_files(data, self.config.clone()).await
+    }
+}
+
+/// Writes all batches of `stream` to files named `file_name`, one per
+/// distinct value of the partition columns, returning the number of
+/// rows written.
+///
+/// Without partition columns, the file is written even if `stream` has
+/// no rows.
+pub(crate) async fn write_stream(
+    stream: SendableRecordBatchStream,
+    config: FileWriteConfig,
+    file_name: String,
+) -> Result<u64> {
+    let (row_count, paths) = write_partition(stream, &config, file_name, true).await?;
+    for path in &paths {
+        config.invalidate_listings(path);
+    }
+    Ok(row_count)
+}
+
+/// Writes all batches of `stream` as [`write_stream`] does, returning the
+/// number of rows written and the paths of the written files. Without
+/// partition columns, the file is only written for a stream without rows
+/// if `write_empty` is true.
+///
+/// If writing any file fails, the uploads of the other files are aborted
+/// and the files already written are deleted.
+async fn write_partition(
+    mut stream: SendableRecordBatchStream,
+    config: &FileWriteConfig,
+    file_name: String,
+    write_empty: bool,
+) -> Result<(u64, Vec<Path>)> {
+    let schema = stream.schema();
+    let partition_indices = config
+        .partition_cols
+        .iter()
+        .map(|name| schema.index_of(name).map_err(DataFusionError::ArrowError))
+        .collect::<Result<Vec<_>>>()?;
+    let file_indices: Vec<_> = (0..schema.fields().len())
+        .filter(|i| !partition_indices.contains(i))
+        .collect();
+    let file_schema = Arc::new(schema.project(&file_indices)?);
+
+    let mut writers: HashMap<Vec<String>, FileWriter> = HashMap::new();
+    let result = async {
+        if write_empty && partition_indices.is_empty() {
+            let path = config.base_path.child(file_name.as_str());
+            let writer = FileWriter::try_new(config, file_schema.clone(), path).await?;
+            writers.insert(vec![], writer);
+        }
+
+        let mut row_count = 0;
+        while let Some(batch) = stream.next().await {
+            let batch = batch?;
+            row_count += batch.num_rows() as u64;
+
+            for (partition_values, batch) in
+                split_batch(&batch, &partition_indices, &file_indices, &file_schema)?
+            {
+                if !writers.contains_key(&partition_values) {
+                    let path = partition_path(
+                        &config.base_path,
+                        &config.partition_cols,
+                        &partition_values,
+                        &file_name,
+                    );
+                    let writer =
+                        FileWriter::try_new(config, file_schema.clone(), path).await?;
+                    writers.insert(partition_values.clone(), writer);
+                }
+                let writer = writers.get_mut(&partition_values).unwrap();
+                writer.write(&batch).await?;
+            }
+        }
+        Ok(row_count)
+    }
+    .await;
+
+    let mut result = result.map(|row_count| (row_count, vec![]));
+    for (_, mut writer) in writers.drain() {
+        match &mut result {
+            Ok((_, paths)) => match writer.finish().await {
+                Ok(()) => paths.push(writer.path),
+                Err(e) => {
+                    writer.abort(&config.store).await;
+                    delete_files(&config.store, paths).await;
+                    result = Err(e);
+                }
+            },
+            Err(_) => writer.abort(&config.store).await,
+        }
+    }
+    result
+}
+
+/// Splits `batch` into batches of `file_schema`, one per distinct
+/// combination of values of the columns at `partition_indices`
+fn split_batch(
+    batch: &RecordBatch,
+    partition_indices: &[usize],
+    file_indices: &[usize],
+    file_schema: &SchemaRef,
+) -> Result<Vec<(Vec<String>, RecordBatch)>> {
+    if batch.num_rows() == 0 {
+        return Ok(vec![]);
+    }
+    if partition_indices.is_empty() {
+        let columns = file_indices
+            .iter()
+            .map(|i| batch.column(*i).clone())
+            .collect();
+        return Ok(vec![(
+            vec![],
+            RecordBatch::try_new(file_schema.clone(), columns)?,
+        )]);
+    }
+
+    let mut groups: HashMap<Vec<String>, Vec<u32>> = HashMap::new();
+    for row in 0..batch.num_rows() {
+        let values = partition_indices
+            .iter()
+            .map(|i| {
+                let column = batch.column(*i);
+                if column.is_null(row) {
+                    Ok(DEFAULT_PARTITION_VALUE.to_string())
+                } else {
+                    array_value_to_string(column, row)
+                        .map_err(DataFusionError::ArrowError)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        groups.entry(values).or_default().push(row as u32);
+    }
+
+    groups
+        .into_iter()
+        .map(|(values, rows)| {
+            let indices = UInt32Array::from(rows);
+            let columns = file_indices
+                .iter()
+                .map(|i| take(batch.column(*i).as_ref(), &indices, None))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok((values, RecordBatch::try_new(file_schema.clone(), columns)?))
+        })
+        .collect()
+}
+
+/// Returns the path of `file_name` in the Hive style partition
+/// directory of `partition_values`
+fn partition_path(
+    base_path: &Path,
+    partition_cols: &[String],
+    partition_values: &[String],
+    file_name: &str,
+) -> Path {
+    partition_cols
+        .iter()
+        .zip(partition_values)
+        .fold(base_path.clone(), |path, (col, value)| {
+            path.child(format!("{col}={value}"))
+        })
+        .child(file_name)
+}
+
+/// A file being written to an object store with a multipart upload,
+/// compressed with the compression type of the format
+struct FileWriter {
+    path: Path,
+    multipart_id: MultipartId,
+    serializer: Box<dyn BatchSerializer>,
+    writer: Box<dyn AsyncWrite + Unpin + Send>,
+}
+
+impl FileWriter {
+    async fn try_new(
+        config: &FileWriteConfig,
+        schema: SchemaRef,
+        path: Path,
+    ) -> Result<Self> {
+        let serializer = config.format.create_serializer(schema)?;
+        let (multipart_id, writer) = config.store.put_multipart(&path).await?;
+        let writer = config
+            .format
+            .file_compression_type()
+            .convert_async_writer(writer)?;
+        Ok(Self {
+            path,
+            multipart_id,
+            serializer,
+            writer,
+        })
+    }
+
+    async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let bytes = self.serializer.serialize(batch)?;
+        self.writer.write_all(&bytes).await?;
+        Ok(())
+    }
+
+    async fn finish(&mut self) -> Result<()> {
+        let bytes = self.serializer.finish()?;
+        self.writer.write_all(&bytes).await?;
+        self.writer.shutdown().await?;
+        Ok(())
+    }
+
+    /// Abort the upload, ignoring errors as the write already failed
+    async fn abort(self, store: &Arc<dyn ObjectStore>) {
+        if let Err(e) = store.abort_multipart(&self.path, &self.multipart_id).await {
+            log::debug!("Failed to abort upload of {}: {e}", self.path);
+        }
+    }
+}
+
+/// Deletes the written files at `paths`, ignoring errors as the write
+/// already failed
+async fn delete_files(store: &Arc<dyn ObjectStore>, paths: &[Path]) {
+    for path in paths {
+        if let Err(e) = store.delete(path).await {
+            log::debug!("Failed to delete {path}: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datasource::file_format::csv::CsvFormat;
+    use crate::physical_plan::stream::RecordBatchStreamAdapter;
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+    use object_store::memory::InMemory;
+
+    #[test]
+    fn split_batch_by_partition_values() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("p", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+                Arc::new(StringArray::from(vec![
+                    Some("x"),
+                    None,
+                    Some("x"),
+                    Some("y"),
+                ])),
+            ],
+        )?;
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+
+        let mut split = split_batch(&batch, &[1], &[0], &file_schema)?;
+        split.sort_by(|a, b| a.0.cmp(&b.0));
+        let split: Vec<_> = split
+            .into_iter()
+            .map(|(values, batch)| {
+                let a = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec();
+                (values, a)
+            })
+            .collect();
+        assert_eq!(
+            split,
+            vec![
+                (vec![DEFAULT_PARTITION_VALUE.to_string()], vec![2]),
+                (vec!["x".to_string()], vec![1, 3]),
+                (vec!["y".to_string()], vec![4]),
+            ]
+        );
+
+        let path = partition_path(
+            &Path::from("table"),
+            &["p".to_string()],
+            &["x".to_string()],
+            "file.csv",
+        );
+        assert_eq!(path.as_ref(), "table/p=x/file.csv");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_write_leaves_no_files() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("p", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec!["x", "y", "x"])),
+            ],
+        )?;
+        let stream = |results: Vec<Result<RecordBatch>>| -> SendableRecordBatchStream {
+            Box::pin(RecordBatchStreamAdapter::new(
+                schema.clone(),
+                futures::stream::iter(results),
+            ))
+        };
+        // the first stream succeeds, while the second one fails after
+        // starting to write a file
+        let streams = vec![
+            stream(vec![Ok(batch.clone())]),
+            stream(vec![
+                Ok(batch),
+                Err(DataFusionError::Execution("failed stream".to_string())),
+            ]),
+        ];
+
+        let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+        let config = FileWriteConfig {
+            store: store.clone(),
+            base_path: Path::from("table"),
+            format: Arc::new(CsvFormat::default()),
+            file_extension: ".csv".to_string(),
+            partition_cols: vec!["p".to_string()],
+            list_files_cache: None,
+        };
+        let err = write_files(streams, config).await.unwrap_err();
+        assert_eq!(err.to_string(), "Execution error: failed stream");
+
+        let files: Vec<_> = store.list(None).await?.try_collect().await?;
+        assert!(files.is_empty(), "files left by a failed write: {files:?}");
+        Ok(())
+    }
+}
@@ -21,7 +21,9 @@ use std::sync::Arc;
 
 use arrow::array::new_empty_array;
 use arrow::{
-    array::{ArrayBuilder, ArrayRef, Date64Builder, StringBuilder, UInt64Builder},
+    array::{
+        ArrayBuilder, ArrayRef, Date64Builder, StringBuilder, UInt64Array, UInt64Builder,
+    },
     datatypes::{DataType, Field, Schema},
     record_batch::RecordBatch,
 };
@@ -30,11 +32,16 @@ use futures::{stream::BoxStream, TryStreamExt};
 use log::debug;
 
 use crate::{
-    datasource::MemTable, error::Result, execution::context::SessionContext,
+    datasource::MemTable,
+    error::Result,
+    execution::context::{SessionContext, SessionState},
+    physical_optimizer::pruning::PruningStatistics,
+    physical_plan::{ColumnStatistics, Statistics},
     scalar::ScalarValue,
 };
 
 use super::PartitionedFile;
+use crate::datasource::file_format::write::DEFAULT_PARTITION_VALUE;
 use crate::datasource::listing::ListingTableUrl;
 use datafusion_common::tree_node::{TreeNode, VisitRecursion};
 use datafusion_common::{
@@ -136,6 +143,50 @@ pub fn expr_applicable_for_cols(col_names: &[String], expr: &Expr) -> bool {
     is_applicable
 }
 
+/// Exposes the min/max statistics collected for a single file, so that a
+/// [`PruningPredicate`](crate::physical_optimizer::pruning::PruningPredicate)
+/// can decide whether the file may contain rows matching a filter
+pub struct FileStatisticsPruning<'a> {
+    file_schema: &'a Schema,
+    statistics: &'a Statistics,
+}
+
+impl<'a> FileStatisticsPruning<'a> {
+    /// Create pruning statistics for a file with the given schema
+    pub fn new(file_schema: &'a Schema, statistics: &'a Statistics) -> Self {
+        Self {
+            file_schema,
+            statistics,
+        }
+    }
+
+    fn column_statistics(&self, column: &Column) -> Option<&ColumnStatistics> {
+        let idx = self.file_schema.index_of(&column.name).ok()?;
+        self.statistics.column_statistics.as_ref()?.get(idx)
+    }
+}
+
+impl<'a> PruningStatistics for FileStatisticsPruning<'a> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        let min = self.column_statistics(column)?.min_value.as_ref()?;
+        Some(min.to_array())
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        let max = self.column_statistics(column)?.max_value.as_ref()?;
+        Some(max.to_array())
+    }
+
+    fn num_containers(&self) -> usize {
+        1
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        let null_count = self.column_statistics(column)?.null_count?;
+        Some(Arc::new(UInt64Array::from(vec![null_count as u64])))
+    }
+}
+
 /// Partition the list of files into `n` groups
 pub fn split_files(
     partitioned_files: Vec<PartitionedFile>,
@@ -161,13 +212,14 @@ pub fn split_files(
 /// to first list the folders relative to the first partition dimension,
 /// prune those, then list only the contain of the remaining folders.
 pub async fn pruned_partition_list<'a>(
+    ctx: &'a SessionState,
     store: &'a dyn ObjectStore,
     table_path: &'a ListingTableUrl,
     filters: &'a [Expr],
     file_extension: &'a str,
     table_partition_cols: &'a [(String, DataType)],
 ) -> Result<BoxStream<'a, Result<PartitionedFile>>> {
-    let list = table_path.list_all_files(store, file_extension);
+    let list = table_path.list_all_files(ctx, store, file_extension);
 
     // if no partition col => simply list all the files
     if table_partition_cols.is_empty() {
@@ -206,16 +258,13 @@ pub async fn pruned_partition_list<'a>(
                     p.iter()
                         .zip(table_partition_cols)
                         .map(|(&part_value, part_column)| {
-                            ScalarValue::try_from_string(
-                                part_value.to_string(),
-                                &part_column.1,
-                            )
-                            .unwrap_or_else(|_| {
-                                panic!(
-                                    "Failed to cast str {} to type {}",
-                                    part_value, part_column.1
-                                )
-                            })
+                            parse_partition_value(part_value, &part_column.1)
+                                .unwrap_or_else(|_| {
+                                    panic!(
+                                        "Failed to cast str {} to type {}",
+                                        part_value, part_column.1
+                                    )
+                                })
                         })
                         .collect()
                 });
@@ -282,10 +331,8 @@ fn paths_to_batch(
             length_builder.append_value(file_meta.size as u64);
             modified_builder.append_value(file_meta.last_modified.timestamp_millis());
             for (i, part_val) in partition_values.iter().enumerate() {
-                let scalar_val = ScalarValue::try_from_string(
-                    part_val.to_string(),
-                    &table_partition_cols[i].1,
-                )?;
+                let scalar_val =
+                    parse_partition_value(part_val, &table_partition_cols[i].1)?;
                 partition_scalar_values[i].push(scalar_val);
             }
         } else {
@@ -315,7 +362,7 @@ fn paths_to_batch(
         Field::new(FILE_MODIFIED_COLUMN_NAME, DataType::Date64, true),
     ];
     for part_col in table_partition_cols {
-        fields.push(Field::new(&part_col.0, part_col.1.to_owned(), false));
+        fields.push(Field::new(&part_col.0, part_col.1.to_owned(), true));
     }
 
     let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), col_arrays)?;
@@ -364,6 +411,16 @@ fn to_timestamp_millis(v: i64) -> Result<chrono::DateTime<Utc>> {
     }
 }
 
+/// Parses the partition value of a directory name, the directories of null
+/// values being named [`DEFAULT_PARTITION_VALUE`]
+fn parse_partition_value(value: &str, data_type: &DataType) -> Result<ScalarValue> {
+    if value == DEFAULT_PARTITION_VALUE {
+        ScalarValue::try_from(data_type)
+    } else {
+        ScalarValue::try_from_string(value.to_string(), data_type)
+    }
+}
+
 /// Extract the partition values for the given `file_path` (in the given `table_path`)
 /// associated to the partitions defined by `table_partition_cols`
 fn parse_partitions_for_path<'a>(
@@ -439,7 +496,9 @@ mod tests {
             ("tablepath/file.parquet", 100),
         ]);
         let filter = Expr::eq(col("mypartition"), lit("val1"));
+        let state = SessionContext::new().state();
         let pruned = pruned_partition_list(
+            &state,
             store.as_ref(),
             &ListingTableUrl::parse("file:///tablepath/").unwrap(),
             &[filter],
@@ -462,7 +521,9 @@ mod tests {
             ("tablepath/mypartition=val1/other=val3/file.parquet", 100),
         ]);
         let filter = Expr::eq(col("mypartition"), lit("val1"));
+        let state = SessionContext::new().state();
         let pruned = pruned_partition_list(
+            &state,
             store.as_ref(),
             &ListingTableUrl::parse("file:///tablepath/").unwrap(),
             &[filter],
@@ -509,7 +570,9 @@ mod tests {
         let filter2 = Expr::eq(col("part2"), lit("p2v1"));
         // filter3 cannot be resolved at partition pruning
         let filter3 = Expr::eq(col("part2"), col("other"));
+        let state = SessionContext::new().state();
         let pruned = pruned_partition_list(
+            &state,
             store.as_ref(),
             &ListingTableUrl::parse("file:///tablepath/").unwrap(),
             &[filter1, filter2, filter3],
@@ -27,36 +27,46 @@ use dashmap::DashMap;
 use datafusion_common::ToDFSchema;
 use datafusion_expr::expr::Sort;
 use datafusion_optimizer::utils::conjunction;
-use datafusion_physical_expr::{create_physical_expr, PhysicalSortExpr};
+use datafusion_physical_expr::{create_physical_expr, PhysicalExpr, PhysicalSortExpr};
 use futures::{future, stream, StreamExt, TryStreamExt};
+use log::debug;
 use object_store::path::Path;
 use object_store::ObjectMeta;
 
 use crate::datasource::file_format::file_type::{FileCompressionType, FileType};
 use crate::datasource::{
     file_format::{
-        avro::AvroFormat, csv::CsvFormat, json::JsonFormat, parquet::ParquetFormat,
+        arrow::ArrowFormat,
+        avro::AvroFormat,
+        csv::CsvFormat,
+        json::JsonFormat,
+        parquet::ParquetFormat,
+        write::{FileSink, FileWriteConfig},
         FileFormat,
     },
     get_statistics_with_limit,
     listing::ListingTableUrl,
     TableProvider, TableType,
 };
+use crate::execution::cache::FileStatisticsKey;
 use crate::logical_expr::TableProviderFilterPushDown;
+use crate::physical_optimizer::pruning::PruningPredicate;
 use crate::physical_plan;
 use crate::{
     error::{DataFusionError, Result},
     execution::context::SessionState,
     logical_expr::Expr,
     physical_plan::{
-        empty::EmptyExec, file_format::FileScanConfig, project_schema, ExecutionPlan,
-        Statistics,
+        empty::EmptyExec, file_format::FileScanConfig, insert::InsertExec,
+        project_schema, projection::ProjectionExec, ExecutionPlan, Statistics,
     },
 };
 
 use super::PartitionedFile;
 
-use super::helpers::{expr_applicable_for_cols, pruned_partition_list, split_files};
+use super::helpers::{
+    expr_applicable_for_cols, pruned_partition_list, split_files, FileStatisticsPruning,
+};
 
 /// Configuration for creating a [`ListingTable`]
 #[derive(Debug, Clone)]
@@ -135,6 +145,7 @@ impl ListingTableConfig {
             .map_err(|_| DataFusionError::Internal(err_msg))?;
 
         let file_format: Arc<dyn FileFormat> = match file_type {
+            FileType::ARROW => Arc::new(ArrowFormat::default()),
             FileType::AVRO => Arc::new(AvroFormat::default()),
             FileType::CSV => Arc::new(
                 CsvFormat::default().with_file_compression_type(file_compression_type),
@@ -158,7 +169,7 @@ impl ListingTableConfig {
             .table_paths
             .get(0)
             .unwrap()
-            .list_all_files(store.as_ref(), "")
+            .list_all_files(state, store.as_ref(), "")
             .next()
             .await
             .ok_or_else(|| DataFusionError::Internal("No files for table".into()))??;
@@ -217,6 +228,8 @@ pub struct ListingOptions {
     /// Set true to try to guess statistics from the files.
     /// This can add a lot of overhead as it will usually require files
     /// to be opened and at least partially parsed.
+    /// The min/max values of these statistics are used to skip the files
+    /// that can't match the filters of a scan.
     pub collect_stat: bool,
     /// Group files to avoid that the number of partitions exceeds
     /// this limit
@@ -332,6 +345,11 @@ impl ListingOptions {
     ///
     /// - The partition columns are solely extracted from the file path. Especially they are NOT part of the parquet files itself.
     ///
+    /// - The partition columns are nullable: files in directories named
+    /// `col=__HIVE_DEFAULT_PARTITION__`, such as those written for null
+    /// values by inserts into the table or by Hive, have null values for
+    /// `col`. Directories of other values are never read as nulls.
+    ///
     /// # Example
     ///
     /// ```
@@ -436,7 +454,7 @@ impl ListingOptions {
         let store = state.runtime_env().object_store(table_path)?;
 
         let files: Vec<_> = table_path
-            .list_all_files(store.as_ref(), &self.file_extension)
+            .list_all_files(state, store.as_ref(), &self.file_extension)
             .try_collect()
             .await?;
 
@@ -577,7 +595,7 @@ impl ListingTable {
         // Add the partition columns to the file schema
         let mut table_fields = file_schema.fields().clone();
         for (part_col_name, part_col_type) in &options.table_partition_cols {
-            table_fields.push(Field::new(part_col_name, part_col_type.clone(), false));
+            table_fields.push(Field::new(part_col_name, part_col_type.clone(), true));
         }
         let infinite_source = options.infinite_source;
 
@@ -758,9 +776,116 @@ impl TableProvider for ListingTable {
     fn get_table_definition(&self) -> Option<&str> {
         self.definition.as_deref()
     }
+
+    /// Writes the output of `input` as new files in the directory of
+    /// this table, using its [`FileFormat`]. Rows are written to Hive
+    /// style `col=value` directories of the table partition columns.
+    async fn insert_into(
+        &self,
+        state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let table_path = match self.table_paths.as_slice() {
+            [table_path] if table_path.is_collection() => table_path,
+            _ => {
+                return Err(DataFusionError::NotImplemented(
+                    "Inserting into a ListingTable is only supported for tables \
+                     with a single directory path"
+                        .to_string(),
+                ))
+            }
+        };
+
+        // Check that the plan produces the columns of this table, in order
+        let plan_schema = input.schema();
+        let matches = plan_schema.fields().len() == self.table_schema.fields().len()
+            && plan_schema
+                .fields()
+                .iter()
+                .zip(self.table_schema.fields())
+                .all(|(a, b)| a.data_type() == b.data_type());
+        if !matches {
+            return Err(DataFusionError::Plan(
+                "Inserting query must have the same schema with the table.".to_string(),
+            ));
+        }
+
+        // Name the output columns after the table's, so the partition
+        // columns can be found by name
+        let plan = Arc::new(ProjectionExec::try_new(
+            self.table_schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let expr: Arc<dyn PhysicalExpr> =
+                        Arc::new(physical_plan::expressions::Column::new(
+                            plan_schema.field(i).name(),
+                            i,
+                        ));
+                    (expr, field.name().clone())
+                })
+                .collect(),
+            input,
+        )?);
+
+        let config = FileWriteConfig {
+            store: state.runtime_env().object_store(table_path)?,
+            base_path: table_path.prefix().clone(),
+            format: self.options.format.clone(),
+            file_extension: self.options.file_extension.clone(),
+            partition_cols: self
+                .options
+                .table_partition_cols
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            list_files_cache: state.runtime_env().cache_manager.list_files_cache(),
+        };
+        Ok(Arc::new(InsertExec::new(
+            plan,
+            Arc::new(FileSink::new(config)),
+        )))
+    }
 }
 
 impl ListingTable {
+    /// Creates the predicate used to skip files based on their statistics, if any
+    fn file_pruning_predicate(
+        &self,
+        ctx: &SessionState,
+        filters: &[Expr],
+    ) -> Result<Option<PruningPredicate>> {
+        if !self.options.collect_stat {
+            return Ok(None);
+        }
+        let file_cols = self
+            .file_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        let file_filters = filters
+            .iter()
+            .filter(|filter| expr_applicable_for_cols(&file_cols, filter))
+            .cloned()
+            .collect::<Vec<_>>();
+        let expr = match conjunction(file_filters) {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        let file_df_schema = self.file_schema.as_ref().clone().to_dfschema()?;
+        let expr = create_physical_expr(
+            &expr,
+            &file_df_schema,
+            &self.file_schema,
+            ctx.execution_props(),
+        )?;
+        let predicate = PruningPredicate::try_new(expr, self.file_schema.clone())?;
+        Ok((!predicate.allways_true()).then_some(predicate))
+    }
+
     /// Get the list of files for a scan as well as the file level statistics.
     /// The list is grouped to let the execution plan know how the files should
     /// be distributed to different threads / executors.
@@ -776,6 +901,7 @@ impl ListingTable {
         // list files (with partitions)
         let file_list = future::try_join_all(self.table_paths.iter().map(|table_path| {
             pruned_partition_list(
+                ctx,
                 store.as_ref(),
                 table_path,
                 filters,
@@ -787,25 +913,35 @@ impl ListingTable {
 
         let file_list = stream::iter(file_list).flatten();
 
-        // collect the statistics if required by the config
+        // collect the statistics if required by the config, sharing them
+        // across tables if the runtime caches file statistics
+        let statistics_cache = ctx.runtime_env().cache_manager.file_statistics_cache();
         let files = file_list.then(|part_file| async {
             let part_file = part_file?;
+            let meta = &part_file.object_meta;
             let statistics = if self.options.collect_stat {
-                match self.collected_statistics.get(&part_file.object_meta) {
+                let key = FileStatisticsKey::new(meta.clone(), self.file_schema.clone());
+                let cached = match &statistics_cache {
+                    Some(cache) => cache.get(&key).map(|s| s.as_ref().clone()),
+                    None => self.collected_statistics.get(meta),
+                };
+                match cached {
                     Some(statistics) => statistics,
                     None => {
                         let statistics = self
                             .options
                             .format
-                            .infer_stats(
-                                ctx,
-                                &store,
-                                self.file_schema.clone(),
-                                &part_file.object_meta,
-                            )
+                            .infer_stats(ctx, &store, self.file_schema.clone(), meta)
                             .await?;
-                        self.collected_statistics
-                            .save(part_file.object_meta.clone(), statistics.clone());
+                        match &statistics_cache {
+                            Some(cache) => {
+                                cache.put(&key, Arc::new(statistics.clone()));
+                            }
+                            None => {
+                                self.collected_statistics
+                                    .save(meta.clone(), statistics.clone());
+                            }
+                        }
                         statistics
                     }
                 }
@@ -815,6 +951,28 @@ impl ListingTable {
             Ok((part_file, statistics)) as Result<(PartitionedFile, Statistics)>
         });
 
+        // skip the files whose statistics show they can't match the filters
+        let pruning_predicate = self.file_pruning_predicate(ctx, filters)?;
+        let files = files.try_filter(|(part_file, statistics)| {
+            let keep = match &pruning_predicate {
+                Some(predicate) => {
+                    let stats = FileStatisticsPruning::new(&self.file_schema, statistics);
+                    match predicate.prune(&stats) {
+                        Ok(keep) => keep[0],
+                        Err(e) => {
+                            debug!(
+                                "Error evaluating file pruning predicate on {}: {e}",
+                                part_file.object_meta.location
+                            );
+                            true
+                        }
+                    }
+                }
+                None => true,
+            };
+            future::ready(keep)
+        });
+
         let (files, statistics) =
             get_statistics_with_limit(files, self.schema(), limit).await?;
 
@@ -829,16 +987,27 @@ impl ListingTable {
 mod tests {
     use super::*;
     use crate::datasource::file_format::file_type::GetExt;
+    use crate::execution::cache::cache_unit::{
+        DefaultFileStatisticsCache, DefaultListFilesCache,
+    };
+    use crate::execution::cache::{
+        CacheManagerConfig, FileStatisticsCache, ListFilesCache,
+    };
+    use crate::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use crate::prelude::*;
     use crate::{
         datasource::file_format::{avro::AvroFormat, parquet::ParquetFormat},
         execution::options::ReadOptions,
         logical_expr::{col, lit},
+        physical_plan::file_format::ParquetExec,
         test::{columns, object_store::register_test_store},
     };
+    use arrow::array::Int64Array;
     use arrow::datatypes::DataType;
+    use arrow::record_batch::RecordBatch;
     use chrono::DateTime;
     use datafusion_common::assert_contains;
+    use parquet::arrow::ArrowWriter;
     use rstest::*;
     use std::fs::File;
     use tempfile::TempDir;
@@ -915,6 +1084,178 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn prune_files_with_statistics() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        // three files with the ranges [0, 10), [10, 20) and [20, 30)
+        for i in 0..3 {
+            let batch = RecordBatch::try_new(
+                file_schema.clone(),
+                vec![Arc::new(Int64Array::from_iter_values(10 * i..10 * (i + 1)))],
+            )?;
+            let file = File::create(tmp_dir.path().join(format!("{i}.parquet")))?;
+            let mut writer = ArrowWriter::try_new(file, file_schema.clone(), None)?;
+            writer.write(&batch)?;
+            writer.close()?;
+        }
+
+        let ctx = SessionContext::new();
+        let state = ctx.state();
+        let path = tmp_dir.path().to_str().unwrap();
+        let schema = &file_schema;
+
+        let filter = col("a").gt(lit(15i64));
+        let count = count_scanned_files(&state, path, schema, true, filter).await?;
+        assert_eq!(count, 2);
+        let filter = col("a").eq(lit(25i64));
+        let count = count_scanned_files(&state, path, schema, true, filter).await?;
+        assert_eq!(count, 1);
+        // no file can match: the scan is an `EmptyExec`
+        let filter = col("a").lt(lit(0i64));
+        let count = count_scanned_files(&state, path, schema, true, filter).await?;
+        assert_eq!(count, 0);
+        // no pruning without statistics
+        let filter = col("a").gt(lit(15i64));
+        let count = count_scanned_files(&state, path, schema, false, filter).await?;
+        assert_eq!(count, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_files_from_cache() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let write_file = |name: &str| -> Result<()> {
+            let batch = RecordBatch::try_new(
+                file_schema.clone(),
+                vec![Arc::new(Int64Array::from(vec![1, 2]))],
+            )?;
+            let file = File::create(tmp_dir.path().join(name))?;
+            let mut writer = ArrowWriter::try_new(file, file_schema.clone(), None)?;
+            writer.write(&batch)?;
+            writer.close()?;
+            Ok(())
+        };
+
+        let list_files_cache: ListFilesCache = Arc::new(DefaultListFilesCache::new(10));
+        let cache_config =
+            CacheManagerConfig::default().with_list_files_cache(Some(list_files_cache));
+        let runtime =
+            RuntimeEnv::new(RuntimeConfig::new().with_cache_manager(cache_config))?;
+        let ctx = SessionContext::with_config_rt(SessionConfig::new(), Arc::new(runtime));
+        let state = ctx.state();
+        let path = tmp_dir.path().to_str().unwrap();
+        let filter = col("a").gt(lit(0i64));
+
+        write_file("0.parquet")?;
+        let count =
+            count_scanned_files(&state, path, &file_schema, true, filter.clone()).await?;
+        assert_eq!(count, 1);
+
+        // the file added after the directory was listed is not seen
+        write_file("1.parquet")?;
+        let count =
+            count_scanned_files(&state, path, &file_schema, true, filter.clone()).await?;
+        assert_eq!(count, 1);
+
+        // until the cached listing is removed
+        state
+            .runtime_env()
+            .cache_manager
+            .list_files_cache()
+            .unwrap()
+            .clear();
+        let count = count_scanned_files(&state, path, &file_schema, true, filter).await?;
+        assert_eq!(count, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn statistics_cache_keyed_by_schema() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            file_schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(Int64Array::from(vec![100, 101])),
+            ],
+        )?;
+        let file = File::create(tmp_dir.path().join("0.parquet"))?;
+        let mut writer = ArrowWriter::try_new(file, file_schema.clone(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let statistics_cache: FileStatisticsCache =
+            Arc::new(DefaultFileStatisticsCache::new(10));
+        let cache_config = CacheManagerConfig::default()
+            .with_file_statistics_cache(Some(statistics_cache.clone()));
+        let runtime =
+            RuntimeEnv::new(RuntimeConfig::new().with_cache_manager(cache_config))?;
+        let ctx = SessionContext::with_config_rt(SessionConfig::new(), Arc::new(runtime));
+        let state = ctx.state();
+        let path = tmp_dir.path().to_str().unwrap();
+
+        let count = count_scanned_files(
+            &state,
+            path,
+            &file_schema,
+            true,
+            col("a").gt(lit(50i64)),
+        )
+        .await?;
+        assert_eq!(count, 0);
+        assert_eq!(statistics_cache.len(), 1);
+
+        // a table over the same file with its columns reordered must not
+        // use the statistics cached for the other column order
+        let reordered_schema = Arc::new(Schema::new(vec![
+            Field::new("b", DataType::Int64, false),
+            Field::new("a", DataType::Int64, false),
+        ]));
+        let count = count_scanned_files(
+            &state,
+            path,
+            &reordered_schema,
+            true,
+            col("b").gt(lit(50i64)),
+        )
+        .await?;
+        assert_eq!(count, 1);
+        assert_eq!(statistics_cache.len(), 2);
+
+        Ok(())
+    }
+
+    /// Returns the number of files scanned by a parquet table filtered by `filter`
+    async fn count_scanned_files(
+        state: &SessionState,
+        table_path: &str,
+        file_schema: &SchemaRef,
+        collect_stat: bool,
+        filter: Expr,
+    ) -> Result<usize> {
+        let options = ListingOptions::new(Arc::new(ParquetFormat::default()))
+            .with_collect_stat(collect_stat);
+        let config = ListingTableConfig::new(ListingTableUrl::parse(table_path)?)
+            .with_listing_options(options)
+            .with_schema(file_schema.clone());
+        let table = ListingTable::try_new(config)?;
+        let exec = table.scan(state, None, &[filter], None).await?;
+        Ok(match exec.as_any().downcast_ref::<ParquetExec>() {
+            Some(exec) => exec.base_config().file_groups.concat().len(),
+            None => 0,
+        })
+    }
+
     #[tokio::test]
     async fn load_table_stats_when_no_stats() -> Result<()> {
         let testdata = crate::test_util::parquet_test_data();
@@ -1393,6 +1734,192 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case(Arc::new(CsvFormat::default()), ".csv")]
+    #[case(Arc::new(JsonFormat::default()), ".json")]
+    #[case(Arc::new(ParquetFormat::default()), ".parquet")]
+    #[tokio::test]
+    async fn test_insert_into(
+        #[case] format: Arc<dyn FileFormat>,
+        #[case] file_extension: &str,
+    ) -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let options = ListingOptions::new(format).with_file_extension(file_extension);
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(options)
+            .with_schema(schema);
+        let table = ListingTable::try_new(config)?;
+
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(table))?;
+        ctx.sql("INSERT INTO t VALUES (1, 'x'), (2, 'y')")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("INSERT INTO t SELECT a + 10, b FROM t")
+            .await?
+            .collect()
+            .await?;
+
+        let batches = ctx.sql("SELECT * FROM t").await?.collect().await?;
+        let expected = vec![
+            "+----+---+",
+            "| a  | b |",
+            "+----+---+",
+            "| 1  | x |",
+            "| 11 | x |",
+            "| 12 | y |",
+            "| 2  | y |",
+            "+----+---+",
+        ];
+        crate::assert_batches_sorted_eq!(expected, &batches);
+
+        // each insert wrote new files next to the existing ones
+        let files = std::fs::read_dir(tmp_dir.path())?
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        assert!(files.len() >= 2, "{files:?}");
+        assert!(
+            files.iter().all(|f| f.ends_with(file_extension)),
+            "{files:?}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_insert_into_with_list_files_cache() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(ListingOptions::new(Arc::new(CsvFormat::default())))
+            .with_schema(schema);
+        let table = ListingTable::try_new(config)?;
+
+        let list_files_cache: ListFilesCache = Arc::new(DefaultListFilesCache::new(10));
+        let cache_config =
+            CacheManagerConfig::default().with_list_files_cache(Some(list_files_cache));
+        let runtime =
+            RuntimeEnv::new(RuntimeConfig::new().with_cache_manager(cache_config))?;
+        let ctx = SessionContext::with_config_rt(SessionConfig::new(), Arc::new(runtime));
+        ctx.register_table("t", Arc::new(table))?;
+
+        // the listing of the empty table is cached
+        let batches = ctx.sql("SELECT * FROM t").await?.collect().await?;
+        assert!(batches.iter().all(|batch| batch.num_rows() == 0));
+
+        // and removed by the insert, so that the inserted rows are seen
+        ctx.sql("INSERT INTO t VALUES (1), (2)")
+            .await?
+            .collect()
+            .await?;
+        let batches = ctx.sql("SELECT * FROM t").await?.collect().await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+        crate::assert_batches_sorted_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_insert_into_partitioned() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let options = ListingOptions::new(Arc::new(ParquetFormat::default()))
+            .with_table_partition_cols(vec![
+                ("p1".to_string(), DataType::Utf8),
+                ("p2".to_string(), DataType::Utf8),
+            ]);
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(options)
+            .with_schema(file_schema);
+        let table = ListingTable::try_new(config)?;
+
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(table))?;
+        ctx.sql(
+            "INSERT INTO t VALUES (1, 'x', 'u'), (2, 'y', 'u'), (3, 'x', 'u'), (4, 'x', NULL)",
+        )
+        .await?
+        .collect()
+        .await?;
+
+        let mut dirs = vec![];
+        for p1 in std::fs::read_dir(tmp_dir.path())? {
+            let p1 = p1?;
+            for p2 in std::fs::read_dir(p1.path())? {
+                let p2 = p2?.file_name().into_string().unwrap();
+                dirs.push(format!("{}/{p2}", p1.file_name().into_string().unwrap()));
+            }
+        }
+        dirs.sort();
+        assert_eq!(
+            dirs,
+            vec![
+                "p1=x/p2=__HIVE_DEFAULT_PARTITION__",
+                "p1=x/p2=u",
+                "p1=y/p2=u",
+            ]
+        );
+
+        let batches = ctx
+            .sql("SELECT a, p1, p2 FROM t WHERE p1 = 'x'")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+---+----+----+",
+            "| a | p1 | p2 |",
+            "+---+----+----+",
+            "| 1 | x  | u  |",
+            "| 3 | x  | u  |",
+            "| 4 | x  |    |",
+            "+---+----+----+",
+        ];
+        crate::assert_batches_sorted_eq!(expected, &batches);
+
+        // null partition values are read back as nulls
+        let batches = ctx
+            .sql("SELECT a FROM t WHERE p2 IS NULL")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 4 |", "+---+"];
+        crate::assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_insert_into_schema_mismatch() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let table_path = ListingTableUrl::parse(tmp_dir.path().to_str().unwrap())?;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let config = ListingTableConfig::new(table_path)
+            .with_listing_options(ListingOptions::new(Arc::new(CsvFormat::default())))
+            .with_schema(schema);
+        let table = ListingTable::try_new(config)?;
+
+        let ctx = SessionContext::new();
+        let input = ctx
+            .sql("SELECT 'x' AS a")
+            .await?
+            .create_physical_plan()
+            .await?;
+        let err = table.insert_into(&ctx.state(), input).await.unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Inserting query must have the same schema with the table."
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_statistics_cache() {
         let meta = ObjectMeta {
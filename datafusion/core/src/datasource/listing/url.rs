@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::datasource::object_store::ObjectStoreUrl;
+use crate::execution::context::SessionState;
 use datafusion_common::{DataFusionError, Result};
 use futures::stream::BoxStream;
 use futures::{StreamExt, TryStreamExt};
@@ -24,6 +25,7 @@ use itertools::Itertools;
 use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
 use percent_encoding;
+use std::sync::Arc;
 use url::Url;
 
 /// A parsed URL identifying files for a listing table, see [`ListingTableUrl::parse`]
@@ -124,6 +126,17 @@ impl ListingTableUrl {
         self.url.scheme()
     }
 
+    /// Returns true if this URL identifies a directory, rather than a
+    /// single file
+    pub fn is_collection(&self) -> bool {
+        self.url.as_str().ends_with('/')
+    }
+
+    /// Returns the path prefix of this URL within its object store
+    pub(crate) fn prefix(&self) -> &Path {
+        &self.prefix
+    }
+
     /// Strips the prefix of this [`ListingTableUrl`] from the provided path, returning
     /// an iterator of the remaining path segments
     pub(crate) fn strip_prefix<'a, 'b: 'a>(
@@ -140,18 +153,37 @@ impl ListingTableUrl {
     }
 
     /// List all files identified by this [`ListingTableUrl`] for the provided `file_extension`
+    ///
+    /// Collections are listed from the list files cache of the runtime, if enabled
     pub(crate) fn list_all_files<'a>(
         &'a self,
+        ctx: &'a SessionState,
         store: &'a dyn ObjectStore,
         file_extension: &'a str,
     ) -> BoxStream<'a, Result<ObjectMeta>> {
+        let list_files_cache = ctx.runtime_env().cache_manager.list_files_cache();
         // If the prefix is a file, use a head request, otherwise list
-        let is_dir = self.url.as_str().ends_with('/');
-        let list = match is_dir {
-            true => futures::stream::once(store.list(Some(&self.prefix)))
+        let list = match (self.is_collection(), list_files_cache) {
+            (true, None) => futures::stream::once(store.list(Some(&self.prefix)))
                 .try_flatten()
                 .boxed(),
-            false => futures::stream::once(store.head(&self.prefix)).boxed(),
+            (true, Some(cache)) => futures::stream::once(async move {
+                let objects = match cache.get(&self.prefix) {
+                    Some(objects) => objects,
+                    None => {
+                        let objects: Vec<_> =
+                            store.list(Some(&self.prefix)).await?.try_collect().await?;
+                        let objects = Arc::new(objects);
+                        cache.put(&self.prefix, objects.clone());
+                        objects
+                    }
+                };
+                let objects = objects.to_vec().into_iter().map(Ok);
+                Ok::<_, object_store::Error>(futures::stream::iter(objects))
+            })
+            .try_flatten()
+            .boxed(),
+            (false, _) => futures::stream::once(store.head(&self.prefix)).boxed(),
         };
 
         list.map_err(Into::into)
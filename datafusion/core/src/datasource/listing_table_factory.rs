@@ -18,6 +18,7 @@
 //! Factory for creating ListingTables with default options
 
 use crate::datasource::datasource::TableProviderFactory;
+use crate::datasource::file_format::arrow::ArrowFormat;
 use crate::datasource::file_format::avro::AvroFormat;
 use crate::datasource::file_format::csv::CsvFormat;
 use crate::datasource::file_format::file_type::{FileCompressionType, FileType};
@@ -33,9 +34,43 @@ use arrow::datatypes::{DataType, SchemaRef};
 use async_trait::async_trait;
 use datafusion_common::DataFusionError;
 use datafusion_expr::CreateExternalTable;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
+/// Applies the CSV parsing options of `CREATE EXTERNAL TABLE ... OPTIONS (...)`
+/// to `format`. Option keys are case insensitive.
+fn csv_format_with_options(
+    mut format: CsvFormat,
+    options: &HashMap<String, String>,
+) -> datafusion_common::Result<CsvFormat> {
+    for (key, value) in options {
+        format = match key.to_lowercase().as_str() {
+            "quote" => format.with_quote(single_byte_option("quote", value)?),
+            "escape" => format.with_escape(Some(single_byte_option("escape", value)?)),
+            "comment" => format.with_comment(Some(single_byte_option("comment", value)?)),
+            "null_value" => format.with_null_value(Some(value.clone())),
+            _ => {
+                return Err(DataFusionError::Plan(format!(
+                    "Unsupported CSV option '{key}', expected one of \
+                     'quote', 'escape', 'comment' or 'null_value'"
+                )))
+            }
+        };
+    }
+    Ok(format)
+}
+
+/// Parses the value of a CSV option that must be a single byte character
+fn single_byte_option(key: &str, value: &str) -> datafusion_common::Result<u8> {
+    match value.as_bytes() {
+        [b] => Ok(*b),
+        _ => Err(DataFusionError::Plan(format!(
+            "CSV option '{key}' must be a single character, got '{value}'"
+        ))),
+    }
+}
+
 /// A `TableProviderFactory` capable of creating new `ListingTable`s
 pub struct ListingTableFactory {}
 
@@ -68,14 +103,16 @@ impl TableProviderFactory for ListingTableFactory {
             file_type.get_ext_with_compression(file_compression_type.to_owned())?;
 
         let file_format: Arc<dyn FileFormat> = match file_type {
-            FileType::CSV => Arc::new(
+            FileType::CSV => Arc::new(csv_format_with_options(
                 CsvFormat::default()
                     .with_has_header(cmd.has_header)
                     .with_delimiter(cmd.delimiter as u8)
                     .with_file_compression_type(file_compression_type),
-            ),
+                &cmd.options,
+            )?),
             FileType::PARQUET => Arc::new(ParquetFormat::default()),
             FileType::AVRO => Arc::new(AvroFormat::default()),
+            FileType::ARROW => Arc::new(ArrowFormat::default()),
             FileType::JSON => Arc::new(
                 JsonFormat::default().with_file_compression_type(file_compression_type),
             ),
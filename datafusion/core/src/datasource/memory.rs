@@ -21,25 +21,40 @@
 
 use futures::{StreamExt, TryStreamExt};
 use std::any::Any;
+use std::fmt::{self, Display};
 use std::sync::Arc;
 
+use arrow::array::BooleanArray;
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{and_kleene, filter_record_batch, is_not_null, not};
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use async_trait::async_trait;
-use datafusion_expr::LogicalPlan;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::DFSchema;
+use datafusion_expr::ExprSchemable;
+use datafusion_optimizer::utils::conjunction;
 use tokio::sync::RwLock;
 use tokio::task;
 
 use crate::datasource::{TableProvider, TableType};
 use crate::error::{DataFusionError, Result};
-use crate::execution::context::SessionState;
+use crate::execution::context::{SessionState, TaskContext};
 use crate::logical_expr::Expr;
+use crate::physical_expr::create_physical_expr;
 use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
 use crate::physical_plan::common;
 use crate::physical_plan::common::AbortOnDropSingle;
+use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::insert::{
+    make_count_batch, make_count_schema, DataSink, InsertExec,
+};
 use crate::physical_plan::memory::MemoryExec;
-use crate::physical_plan::ExecutionPlan;
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
 use crate::physical_plan::{repartition::RepartitionExec, Partitioning};
+use crate::physical_plan::{
+    DisplayFormatType, ExecutionPlan, PhysicalExpr, SendableRecordBatchStream, Statistics,
+};
 
 /// In-memory table
 #[derive(Debug)]
@@ -155,30 +170,27 @@ impl TableProvider for MemTable {
         )?))
     }
 
-    /// Inserts the execution results of a given [LogicalPlan] into this [MemTable].
-    /// The `LogicalPlan` must have the same schema as this `MemTable`.
+    /// Returns an [`InsertExec`] that inserts the execution results of
+    /// `input` into this [`MemTable`]. The plan must have the same schema
+    /// as this `MemTable`.
     ///
-    /// # Arguments
-    ///
-    /// * `state` - The [SessionState] containing the context for executing the plan.
-    /// * `input` - The [LogicalPlan] to execute and insert.
-    ///
-    /// # Returns
-    ///
-    /// * A `Result` indicating success or failure.
-    async fn insert_into(&self, state: &SessionState, input: &LogicalPlan) -> Result<()> {
-        // Create a physical plan from the logical plan.
-        let plan = state.create_physical_plan(input).await?;
-
+    /// The input is repartitioned to match the partitions of the table,
+    /// unless the table is empty, and each input partition is appended
+    /// to the corresponding table partition.
+    async fn insert_into(
+        &self,
+        _state: &SessionState,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
         // Check that the schema of the plan matches the schema of this table.
-        if !plan.schema().eq(&self.schema) {
+        if !input.schema().eq(&self.schema) {
             return Err(DataFusionError::Plan(
                 "Inserting query must have the same schema with the table.".to_string(),
             ));
         }
 
         // Get the number of partitions in the plan and the table.
-        let plan_partition_count = plan.output_partitioning().partition_count();
+        let plan_partition_count = input.output_partitioning().partition_count();
         let table_partition_count = self.batches.read().await.len();
 
         // Adjust the plan as necessary to match the number of partitions in the table.
@@ -186,30 +198,130 @@ impl TableProvider for MemTable {
             == table_partition_count
             || table_partition_count == 0
         {
-            plan
+            input
         } else if table_partition_count == 1 {
             // If the table has only one partition, coalesce the partitions in the plan.
-            Arc::new(CoalescePartitionsExec::new(plan))
+            Arc::new(CoalescePartitionsExec::new(input))
         } else {
             // Otherwise, repartition the plan using a round-robin partitioning scheme.
             Arc::new(RepartitionExec::try_new(
-                plan,
+                input,
                 Partitioning::RoundRobinBatch(table_partition_count),
             )?)
         };
 
-        // Get the task context from the session state.
-        let task_ctx = state.task_ctx();
-
-        // Execute the plan and collect the results into batches.
-        let mut tasks = vec![];
-        for idx in 0..plan.output_partitioning().partition_count() {
-            let stream = plan.execute(idx, task_ctx.clone())?;
-            let handle = task::spawn(async move {
-                stream.try_collect().await.map_err(DataFusionError::from)
-            });
-            tasks.push(AbortOnDropSingle::new(handle));
-        }
+        let sink = Arc::new(MemSink::new(self.batches.clone()));
+        Ok(Arc::new(InsertExec::new(plan, sink)))
+    }
+
+    /// Returns a plan deleting the rows of this [`MemTable`] for which
+    /// all `filters` are true.
+    async fn delete_from(
+        &self,
+        state: &SessionState,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let predicate = self.create_predicate(state, filters)?;
+        Ok(Arc::new(MemTableDmlExec::new(
+            self.batches.clone(),
+            MemTableDmlOp::Delete,
+            predicate,
+        )))
+    }
+
+    /// Returns a plan updating the rows of this [`MemTable`] for which
+    /// all `filters` are true. The new values are cast to the types of
+    /// the updated columns if necessary.
+    async fn update(
+        &self,
+        state: &SessionState,
+        assignments: Vec<(String, Expr)>,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        let assignments = assignments
+            .into_iter()
+            .map(|(name, expr)| {
+                let index = self.schema.index_of(&name)?;
+                let expr =
+                    expr.cast_to(self.schema.field(index).data_type(), &df_schema)?;
+                let expr = create_physical_expr(
+                    &expr,
+                    &df_schema,
+                    &self.schema,
+                    state.execution_props(),
+                )?;
+                Ok((index, expr))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let predicate = self.create_predicate(state, filters)?;
+        Ok(Arc::new(MemTableDmlExec::new(
+            self.batches.clone(),
+            MemTableDmlOp::Update(assignments),
+            predicate,
+        )))
+    }
+}
+
+impl MemTable {
+    /// Creates the physical predicate of the conjunction of `filters`,
+    /// or `None` if there are no filters
+    fn create_predicate(
+        &self,
+        state: &SessionState,
+        filters: Vec<Expr>,
+    ) -> Result<Option<Arc<dyn PhysicalExpr>>> {
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        conjunction(filters)
+            .map(|predicate| {
+                create_physical_expr(
+                    &predicate,
+                    &df_schema,
+                    &self.schema,
+                    state.execution_props(),
+                )
+            })
+            .transpose()
+    }
+}
+
+/// A [`DataSink`] appending to the partitions of a [`MemTable`]
+#[derive(Debug)]
+struct MemSink {
+    /// Target locations for writing data
+    batches: Arc<RwLock<Vec<Vec<RecordBatch>>>>,
+}
+
+impl MemSink {
+    fn new(batches: Arc<RwLock<Vec<Vec<RecordBatch>>>>) -> Self {
+        Self { batches }
+    }
+}
+
+impl Display for MemSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MemoryTable")
+    }
+}
+
+#[async_trait]
+impl DataSink for MemSink {
+    async fn write_all(
+        &self,
+        data: Vec<SendableRecordBatchStream>,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        // Collect the results of each partition concurrently.
+        let tasks = data
+            .into_iter()
+            .map(|stream| {
+                let handle = task::spawn(async move {
+                    stream.try_collect().await.map_err(DataFusionError::from)
+                });
+                AbortOnDropSingle::new(handle)
+            })
+            .collect::<Vec<_>>();
         let results = futures::future::join_all(tasks)
             .await
             .into_iter()
@@ -218,31 +330,217 @@ impl TableProvider for MemTable {
             })
             .collect::<Result<Vec<Vec<RecordBatch>>>>()?;
 
+        let row_count = results
+            .iter()
+            .flatten()
+            .map(|batch| batch.num_rows() as u64)
+            .sum();
+
         // Write the results into the table.
         let mut all_batches = self.batches.write().await;
 
         if all_batches.is_empty() {
             *all_batches = results
         } else {
-            for (batches, result) in all_batches.iter_mut().zip(results.into_iter()) {
-                batches.extend(result);
+            // the input is planned to match the partitions of the table,
+            // but may have been repartitioned since, so the partitions of
+            // the input are distributed round robin over the table
+            let partition_count = all_batches.len();
+            for (i, result) in results.into_iter().enumerate() {
+                all_batches[i % partition_count].extend(result);
             }
         }
 
-        Ok(())
+        Ok(row_count)
+    }
+}
+
+/// The modification of the rows of a [`MemTable`] by [`MemTableDmlExec`]
+#[derive(Debug, Clone)]
+enum MemTableDmlOp {
+    /// Delete the rows
+    Delete,
+    /// Set the columns at the given indices to the values of the
+    /// expressions
+    Update(Vec<(usize, Arc<dyn PhysicalExpr>)>),
+}
+
+/// Execution plan deleting or updating the rows of a [`MemTable`]
+/// matching a predicate, in place.
+///
+/// The plan produces a single row with the number of modified rows,
+/// in a `UInt64` column named `count`.
+#[derive(Debug)]
+struct MemTableDmlExec {
+    /// The partitions of the modified table
+    batches: Arc<RwLock<Vec<Vec<RecordBatch>>>>,
+    /// The modification applied to the matching rows
+    op: MemTableDmlOp,
+    /// Selects the modified rows, or all rows if `None`
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// Schema describing the structure of the output
+    count_schema: SchemaRef,
+}
+
+impl MemTableDmlExec {
+    fn new(
+        batches: Arc<RwLock<Vec<Vec<RecordBatch>>>>,
+        op: MemTableDmlOp,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        Self {
+            batches,
+            op,
+            predicate,
+            count_schema: make_count_schema(),
+        }
+    }
+}
+
+impl ExecutionPlan for MemTableDmlExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.count_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if children.is_empty() {
+            Ok(self)
+        } else {
+            Err(DataFusionError::Internal(format!(
+                "Children cannot be replaced in {self:?}"
+            )))
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "MemTableDmlExec invalid partition {partition}"
+            )));
+        }
+
+        let batches = self.batches.clone();
+        let op = self.op.clone();
+        let predicate = self.predicate.clone();
+        let count_schema = self.count_schema.clone();
+        let stream = futures::stream::once(async move {
+            // hold the lock for the whole modification, so that it is
+            // not interleaved with concurrent writes
+            let mut partitions = batches.write().await;
+            let mut count = 0;
+            let mut new_partitions = Vec::with_capacity(partitions.len());
+            for batches in partitions.iter() {
+                let mut new_batches = Vec::with_capacity(batches.len());
+                for batch in batches {
+                    let mask = evaluate_mask(batch, predicate.as_ref())?;
+                    count += mask.true_count() as u64;
+                    new_batches.push(apply_dml(batch, &mask, &op)?);
+                }
+                new_partitions.push(new_batches);
+            }
+            // the table is only modified if all batches were processed
+            *partitions = new_partitions;
+            make_count_batch(count_schema, count)
+        })
+        .boxed();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.count_schema.clone(),
+            stream,
+        )))
+    }
+
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                let op = match self.op {
+                    MemTableDmlOp::Delete => "Delete",
+                    MemTableDmlOp::Update(_) => "Update",
+                };
+                write!(f, "MemTableDmlExec: op={op}")?;
+                if let Some(predicate) = &self.predicate {
+                    write!(f, ", predicate={predicate}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+/// Evaluates `predicate` on `batch`, treating nulls as false
+fn evaluate_mask(
+    batch: &RecordBatch,
+    predicate: Option<&Arc<dyn PhysicalExpr>>,
+) -> Result<BooleanArray> {
+    match predicate {
+        Some(predicate) => {
+            let mask = predicate.evaluate(batch)?.into_array(batch.num_rows());
+            let mask = as_boolean_array(&mask)?;
+            Ok(and_kleene(mask, &is_not_null(mask)?)?)
+        }
+        None => Ok(BooleanArray::from(vec![true; batch.num_rows()])),
+    }
+}
+
+/// Returns `batch` with `op` applied to the rows selected by `mask`
+fn apply_dml(
+    batch: &RecordBatch,
+    mask: &BooleanArray,
+    op: &MemTableDmlOp,
+) -> Result<RecordBatch> {
+    match op {
+        MemTableDmlOp::Delete => Ok(filter_record_batch(batch, &not(mask)?)?),
+        MemTableDmlOp::Update(assignments) => {
+            let mut columns = batch.columns().to_vec();
+            for (index, expr) in assignments {
+                let value = expr.evaluate(batch)?.into_array(batch.num_rows());
+                columns[*index] = zip(mask, value.as_ref(), batch.column(*index))?;
+            }
+            Ok(RecordBatch::try_new(batch.schema(), columns)?)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assert_batches_eq;
     use crate::datasource::provider_as_source;
     use crate::from_slice::FromSlice;
-    use crate::prelude::SessionContext;
-    use arrow::array::Int32Array;
+    use crate::physical_plan::collect;
+    use crate::prelude::{col, lit, SessionContext};
+    use arrow::array::{Int32Array, Int64Array};
     use arrow::datatypes::{DataType, Field, Schema};
     use arrow::error::ArrowError;
-    use datafusion_expr::LogicalPlanBuilder;
+    use datafusion_common::cast::as_uint64_array;
+    use datafusion_expr::{LogicalPlan, LogicalPlanBuilder};
     use futures::StreamExt;
     use std::collections::HashMap;
 
@@ -485,6 +783,19 @@ mod tests {
         ))
     }
 
+    /// Inserts the output of `input` into `table`, returning the number of
+    /// inserted rows
+    async fn insert_into_table(
+        table: &MemTable,
+        ctx: &SessionContext,
+        input: &LogicalPlan,
+    ) -> Result<u64> {
+        let input = ctx.state().create_physical_plan(input).await?;
+        let exec = table.insert_into(&ctx.state(), input).await?;
+        let batches = collect(exec, ctx.task_ctx()).await?;
+        Ok(as_uint64_array(batches[0].column(0))?.value(0))
+    }
+
     fn create_initial_ctx() -> Result<(SessionContext, SchemaRef, RecordBatch)> {
         // Create a new session context
         let session_ctx = SessionContext::new();
@@ -510,9 +821,10 @@ mod tests {
         let single_partition_table_scan =
             create_mem_table_scan(schema.clone(), vec![vec![batch.clone()]])?;
         // Insert the data from the provider into the table
-        initial_table
-            .insert_into(&session_ctx.state(), &single_partition_table_scan)
-            .await?;
+        let count =
+            insert_into_table(&initial_table, &session_ctx, &single_partition_table_scan)
+                .await?;
+        assert_eq!(count, 3);
         // Ensure that the table now contains two batches of data in the same partition
         assert_eq!(initial_table.batches.read().await.get(0).unwrap().len(), 2);
 
@@ -523,8 +835,7 @@ mod tests {
         )?;
 
         // Insert the data from the provider into the table. We expect coalescing partitions.
-        initial_table
-            .insert_into(&session_ctx.state(), &multi_partition_table_scan)
+        insert_into_table(&initial_table, &session_ctx, &multi_partition_table_scan)
             .await?;
         // Ensure that the table now contains 4 batches of data with only 1 partition
         assert_eq!(initial_table.batches.read().await.get(0).unwrap().len(), 4);
@@ -548,8 +859,7 @@ mod tests {
         )?;
 
         // insert the data from the 1 partition data source provider into the initial table
-        initial_table
-            .insert_into(&session_ctx.state(), &single_partition_table_scan)
+        insert_into_table(&initial_table, &session_ctx, &single_partition_table_scan)
             .await?;
 
         // We expect round robin repartition here, each partition gets 1 batch.
@@ -562,8 +872,7 @@ mod tests {
             vec![vec![batch.clone()], vec![batch]],
         )?;
         // We expect one-to-one partition mapping.
-        initial_table
-            .insert_into(&session_ctx.state(), &multi_partition_table_scan)
+        insert_into_table(&initial_table, &session_ctx, &multi_partition_table_scan)
             .await?;
         // Ensure that the table now contains 3 batches of data with 2 partitions.
         assert_eq!(initial_table.batches.read().await.get(0).unwrap().len(), 3);
@@ -584,8 +893,7 @@ mod tests {
         )?;
 
         // insert the data from the 1 partition data source provider into the initial table
-        initial_table
-            .insert_into(&session_ctx.state(), &single_partition_table_scan)
+        insert_into_table(&initial_table, &session_ctx, &single_partition_table_scan)
             .await?;
 
         assert_eq!(initial_table.batches.read().await.get(0).unwrap().len(), 2);
@@ -596,11 +904,116 @@ mod tests {
             vec![vec![batch.clone()], vec![batch]],
         )?;
         // We expect coalesce partitions here.
-        initial_table
-            .insert_into(&session_ctx.state(), &single_partition_table_scan)
+        insert_into_table(&initial_table, &session_ctx, &single_partition_table_scan)
             .await?;
         // Ensure that the table now contains 3 batches of data with 2 partitions.
         assert_eq!(initial_table.batches.read().await.get(0).unwrap().len(), 4);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_mem_sink_partition_count_mismatch() -> Result<()> {
+        let (session_ctx, schema, batch) = create_initial_ctx()?;
+        let table = MemTable::try_new(
+            schema.clone(),
+            vec![vec![batch.clone()], vec![batch.clone()]],
+        )?;
+
+        // an input with more partitions than the table, as if it had been
+        // repartitioned after planning the insert
+        let input = MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch.clone()], vec![batch]],
+            schema,
+            None,
+        )?;
+        let task_ctx = session_ctx.task_ctx();
+        let streams = (0..3)
+            .map(|i| input.execute(i, task_ctx.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        let sink = MemSink::new(table.batches.clone());
+        let count = sink.write_all(streams, &task_ctx).await?;
+        assert_eq!(count, 9);
+
+        // no partition of the input is dropped
+        let batches = table.batches.read().await;
+        assert_eq!(batches[0].len(), 3);
+        assert_eq!(batches[1].len(), 2);
+        Ok(())
+    }
+
+    /// Executes `exec`, returning the number of modified rows
+    async fn execute_count(
+        exec: Arc<dyn ExecutionPlan>,
+        ctx: &SessionContext,
+    ) -> Result<u64> {
+        let batches = collect(exec, ctx.task_ctx()).await?;
+        Ok(as_uint64_array(batches[0].column(0))?.value(0))
+    }
+
+    #[tokio::test]
+    async fn test_delete_from() -> Result<()> {
+        let (session_ctx, schema, batch) = create_initial_ctx()?;
+        let table =
+            MemTable::try_new(schema.clone(), vec![vec![batch.clone()], vec![batch]])?;
+
+        let exec = table
+            .delete_from(&session_ctx.state(), vec![col("a").gt_eq(lit(2))])
+            .await?;
+        assert_eq!(execute_count(exec, &session_ctx).await?, 4);
+
+        let exec = table.scan(&session_ctx.state(), None, &[], None).await?;
+        let actual = collect(exec, session_ctx.task_ctx()).await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 1 |", "+---+"];
+        assert_batches_eq!(expected, &actual);
+
+        // without filters, all rows are deleted
+        let exec = table.delete_from(&session_ctx.state(), vec![]).await?;
+        assert_eq!(execute_count(exec, &session_ctx).await?, 2);
+        let exec = table.scan(&session_ctx.state(), None, &[], None).await?;
+        let actual = collect(exec, session_ctx.task_ctx()).await?;
+        assert_eq!(actual.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_slice([1, 2, 3])),
+                Arc::new(Int64Array::from(vec![Some(10), None, Some(30)])),
+            ],
+        )?;
+        let table = MemTable::try_new(schema, vec![vec![batch]])?;
+
+        // the predicate is null for the second row, which is not updated,
+        // and the new value of `b` is cast to Int64
+        let exec = table
+            .update(
+                &session_ctx.state(),
+                vec![("b".to_string(), col("a") * lit(100))],
+                vec![col("b").lt(lit(20i64)).or(col("a").eq(lit(3)))],
+            )
+            .await?;
+        assert_eq!(execute_count(exec, &session_ctx).await?, 2);
+
+        let exec = table.scan(&session_ctx.state(), None, &[], None).await?;
+        let actual = collect(exec, session_ctx.task_ctx()).await?;
+        let expected = vec![
+            "+---+-----+",
+            "| a | b   |",
+            "+---+-----+",
+            "| 1 | 100 |",
+            "| 2 |     |",
+            "| 3 | 300 |",
+            "+---+-----+",
+        ];
+        assert_batches_eq!(expected, &actual);
+        Ok(())
+    }
 }
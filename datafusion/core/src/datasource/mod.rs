@@ -18,6 +18,7 @@
 //! DataFusion data sources
 
 #![allow(clippy::module_inception)]
+pub mod cte_worktable;
 pub mod datasource;
 pub mod default_table_source;
 pub mod empty;
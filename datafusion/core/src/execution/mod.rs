@@ -45,6 +45,7 @@ pub mod context;
 pub use crate::datasource::file_format::options;
 
 // backwards compatibility
+pub use datafusion_execution::cache;
 pub use datafusion_execution::disk_manager;
 pub use datafusion_execution::memory_pool;
 pub use datafusion_execution::registry;
@@ -179,6 +179,7 @@
 //! * extend the planner to use user-defined logical and physical nodes ([`QueryPlanner`](execution::context::QueryPlanner))
 //! * declare and use user-defined scalar functions ([`ScalarUDF`](physical_plan::udf::ScalarUDF))
 //! * declare and use user-defined aggregate functions ([`AggregateUDF`](physical_plan::udaf::AggregateUDF))
+//! * declare and use user-defined window functions ([`WindowUDF`](logical_expr::WindowUDF))
 //!
 //! You can find examples of each of them in examples section.
 //!
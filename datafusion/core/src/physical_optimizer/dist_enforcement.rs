@@ -1898,7 +1898,6 @@ mod tests {
         ];
         let right = projection_exec_with_alias(parquet_exec(), alias_pairs);
 
-        // SortMergeJoin does not support RightSemi and RightAnti join now
         let join_types = vec![
             JoinType::Inner,
             JoinType::Left,
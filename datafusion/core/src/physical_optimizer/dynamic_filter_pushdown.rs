@@ -0,0 +1,385 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Push the bounds of the build side join keys of hash joins down to the
+//! scans and filters of their probe side.
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::logical_expr::{JoinType, Operator};
+use crate::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use crate::physical_plan::expressions::{
+    BinaryExpr, Column, DynamicFilter, DynamicFilterExpr,
+};
+use crate::physical_plan::file_format::ParquetExec;
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::joins::{HashJoinExec, PartitionMode};
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::sorts::sort::SortExec;
+use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use crate::physical_plan::{ExecutionPlan, PhysicalExpr};
+
+use super::optimizer::PhysicalOptimizerRule;
+use datafusion_common::tree_node::{Transformed, TreeNode};
+
+/// The DynamicFilterPushdown rule attaches a [`DynamicFilter`] to the hash joins
+/// collecting their build side, and applies it to the probe side join keys with
+/// [`DynamicFilterExpr`]s pushed down to the `ParquetExec` and `FilterExec`
+/// operators of the probe side.
+///
+/// Once the build side is collected, the filter only accepts the probe side rows
+/// whose join keys are within the minimum and maximum values of the build side
+/// join keys, so that parquet scans can prune row groups and pages, and skip
+/// rows while decoding when filter pushdown is enabled.
+///
+/// The filter is only pushed down for the join types whose output does not
+/// contain the probe side rows without a match, and through the operators that
+/// keep the columns of their input and don't change which of its rows reach
+/// the join.
+#[derive(Default)]
+pub struct DynamicFilterPushdown {}
+
+impl DynamicFilterPushdown {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for DynamicFilterPushdown {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.enable_dynamic_filter_pushdown {
+            return Ok(plan);
+        }
+        plan.transform_up(&|plan| {
+            let pushed_down = match plan.as_any().downcast_ref::<HashJoinExec>() {
+                Some(hash_join) => push_down_join_filter(hash_join)?,
+                None => None,
+            };
+            Ok(match pushed_down {
+                Some(plan) => Transformed::Yes(plan),
+                None => Transformed::No(plan),
+            })
+        })
+    }
+
+    fn name(&self) -> &str {
+        "dynamic_filter_pushdown"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Whether the probe side rows filtered out by the dynamic filter of the join
+/// can't be part of its output
+fn supports_dynamic_filter(hash_join: &HashJoinExec) -> bool {
+    *hash_join.partition_mode() == PartitionMode::CollectLeft
+        && !hash_join.null_equals_null()
+        && hash_join.dynamic_filter().is_none()
+        && matches!(
+            hash_join.join_type(),
+            JoinType::Inner
+                | JoinType::Left
+                | JoinType::LeftSemi
+                | JoinType::LeftAnti
+                | JoinType::RightSemi
+        )
+}
+
+/// Returns the join with a dynamic filter pushed down to its probe side, or
+/// `None` if there is nowhere to apply it
+fn push_down_join_filter(
+    hash_join: &HashJoinExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    if !supports_dynamic_filter(hash_join) {
+        return Ok(None);
+    }
+
+    let filter = Arc::new(DynamicFilter::new());
+    let keys = hash_join
+        .on()
+        .iter()
+        .map(|(_, right)| Arc::new(right.clone()) as Arc<dyn PhysicalExpr>)
+        .collect();
+    let right = match push_down(hash_join.right(), keys, &filter)? {
+        Some(right) => right,
+        None => return Ok(None),
+    };
+
+    let join = HashJoinExec::try_new(
+        hash_join.left().clone(),
+        right,
+        hash_join.on().to_vec(),
+        hash_join.filter().cloned(),
+        hash_join.join_type(),
+        *hash_join.partition_mode(),
+        hash_join.null_equals_null(),
+    )?
+    .with_dynamic_filter(filter);
+    Ok(Some(Arc::new(join)))
+}
+
+/// Applies the filter to the given keys of the output of `plan`, in the
+/// `ParquetExec` and `FilterExec` operators it can be pushed down to. Returns
+/// `None` if there are none.
+fn push_down(
+    plan: &Arc<dyn ExecutionPlan>,
+    keys: Vec<Arc<dyn PhysicalExpr>>,
+    filter: &Arc<DynamicFilter>,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let any = plan.as_any();
+
+    if let Some(parquet) = any.downcast_ref::<ParquetExec>() {
+        // The predicate of the scan refers to the columns of the file schema,
+        // and can't apply to the partition columns
+        let config = parquet.base_config();
+        let keys = keys
+            .iter()
+            .map(|key| {
+                let column = key.as_any().downcast_ref::<Column>()?;
+                let index = match &config.projection {
+                    Some(projection) => *projection.get(column.index())?,
+                    None => column.index(),
+                };
+                (index < config.file_schema.fields().len()).then(|| {
+                    let name = config.file_schema.field(index).name();
+                    Arc::new(Column::new(name, index)) as Arc<dyn PhysicalExpr>
+                })
+            })
+            .collect::<Option<Vec<_>>>();
+        return Ok(keys.map(|keys| {
+            let predicate = Arc::new(DynamicFilterExpr::new(keys, filter.clone()));
+            Arc::new(parquet.with_additional_predicate(predicate)) as _
+        }));
+    }
+
+    if let Some(filter_exec) = any.downcast_ref::<FilterExec>() {
+        let input = push_down(filter_exec.input(), keys.clone(), filter)?
+            .unwrap_or_else(|| filter_exec.input().clone());
+        let predicate = Arc::new(BinaryExpr::new(
+            filter_exec.predicate().clone(),
+            Operator::And,
+            Arc::new(DynamicFilterExpr::new(keys, filter.clone())),
+        ));
+        return Ok(Some(Arc::new(FilterExec::try_new(predicate, input)?)));
+    }
+
+    if let Some(projection) = any.downcast_ref::<ProjectionExec>() {
+        let keys = keys
+            .iter()
+            .map(|key| {
+                let column = key.as_any().downcast_ref::<Column>()?;
+                let (expr, _) = projection.expr().get(column.index())?;
+                expr.as_any()
+                    .downcast_ref::<Column>()
+                    .map(|column| Arc::new(column.clone()) as Arc<dyn PhysicalExpr>)
+            })
+            .collect::<Option<Vec<_>>>();
+        return match keys {
+            Some(keys) => {
+                with_new_child(plan, push_down(projection.input(), keys, filter)?)
+            }
+            None => Ok(None),
+        };
+    }
+
+    // A sort with a fetch only outputs its first rows, which may change when
+    // its input is filtered
+    let passes_through = any.is::<CoalesceBatchesExec>()
+        || any.is::<CoalescePartitionsExec>()
+        || any.is::<RepartitionExec>()
+        || any.is::<SortPreservingMergeExec>()
+        || any
+            .downcast_ref::<SortExec>()
+            .map(|sort| sort.fetch().is_none())
+            .unwrap_or(false);
+    if passes_through {
+        let child = plan.children()[0].clone();
+        return with_new_child(plan, push_down(&child, keys, filter)?);
+    }
+
+    Ok(None)
+}
+
+fn with_new_child(
+    plan: &Arc<dyn ExecutionPlan>,
+    child: Option<Arc<dyn ExecutionPlan>>,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    child
+        .map(|child| plan.clone().with_new_children(vec![child]))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_batches_sorted_eq,
+        physical_plan::{collect, displayable, expressions::col, expressions::lit},
+        prelude::SessionContext,
+        test::build_table_scan_i32,
+    };
+
+    use super::*;
+
+    fn join(
+        right: Arc<dyn ExecutionPlan>,
+        join_type: &JoinType,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let left = build_table_scan_i32(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![7, 8, 9]),
+        );
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+        Ok(Arc::new(HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            join_type,
+            PartitionMode::CollectLeft,
+            false,
+        )?))
+    }
+
+    /// Returns a filter of the rows with a positive `a2`
+    fn probe_side() -> Result<Arc<dyn ExecutionPlan>> {
+        let input = build_table_scan_i32(
+            ("a2", &vec![10, 20, 30, -40]),
+            ("b2", &vec![1, 5, 9, 6]),
+            ("c2", &vec![70, 80, 90, 100]),
+        );
+        let predicate = Arc::new(BinaryExpr::new(
+            col("a2", &input.schema())?,
+            Operator::Gt,
+            lit(0),
+        ));
+        Ok(Arc::new(FilterExec::try_new(predicate, input)?))
+    }
+
+    fn optimize(plan: Arc<dyn ExecutionPlan>) -> Result<Arc<dyn ExecutionPlan>> {
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_dynamic_filter_pushdown = true;
+        DynamicFilterPushdown::new().optimize(plan, &config)
+    }
+
+    fn plan_string(plan: &Arc<dyn ExecutionPlan>) -> Vec<String> {
+        displayable(plan.as_ref())
+            .indent()
+            .to_string()
+            .trim()
+            .lines()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn push_down_into_filter() -> Result<()> {
+        let plan = optimize(join(probe_side()?, &JoinType::Inner)?)?;
+        let expected = vec![
+            "HashJoinExec: mode=CollectLeft, join_type=Inner, on=[(Column { name: \"b1\", index: 1 }, Column { name: \"b2\", index: 1 })]",
+            "  MemoryExec: partitions=1, partition_sizes=[1]",
+            "  FilterExec: a2@0 > 0 AND DynamicFilter [true]",
+            "    MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(plan_string(&plan), expected);
+
+        let hash_join = plan.as_any().downcast_ref::<HashJoinExec>().unwrap();
+        let filter = hash_join.dynamic_filter().unwrap().clone();
+        assert!(!filter.is_complete());
+
+        let task_ctx = SessionContext::new().task_ctx();
+        let batches = collect(plan.clone(), task_ctx).await?;
+        let expected = vec![
+            "+----+----+----+----+----+----+",
+            "| a1 | b1 | c1 | a2 | b2 | c2 |",
+            "+----+----+----+----+----+----+",
+            "| 2  | 5  | 8  | 20 | 5  | 80 |",
+            "+----+----+----+----+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        assert!(filter.is_complete());
+        assert_eq!(
+            plan_string(&plan)[2],
+            "  FilterExec: a2@0 > 0 AND DynamicFilter [b2@1 >= 4 AND b2@1 <= 6]"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn push_down_through_projection() -> Result<()> {
+        let input = probe_side()?;
+        let schema = input.schema();
+        let projection: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::try_new(
+            vec![
+                (col("c2", &schema)?, "a2".to_string()),
+                (col("b2", &schema)?, "b2".to_string()),
+            ],
+            input,
+        )?);
+        let plan = optimize(join(projection, &JoinType::LeftSemi)?)?;
+        let expected = vec![
+            "HashJoinExec: mode=CollectLeft, join_type=LeftSemi, on=[(Column { name: \"b1\", index: 1 }, Column { name: \"b2\", index: 1 })]",
+            "  MemoryExec: partitions=1, partition_sizes=[1]",
+            "  ProjectionExec: expr=[c2@2 as a2, b2@1 as b2]",
+            "    FilterExec: a2@0 > 0 AND DynamicFilter [true]",
+            "      MemoryExec: partitions=1, partition_sizes=[1]",
+        ];
+        assert_eq!(plan_string(&plan), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn not_pushed_down() -> Result<()> {
+        // The probe side rows without a match are part of the output
+        let plan = join(probe_side()?, &JoinType::Right)?;
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(plan_string(&optimized), plan_string(&plan));
+        assert!(optimized
+            .as_any()
+            .downcast_ref::<HashJoinExec>()
+            .unwrap()
+            .dynamic_filter()
+            .is_none());
+
+        // There is no operator to apply the filter
+        let input =
+            build_table_scan_i32(("a2", &vec![10]), ("b2", &vec![5]), ("c2", &vec![70]));
+        let plan = join(input, &JoinType::Inner)?;
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(plan_string(&optimized), plan_string(&plan));
+
+        // Disabled
+        let plan = join(probe_side()?, &JoinType::Inner)?;
+        let optimized =
+            DynamicFilterPushdown::new().optimize(plan.clone(), &ConfigOptions::new())?;
+        assert_eq!(plan_string(&optimized), plan_string(&plan));
+        Ok(())
+    }
+}
@@ -0,0 +1,921 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reorder connected inner hash joins based on the cardinalities estimated
+//! from the available statistics.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::ConfigOptions;
+use crate::error::{DataFusionError, Result};
+use crate::logical_expr::JoinType;
+use crate::physical_plan::expressions::Column;
+use crate::physical_plan::joins::utils::max_distinct_count;
+use crate::physical_plan::joins::{HashJoinExec, PartitionMode};
+use crate::physical_plan::projection::ProjectionExec;
+use crate::physical_plan::{with_new_children_if_necessary, ExecutionPlan, PhysicalExpr};
+
+use super::optimizer::PhysicalOptimizerRule;
+
+/// The JoinReordering rule rebuilds the trees of connected inner hash joins
+/// (without join filters) in the order that minimizes the sum of the estimated
+/// cardinalities of the intermediate join results.
+///
+/// The cardinality of joining a set of inputs is estimated from the number of rows
+/// of each input and the distinct counts of the join columns, as the product of the
+/// numbers of rows divided, for each pair of joined inputs, by the largest distinct
+/// count of their join columns. When the distinct count of a column is not known,
+/// the column is assumed to be unique.
+///
+/// For up to [`join_reordering_dp_threshold`] inputs, all the join orders without
+/// cross joins are enumerated with dynamic programming. Above it, the joins are
+/// ordered greedily, by joining first the inputs producing the smallest result.
+/// As the dynamic programming takes time exponential in the number of inputs,
+/// thresholds above [`MAX_DP_THRESHOLD`] are lowered to it.
+///
+/// The join tree is left unchanged when the number of rows of any input is not
+/// available, or when the original order is estimated to be as good as the
+/// reordered one. A projection restores the original column order of the
+/// reordered joins. The build and probe sides of each join are chosen afterwards
+/// by the [`JoinSelection`](super::join_selection::JoinSelection) rule.
+///
+/// [`join_reordering_dp_threshold`]: crate::config::OptimizerOptions::join_reordering_dp_threshold
+#[derive(Default)]
+pub struct JoinReordering {}
+
+/// The maximum number of inputs whose join orders are enumerated with dynamic
+/// programming, whatever the configured threshold
+pub const MAX_DP_THRESHOLD: usize = 12;
+
+impl JoinReordering {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for JoinReordering {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let config = &config.optimizer;
+        if !config.enable_join_reordering {
+            return Ok(plan);
+        }
+        let dp_threshold = config.join_reordering_dp_threshold.min(MAX_DP_THRESHOLD);
+        optimize_joins(plan, dp_threshold)
+    }
+
+    fn name(&self) -> &str {
+        "join_reordering"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Order in which a set of inputs is joined. The inputs are identified by their
+/// index in the [`JoinGraph`].
+#[derive(Debug, Clone, PartialEq)]
+enum JoinTree {
+    Input(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinTree {
+    /// Bit set of the inputs joined by this tree
+    fn inputs(&self) -> u64 {
+        match self {
+            JoinTree::Input(idx) => 1 << idx,
+            JoinTree::Join(left, right) => left.inputs() | right.inputs(),
+        }
+    }
+}
+
+/// The inputs of a tree of inner hash joins and the equi-join conditions
+/// between them.
+struct JoinGraph {
+    /// Inputs of the joins, in the order of their columns in the join output
+    inputs: Vec<Arc<dyn ExecutionPlan>>,
+    /// Index of the first column of each input in the join output
+    offsets: Vec<usize>,
+    /// Number of columns of the join output
+    width: usize,
+    /// Equi-join conditions, as pairs of column indices in the join output
+    on: Vec<(usize, usize)>,
+}
+
+impl JoinGraph {
+    /// Returns the input of the given column of the join output, and the index
+    /// of the column in that input
+    fn input_of(&self, column: usize) -> (usize, usize) {
+        let input = self.offsets.partition_point(|offset| *offset <= column) - 1;
+        (input, column - self.offsets[input])
+    }
+
+    fn num_columns(&self, input: usize) -> usize {
+        self.inputs[input].schema().fields().len()
+    }
+}
+
+/// Whether the given join can be reordered with the root of the join tree
+fn is_reorderable(join: &HashJoinExec, root: &HashJoinExec) -> bool {
+    join.join_type() == &JoinType::Inner
+        && join.filter().is_none()
+        && join.partition_mode() == root.partition_mode()
+        && join.null_equals_null() == root.null_equals_null()
+}
+
+/// Collects the inputs and the join conditions of the tree of reorderable joins
+/// rooted at the given plan, returning its join order
+fn collect_join_graph(
+    plan: &Arc<dyn ExecutionPlan>,
+    root: &HashJoinExec,
+    graph: &mut JoinGraph,
+) -> Option<JoinTree> {
+    match plan.as_any().downcast_ref::<HashJoinExec>() {
+        Some(join) if is_reorderable(join, root) => {
+            let left_offset = graph.width;
+            let left = collect_join_graph(join.left(), root, graph)?;
+            let right_offset = graph.width;
+            let right = collect_join_graph(join.right(), root, graph)?;
+            graph.on.extend(
+                join.on()
+                    .iter()
+                    .map(|(l, r)| (left_offset + l.index(), right_offset + r.index())),
+            );
+            Some(JoinTree::Join(Box::new(left), Box::new(right)))
+        }
+        _ => {
+            // Input sets are represented as bit sets
+            if graph.inputs.len() == u64::BITS as usize {
+                return None;
+            }
+            graph.inputs.push(Arc::clone(plan));
+            graph.offsets.push(graph.width);
+            graph.width += plan.schema().fields().len();
+            Some(JoinTree::Input(graph.inputs.len() - 1))
+        }
+    }
+}
+
+/// Estimates the cardinality of joining any set of inputs of a [`JoinGraph`]
+struct CardinalityEstimator {
+    /// Estimated number of rows of each input
+    num_rows: Vec<f64>,
+    /// For each pair of joined inputs, the largest distinct count of their join columns
+    selectivities: HashMap<(usize, usize), f64>,
+    /// Bit set of the inputs joined with each input
+    neighbors: Vec<u64>,
+}
+
+impl CardinalityEstimator {
+    /// Returns `None` when the number of rows of some input is not known
+    fn try_new(graph: &JoinGraph) -> Option<Self> {
+        let statistics = graph
+            .inputs
+            .iter()
+            .map(|input| input.statistics())
+            .collect::<Vec<_>>();
+        let num_rows = statistics
+            .iter()
+            .map(|stats| stats.num_rows.map(|num_rows| num_rows as f64))
+            .collect::<Option<Vec<_>>>()?;
+
+        // Unknown distinct counts default to the number of rows of the input
+        let distinct_count = |column: usize| {
+            let (input, index) = graph.input_of(column);
+            let input_rows = statistics[input].num_rows.unwrap_or_default();
+            statistics[input]
+                .column_statistics
+                .as_ref()
+                .and_then(|column_stats| {
+                    max_distinct_count(input_rows, column_stats[index].clone())
+                })
+                .unwrap_or(input_rows)
+                .max(1) as f64
+        };
+
+        let mut selectivities = HashMap::new();
+        let mut neighbors = vec![0; graph.inputs.len()];
+        for (left, right) in &graph.on {
+            let (left_input, _) = graph.input_of(*left);
+            let (right_input, _) = graph.input_of(*right);
+            neighbors[left_input] |= 1 << right_input;
+            neighbors[right_input] |= 1 << left_input;
+
+            let key = (left_input.min(right_input), left_input.max(right_input));
+            let distinct = distinct_count(*left).max(distinct_count(*right));
+            let entry = selectivities.entry(key).or_insert(1.0);
+            *entry = distinct.max(*entry);
+        }
+
+        Some(Self {
+            num_rows,
+            selectivities,
+            neighbors,
+        })
+    }
+
+    /// Estimated number of rows of the join of the given set of inputs
+    fn cardinality(&self, inputs: u64) -> f64 {
+        let rows = self
+            .num_rows
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| inputs & (1 << idx) != 0)
+            .map(|(_, num_rows)| num_rows)
+            .product::<f64>();
+        self.selectivities
+            .iter()
+            .filter(|((left, right), _)| {
+                inputs & (1 << left) != 0 && inputs & (1 << right) != 0
+            })
+            .fold(rows, |rows, (_, distinct)| rows / distinct)
+    }
+
+    /// Whether some input of `left` is joined with some input of `right`
+    fn connected(&self, left: u64, right: u64) -> bool {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .any(|(idx, neighbors)| left & (1 << idx) != 0 && neighbors & right != 0)
+    }
+
+    /// Sum of the estimated cardinalities of the joins of the tree
+    fn cost(&self, tree: &JoinTree) -> f64 {
+        match tree {
+            JoinTree::Input(_) => 0.0,
+            JoinTree::Join(left, right) => {
+                self.cost(left) + self.cost(right) + self.cardinality(tree.inputs())
+            }
+        }
+    }
+}
+
+/// Finds the join order of least cost by enumerating the connected subsets of the
+/// inputs, from the smallest to the largest.
+fn dp_join_order(
+    num_inputs: usize,
+    estimator: &CardinalityEstimator,
+) -> Option<JoinTree> {
+    // Cost of the best join order of each connected subset and its split
+    let mut best: HashMap<u64, (f64, Option<(u64, u64)>)> = HashMap::new();
+    for idx in 0..num_inputs {
+        best.insert(1 << idx, (0.0, None));
+    }
+
+    let all_inputs = u64::MAX >> (u64::BITS as usize - num_inputs);
+    for inputs in 1..=all_inputs {
+        if inputs.count_ones() < 2 {
+            continue;
+        }
+        let cardinality = estimator.cardinality(inputs);
+        let lowest = inputs & inputs.wrapping_neg();
+        // Enumerate the splits in two non-empty subsets, where the left one
+        // contains the lowest input
+        let mut left = (inputs - 1) & inputs;
+        while left != 0 {
+            let right = inputs & !left;
+            let cost = match (best.get(&left), best.get(&right)) {
+                (Some((left_cost, _)), Some((right_cost, _))) if left & lowest != 0 => {
+                    Some(left_cost + right_cost + cardinality)
+                }
+                _ => None,
+            };
+            if let Some(cost) = cost {
+                if estimator.connected(left, right)
+                    && best.get(&inputs).map_or(true, |(best, _)| cost < *best)
+                {
+                    best.insert(inputs, (cost, Some((left, right))));
+                }
+            }
+            left = (left - 1) & inputs;
+        }
+    }
+
+    fn build_tree(
+        inputs: u64,
+        best: &HashMap<u64, (f64, Option<(u64, u64)>)>,
+    ) -> Option<JoinTree> {
+        match best.get(&inputs)? {
+            (_, None) => Some(JoinTree::Input(inputs.trailing_zeros() as usize)),
+            (_, Some((left, right))) => Some(JoinTree::Join(
+                Box::new(build_tree(*left, best)?),
+                Box::new(build_tree(*right, best)?),
+            )),
+        }
+    }
+    build_tree(all_inputs, &best)
+}
+
+/// Builds the join order by repeatedly joining the two connected join trees
+/// whose join has the smallest estimated cardinality.
+fn greedy_join_order(
+    num_inputs: usize,
+    estimator: &CardinalityEstimator,
+) -> Option<JoinTree> {
+    let mut trees = (0..num_inputs).map(JoinTree::Input).collect::<Vec<_>>();
+    while trees.len() > 1 {
+        let mut best: Option<(f64, usize, usize)> = None;
+        for (left, left_tree) in trees.iter().enumerate() {
+            for (right, right_tree) in trees.iter().enumerate().skip(left + 1) {
+                let (left_inputs, right_inputs) =
+                    (left_tree.inputs(), right_tree.inputs());
+                if !estimator.connected(left_inputs, right_inputs) {
+                    continue;
+                }
+                let cardinality = estimator.cardinality(left_inputs | right_inputs);
+                if best.map_or(true, |(best, _, _)| cardinality < best) {
+                    best = Some((cardinality, left, right));
+                }
+            }
+        }
+        let (_, left, right) = best?;
+        // `right` is greater than `left`, so removing it first keeps `left` valid
+        let right = trees.remove(right);
+        let left = trees.remove(left);
+        trees.push(JoinTree::Join(Box::new(left), Box::new(right)));
+    }
+    trees.pop()
+}
+
+/// Reorders the trees of joins of the plan, from the top. The largest join trees
+/// are reordered first, so that their joins are not reordered separately.
+fn optimize_joins(
+    plan: Arc<dyn ExecutionPlan>,
+    dp_threshold: usize,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() {
+        if let Some(reordered) = reorder_joins(&plan, hash_join, dp_threshold)? {
+            return Ok(reordered);
+        }
+    }
+    let children = plan
+        .children()
+        .into_iter()
+        .map(|child| optimize_joins(child, dp_threshold))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(with_new_children_if_necessary(plan, children)?.into())
+}
+
+/// Reorders the tree of joins rooted at the given join, after optimizing the
+/// inputs of the tree. Returns `None` when the join is not reorderable, in
+/// which case its children remain to be optimized.
+fn reorder_joins(
+    plan: &Arc<dyn ExecutionPlan>,
+    root: &HashJoinExec,
+    dp_threshold: usize,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    if !is_reorderable(root, root) {
+        return Ok(None);
+    }
+
+    let mut graph = JoinGraph {
+        inputs: vec![],
+        offsets: vec![],
+        width: 0,
+        on: vec![],
+    };
+    let original = match (
+        collect_join_graph(root.left(), root, &mut graph),
+        graph.width,
+        collect_join_graph(root.right(), root, &mut graph),
+    ) {
+        (Some(left), right_offset, Some(right)) => {
+            graph.on.extend(
+                root.on()
+                    .iter()
+                    .map(|(l, r)| (l.index(), right_offset + r.index())),
+            );
+            JoinTree::Join(Box::new(left), Box::new(right))
+        }
+        _ => return Ok(None),
+    };
+
+    // The joins of the tree are all handled here, so only the inputs remain to
+    // be optimized. Their schemas are preserved, and so are the join conditions.
+    graph.inputs = graph
+        .inputs
+        .into_iter()
+        .map(|input| optimize_joins(input, dp_threshold))
+        .collect::<Result<_>>()?;
+
+    // The inputs of a single join are only swapped, by the JoinSelection rule
+    let num_inputs = graph.inputs.len();
+    let estimator = match CardinalityEstimator::try_new(&graph) {
+        Some(estimator) if num_inputs >= 3 => estimator,
+        _ => return with_new_inputs(plan, root, &graph).map(Some),
+    };
+
+    let reordered = if num_inputs <= dp_threshold {
+        dp_join_order(num_inputs, &estimator)
+    } else {
+        greedy_join_order(num_inputs, &estimator)
+    };
+    let reordered = match reordered {
+        Some(reordered) if estimator.cost(&reordered) < estimator.cost(&original) => {
+            reordered
+        }
+        _ => return with_new_inputs(plan, root, &graph).map(Some),
+    };
+
+    let (plan, input_order) = build_join(
+        &reordered,
+        &graph,
+        &estimator,
+        *root.partition_mode(),
+        root.null_equals_null(),
+    )?;
+    Ok(Some(restore_column_order(
+        plan,
+        &input_order,
+        &graph,
+        root,
+    )?))
+}
+
+/// Rebuilds the tree of joins rooted at the given join in its original order,
+/// on top of the inputs of the graph
+fn with_new_inputs(
+    plan: &Arc<dyn ExecutionPlan>,
+    root: &HashJoinExec,
+    graph: &JoinGraph,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    fn rebuild(
+        plan: &Arc<dyn ExecutionPlan>,
+        root: &HashJoinExec,
+        inputs: &mut std::slice::Iter<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        match plan.as_any().downcast_ref::<HashJoinExec>() {
+            Some(join) if is_reorderable(join, root) => {
+                let left = rebuild(join.left(), root, inputs)?;
+                let right = rebuild(join.right(), root, inputs)?;
+                Ok(
+                    with_new_children_if_necessary(Arc::clone(plan), vec![left, right])?
+                        .into(),
+                )
+            }
+            _ => inputs.next().cloned().ok_or_else(|| {
+                DataFusionError::Internal(
+                    "Missing input when rebuilding the join tree".to_string(),
+                )
+            }),
+        }
+    }
+    rebuild(plan, root, &mut graph.inputs.iter())
+}
+
+/// Creates the joins of the given join tree. Returns the plan and the order of
+/// the inputs in its output.
+fn build_join(
+    tree: &JoinTree,
+    graph: &JoinGraph,
+    estimator: &CardinalityEstimator,
+    partition_mode: PartitionMode,
+    null_equals_null: bool,
+) -> Result<(Arc<dyn ExecutionPlan>, Vec<usize>)> {
+    let (left, right) = match tree {
+        JoinTree::Input(idx) => return Ok((Arc::clone(&graph.inputs[*idx]), vec![*idx])),
+        JoinTree::Join(left, right) => (left, right),
+    };
+    // Keep the smaller side on the left (build) side
+    let (left, right) =
+        if estimator.cardinality(left.inputs()) > estimator.cardinality(right.inputs()) {
+            (right, left)
+        } else {
+            (left, right)
+        };
+    let (left_plan, left_order) =
+        build_join(left, graph, estimator, partition_mode, null_equals_null)?;
+    let (right_plan, right_order) =
+        build_join(right, graph, estimator, partition_mode, null_equals_null)?;
+
+    let (left_inputs, right_inputs) = (left.inputs(), right.inputs());
+    let on = graph
+        .on
+        .iter()
+        .filter_map(|(a, b)| {
+            let (a_input, _) = graph.input_of(*a);
+            let (b_input, _) = graph.input_of(*b);
+            if left_inputs & (1 << a_input) != 0 && right_inputs & (1 << b_input) != 0 {
+                Some((*a, *b))
+            } else if left_inputs & (1 << b_input) != 0
+                && right_inputs & (1 << a_input) != 0
+            {
+                Some((*b, *a))
+            } else {
+                None
+            }
+        })
+        .map(|(l, r)| {
+            (
+                column_in(&left_plan, &left_order, graph, l),
+                column_in(&right_plan, &right_order, graph, r),
+            )
+        })
+        .collect();
+
+    let join = HashJoinExec::try_new(
+        left_plan,
+        right_plan,
+        on,
+        None,
+        &JoinType::Inner,
+        partition_mode,
+        null_equals_null,
+    )?;
+    Ok((
+        Arc::new(join),
+        left_order.into_iter().chain(right_order).collect(),
+    ))
+}
+
+/// Returns the index of the given column of the original join output in the
+/// output of `plan`, whose inputs are in the given order
+fn column_index(input_order: &[usize], graph: &JoinGraph, column: usize) -> usize {
+    let (input, index) = graph.input_of(column);
+    input_order
+        .iter()
+        .take_while(|idx| **idx != input)
+        .map(|idx| graph.num_columns(*idx))
+        .sum::<usize>()
+        + index
+}
+
+/// Returns the given column of the original join output as a column of `plan`
+fn column_in(
+    plan: &Arc<dyn ExecutionPlan>,
+    input_order: &[usize],
+    graph: &JoinGraph,
+    column: usize,
+) -> Column {
+    let index = column_index(input_order, graph, column);
+    Column::new(plan.schema().field(index).name(), index)
+}
+
+/// Adds a projection on top of the reordered joins to restore the column order
+/// of the original joins, when their inputs were reordered.
+fn restore_column_order(
+    plan: Arc<dyn ExecutionPlan>,
+    input_order: &[usize],
+    graph: &JoinGraph,
+    root: &HashJoinExec,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    if input_order
+        .iter()
+        .enumerate()
+        .all(|(idx, input)| idx == *input)
+    {
+        return Ok(plan);
+    }
+    let schema = root.schema();
+    let expr = (0..graph.width)
+        .map(|column| {
+            (
+                Arc::new(column_in(&plan, input_order, graph, column))
+                    as Arc<dyn PhysicalExpr>,
+                schema.field(column).name().to_owned(),
+            )
+        })
+        .collect();
+    Ok(Arc::new(ProjectionExec::try_new(expr, plan)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        physical_plan::{displayable, ColumnStatistics, Statistics},
+        test::exec::StatisticsExec,
+    };
+
+    use super::*;
+
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    /// Creates an input with the given number of rows and columns of the given
+    /// distinct counts
+    fn create_input(
+        num_rows: Option<usize>,
+        columns: &[(&str, usize)],
+    ) -> Arc<dyn ExecutionPlan> {
+        Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows,
+                column_statistics: Some(
+                    columns
+                        .iter()
+                        .map(|(_, distinct_count)| ColumnStatistics {
+                            distinct_count: Some(*distinct_count),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            Schema::new(
+                columns
+                    .iter()
+                    .map(|(name, _)| Field::new(*name, DataType::Int32, false))
+                    .collect(),
+            ),
+        ))
+    }
+
+    fn join(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: (&str, &str),
+    ) -> Arc<dyn ExecutionPlan> {
+        let on = vec![(
+            Column::new_with_schema(on.0, &left.schema()).unwrap(),
+            Column::new_with_schema(on.1, &right.schema()).unwrap(),
+        )];
+        Arc::new(
+            HashJoinExec::try_new(
+                left,
+                right,
+                on,
+                None,
+                &JoinType::Inner,
+                PartitionMode::Partitioned,
+                false,
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Returns three inputs where joining `a` and `b` first multiplies the rows:
+    /// * a 1K rows @ (a_x: 10 distinct)
+    /// * b 1K rows @ (b_x: 10 distinct, b_y: 1K distinct)
+    /// * c 10 rows @ (c_y: 10 distinct)
+    fn create_inputs(
+        num_rows: Option<usize>,
+    ) -> (
+        Arc<dyn ExecutionPlan>,
+        Arc<dyn ExecutionPlan>,
+        Arc<dyn ExecutionPlan>,
+    ) {
+        (
+            create_input(num_rows, &[("a_x", 10), ("a_z", 1000)]),
+            create_input(num_rows, &[("b_x", 10), ("b_y", 1000)]),
+            create_input(num_rows.map(|_| 10), &[("c_y", 10)]),
+        )
+    }
+
+    fn optimize(
+        plan: Arc<dyn ExecutionPlan>,
+        dp_threshold: usize,
+    ) -> Arc<dyn ExecutionPlan> {
+        let mut config = ConfigOptions::new();
+        config.optimizer.enable_join_reordering = true;
+        config.optimizer.join_reordering_dp_threshold = dp_threshold;
+        JoinReordering::new().optimize(plan, &config).unwrap()
+    }
+
+    fn format(plan: &Arc<dyn ExecutionPlan>) -> String {
+        displayable(plan.as_ref()).indent().to_string()
+    }
+
+    /// Checks that the plan joins `c` and `b` first, and then `a`
+    fn assert_reordered(
+        original: &Arc<dyn ExecutionPlan>,
+        optimized: &Arc<dyn ExecutionPlan>,
+        inputs: (
+            &Arc<dyn ExecutionPlan>,
+            &Arc<dyn ExecutionPlan>,
+            &Arc<dyn ExecutionPlan>,
+        ),
+    ) {
+        let (a, b, c) = inputs;
+        assert_eq!(original.schema(), optimized.schema());
+
+        let projection = optimized
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .expect("The column order should be restored with a projection");
+        let expr = projection
+            .expr()
+            .iter()
+            .map(|(expr, name)| format!("{expr} as {name}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            expr,
+            [
+                "a_x@3 as a_x",
+                "a_z@4 as a_z",
+                "b_x@1 as b_x",
+                "b_y@2 as b_y",
+                "c_y@0 as c_y"
+            ]
+        );
+
+        let top = projection
+            .input()
+            .as_any()
+            .downcast_ref::<HashJoinExec>()
+            .expect("HashJoinExec");
+        assert_eq!(top.on(), [(Column::new("b_x", 1), Column::new("a_x", 0))]);
+        assert!(Arc::ptr_eq(top.right(), a));
+
+        let bottom = top
+            .left()
+            .as_any()
+            .downcast_ref::<HashJoinExec>()
+            .expect("HashJoinExec");
+        assert_eq!(
+            bottom.on(),
+            [(Column::new("c_y", 0), Column::new("b_y", 1))]
+        );
+        assert!(Arc::ptr_eq(bottom.left(), c));
+        assert!(Arc::ptr_eq(bottom.right(), b));
+    }
+
+    #[test]
+    fn test_reorder_joins() {
+        let (a, b, c) = create_inputs(Some(1000));
+        let original = join(
+            join(a.clone(), b.clone(), ("a_x", "b_x")),
+            c.clone(),
+            ("b_y", "c_y"),
+        );
+
+        let optimized = optimize(original.clone(), 10);
+        assert_reordered(&original, &optimized, (&a, &b, &c));
+    }
+
+    #[test]
+    fn test_reorder_joins_greedy() {
+        let (a, b, c) = create_inputs(Some(1000));
+        let original = join(
+            join(a.clone(), b.clone(), ("a_x", "b_x")),
+            c.clone(),
+            ("b_y", "c_y"),
+        );
+
+        let optimized = optimize(original.clone(), 2);
+        assert_reordered(&original, &optimized, (&a, &b, &c));
+    }
+
+    /// Creates a star join of a fact table of 1M rows with 7 dimension tables,
+    /// in the order `d1` to `d7`. The fact table has a key column `f_k{i}` of
+    /// 1K distinct values for each dimension `d{i}`, whose `d{i}_k` key only
+    /// matches `1000 - 100 * i` of them, so that `d7` is the most selective.
+    fn create_star_join() -> Arc<dyn ExecutionPlan> {
+        let fact_columns = (1..=7)
+            .map(|i| (format!("f_k{i}"), 1000))
+            .collect::<Vec<_>>();
+        let fact_columns = fact_columns
+            .iter()
+            .map(|(name, distinct_count)| (name.as_str(), *distinct_count))
+            .collect::<Vec<_>>();
+        let fact = create_input(Some(1_000_000), &fact_columns);
+        (1..=7).fold(fact, |plan, i| {
+            let dim_rows = 1000 - 100 * i;
+            let dim_key = format!("d{i}_k");
+            let dim = create_input(Some(dim_rows), &[(dim_key.as_str(), dim_rows)]);
+            join(plan, dim, (format!("f_k{i}").as_str(), dim_key.as_str()))
+        })
+    }
+
+    /// Returns the first column of each input of the given tree of joins, in
+    /// the order in which the inputs are joined
+    fn join_order(plan: &Arc<dyn ExecutionPlan>) -> Vec<String> {
+        fn visit(plan: &Arc<dyn ExecutionPlan>, order: &mut Vec<String>) {
+            match plan.as_any().downcast_ref::<HashJoinExec>() {
+                Some(join) => {
+                    let (first, second) = if join.right().as_any().is::<HashJoinExec>() {
+                        (join.right(), join.left())
+                    } else {
+                        (join.left(), join.right())
+                    };
+                    visit(first, order);
+                    visit(second, order);
+                }
+                None => order.push(plan.schema().field(0).name().clone()),
+            }
+        }
+        let mut order = vec![];
+        visit(plan, &mut order);
+        order
+    }
+
+    /// Checks that the star join joins the fact table with the most selective
+    /// dimensions first, and that its output columns are unchanged
+    fn assert_star_join_reordered(
+        original: &Arc<dyn ExecutionPlan>,
+        optimized: &Arc<dyn ExecutionPlan>,
+    ) {
+        assert_eq!(original.schema(), optimized.schema());
+
+        let projection = optimized
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .expect("The column order should be restored with a projection");
+        for (expr, name) in projection.expr() {
+            let column = expr.as_any().downcast_ref::<Column>().expect("Column");
+            assert_eq!(column.name(), name.as_str());
+        }
+
+        assert_eq!(
+            join_order(projection.input()),
+            ["d7_k", "f_k1", "d6_k", "d5_k", "d4_k", "d3_k", "d2_k", "d1_k"]
+        );
+    }
+
+    #[test]
+    fn test_reorder_star_join() {
+        let original = create_star_join();
+        assert_eq!(
+            join_order(&original),
+            ["f_k1", "d1_k", "d2_k", "d3_k", "d4_k", "d5_k", "d6_k", "d7_k"]
+        );
+
+        let optimized = optimize(original.clone(), 10);
+        assert_star_join_reordered(&original, &optimized);
+    }
+
+    #[test]
+    fn test_reorder_star_join_greedy() {
+        // 8 inputs, above the threshold of the dynamic programming
+        let original = create_star_join();
+
+        let optimized = optimize(original.clone(), 4);
+        assert_star_join_reordered(&original, &optimized);
+    }
+
+    #[test]
+    fn test_reorder_large_star_join() {
+        // 21 inputs, above the maximum threshold of the dynamic programming,
+        // so that the joins are ordered greedily
+        let fact_columns = (1..=20)
+            .map(|i| (format!("f_k{i}"), 1000))
+            .collect::<Vec<_>>();
+        let fact_columns = fact_columns
+            .iter()
+            .map(|(name, distinct_count)| (name.as_str(), *distinct_count))
+            .collect::<Vec<_>>();
+        let fact = create_input(Some(1_000_000), &fact_columns);
+        let original = (1..=20).fold(fact, |plan, i| {
+            let dim_rows = 1000 - 40 * i;
+            let dim_key = format!("d{i}_k");
+            let dim = create_input(Some(dim_rows), &[(dim_key.as_str(), dim_rows)]);
+            join(plan, dim, (format!("f_k{i}").as_str(), dim_key.as_str()))
+        });
+
+        let optimized = optimize(original.clone(), usize::MAX);
+        assert_eq!(original.schema(), optimized.schema());
+        let projection = optimized
+            .as_any()
+            .downcast_ref::<ProjectionExec>()
+            .expect("The column order should be restored with a projection");
+        let order = join_order(projection.input());
+        assert_eq!(order[..3], ["d20_k", "f_k1", "d19_k"]);
+    }
+
+    #[test]
+    fn test_keep_best_join_order() {
+        let (a, b, c) = create_inputs(Some(1000));
+        let original = join(a, join(b, c, ("b_y", "c_y")), ("a_x", "b_x"));
+
+        let optimized = optimize(original.clone(), 10);
+        assert_eq!(format(&original), format(&optimized));
+    }
+
+    #[test]
+    fn test_keep_join_order_without_statistics() {
+        let (a, b, c) = create_inputs(None);
+        let original = join(join(a, b, ("a_x", "b_x")), c, ("b_y", "c_y"));
+
+        let optimized = optimize(original.clone(), 10);
+        assert_eq!(format(&original), format(&optimized));
+    }
+
+    #[test]
+    fn test_join_reordering_disabled() {
+        let (a, b, c) = create_inputs(Some(1000));
+        let original = join(join(a, b, ("a_x", "b_x")), c, ("b_y", "c_y"));
+
+        let optimized = JoinReordering::new()
+            .optimize(original.clone(), &ConfigOptions::new())
+            .unwrap();
+        assert_eq!(format(&original), format(&optimized));
+    }
+}
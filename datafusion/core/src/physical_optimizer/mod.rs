@@ -21,7 +21,9 @@
 pub mod aggregate_statistics;
 pub mod coalesce_batches;
 pub mod dist_enforcement;
+pub mod dynamic_filter_pushdown;
 pub mod global_sort_selection;
+pub mod join_reordering;
 pub mod join_selection;
 pub mod optimizer;
 pub mod pipeline_checker;
@@ -38,7 +38,9 @@ use crate::physical_plan::ExecutionPlan;
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::DataFusionError;
 use datafusion_expr::logical_plan::JoinType;
-use datafusion_physical_expr::expressions::{BinaryExpr, CastExpr, Column, Literal};
+use datafusion_physical_expr::expressions::{
+    BinaryExpr, CastExpr, Column, Literal, NegativeExpr,
+};
 use datafusion_physical_expr::intervals::{is_datatype_supported, is_operator_supported};
 use datafusion_physical_expr::PhysicalExpr;
 
@@ -96,14 +98,18 @@ impl PhysicalOptimizerRule for PipelineFixer {
 /// Currently, we do not support all [PhysicalExpr]s for interval calculations.
 /// We do not support every type of [Operator]s either. Over time, this check
 /// will relax as more types of [PhysicalExpr]s and [Operator]s are supported.
-/// Currently, [CastExpr], [BinaryExpr], [Column] and [Literal] is supported.
+/// Currently, [CastExpr], [NegativeExpr], [BinaryExpr], [Column] and [Literal]
+/// are supported.
 fn check_support(expr: &Arc<dyn PhysicalExpr>) -> bool {
     let expr_any = expr.as_any();
     let expr_supported = if let Some(binary_expr) = expr_any.downcast_ref::<BinaryExpr>()
     {
         is_operator_supported(binary_expr.op())
     } else {
-        expr_any.is::<Column>() || expr_any.is::<Literal>() || expr_any.is::<CastExpr>()
+        expr_any.is::<Column>()
+            || expr_any.is::<Literal>()
+            || expr_any.is::<CastExpr>()
+            || expr_any.is::<NegativeExpr>()
     };
     expr_supported && expr.children().iter().all(check_support)
 }
@@ -319,6 +325,12 @@ mod util_tests {
         assert!(check_support(&supported_expr));
         let supported_expr_2 = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
         assert!(check_support(&supported_expr_2));
+        let supported_expr_3 = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("a", 0)),
+            Operator::Multiply,
+            Arc::new(NegativeExpr::new(Arc::new(Column::new("a", 0)))),
+        )) as Arc<dyn PhysicalExpr>;
+        assert!(check_support(&supported_expr_3));
         let unsupported_expr = Arc::new(BinaryExpr::new(
             Arc::new(Column::new("a", 0)),
             Operator::Or,
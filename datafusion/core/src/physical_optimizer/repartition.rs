@@ -24,7 +24,8 @@ use crate::config::ConfigOptions;
 use crate::error::Result;
 use crate::physical_plan::Partitioning::*;
 use crate::physical_plan::{
-    file_format::ParquetExec, repartition::RepartitionExec,
+    file_format::{ArrowExec, ParquetExec},
+    repartition::RepartitionExec,
     with_new_children_if_necessary, ExecutionPlan,
 };
 
@@ -247,7 +248,7 @@ fn optimize_partitions(
         });
     }
 
-    // For ParquetExec return internally repartitioned version of the plan in case `repartition_file_scans` is set
+    // For ParquetExec and ArrowExec return internally repartitioned version of the plan in case `repartition_file_scans` is set
     if let Some(parquet_exec) = new_plan.as_any().downcast_ref::<ParquetExec>() {
         if repartition_file_scans {
             return Ok(Transformed::Yes(Arc::new(
@@ -256,6 +257,14 @@ fn optimize_partitions(
             )));
         }
     }
+    if let Some(arrow_exec) = new_plan.as_any().downcast_ref::<ArrowExec>() {
+        if repartition_file_scans {
+            return Ok(Transformed::Yes(Arc::new(
+                arrow_exec
+                    .get_repartitioned(target_partitions, repartition_file_min_size),
+            )));
+        }
+    }
 
     // Otherwise - return plan wrapped up in RepartitionExec
     Ok(Transformed::Yes(Arc::new(RepartitionExec::try_new(
@@ -331,7 +340,7 @@ mod tests {
         AggregateExec, AggregateMode, PhysicalGroupBy,
     };
     use crate::physical_plan::expressions::{col, PhysicalSortExpr};
-    use crate::physical_plan::file_format::{FileScanConfig, ParquetExec};
+    use crate::physical_plan::file_format::{ArrowExec, FileScanConfig, ParquetExec};
     use crate::physical_plan::filter::FilterExec;
     use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
     use crate::physical_plan::projection::ProjectionExec;
@@ -363,6 +372,21 @@ mod tests {
         ))
     }
 
+    /// Create a non sorted arrow exec
+    fn arrow_exec() -> Arc<ArrowExec> {
+        Arc::new(ArrowExec::new(FileScanConfig {
+            object_store_url: ObjectStoreUrl::parse("test:///").unwrap(),
+            file_schema: schema(),
+            file_groups: vec![vec![PartitionedFile::new("x".to_string(), 100)]],
+            statistics: Statistics::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        }))
+    }
+
     /// Create a non sorted parquet exec over two files / partitions
     fn parquet_exec_two_partitions() -> Arc<ParquetExec> {
         Arc::new(ParquetExec::new(
@@ -919,6 +943,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parallelization_arrow_single_partition() -> Result<()> {
+        let plan = aggregate(arrow_exec());
+
+        let expected = [
+            "AggregateExec: mode=Final, gby=[], aggr=[]",
+            "CoalescePartitionsExec",
+            "AggregateExec: mode=Partial, gby=[], aggr=[]",
+            "ArrowExec: limit=None, files={2 groups: [[x:0..50], [x:50..100]]}",
+        ];
+
+        assert_optimized!(expected, plan, 2, true, 10);
+        Ok(())
+    }
+
     #[test]
     fn parallelization_two_partitions() -> Result<()> {
         let plan = aggregate(parquet_exec_two_partitions());
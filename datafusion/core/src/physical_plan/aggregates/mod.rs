@@ -294,6 +294,7 @@ impl AggregateExec {
                     self.aggr_expr.clone(),
                     input,
                     baseline_metrics,
+                    self.metrics.clone(),
                     batch_size,
                     context,
                     partition,
@@ -711,6 +712,7 @@ fn evaluate_group_by(
 #[cfg(test)]
 mod tests {
     use crate::execution::context::{SessionConfig, TaskContext};
+    use crate::execution::disk_manager::DiskManagerConfig;
     use crate::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use crate::from_slice::FromSlice;
     use crate::physical_plan::aggregates::{
@@ -733,6 +735,7 @@ mod tests {
 
     use super::StreamType;
     use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+    use crate::physical_plan::memory::MemoryExec;
     use crate::physical_plan::{
         ExecutionPlan, Partitioning, RecordBatchStream, SendableRecordBatchStream,
         Statistics,
@@ -1108,11 +1111,16 @@ mod tests {
             Arc::new(TestYieldingExec { yield_first: true });
         let input_schema = input.schema();
 
+        // disable the disk manager so that grouped aggregations cannot spill
         let session_ctx = SessionContext::with_config_rt(
             SessionConfig::default(),
             Arc::new(
-                RuntimeEnv::new(RuntimeConfig::default().with_memory_limit(1, 1.0))
-                    .unwrap(),
+                RuntimeEnv::new(
+                    RuntimeConfig::default()
+                        .with_memory_limit(1, 1.0)
+                        .with_disk_manager(DiskManagerConfig::Disabled),
+                )
+                .unwrap(),
             ),
         );
         let task_ctx = session_ctx.task_ctx();
@@ -1189,6 +1197,88 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_spill_grouped_aggregate() -> Result<()> {
+        let (schema, batches) = some_data();
+        let input: Arc<dyn ExecutionPlan> =
+            Arc::new(MemoryExec::try_new(&[batches], schema.clone(), None)?);
+
+        // every batch exceeds the memory limit, so the state is spilled each time
+        let session_ctx = SessionContext::with_config_rt(
+            SessionConfig::default(),
+            Arc::new(RuntimeEnv::new(
+                RuntimeConfig::default().with_memory_limit(1, 1.0),
+            )?),
+        );
+        let task_ctx = session_ctx.task_ctx();
+
+        let groups =
+            PhysicalGroupBy::new_single(vec![(col("a", &schema)?, "a".to_string())]);
+
+        // `AVG` uses row accumulators, `MEDIAN` uses normal accumulators
+        let aggregates: Vec<Arc<dyn AggregateExpr>> = vec![
+            Arc::new(Avg::new(
+                col("b", &schema)?,
+                "AVG(b)".to_string(),
+                DataType::Float64,
+            )),
+            Arc::new(Median::new(
+                col("b", &schema)?,
+                "MEDIAN(b)".to_string(),
+                DataType::Float64,
+            )),
+        ];
+
+        let partial_aggregate = Arc::new(AggregateExec::try_new(
+            AggregateMode::Partial,
+            groups.clone(),
+            aggregates.clone(),
+            input,
+            schema.clone(),
+        )?);
+
+        let final_group: Vec<(Arc<dyn PhysicalExpr>, String)> = groups
+            .expr()
+            .iter()
+            .map(|(_expr, name)| Ok((col(name, &schema)?, name.clone())))
+            .collect::<Result<_>>()?;
+        let merged_aggregate = Arc::new(AggregateExec::try_new(
+            AggregateMode::Final,
+            PhysicalGroupBy::new_single(final_group),
+            aggregates,
+            Arc::new(CoalescePartitionsExec::new(partial_aggregate.clone())),
+            schema,
+        )?);
+
+        let result =
+            crate::physical_plan::collect(merged_aggregate.clone(), task_ctx).await?;
+
+        let expected = vec![
+            "+---+--------------------+-----------+",
+            "| a | AVG(b)             | MEDIAN(b) |",
+            "+---+--------------------+-----------+",
+            "| 2 | 1.0                | 1.0       |",
+            "| 3 | 2.3333333333333335 | 2.0       |",
+            "| 4 | 3.6666666666666665 | 4.0       |",
+            "+---+--------------------+-----------+",
+        ];
+        assert_batches_sorted_eq!(expected, &result);
+
+        for aggregate in [partial_aggregate, merged_aggregate] {
+            let metrics = aggregate.metrics().unwrap();
+            assert!(metrics.spill_count().unwrap() > 0);
+            assert!(metrics.spilled_bytes().unwrap() > 0);
+        }
+
+        assert_eq!(
+            session_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The aggregation should have returned all memory used back to the memory manager"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_drop_cancel_without_groups() -> Result<()> {
         let session_ctx = SessionContext::new();
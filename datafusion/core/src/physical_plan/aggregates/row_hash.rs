@@ -31,18 +31,25 @@ use futures::stream::{Stream, StreamExt};
 
 use crate::execution::context::TaskContext;
 use crate::execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
+use crate::execution::runtime_env::RuntimeEnv;
 use crate::physical_plan::aggregates::{
     evaluate_group_by, evaluate_many, group_schema, AccumulatorItem, AggregateMode,
     PhysicalGroupBy, RowAccumulatorItem,
 };
-use crate::physical_plan::metrics::{BaselineMetrics, RecordOutput};
+use crate::physical_plan::common::{read_spill_as_stream, spill_record_batches};
+use crate::physical_plan::expressions::{Column, PhysicalSortExpr};
+use crate::physical_plan::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MemTrackingMetrics, RecordOutput,
+};
+use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeStream;
+use crate::physical_plan::sorts::SortedStream;
 use crate::physical_plan::{aggregates, AggregateExpr, PhysicalExpr};
 use crate::physical_plan::{RecordBatchStream, SendableRecordBatchStream};
 
 use crate::execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use arrow::array::{new_null_array, PrimitiveArray};
 use arrow::array::{Array, UInt32Builder};
-use arrow::compute::cast;
+use arrow::compute::{cast, SortOptions};
 use arrow::datatypes::{DataType, Schema, UInt32Type};
 use arrow::{array::ArrayRef, compute};
 use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
@@ -53,6 +60,8 @@ use datafusion_row::layout::RowLayout;
 use datafusion_row::reader::{read_row, RowReader};
 use datafusion_row::{MutableRecordBatch, RowType};
 use hashbrown::raw::RawTable;
+use log::debug;
+use tempfile::NamedTempFile;
 
 /// Grouping aggregate with row-format aggregation states inside.
 ///
@@ -68,6 +77,14 @@ use hashbrown::raw::RawTable;
 /// 4. The state's RecordBatch is `merge`d to a new state
 /// 5. The state is mapped to the final value
 ///
+/// If the memory reservation cannot grow while reading the input, the
+/// aggregation state collected so far is sorted by group key and spilled to
+/// disk as intermediate state, and the stream continues with an empty state.
+/// Once the input is exhausted, the spill files are merged in group key order
+/// and fed back through the stream, merging the intermediate states. Since the
+/// merged input is sorted, every group but the last one of each batch is
+/// complete and is emitted right away, which keeps the memory usage bounded.
+///
 /// [Compact]: datafusion_row::layout::RowType::Compact
 /// [WordAligned]: datafusion_row::layout::RowType::WordAligned
 pub(crate) struct GroupedHashAggregateStream {
@@ -90,6 +107,8 @@ pub(crate) struct GroupedHashAggregateStream {
     row_aggr_layout: Arc<RowLayout>,
 
     baseline_metrics: BaselineMetrics,
+    /// metrics of the operator, which also record the merge of spill files
+    metrics: ExecutionPlanMetricsSet,
     random_state: RandomState,
     /// size to be used for resulting RecordBatches
     batch_size: usize,
@@ -100,6 +119,35 @@ pub(crate) struct GroupedHashAggregateStream {
     /// first element in the array corresponds to normal accumulators
     /// second element in the array corresponds to row accumulators
     indices: [Vec<Range<usize>>; 2],
+
+    /// runtime environment, used to create spill files
+    runtime: Arc<RuntimeEnv>,
+    /// partition index, used to name spill related resources
+    partition: usize,
+    /// state used to spill the aggregation state to disk and read it back
+    spill_state: SpillState,
+}
+
+/// Tracks the spill files of a [`GroupedHashAggregateStream`] and what is
+/// needed to merge them back once the input is exhausted.
+struct SpillState {
+    /// sorted runs of intermediate aggregation state spilled so far
+    spills: Vec<NamedTempFile>,
+    /// schema of the spilled batches: the group columns followed by the
+    /// intermediate state fields of every aggregate
+    spill_schema: SchemaRef,
+    /// range of each accumulator in `spill_schema`, laid out like `indices`
+    spill_indices: [Vec<Range<usize>>; 2],
+    /// sort order of the spilled batches, i.e. all group columns ascending
+    spill_expr: Vec<PhysicalSortExpr>,
+    /// group by expressions over `spill_schema`, used while merging
+    merging_group_by: PhysicalGroupBy,
+    /// row aggregate expressions over `spill_schema`, used while merging
+    merging_row_aggregate_expressions: Vec<Vec<Arc<dyn PhysicalExpr>>>,
+    /// normal aggregate expressions over `spill_schema`, used while merging
+    merging_normal_aggregate_expressions: Vec<Vec<Arc<dyn PhysicalExpr>>>,
+    /// true once the input has been replaced by the merged spill files
+    is_stream_merging: bool,
 }
 
 #[derive(Debug)]
@@ -128,6 +176,7 @@ impl GroupedHashAggregateStream {
         aggr_expr: Vec<Arc<dyn AggregateExpr>>,
         input: SendableRecordBatchStream,
         baseline_metrics: BaselineMetrics,
+        metrics: ExecutionPlanMetricsSet,
         batch_size: usize,
         context: Arc<TaskContext>,
         partition: usize,
@@ -146,12 +195,33 @@ impl GroupedHashAggregateStream {
         // col_idx_base to the group expression count.
         let all_aggregate_expressions =
             aggregates::aggregate_expressions(&aggr_expr, &mode, start_idx)?;
-        for (expr, others) in aggr_expr.iter().zip(all_aggregate_expressions.into_iter())
+        // The expressions to merge spilled intermediate states, which are always
+        // laid out like the output of a partial aggregation.
+        let all_merging_expressions = aggregates::aggregate_expressions(
+            &aggr_expr,
+            &AggregateMode::Final,
+            start_idx,
+        )?;
+        let mut spill_start_idx = start_idx;
+        let mut row_spill_indices = vec![];
+        let mut merging_row_aggregate_expressions = vec![];
+        let mut normal_spill_indices = vec![];
+        let mut merging_normal_aggregate_expressions = vec![];
+        for ((expr, others), merging) in aggr_expr
+            .iter()
+            .zip(all_aggregate_expressions.into_iter())
+            .zip(all_merging_expressions.into_iter())
         {
+            let n_state_fields = expr.state_fields()?.len();
+            let spill_range = Range {
+                start: spill_start_idx,
+                end: spill_start_idx + n_state_fields,
+            };
+            spill_start_idx += n_state_fields;
             let n_fields = match mode {
                 // In partial aggregation, we keep additional fields in order to successfully
                 // merge aggregation results downstream.
-                AggregateMode::Partial => expr.state_fields()?.len(),
+                AggregateMode::Partial => n_state_fields,
                 _ => 1,
             };
             // Stores range of each expression:
@@ -163,10 +233,14 @@ impl GroupedHashAggregateStream {
                 row_aggregate_expressions.push(others);
                 row_agg_indices.push(aggr_range);
                 row_aggr_expr.push(expr.clone());
+                merging_row_aggregate_expressions.push(merging);
+                row_spill_indices.push(spill_range);
             } else {
                 normal_aggregate_expressions.push(others);
                 normal_agg_indices.push(aggr_range);
                 normal_aggr_expr.push(expr.clone());
+                merging_normal_aggregate_expressions.push(merging);
+                normal_spill_indices.push(spill_range);
             }
             start_idx += n_fields;
         }
@@ -187,9 +261,48 @@ impl GroupedHashAggregateStream {
         let row_aggr_layout =
             Arc::new(RowLayout::new(&row_aggr_schema, RowType::WordAligned));
 
+        let spill_schema = Arc::new(Schema::new(
+            group_schema
+                .fields()
+                .iter()
+                .chain(aggr_state_schema(&aggr_expr)?.fields().iter())
+                .cloned()
+                .collect(),
+        ));
+        let group_columns = group_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| {
+                (
+                    Arc::new(Column::new(field.name(), idx)) as Arc<dyn PhysicalExpr>,
+                    field.name().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let spill_expr = group_columns
+            .iter()
+            .map(|(expr, _)| PhysicalSortExpr {
+                expr: expr.clone(),
+                options: SortOptions::default(),
+            })
+            .collect();
+        let spill_state = SpillState {
+            spills: vec![],
+            spill_schema,
+            spill_indices: [normal_spill_indices, row_spill_indices],
+            spill_expr,
+            merging_group_by: PhysicalGroupBy::new_single(group_columns),
+            merging_row_aggregate_expressions,
+            merging_normal_aggregate_expressions,
+            is_stream_merging: false,
+        };
+
         let name = format!("GroupedHashAggregateStream[{partition}]");
         let row_aggr_state = RowAggregationState {
-            reservation: MemoryConsumer::new(name).register(context.memory_pool()),
+            reservation: MemoryConsumer::new(name)
+                .with_can_spill(true)
+                .register(context.memory_pool()),
             map: RawTable::with_capacity(0),
             group_states: Vec::with_capacity(0),
         };
@@ -210,6 +323,7 @@ impl GroupedHashAggregateStream {
             row_aggr_schema,
             row_aggr_layout,
             baseline_metrics,
+            metrics,
             normal_aggregate_expressions,
             row_aggregate_expressions,
             row_aggr_state,
@@ -217,6 +331,9 @@ impl GroupedHashAggregateStream {
             batch_size,
             row_group_skip_position: 0,
             indices: [normal_agg_indices, row_agg_indices],
+            runtime: context.runtime_env(),
+            partition,
+            spill_state,
         })
     }
 }
@@ -244,18 +361,39 @@ impl Stream for GroupedHashAggregateStream {
                             // This happens AFTER we actually used the memory, but simplifies the whole accounting and we are OK with
                             // overshooting a bit. Also this means we either store the whole record batch or not.
                             let result = result.and_then(|allocated| {
-                                self.row_aggr_state.reservation.try_grow(allocated)
+                                self.update_memory_reservation(allocated)
                             });
 
                             if let Err(e) = result {
                                 return Poll::Ready(Some(Err(e)));
                             }
+
+                            if self.spill_state.is_stream_merging {
+                                let timer = elapsed_compute.timer();
+                                let result = self.emit_completed_groups();
+                                timer.done();
+
+                                match result {
+                                    Ok(Some(batch)) => {
+                                        let batch =
+                                            batch.record_output(&self.baseline_metrics);
+                                        return Poll::Ready(Some(Ok(batch)));
+                                    }
+                                    Ok(None) => {}
+                                    Err(e) => return Poll::Ready(Some(Err(e))),
+                                }
+                            }
                         }
                         // inner had error, return to caller
                         Some(Err(e)) => return Poll::Ready(Some(Err(e))),
-                        // inner is done, producing output
+                        // inner is done, merge the spilled state if any, otherwise
+                        // produce output
                         None => {
-                            self.exec_state = ExecutionState::ProducingOutput;
+                            if self.spill_state.spills.is_empty() {
+                                self.exec_state = ExecutionState::ProducingOutput;
+                            } else if let Err(e) = self.update_merged_stream() {
+                                return Poll::Ready(Some(Err(e)));
+                            }
                         }
                     }
                 }
@@ -317,6 +455,11 @@ impl GroupedHashAggregateStream {
         let normal_aggr_input_values =
             evaluate_many(&self.normal_aggregate_expressions, &batch)?;
 
+        // Spilled state is always intermediate state, so it is merged
+        // regardless of the aggregation mode.
+        let merge_states = self.spill_state.is_stream_merging
+            || !matches!(self.mode, AggregateMode::Partial);
+
         let row_converter_size_pre = self.row_converter.size();
         for group_values in &group_by_values {
             let group_rows = self.row_converter.convert_columns(group_values)?;
@@ -374,21 +517,7 @@ impl GroupedHashAggregateStream {
 
                         // NOTE: do NOT include the `RowGroupState` struct size in here because this is captured by
                         // `group_states` (see allocation down below)
-                        allocated += (std::mem::size_of::<u8>()
-                            * group_state.group_by_values.as_ref().len())
-                            + (std::mem::size_of::<u8>()
-                                * group_state.aggregation_buffer.capacity())
-                            + (std::mem::size_of::<u32>()
-                                * group_state.indices.capacity());
-
-                        // Allocation done by normal accumulators
-                        allocated += (std::mem::size_of::<Box<dyn Accumulator>>()
-                            * group_state.accumulator_set.capacity())
-                            + group_state
-                                .accumulator_set
-                                .iter()
-                                .map(|accu| accu.size())
-                                .sum::<usize>();
+                        allocated += group_state.allocated_size();
 
                         // for hasher function, use precomputed hash value
                         row_map.insert_accounted(
@@ -453,15 +582,11 @@ impl GroupedHashAggregateStream {
                                 0,
                                 group_state.aggregation_buffer.as_mut_slice(),
                             );
-                            match self.mode {
-                                AggregateMode::Partial => {
-                                    accumulator.update_batch(&values, &mut state_accessor)
-                                }
-                                AggregateMode::FinalPartitioned
-                                | AggregateMode::Final => {
-                                    // note: the aggregation here is over states, not values, thus the merge
-                                    accumulator.merge_batch(&values, &mut state_accessor)
-                                }
+                            if merge_states {
+                                // note: the aggregation here is over states, not values, thus the merge
+                                accumulator.merge_batch(&values, &mut state_accessor)
+                            } else {
+                                accumulator.update_batch(&values, &mut state_accessor)
                             }
                         })
                         // 2.5
@@ -485,15 +610,11 @@ impl GroupedHashAggregateStream {
                         })
                         .try_for_each(|(accumulator, values)| {
                             let size_pre = accumulator.size();
-                            let res = match self.mode {
-                                AggregateMode::Partial => {
-                                    accumulator.update_batch(&values)
-                                }
-                                AggregateMode::FinalPartitioned
-                                | AggregateMode::Final => {
-                                    // note: the aggregation here is over states, not values, thus the merge
-                                    accumulator.merge_batch(&values)
-                                }
+                            let res = if merge_states {
+                                // note: the aggregation here is over states, not values, thus the merge
+                                accumulator.merge_batch(&values)
+                            } else {
+                                accumulator.update_batch(&values)
                             };
                             let size_post = accumulator.size();
                             allocated += size_post.saturating_sub(size_pre);
@@ -531,6 +652,24 @@ pub struct RowGroupState {
     pub indices: Vec<u32>,
 }
 
+impl RowGroupState {
+    /// Number of bytes allocated by this group outside of the
+    /// `RowGroupState` struct itself
+    fn allocated_size(&self) -> usize {
+        (std::mem::size_of::<u8>() * self.group_by_values.as_ref().len())
+            + (std::mem::size_of::<u8>() * self.aggregation_buffer.capacity())
+            + (std::mem::size_of::<u32>() * self.indices.capacity())
+            // Allocation done by normal accumulators
+            + (std::mem::size_of::<Box<dyn Accumulator>>()
+                * self.accumulator_set.capacity())
+            + self
+                .accumulator_set
+                .iter()
+                .map(|accu| accu.size())
+                .sum::<usize>()
+    }
+}
+
 /// The state of all the groups
 pub struct RowAggregationState {
     pub reservation: MemoryReservation,
@@ -575,11 +714,29 @@ impl GroupedHashAggregateStream {
             skip_items + self.batch_size,
             self.row_aggr_state.group_states.len(),
         );
-        let group_state_chunk = &self.row_aggr_state.group_states[skip_items..end_idx];
+        self.create_batch_from_groups(
+            skip_items..end_idx,
+            self.mode,
+            &self.schema,
+            &self.indices,
+        )
+        .map(Some)
+    }
+
+    /// Create a RecordBatch with the group keys and accumulator' states or
+    /// values of the groups in `group_range`, laid out according to `schema`
+    /// and `indices`. `mode` decides whether states or final values are produced.
+    fn create_batch_from_groups(
+        &self,
+        group_range: Range<usize>,
+        mode: AggregateMode,
+        schema: &SchemaRef,
+        indices: &[Vec<Range<usize>>; 2],
+    ) -> Result<RecordBatch> {
+        let group_state_chunk = &self.row_aggr_state.group_states[group_range];
 
         if group_state_chunk.is_empty() {
-            let schema = self.schema.clone();
-            return Ok(Some(RecordBatch::new_empty(schema)));
+            return Ok(RecordBatch::new_empty(schema.clone()));
         }
 
         // Buffers for each distinct group (i.e. row accumulator memories)
@@ -588,9 +745,9 @@ impl GroupedHashAggregateStream {
             .map(|gs| gs.aggregation_buffer.clone())
             .collect::<Vec<_>>();
 
-        let output_fields = self.schema.fields();
+        let output_fields = schema.fields();
         // Store row accumulator results (either final output or intermediate state):
-        let row_columns = match self.mode {
+        let row_columns = match mode {
             AggregateMode::Partial => {
                 read_as_batch(&state_buffers, &self.row_aggr_schema, RowType::WordAligned)
             }
@@ -607,7 +764,7 @@ impl GroupedHashAggregateStream {
                         })
                         .collect::<Result<Vec<_>>>()?;
                     // Get corresponding field for row accumulator
-                    let field = &output_fields[self.indices[1][idx].start];
+                    let field = &output_fields[indices[1][idx].start];
                     let result = if current.is_empty() {
                         Ok(arrow::array::new_empty_array(field.data_type()))
                     } else {
@@ -625,9 +782,9 @@ impl GroupedHashAggregateStream {
 
         // Store normal accumulator results (either final output or intermediate state):
         let mut columns = vec![];
-        for (idx, &Range { start, end }) in self.indices[0].iter().enumerate() {
+        for (idx, &Range { start, end }) in indices[0].iter().enumerate() {
             for (field_idx, field) in output_fields[start..end].iter().enumerate() {
-                let current = match self.mode {
+                let current = match mode {
                     AggregateMode::Partial => ScalarValue::iter_to_array(
                         group_state_chunk.iter().map(|row_group_state| {
                             row_group_state.accumulator_set[idx]
@@ -662,8 +819,7 @@ impl GroupedHashAggregateStream {
         let mut output: Vec<ArrayRef> = self.row_converter.convert_rows(group_buffers)?;
 
         // The size of the place occupied by row and normal accumulators
-        let extra: usize = self
-            .indices
+        let extra: usize = indices
             .iter()
             .flatten()
             .map(|Range { start, end }| end - start)
@@ -675,13 +831,172 @@ impl GroupedHashAggregateStream {
         // the output schema:
         let results = [columns.into_iter(), row_columns.into_iter()];
         for (outer, mut current) in results.into_iter().enumerate() {
-            for &Range { start, end } in self.indices[outer].iter() {
+            for &Range { start, end } in indices[outer].iter() {
                 for item in output.iter_mut().take(end).skip(start) {
                     *item = current.next().expect("Columns cannot be empty");
                 }
             }
         }
-        Ok(Some(RecordBatch::try_new(self.schema.clone(), output)?))
+        Ok(RecordBatch::try_new(schema.clone(), output)?)
+    }
+}
+
+impl GroupedHashAggregateStream {
+    /// Record `allocated` additional bytes in the memory reservation, spilling
+    /// the aggregation state to disk if the reservation cannot grow.
+    fn update_memory_reservation(&mut self, allocated: usize) -> Result<()> {
+        if self.spill_state.is_stream_merging {
+            // While merging the spilled state, completed groups are emitted
+            // after every batch so the memory used here stays bounded by the
+            // batch size, and there is nothing left to spill.
+            self.row_aggr_state.reservation.grow(allocated);
+            return Ok(());
+        }
+
+        match self.row_aggr_state.reservation.try_grow(allocated) {
+            Ok(()) => Ok(()),
+            // The memory of the current batch is already in use, spilling the
+            // whole state releases it together with everything else.
+            Err(_) if !self.row_aggr_state.group_states.is_empty() => self.spill(),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sort the current aggregation state by group key, write it to a new
+    /// spill file as intermediate state and release its memory.
+    fn spill(&mut self) -> Result<()> {
+        let spillfile = self
+            .runtime
+            .disk_manager
+            .create_tmp_file("GroupedHashAggregateStream spill")?;
+
+        debug!(
+            "Spilling {} groups of GroupedHashAggregateStream[{}] to disk",
+            self.row_aggr_state.group_states.len(),
+            self.partition
+        );
+
+        // The row format of the group keys sorts like the group columns
+        // ordered by `spill_expr`, so the spilled run is sorted by group key.
+        self.row_aggr_state.group_states.sort_unstable_by(|a, b| {
+            a.group_by_values.row().cmp(&b.group_by_values.row())
+        });
+
+        let num_groups = self.row_aggr_state.group_states.len();
+        let batches = (0..num_groups)
+            .step_by(self.batch_size)
+            .map(|start| {
+                self.create_batch_from_groups(
+                    start..min(start + self.batch_size, num_groups),
+                    AggregateMode::Partial,
+                    &self.spill_state.spill_schema,
+                    &self.spill_state.spill_indices,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let spilled_bytes = spill_record_batches(
+            batches,
+            spillfile.path().into(),
+            self.spill_state.spill_schema.clone(),
+        )?;
+
+        let RowAggregationState {
+            reservation,
+            map,
+            group_states,
+        } = &mut self.row_aggr_state;
+        *map = RawTable::with_capacity(0);
+        *group_states = Vec::with_capacity(0);
+        reservation.free();
+
+        self.baseline_metrics.record_spill(spilled_bytes);
+        self.spill_state.spills.push(spillfile);
+        Ok(())
+    }
+
+    /// Spill the remaining in-memory state and replace the input with a
+    /// stream merging all spill files in group key order. From now on the
+    /// stream aggregates the intermediate state read back from disk.
+    fn update_merged_stream(&mut self) -> Result<()> {
+        if !self.row_aggr_state.group_states.is_empty() {
+            self.spill()?;
+        }
+
+        let schema = self.spill_state.spill_schema.clone();
+        let streams = self
+            .spill_state
+            .spills
+            .drain(..)
+            .map(|spill| {
+                let stream = read_spill_as_stream(spill, schema.clone())?;
+                Ok(SortedStream::new(stream, 0))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let tracking_metrics = MemTrackingMetrics::new(
+            &self.metrics,
+            &self.runtime.memory_pool,
+            self.partition,
+        );
+        self.input = Box::pin(SortPreservingMergeStream::new_from_streams(
+            streams,
+            schema,
+            &self.spill_state.spill_expr,
+            tracking_metrics,
+            self.batch_size,
+        )?);
+
+        let spill_state = &mut self.spill_state;
+        self.group_by = std::mem::take(&mut spill_state.merging_group_by);
+        self.row_aggregate_expressions =
+            std::mem::take(&mut spill_state.merging_row_aggregate_expressions);
+        self.normal_aggregate_expressions =
+            std::mem::take(&mut spill_state.merging_normal_aggregate_expressions);
+        spill_state.is_stream_merging = true;
+        Ok(())
+    }
+
+    /// While merging the spilled state, the input is sorted by group key, so
+    /// all groups except the last one seen are complete. Emit them and keep
+    /// only the last group, which may continue in the next batch.
+    fn emit_completed_groups(&mut self) -> Result<Option<RecordBatch>> {
+        let num_groups = self.row_aggr_state.group_states.len();
+        if num_groups <= 1 {
+            return Ok(None);
+        }
+
+        let batch = self.create_batch_from_groups(
+            0..num_groups - 1,
+            self.mode,
+            &self.schema,
+            &self.indices,
+        )?;
+
+        let RowAggregationState {
+            reservation,
+            map,
+            group_states,
+        } = &mut self.row_aggr_state;
+        group_states.drain(0..num_groups - 1);
+
+        // Re-register the remaining group, which now lives at index 0
+        let last_group = &group_states[0];
+        let group_values = self
+            .row_converter
+            .convert_rows([last_group.group_by_values.row()])?;
+        let mut hashes = vec![0; 1];
+        create_hashes(&group_values, &self.random_state, &mut hashes)?;
+        map.clear();
+        map.insert(hashes[0], (hashes[0], 0), |(hash, _group_index)| *hash);
+
+        reservation.resize(
+            last_group.allocated_size()
+                + std::mem::size_of::<RowGroupState>() * group_states.capacity()
+                + std::mem::size_of::<(u64, usize)>() * map.capacity(),
+        );
+
+        Ok(Some(batch))
     }
 }
 
@@ -0,0 +1,519 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for reading Arrow IPC files
+use crate::datasource::file_format::arrow::fetch_footer;
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::file_format::file_stream::{
+    FileOpenFuture, FileOpener, FileStream,
+};
+use crate::physical_plan::file_format::FileMeta;
+use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::physical_plan::{
+    DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+};
+use arrow::buffer::Buffer;
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::ipc::convert::fb_to_schema;
+use arrow::ipc::reader::{read_dictionary, read_record_batch, FileReader};
+use arrow::ipc::{root_as_footer, root_as_message, Block, Message};
+
+use futures::StreamExt;
+use object_store::{GetResult, ObjectStore};
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use super::{get_output_ordering, FileScanConfig};
+
+/// Execution plan for scanning Arrow IPC files
+#[derive(Debug, Clone)]
+pub struct ArrowExec {
+    base_config: FileScanConfig,
+    projected_statistics: Statistics,
+    projected_schema: SchemaRef,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl ArrowExec {
+    /// Create a new Arrow reader execution plan provided base configurations
+    pub fn new(base_config: FileScanConfig) -> Self {
+        let (projected_schema, projected_statistics) = base_config.project();
+
+        Self {
+            base_config,
+            projected_schema,
+            projected_statistics,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Ref to the base configs
+    pub fn base_config(&self) -> &FileScanConfig {
+        &self.base_config
+    }
+
+    /// Redistribute files across partitions according to their size
+    pub fn get_repartitioned(
+        &self,
+        target_partitions: usize,
+        repartition_file_min_size: usize,
+    ) -> Self {
+        let mut new_arrow_exec = self.clone();
+        if let Some(file_groups) = self
+            .base_config
+            .repartition_file_groups(target_partitions, repartition_file_min_size)
+        {
+            new_arrow_exec.base_config.file_groups = file_groups;
+        }
+        new_arrow_exec
+    }
+}
+
+impl ExecutionPlan for ArrowExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.projected_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(self.base_config.file_groups.len())
+    }
+
+    fn unbounded_output(&self, _: &[bool]) -> Result<bool> {
+        Ok(self.base_config.infinite_source)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        get_output_ordering(&self.base_config)
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        Vec::new()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.base_config.object_store_url)?;
+        let opener = ArrowOpener {
+            object_store,
+            projection: self.base_config.file_column_projection_indices(),
+        };
+
+        let stream =
+            FileStream::new(&self.base_config, partition, opener, &self.metrics)?;
+
+        Ok(Box::pin(stream) as SendableRecordBatchStream)
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(
+                    f,
+                    "ArrowExec: limit={:?}, files={}",
+                    self.base_config.limit,
+                    super::FileGroupsDisplay(&self.base_config.file_groups),
+                )
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        self.projected_statistics.clone()
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Opens Arrow IPC files, yielding the record batches they contain.
+///
+/// If the file comes with a [`FileRange`](crate::datasource::listing::FileRange),
+/// only the record batches whose block starts within that range are fetched
+/// (along with the dictionaries of the file), so that a file split into several
+/// ranges yields every batch exactly once.
+struct ArrowOpener {
+    object_store: Arc<dyn ObjectStore>,
+    projection: Option<Vec<usize>>,
+}
+
+impl FileOpener for ArrowOpener {
+    fn open(&self, file_meta: FileMeta) -> Result<FileOpenFuture> {
+        let store = self.object_store.clone();
+        let projection = self.projection.clone();
+        Ok(Box::pin(async move {
+            let range = match &file_meta.range {
+                None => {
+                    return match store.get(file_meta.location()).await? {
+                        GetResult::File(file, _) => {
+                            let reader = FileReader::try_new(file, projection)?;
+                            Ok(futures::stream::iter(reader).boxed())
+                        }
+                        r @ GetResult::Stream(_) => {
+                            let bytes = r.bytes().await?;
+                            let reader =
+                                FileReader::try_new(Cursor::new(bytes), projection)?;
+                            Ok(futures::stream::iter(reader).boxed())
+                        }
+                    };
+                }
+                Some(range) => range.clone(),
+            };
+
+            let invalid = |msg: &str| {
+                DataFusionError::Execution(format!(
+                    "Unable to read Arrow IPC file {}: {msg}",
+                    file_meta.location()
+                ))
+            };
+
+            let footer = fetch_footer(store.as_ref(), &file_meta.object_meta).await?;
+            let footer = root_as_footer(&footer).map_err(|e| invalid(&e.to_string()))?;
+            let schema = Arc::new(fb_to_schema(
+                footer.schema().ok_or_else(|| invalid("missing schema"))?,
+            ));
+
+            let block_range = |block: &Block| {
+                let start = block.offset() as usize;
+                start
+                    ..start
+                        + block.metaDataLength() as usize
+                        + block.bodyLength() as usize
+            };
+            let dictionary_ranges = footer
+                .dictionaries()
+                .map(|blocks| blocks.iter().map(block_range).collect::<Vec<_>>())
+                .unwrap_or_default();
+            let batch_ranges = footer
+                .recordBatches()
+                .map(|blocks| {
+                    blocks
+                        .iter()
+                        .filter(|block| {
+                            (range.start..range.end).contains(&block.offset())
+                        })
+                        .map(block_range)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            if batch_ranges.is_empty() {
+                return Ok(futures::stream::empty().boxed());
+            }
+
+            let ranges = dictionary_ranges
+                .iter()
+                .chain(&batch_ranges)
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut blocks = store.get_ranges(file_meta.location(), &ranges).await?;
+            let batch_blocks = blocks.split_off(dictionary_ranges.len());
+
+            let mut dictionaries_by_id = HashMap::new();
+            for block in blocks {
+                let (message, body) = read_block(&block)?;
+                let dictionary = message
+                    .header_as_dictionary_batch()
+                    .ok_or_else(|| invalid("expected a dictionary batch"))?;
+                read_dictionary(
+                    &body,
+                    dictionary,
+                    &schema,
+                    &mut dictionaries_by_id,
+                    &message.version(),
+                )?;
+            }
+
+            let location = file_meta.location().clone();
+            Ok(futures::stream::iter(batch_blocks.into_iter().map(move |block| {
+                let (message, body) = read_block(&block)?;
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(format!(
+                        "Unable to read Arrow IPC file {location}: expected a record batch"
+                    ))
+                })?;
+                read_record_batch(
+                    &body,
+                    batch,
+                    schema.clone(),
+                    &dictionaries_by_id,
+                    projection.as_deref(),
+                    &message.version(),
+                )
+            }))
+            .boxed())
+        }))
+    }
+}
+
+/// Marker preceding the length of the messages of an Arrow IPC file
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// Splits an Arrow IPC file block into its message header and its body
+fn read_block(block: &[u8]) -> Result<(Message<'_>, Buffer), ArrowError> {
+    // the message is prefixed by its length, itself preceded by a continuation
+    // marker in files written since version 0.15
+    let prefix_len = if block.starts_with(&CONTINUATION_MARKER) {
+        8
+    } else {
+        4
+    };
+    if block.len() < prefix_len {
+        return Err(ArrowError::IoError("Truncated Arrow IPC block".to_string()));
+    }
+    let message_len =
+        i32::from_le_bytes(block[prefix_len - 4..prefix_len].try_into().unwrap());
+    let message_end = usize::try_from(message_len)
+        .ok()
+        .map(|len| prefix_len + len)
+        .filter(|end| *end <= block.len())
+        .ok_or_else(|| {
+            ArrowError::IoError("Invalid Arrow IPC message length".to_string())
+        })?;
+    let message = root_as_message(&block[prefix_len..message_end]).map_err(|e| {
+        ArrowError::IoError(format!("Unable to read Arrow IPC message: {e}"))
+    })?;
+    // the body follows the padded message header and ends the block
+    let body_start = usize::try_from(message.bodyLength())
+        .ok()
+        .and_then(|body_len| block.len().checked_sub(body_len))
+        .filter(|start| *start >= message_end)
+        .ok_or_else(|| {
+            ArrowError::IoError("Invalid Arrow IPC body length".to_string())
+        })?;
+    Ok((message, Buffer::from(&block[body_start..])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigOptions;
+    use crate::datasource::file_format::{arrow::ArrowFormat, FileFormat};
+    use crate::datasource::listing::{FileRange, PartitionedFile};
+    use crate::datasource::object_store::ObjectStoreUrl;
+    use crate::physical_optimizer::optimizer::PhysicalOptimizerRule;
+    use crate::physical_optimizer::repartition::Repartition;
+    use crate::physical_plan::collect;
+    use crate::physical_plan::expressions::lit;
+    use crate::physical_plan::filter::FilterExec;
+    use crate::prelude::SessionContext;
+    use crate::test::object_store::local_unpartitioned_file;
+    use arrow::array::{DictionaryArray, Int32Array};
+    use arrow::compute::cast;
+    use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+    use arrow::ipc::writer::FileWriter;
+    use arrow::record_batch::RecordBatch;
+    use datafusion_common::cast::{as_int32_array, as_string_array};
+    use object_store::local::LocalFileSystem;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn arrow_exec_with_file_ranges() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("test.arrow");
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("id", DataType::Int32, false)]));
+        let mut writer = FileWriter::try_new(std::fs::File::create(&path)?, &schema)?;
+        for i in 0..10 {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int32Array::from(vec![i; 100]))],
+            )?;
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file(&path);
+        let file_schema = ArrowFormat {}
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        // split the file in three ranges, each scanned by its own partition
+        let size = meta.size as i64;
+        let file_groups = [
+            (0, size / 3),
+            (size / 3, 2 * size / 3),
+            (2 * size / 3, size),
+        ]
+        .into_iter()
+        .map(|(start, end)| {
+            vec![PartitionedFile {
+                object_meta: meta.clone(),
+                partition_values: vec![],
+                range: Some(FileRange { start, end }),
+                extensions: None,
+            }]
+        })
+        .collect::<Vec<_>>();
+
+        let exec = Arc::new(ArrowExec::new(FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema,
+            file_groups,
+            statistics: Statistics::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        }));
+        assert_eq!(exec.output_partitioning().partition_count(), 3);
+
+        // every batch is read exactly once across the partitions
+        let mut ids = collect(exec, task_ctx)
+            .await?
+            .iter()
+            .map(|batch| {
+                assert_eq!(batch.num_rows(), 100);
+                as_int32_array(batch.column(0)).unwrap().value(0)
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert_eq!(ids, (0..10).collect::<Vec<_>>());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn arrow_exec_repartitioned() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("test.arrow");
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new(
+                "name",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false,
+            ),
+        ]));
+        let names = ["a", "b", "c"];
+        let mut writer = FileWriter::try_new(std::fs::File::create(&path)?, &schema)?;
+        for i in 0..10 {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(vec![i; 100])),
+                    Arc::new(
+                        (0..100)
+                            .map(|j| names[j % names.len()])
+                            .collect::<DictionaryArray<Int32Type>>(),
+                    ),
+                ],
+            )?;
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+
+        let session_ctx = SessionContext::new();
+        let state = session_ctx.state();
+        let task_ctx = state.task_ctx();
+        let store = Arc::new(LocalFileSystem::new()) as _;
+        let meta = local_unpartitioned_file(&path);
+        let file_schema = ArrowFormat {}
+            .infer_schema(&state, &store, &[meta.clone()])
+            .await?;
+
+        let exec = Arc::new(ArrowExec::new(FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema,
+            file_groups: vec![vec![PartitionedFile {
+                object_meta: meta,
+                partition_values: vec![],
+                range: None,
+                extensions: None,
+            }]],
+            statistics: Statistics::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        }));
+        let plan = Arc::new(FilterExec::try_new(lit(true), exec)?);
+
+        let mut config = ConfigOptions::new();
+        config.execution.target_partitions = 3;
+        config.optimizer.repartition_file_scans = true;
+        config.optimizer.repartition_file_min_size = 10;
+        let optimized = Repartition::new().optimize(plan, &config)?;
+
+        // the file is split in ranges, each scanned by its own partition
+        let arrow_exec = optimized.children()[0]
+            .as_any()
+            .downcast_ref::<ArrowExec>()
+            .expect("ArrowExec")
+            .clone();
+        assert_eq!(arrow_exec.output_partitioning().partition_count(), 3);
+        assert!(arrow_exec
+            .base_config()
+            .file_groups
+            .iter()
+            .flatten()
+            .all(|file| file.range.is_some()));
+
+        // every batch is read exactly once across the partitions
+        let batches = collect(optimized, task_ctx).await?;
+        let mut ids = batches
+            .iter()
+            .map(|batch| {
+                assert_eq!(batch.num_rows(), 100);
+                as_int32_array(batch.column(0)).unwrap().value(0)
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert_eq!(ids, (0..10).collect::<Vec<_>>());
+
+        // dictionaries are read along with the selected batches
+        let names = cast(batches[0].column(1), &DataType::Utf8)?;
+        let names = as_string_array(&names)?;
+        assert_eq!(
+            (0..3).map(|j| names.value(j)).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        Ok(())
+    }
+}
@@ -29,8 +29,14 @@ use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use crate::physical_plan::{
     DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
 };
+use arrow::compute::kernels::boolean::or_kleene;
+use arrow::compute::kernels::comparison::eq_utf8_scalar;
+use arrow::compute::{cast_with_options, nullif, CastOptions};
 use arrow::csv;
-use arrow::datatypes::SchemaRef;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::cast::as_string_array;
 
 use bytes::Buf;
 
@@ -56,6 +62,10 @@ pub struct CsvExec {
     projected_schema: SchemaRef,
     has_header: bool,
     delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    comment: Option<u8>,
+    null_value: Option<String>,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
     file_compression_type: FileCompressionType,
@@ -77,11 +87,39 @@ impl CsvExec {
             projected_statistics,
             has_header,
             delimiter,
+            quote: b'"',
+            escape: None,
+            comment: None,
+            null_value: None,
             metrics: ExecutionPlanMetricsSet::new(),
             file_compression_type,
         }
     }
 
+    /// Set the quote character, defaults to '"'
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Set the escape character within quoted values
+    pub fn with_escape(mut self, escape: Option<u8>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Set the character starting the comment lines to skip
+    pub fn with_comment(mut self, comment: Option<u8>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Set the string read as null
+    pub fn with_null_value(mut self, null_value: Option<String>) -> Self {
+        self.null_value = null_value;
+        self
+    }
+
     /// Ref to the base configs
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
@@ -94,6 +132,26 @@ impl CsvExec {
     pub fn delimiter(&self) -> u8 {
         self.delimiter
     }
+    /// The quote character
+    pub fn quote(&self) -> u8 {
+        self.quote
+    }
+    /// The escape character within quoted values
+    pub fn escape(&self) -> Option<u8> {
+        self.escape
+    }
+    /// The character starting the comment lines to skip
+    pub fn comment(&self) -> Option<u8> {
+        self.comment
+    }
+    /// The string read as null
+    pub fn null_value(&self) -> Option<&str> {
+        self.null_value.as_deref()
+    }
+    /// Compression type of the scanned files
+    pub fn file_compression_type(&self) -> &FileCompressionType {
+        &self.file_compression_type
+    }
 }
 
 impl ExecutionPlan for CsvExec {
@@ -148,6 +206,10 @@ impl ExecutionPlan for CsvExec {
             file_projection: self.base_config.file_column_projection_indices(),
             has_header: self.has_header,
             delimiter: self.delimiter,
+            quote: self.quote,
+            escape: self.escape,
+            comment: self.comment,
+            null_value: self.null_value.clone(),
             object_store,
         });
 
@@ -195,37 +257,187 @@ struct CsvConfig {
     file_projection: Option<Vec<usize>>,
     has_header: bool,
     delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    comment: Option<u8>,
+    null_value: Option<String>,
     object_store: Arc<dyn ObjectStore>,
 }
 
 impl CsvConfig {
-    fn open<R: std::io::Read>(&self, reader: R) -> csv::Reader<R> {
-        let datetime_format = None;
-        csv::Reader::new(
-            reader,
-            Arc::clone(&self.file_schema),
-            self.has_header,
-            Some(self.delimiter),
-            self.batch_size,
-            None,
-            self.file_projection.clone(),
-            datetime_format,
-        )
+    fn open<R: std::io::Read>(&self, reader: R) -> Result<csv::Reader<R>> {
+        Ok(self.builder().build(reader)?)
     }
 
     fn builder(&self) -> csv::ReaderBuilder {
         let mut builder = csv::ReaderBuilder::new()
-            .with_schema(self.file_schema.clone())
+            .with_schema(self.read_schema())
             .with_delimiter(self.delimiter)
+            .with_quote(self.quote)
             .with_batch_size(self.batch_size)
             .has_header(self.has_header);
 
+        if let Some(escape) = self.escape {
+            builder = builder.with_escape(escape);
+        }
         if let Some(proj) = &self.file_projection {
             builder = builder.with_projection(proj.clone());
         }
 
         builder
     }
+
+    /// The schema the files are decoded with. When a null value is set,
+    /// all columns are decoded as strings, to be converted to the file
+    /// schema by [`Self::convert_batch`].
+    fn read_schema(&self) -> SchemaRef {
+        match self.null_value {
+            Some(_) => Arc::new(Schema::new(
+                self.file_schema
+                    .fields()
+                    .iter()
+                    .map(|f| Field::new(f.name(), DataType::Utf8, true))
+                    .collect(),
+            )),
+            None => Arc::clone(&self.file_schema),
+        }
+    }
+
+    /// Converts a batch decoded with [`Self::read_schema`] to the
+    /// (projected) file schema, reading the null values as nulls
+    fn convert_batch(
+        &self,
+        batch: RecordBatch,
+    ) -> std::result::Result<RecordBatch, ArrowError> {
+        let null_value = match &self.null_value {
+            Some(null_value) => null_value,
+            None => return Ok(batch),
+        };
+        let schema = match &self.file_projection {
+            Some(proj) => Arc::new(self.file_schema.project(proj)?),
+            None => Arc::clone(&self.file_schema),
+        };
+
+        let cast_options = CastOptions { safe: false };
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(column, field)| {
+                let strings = as_string_array(column)
+                    .map_err(|e| ArrowError::CastError(e.to_string()))?;
+                // empty values are null too, as when no null value is set
+                let is_null = or_kleene(
+                    &eq_utf8_scalar(strings, null_value)?,
+                    &eq_utf8_scalar(strings, "")?,
+                )?;
+                let column = nullif(column, &is_null)?;
+                cast_with_options(&column, field.data_type(), &cast_options)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        RecordBatch::try_new(schema, columns)
+    }
+}
+
+/// Removes the lines starting with a comment character from CSV data
+/// that is processed in chunks, which may split lines at any position.
+///
+/// Lines of quoted values spanning several lines are not comments, even if
+/// they start with the comment character. As when parsing CSV, quotes
+/// only start a quoted value at the start of a field.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CommentFilter {
+    comment: u8,
+    delimiter: u8,
+    quote: u8,
+    escape: Option<u8>,
+    line_start: bool,
+    field_start: bool,
+    in_comment: bool,
+    quoted: bool,
+    quote_closed: bool,
+    escaped: bool,
+}
+
+impl CommentFilter {
+    pub(crate) fn new(comment: u8, delimiter: u8, quote: u8, escape: Option<u8>) -> Self {
+        Self {
+            comment,
+            delimiter,
+            quote,
+            escape,
+            line_start: true,
+            field_start: true,
+            in_comment: false,
+            quoted: false,
+            quote_closed: false,
+            escaped: false,
+        }
+    }
+
+    /// Removes the commented bytes of the next chunk `buf` in place,
+    /// returning the number of bytes kept at the start of `buf`
+    pub(crate) fn filter(&mut self, buf: &mut [u8]) -> usize {
+        let mut kept = 0;
+        for i in 0..buf.len() {
+            let b = buf[i];
+            if self.line_start && !self.quoted && b == self.comment {
+                self.in_comment = true;
+            }
+            self.line_start = b == b'\n';
+            if self.in_comment {
+                self.in_comment = b != b'\n';
+                self.field_start = true;
+                continue;
+            }
+            let quote_closed = std::mem::take(&mut self.quote_closed);
+            if self.escaped {
+                self.escaped = false;
+            } else if self.quoted {
+                if Some(b) == self.escape && b != self.quote {
+                    self.escaped = true;
+                } else if b == self.quote {
+                    self.quoted = false;
+                    self.quote_closed = true;
+                }
+            } else {
+                // doubled quotes close and reopen the quoted value
+                self.quoted = b == self.quote && (self.field_start || quote_closed);
+            }
+            self.field_start = !self.quoted && (b == self.delimiter || b == b'\n');
+            buf[kept] = b;
+            kept += 1;
+        }
+        kept
+    }
+
+    fn filter_bytes(&mut self, bytes: Bytes) -> Bytes {
+        let mut buf = bytes.to_vec();
+        let len = self.filter(&mut buf);
+        buf.truncate(len);
+        buf.into()
+    }
+}
+
+/// A reader skipping the comment lines of the CSV data of its input
+struct CommentFilterReader<R> {
+    input: R,
+    filter: CommentFilter,
+}
+
+impl<R: std::io::Read> std::io::Read for CommentFilterReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let read = self.input.read(buf)?;
+            if read == 0 {
+                return Ok(0);
+            }
+            let kept = self.filter.filter(&mut buf[..read]);
+            if kept > 0 {
+                return Ok(kept);
+            }
+        }
+    }
 }
 
 struct CsvOpener {
@@ -238,22 +450,51 @@ impl FileOpener for CsvOpener {
         let config = self.config.clone();
         let file_compression_type = self.file_compression_type.to_owned();
         Ok(Box::pin(async move {
-            match config.object_store.get(file_meta.location()).await? {
+            let stream = match config.object_store.get(file_meta.location()).await? {
                 GetResult::File(file, _) => {
                     let decoder = file_compression_type.convert_read(file)?;
-                    Ok(futures::stream::iter(config.open(decoder)).boxed())
+                    let reader = match config.comment {
+                        Some(comment) => config.open(CommentFilterReader {
+                            input: decoder,
+                            filter: CommentFilter::new(
+                                comment,
+                                config.delimiter,
+                                config.quote,
+                                config.escape,
+                            ),
+                        })?,
+                        None => config.open(decoder)?,
+                    };
+                    futures::stream::iter(reader).boxed()
                 }
                 GetResult::Stream(s) => {
                     let mut decoder = config.builder().build_decoder();
                     let s = s.map_err(DataFusionError::from);
                     let mut input = file_compression_type.convert_stream(s)?.fuse();
+                    let mut comment_filter = config.comment.map(|comment| {
+                        CommentFilter::new(
+                            comment,
+                            config.delimiter,
+                            config.quote,
+                            config.escape,
+                        )
+                    });
                     let mut buffered = Bytes::new();
 
                     let s = futures::stream::poll_fn(move |cx| {
                         loop {
                             if buffered.is_empty() {
                                 match ready!(input.poll_next_unpin(cx)) {
-                                    Some(Ok(b)) => buffered = b,
+                                    Some(Ok(b)) => {
+                                        buffered = match &mut comment_filter {
+                                            Some(filter) => filter.filter_bytes(b),
+                                            None => b,
+                                        };
+                                        // the chunk only contained comments
+                                        if buffered.is_empty() {
+                                            continue;
+                                        }
+                                    }
                                     Some(Err(e)) => {
                                         return Poll::Ready(Some(Err(e.into())))
                                     }
@@ -272,9 +513,12 @@ impl FileOpener for CsvOpener {
 
                         Poll::Ready(decoder.flush().transpose())
                     });
-                    Ok(s.boxed())
+                    s.boxed()
                 }
-            }
+            };
+            Ok(stream
+                .map(move |batch| batch.and_then(|batch| config.convert_batch(batch)))
+                .boxed())
         }))
     }
 }
@@ -339,6 +583,33 @@ mod tests {
     use tempfile::TempDir;
     use url::Url;
 
+    #[test]
+    fn comment_filter_honors_quotes() {
+        let data = b"# comment\na,'x\n# quoted',b\n#c\n'it\\'s\n#x'\n";
+        let expected = "a,'x\n# quoted',b\n'it\\'s\n#x'\n";
+        // the data is filtered in chunks splitting the lines anywhere
+        for chunk_size in [1, 3, data.len()] {
+            let mut filter = CommentFilter::new(b'#', b',', b'\'', Some(b'\\'));
+            let mut filtered = vec![];
+            for chunk in data.chunks(chunk_size) {
+                let mut chunk = chunk.to_vec();
+                let len = filter.filter(&mut chunk);
+                filtered.extend_from_slice(&chunk[..len]);
+            }
+            assert_eq!(expected, std::str::from_utf8(&filtered).unwrap());
+        }
+    }
+
+    #[test]
+    fn comment_filter_ignores_quotes_within_fields() {
+        let data = b"a,b'c\n#x\n'd'',#',e\n#y\n";
+        let expected = "a,b'c\n'd'',#',e\n";
+        let mut filter = CommentFilter::new(b'#', b',', b'\'', None);
+        let mut filtered = data.to_vec();
+        let len = filter.filter(&mut filtered);
+        assert_eq!(expected, std::str::from_utf8(&filtered[..len]).unwrap());
+    }
+
     #[rstest(
         file_compression_type,
         case(FileCompressionType::UNCOMPRESSED),
@@ -816,6 +1087,69 @@ mod tests {
         Ok(())
     }
 
+    #[rstest(
+        file_compression_type,
+        case(FileCompressionType::UNCOMPRESSED),
+        case(FileCompressionType::GZIP),
+        case(FileCompressionType::BZIP2),
+        case(FileCompressionType::XZ),
+        case(FileCompressionType::ZSTD)
+    )]
+    #[tokio::test]
+    async fn write_csv_results_with_options(
+        file_compression_type: FileCompressionType,
+    ) -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx =
+            SessionContext::with_config(SessionConfig::new().with_target_partitions(8));
+        let schema = populate_csv_partitions(&tmp_dir, 8, ".csv")?;
+        ctx.register_csv(
+            "test",
+            tmp_dir.path().to_str().unwrap(),
+            CsvReadOptions::new().schema(&schema),
+        )
+        .await?;
+
+        // write the results compressed and partitioned by `odd`
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        let df = ctx.sql("SELECT c1 % 2 AS odd, c2 FROM test").await?;
+        let options = CsvWriteOptions::new()
+            .file_compression_type(file_compression_type.clone())
+            .partition_by(vec!["odd".to_string()]);
+        df.write_csv_with_options(&out_dir, options).await?;
+        assert!(Path::new(&out_dir).join("odd=0").is_dir());
+        assert!(Path::new(&out_dir).join("odd=1").is_dir());
+
+        // read the files back, with `odd` from the directory names
+        let ctx = SessionContext::new();
+        let schema = Schema::new(vec![Field::new("c2", DataType::UInt64, false)]);
+        let file_extension =
+            FileType::CSV.get_ext_with_compression(file_compression_type.clone())?;
+        let options = CsvReadOptions::new()
+            .schema(&schema)
+            .file_extension(&file_extension)
+            .file_compression_type(file_compression_type)
+            .table_partition_cols(vec![("odd".to_string(), DataType::Utf8)]);
+        ctx.register_csv("out", &out_dir, options).await?;
+
+        let result = ctx
+            .sql("SELECT odd, count(*) AS cnt, sum(c2) AS total FROM out GROUP BY odd ORDER BY odd")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+-----+-----+-------+",
+            "| odd | cnt | total |",
+            "+-----+-----+-------+",
+            "| 0   | 44  | 220   |",
+            "| 1   | 44  | 220   |",
+            "+-----+-----+-------+",
+        ];
+        crate::assert_batches_eq!(expected, &result);
+
+        Ok(())
+    }
+
     fn get_value(metrics: &MetricsSet, metric_name: &str) -> usize {
         match metrics.sum_by_name(metric_name) {
             Some(v) => v.as_usize(),
@@ -78,6 +78,11 @@ impl NdJsonExec {
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
     }
+
+    /// Compression type of the scanned files
+    pub fn file_compression_type(&self) -> &FileCompressionType {
+        &self.file_compression_type
+    }
 }
 
 impl ExecutionPlan for NdJsonExec {
@@ -271,7 +276,7 @@ pub async fn plan_to_json(
 #[cfg(test)]
 mod tests {
     use arrow::array::Array;
-    use arrow::datatypes::{Field, Schema};
+    use arrow::datatypes::{DataType, Field, Schema};
     use futures::StreamExt;
     use object_store::local::LocalFileSystem;
 
@@ -396,7 +401,6 @@ mod tests {
         let session_ctx = SessionContext::new();
         let state = session_ctx.state();
         let task_ctx = session_ctx.task_ctx();
-        use arrow::datatypes::DataType;
 
         let (object_store_url, file_groups, file_schema) =
             prepare_store(&state, file_compression_type.to_owned()).await;
@@ -661,6 +665,63 @@ mod tests {
         Ok(())
     }
 
+    #[rstest(
+        file_compression_type,
+        case(FileCompressionType::UNCOMPRESSED),
+        case(FileCompressionType::GZIP),
+        case(FileCompressionType::BZIP2),
+        case(FileCompressionType::XZ),
+        case(FileCompressionType::ZSTD)
+    )]
+    #[tokio::test]
+    async fn write_json_results_with_options(
+        file_compression_type: FileCompressionType,
+    ) -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = SessionContext::new();
+        let path = format!("{TEST_DATA_BASE}/1.json");
+        ctx.register_json("test", path.as_str(), NdJsonReadOptions::default())
+            .await?;
+
+        // write the results compressed and partitioned by `positive`
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        let df = ctx
+            .sql("SELECT a, d, a > 0 AS positive FROM test WHERE a IS NOT NULL")
+            .await?;
+        let options = NdJsonWriteOptions::new()
+            .file_compression_type(file_compression_type.clone())
+            .partition_by(vec!["positive".to_string()]);
+        df.write_json_with_options(&out_dir, options).await?;
+
+        // read the files back, with `positive` from the directory names
+        let ctx = SessionContext::new();
+        let file_extension =
+            FileType::JSON.get_ext_with_compression(file_compression_type.clone())?;
+        let options = NdJsonReadOptions::default()
+            .file_extension(&file_extension)
+            .file_compression_type(file_compression_type)
+            .table_partition_cols(vec![("positive".to_string(), DataType::Utf8)]);
+        ctx.register_json("out", &out_dir, options).await?;
+
+        let result = ctx
+            .sql("SELECT a, d, positive FROM out ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+        let expected = vec![
+            "+-----+------+----------+",
+            "| a   | d    | positive |",
+            "+-----+------+----------+",
+            "| -10 | 4    | false    |",
+            "| 1   | 4    | true     |",
+            "| 2   | text | true     |",
+            "+-----+------+----------+",
+        ];
+        assert_batches_eq!(expected, &result);
+
+        Ok(())
+    }
+
     #[rstest(
         file_compression_type,
         case(FileCompressionType::UNCOMPRESSED),
@@ -17,6 +17,7 @@
 
 //! Execution plans that read file formats
 
+mod arrow_file;
 mod avro;
 #[cfg(test)]
 mod chunked_store;
@@ -24,22 +25,28 @@ mod csv;
 mod file_stream;
 mod json;
 mod parquet;
+mod sink;
 
-pub(crate) use self::csv::plan_to_csv;
 pub use self::csv::CsvExec;
-pub(crate) use self::parquet::plan_to_parquet;
-pub use self::parquet::{ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory};
+pub(crate) use self::csv::{plan_to_csv, CommentFilter};
+pub(crate) use self::parquet::{plan_to_parquet, SortOrderValidator};
+pub use self::parquet::{
+    ParquetExec, ParquetFileMetrics, ParquetFileReaderFactory, ParquetSortingColumn,
+    ParquetWriterOptions,
+};
 use arrow::{
     array::{ArrayData, ArrayRef, BufferBuilder, DictionaryArray},
     buffer::Buffer,
     datatypes::{ArrowNativeType, DataType, Field, Schema, SchemaRef, UInt16Type},
     record_batch::RecordBatch,
 };
+pub use arrow_file::ArrowExec;
 pub use avro::AvroExec;
 use datafusion_physical_expr::PhysicalSortExpr;
 pub use file_stream::{FileOpenFuture, FileOpener, FileStream};
 pub(crate) use json::plan_to_json;
 pub use json::NdJsonExec;
+pub use sink::FileSinkExec;
 
 use crate::datasource::{
     listing::{FileRange, PartitionedFile},
@@ -53,11 +60,13 @@ use crate::{
 use arrow::array::new_null_array;
 use arrow::record_batch::RecordBatchOptions;
 use datafusion_common::tree_node::{TreeNode, VisitRecursion};
+use itertools::Itertools;
 use log::{debug, info, warn};
 use object_store::path::Path;
 use object_store::ObjectMeta;
 use std::{
     borrow::Cow,
+    cmp::min,
     collections::HashMap,
     fmt::{Display, Formatter, Result as FmtResult},
     marker::PhantomData,
@@ -100,6 +109,8 @@ pub fn get_scan_files(
                 parquet_exec.base_config().file_groups.clone()
             } else if let Some(avro_exec) = plan_any.downcast_ref::<AvroExec>() {
                 avro_exec.base_config().file_groups.clone()
+            } else if let Some(arrow_exec) = plan_any.downcast_ref::<ArrowExec>() {
+                arrow_exec.base_config().file_groups.clone()
             } else if let Some(json_exec) = plan_any.downcast_ref::<NdJsonExec>() {
                 json_exec.base_config().file_groups.clone()
             } else if let Some(csv_exec) = plan_any.downcast_ref::<CsvExec>() {
@@ -183,7 +194,7 @@ impl FileScanConfig {
                 table_fields.push(Field::new(
                     &self.table_partition_cols[partition_idx].0,
                     self.table_partition_cols[partition_idx].1.to_owned(),
-                    false,
+                    true,
                 ));
                 // TODO provide accurate stat for partition column (#1186)
                 table_cols_stats.push(ColumnStatistics::default())
@@ -224,6 +235,82 @@ impl FileScanConfig {
                 .collect()
         })
     }
+
+    /// Redistribute the files across `target_partitions` file groups according
+    /// to their size, splitting files into byte ranges where needed.
+    ///
+    /// Returns `None` if the files should be left as they are, either because
+    /// some of them are already read by range or because their total size is
+    /// below `repartition_file_min_size`.
+    fn repartition_file_groups(
+        &self,
+        target_partitions: usize,
+        repartition_file_min_size: usize,
+    ) -> Option<Vec<Vec<PartitionedFile>>> {
+        let flattened_files = self.file_groups.iter().flatten().collect::<Vec<_>>();
+
+        // Perform redistribution only in case all files should be read from beginning to end
+        let has_ranges = flattened_files.iter().any(|f| f.range.is_some());
+        if has_ranges {
+            return None;
+        }
+
+        let total_size = flattened_files
+            .iter()
+            .map(|f| f.object_meta.size as i64)
+            .sum::<i64>();
+        if total_size < (repartition_file_min_size as i64) {
+            return None;
+        }
+
+        let target_partition_size =
+            (total_size as usize + (target_partitions) - 1) / (target_partitions);
+
+        let repartitioned_files = flattened_files
+            .into_iter()
+            .scan(RepartitionState::default(), |state, source_file| {
+                let mut produced_files = vec![];
+                let mut range_start = 0;
+                while range_start < source_file.object_meta.size {
+                    let range_end = min(
+                        range_start
+                            + (target_partition_size - state.current_partition_size),
+                        source_file.object_meta.size,
+                    );
+
+                    let mut produced_file = source_file.clone();
+                    produced_file.range = Some(FileRange {
+                        start: range_start as i64,
+                        end: range_end as i64,
+                    });
+                    produced_files.push((state.current_partition_index, produced_file));
+
+                    if state.current_partition_size + (range_end - range_start)
+                        >= target_partition_size
+                    {
+                        state.current_partition_index += 1;
+                        state.current_partition_size = 0;
+                    } else {
+                        state.current_partition_size += range_end - range_start;
+                    }
+                    range_start = range_end;
+                }
+                Some(produced_files)
+            })
+            .flatten()
+            .group_by(|(partition_idx, _)| *partition_idx)
+            .into_iter()
+            .map(|(_, group)| group.map(|(_, vals)| vals).collect_vec())
+            .collect_vec();
+
+        Some(repartitioned_files)
+    }
+}
+
+#[derive(Default)]
+struct RepartitionState {
+    current_partition_index: usize,
+    current_partition_size: usize,
 }
 
 /// A wrapper to customize partitioned file display
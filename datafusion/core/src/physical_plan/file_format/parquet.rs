@@ -18,10 +18,13 @@
 //! Execution plan for reading Parquet files
 
 use arrow::datatypes::{DataType, SchemaRef};
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{
+    has_dynamic_filter, snapshot_dynamic_filters, BinaryExpr,
+};
 use datafusion_physical_expr::PhysicalExpr;
 use fmt::Debug;
 use std::any::Any;
-use std::cmp::min;
 use std::fmt;
 use std::fs;
 use std::ops::Range;
@@ -34,7 +37,6 @@ use crate::physical_plan::file_format::file_stream::{
 };
 use crate::physical_plan::file_format::FileMeta;
 use crate::{
-    datasource::listing::FileRange,
     error::{DataFusionError, Result},
     execution::context::TaskContext,
     physical_optimizer::pruning::PruningPredicate,
@@ -50,7 +52,6 @@ use arrow::error::ArrowError;
 use bytes::Bytes;
 use futures::future::BoxFuture;
 use futures::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
-use itertools::Itertools;
 use log::debug;
 use object_store::{ObjectMeta, ObjectStore};
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
@@ -65,19 +66,17 @@ mod metrics;
 mod page_filter;
 mod row_filter;
 mod row_groups;
+mod writer;
 
 use crate::physical_plan::common::AbortOnDropSingle;
 use crate::physical_plan::file_format::parquet::page_filter::PagePruningPredicate;
+use crate::physical_plan::file_format::parquet::row_groups::BloomFilterPredicate;
 pub use metrics::ParquetFileMetrics;
+pub(crate) use writer::SortOrderValidator;
+pub use writer::{ParquetSortingColumn, ParquetWriterOptions};
 
 use super::get_output_ordering;
 
-#[derive(Default)]
-struct RepartitionState {
-    current_partition_index: usize,
-    current_partition_size: usize,
-}
-
 /// Execution plan for scanning one or more Parquet partitions
 #[derive(Debug, Clone)]
 pub struct ParquetExec {
@@ -90,6 +89,9 @@ pub struct ParquetExec {
     /// Override for `Self::with_enable_page_index`. If None, uses
     /// values from base_config
     enable_page_index: Option<bool>,
+    /// Override for `Self::with_enable_bloom_filter`. If None, uses
+    /// values from base_config
+    enable_bloom_filter: Option<bool>,
     /// Base configuraton for this scan
     base_config: FileScanConfig,
     projected_statistics: Statistics,
@@ -102,6 +104,8 @@ pub struct ParquetExec {
     pruning_predicate: Option<Arc<PruningPredicate>>,
     /// Optional predicate for pruning pages
     page_pruning_predicate: Option<Arc<PagePruningPredicate>>,
+    /// Optional predicate for pruning row groups using bloom filters
+    bloom_filter_predicate: Option<Arc<BloomFilterPredicate>>,
     /// Optional hint for the size of the parquet metadata
     metadata_size_hint: Option<usize>,
     /// Optional user defined parquet file reader factory
@@ -151,12 +155,18 @@ impl ParquetExec {
             }
         });
 
+        let bloom_filter_predicate = predicate
+            .as_ref()
+            .and_then(BloomFilterPredicate::try_new)
+            .map(Arc::new);
+
         let (projected_schema, projected_statistics) = base_config.project();
 
         Self {
             pushdown_filters: None,
             reorder_filters: None,
             enable_page_index: None,
+            enable_bloom_filter: None,
             base_config,
             projected_schema,
             projected_statistics,
@@ -164,6 +174,7 @@ impl ParquetExec {
             predicate,
             pruning_predicate,
             page_pruning_predicate,
+            bloom_filter_predicate,
             metadata_size_hint,
             parquet_file_reader_factory: None,
         }
@@ -184,6 +195,33 @@ impl ParquetExec {
         self.pruning_predicate.as_ref()
     }
 
+    /// Returns a copy of this scan that also applies `predicate`, ANDed
+    /// with its existing predicate, keeping all other settings.
+    ///
+    /// The predicate may contain [`DynamicFilterExpr`]s, which are
+    /// resolved each time a file is opened.
+    ///
+    /// [`DynamicFilterExpr`]: datafusion_physical_expr::expressions::DynamicFilterExpr
+    pub fn with_additional_predicate(&self, predicate: Arc<dyn PhysicalExpr>) -> Self {
+        let predicate = match &self.predicate {
+            Some(existing) => {
+                Arc::new(BinaryExpr::new(existing.clone(), Operator::And, predicate))
+            }
+            None => predicate,
+        };
+        let mut exec = Self::new(
+            self.base_config.clone(),
+            Some(predicate),
+            self.metadata_size_hint,
+        );
+        exec.pushdown_filters = self.pushdown_filters;
+        exec.reorder_filters = self.reorder_filters;
+        exec.enable_page_index = self.enable_page_index;
+        exec.enable_bloom_filter = self.enable_bloom_filter;
+        exec.parquet_file_reader_factory = self.parquet_file_reader_factory.clone();
+        exec
+    }
+
     /// Optional user defined parquet file reader factory.
     ///
     /// `ParquetFileReaderFactory` complements `TableProvider`, It enables users to provide custom
@@ -249,75 +287,33 @@ impl ParquetExec {
             .unwrap_or(config_options.execution.parquet.enable_page_index)
     }
 
+    /// If enabled, the reader will read the split block bloom filters
+    /// of columns used in `=` and `IN` predicates, and skip row groups
+    /// that the bloom filters prove contain no matching rows
+    pub fn with_enable_bloom_filter(mut self, enable_bloom_filter: bool) -> Self {
+        self.enable_bloom_filter = Some(enable_bloom_filter);
+        self
+    }
+
+    /// Return the value described in [`Self::with_enable_bloom_filter`]
+    fn enable_bloom_filter(&self, config_options: &ConfigOptions) -> bool {
+        self.enable_bloom_filter
+            .unwrap_or(config_options.execution.parquet.bloom_filter_enabled)
+    }
+
     /// Redistribute files across partitions according to their size
     pub fn get_repartitioned(
         &self,
         target_partitions: usize,
         repartition_file_min_size: usize,
     ) -> Self {
-        let flattened_files = self
-            .base_config()
-            .file_groups
-            .iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-        // Perform redistribution only in case all files should be read from beginning to end
-        let has_ranges = flattened_files.iter().any(|f| f.range.is_some());
-        if has_ranges {
-            return self.clone();
-        }
-
-        let total_size = flattened_files
-            .iter()
-            .map(|f| f.object_meta.size as i64)
-            .sum::<i64>();
-        if total_size < (repartition_file_min_size as i64) {
-            return self.clone();
-        }
-
-        let target_partition_size =
-            (total_size as usize + (target_partitions) - 1) / (target_partitions);
-
-        let repartitioned_files = flattened_files
-            .into_iter()
-            .scan(RepartitionState::default(), |state, source_file| {
-                let mut produced_files = vec![];
-                let mut range_start = 0;
-                while range_start < source_file.object_meta.size {
-                    let range_end = min(
-                        range_start
-                            + (target_partition_size - state.current_partition_size),
-                        source_file.object_meta.size,
-                    );
-
-                    let mut produced_file = source_file.clone();
-                    produced_file.range = Some(FileRange {
-                        start: range_start as i64,
-                        end: range_end as i64,
-                    });
-                    produced_files.push((state.current_partition_index, produced_file));
-
-                    if state.current_partition_size + (range_end - range_start)
-                        >= target_partition_size
-                    {
-                        state.current_partition_index += 1;
-                        state.current_partition_size = 0;
-                    } else {
-                        state.current_partition_size += range_end - range_start;
-                    }
-                    range_start = range_end;
-                }
-                Some(produced_files)
-            })
-            .flatten()
-            .group_by(|(partition_idx, _)| *partition_idx)
-            .into_iter()
-            .map(|(_, group)| group.map(|(_, vals)| vals).collect_vec())
-            .collect_vec();
-
         let mut new_parquet_exec = self.clone();
-        new_parquet_exec.base_config.file_groups = repartitioned_files;
+        if let Some(file_groups) = self
+            .base_config
+            .repartition_file_groups(target_partitions, repartition_file_min_size)
+        {
+            new_parquet_exec.base_config.file_groups = file_groups;
+        }
         new_parquet_exec
     }
 }
@@ -386,6 +382,7 @@ impl ExecutionPlan for ParquetExec {
             predicate: self.predicate.clone(),
             pruning_predicate: self.pruning_predicate.clone(),
             page_pruning_predicate: self.page_pruning_predicate.clone(),
+            bloom_filter_predicate: self.bloom_filter_predicate.clone(),
             table_schema: self.base_config.file_schema.clone(),
             metadata_size_hint: self.metadata_size_hint,
             metrics: self.metrics.clone(),
@@ -393,6 +390,7 @@ impl ExecutionPlan for ParquetExec {
             pushdown_filters: self.pushdown_filters(config_options),
             reorder_filters: self.reorder_filters(config_options),
             enable_page_index: self.enable_page_index(config_options),
+            enable_bloom_filter: self.enable_bloom_filter(config_options),
         };
 
         let stream =
@@ -471,6 +469,7 @@ struct ParquetOpener {
     predicate: Option<Arc<dyn PhysicalExpr>>,
     pruning_predicate: Option<Arc<PruningPredicate>>,
     page_pruning_predicate: Option<Arc<PagePruningPredicate>>,
+    bloom_filter_predicate: Option<Arc<BloomFilterPredicate>>,
     table_schema: SchemaRef,
     metadata_size_hint: Option<usize>,
     metrics: ExecutionPlanMetricsSet,
@@ -478,6 +477,7 @@ struct ParquetOpener {
     pushdown_filters: bool,
     reorder_filters: bool,
     enable_page_index: bool,
+    enable_bloom_filter: bool,
 }
 
 impl FileOpener for ParquetOpener {
@@ -490,6 +490,28 @@ impl FileOpener for ParquetOpener {
             &self.metrics,
         );
 
+        let file_size = file_meta.object_meta.size;
+
+        // Bloom filters are fetched with a separate reader, as the
+        // builder takes ownership of the reader used for the data
+        let bloom_filter = match &self.bloom_filter_predicate {
+            Some(predicate) if self.enable_bloom_filter => {
+                let bloom_filter_meta = FileMeta {
+                    object_meta: file_meta.object_meta.clone(),
+                    range: file_meta.range.clone(),
+                    extensions: file_meta.extensions.clone(),
+                };
+                let reader = self.parquet_file_reader_factory.create_reader(
+                    self.partition_index,
+                    bloom_filter_meta,
+                    self.metadata_size_hint,
+                    &self.metrics,
+                )?;
+                Some((reader, predicate.clone()))
+            }
+            _ => None,
+        };
+
         let reader: Box<dyn AsyncFileReader> =
             self.parquet_file_reader_factory.create_reader(
                 self.partition_index,
@@ -501,9 +523,9 @@ impl FileOpener for ParquetOpener {
         let schema_adapter = SchemaAdapter::new(self.table_schema.clone());
         let batch_size = self.batch_size;
         let projection = self.projection.clone();
-        let predicate = self.predicate.clone();
-        let pruning_predicate = self.pruning_predicate.clone();
-        let page_pruning_predicate = self.page_pruning_predicate.clone();
+        let mut predicate = self.predicate.clone();
+        let mut pruning_predicate = self.pruning_predicate.clone();
+        let mut page_pruning_predicate = self.page_pruning_predicate.clone();
         let table_schema = self.table_schema.clone();
         let reorder_predicates = self.reorder_filters;
         let pushdown_filters = self.pushdown_filters;
@@ -511,6 +533,23 @@ impl FileOpener for ParquetOpener {
         let limit = self.limit;
 
         Ok(Box::pin(async move {
+            // Dynamic filters are only known at runtime, so the pruning
+            // predicates are derived from what they publish when the file
+            // is read
+            if let Some(expr) = predicate.as_ref().filter(|p| has_dynamic_filter(p)) {
+                let snapshot = snapshot_dynamic_filters(expr.clone())?;
+                pruning_predicate =
+                    PruningPredicate::try_new(snapshot.clone(), table_schema.clone())
+                        .ok()
+                        .filter(|p| !p.allways_true())
+                        .map(Arc::new);
+                page_pruning_predicate =
+                    PagePruningPredicate::try_new(&snapshot, table_schema.clone())
+                        .ok()
+                        .map(Arc::new);
+                predicate = Some(snapshot);
+            }
+
             let options = ArrowReaderOptions::new().with_page_index(enable_page_index);
             let mut builder =
                 ParquetRecordBatchStreamBuilder::new_with_options(reader, options)
@@ -552,13 +591,29 @@ impl FileOpener for ParquetOpener {
             // Row group pruning: attempt to skip entire row_groups
             // using metadata on the row groups
             let file_metadata = builder.metadata();
-            let row_groups = row_groups::prune_row_groups(
+            let mut row_groups = row_groups::prune_row_groups(
                 file_metadata.row_groups(),
                 file_range,
                 pruning_predicate.as_ref().map(|p| p.as_ref()),
                 &file_metrics,
             );
 
+            // Bloom filter pruning: skip row groups whose bloom filters
+            // prove that equality predicates can't match
+            if let Some((mut reader, predicate)) = bloom_filter {
+                if !row_groups.is_empty() {
+                    row_groups = row_groups::prune_row_groups_by_bloom_filters(
+                        reader.as_mut(),
+                        file_metadata.as_ref(),
+                        file_size,
+                        &row_groups,
+                        predicate.as_ref(),
+                        &file_metrics,
+                    )
+                    .await;
+                }
+            }
+
             // page index pruning: if all data on individual pages can
             // be ruled using page metadata, rows from other columns
             // with that range can be skipped as well
@@ -735,12 +790,20 @@ pub async fn plan_to_parquet(
         let file = fs::File::create(path)?;
         let mut writer =
             ArrowWriter::try_new(file, plan.schema(), writer_properties.clone())?;
+        let mut validator = match &writer_properties {
+            Some(props) => SortOrderValidator::try_new(&plan.schema(), props)?,
+            None => None,
+        };
         let stream = plan.execute(i, task_ctx.clone())?;
         let handle: tokio::task::JoinHandle<Result<()>> =
             tokio::task::spawn(async move {
                 stream
                     .map(|batch| {
-                        writer.write(&batch?).map_err(DataFusionError::ParquetError)
+                        let batch = batch?;
+                        if let Some(validator) = validator.as_mut() {
+                            validator.validate(&batch)?;
+                        }
+                        writer.write(&batch).map_err(DataFusionError::ParquetError)
                     })
                     .try_collect()
                     .await
@@ -821,7 +884,7 @@ mod tests {
         datasource::file_format::{parquet::ParquetFormat, FileFormat},
         physical_plan::collect,
     };
-    use arrow::array::{ArrayRef, Float32Array, Int32Array};
+    use arrow::array::{ArrayRef, Float32Array, Float64Array, Int32Array, StructArray};
     use arrow::datatypes::Schema;
     use arrow::record_batch::RecordBatch;
     use arrow::{
@@ -834,10 +897,15 @@ mod tests {
     use datafusion_expr::{col, lit, when, Expr};
     use datafusion_physical_expr::create_physical_expr;
     use datafusion_physical_expr::execution_props::ExecutionProps;
+    use datafusion_physical_expr::expressions::{
+        col as physical_col, DynamicFilter, DynamicFilterExpr,
+    };
     use futures::StreamExt;
+    use itertools::Itertools;
     use object_store::local::LocalFileSystem;
     use object_store::path::Path;
     use object_store::ObjectMeta;
+    use parquet::file::properties::EnabledStatistics;
     use std::fs::File;
     use std::io::Write;
     use tempfile::TempDir;
@@ -1822,6 +1890,238 @@ mod tests {
         );
     }
 
+    /// Writes `batch` to a temporary parquet file with `props`
+    fn write_parquet_file(
+        batch: &RecordBatch,
+        props: WriterProperties,
+    ) -> Result<tempfile::NamedTempFile> {
+        let mut file = tempfile::NamedTempFile::new()?;
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props))?;
+        writer.write(batch)?;
+        writer.close()?;
+        Ok(file)
+    }
+
+    /// Scans `meta` filtered by `filter`, returning the batches and metrics
+    async fn run_bloom_filter_exec(
+        meta: &ObjectMeta,
+        schema: SchemaRef,
+        filter: Expr,
+        enable_bloom_filter: bool,
+    ) -> Result<(Vec<RecordBatch>, MetricsSet)> {
+        let predicate = logical2physical(&filter, &schema);
+        let parquet_exec = Arc::new(
+            ParquetExec::new(
+                FileScanConfig {
+                    object_store_url: ObjectStoreUrl::local_filesystem(),
+                    file_groups: vec![vec![meta.clone().into()]],
+                    file_schema: schema,
+                    statistics: Statistics::default(),
+                    projection: None,
+                    limit: None,
+                    table_partition_cols: vec![],
+                    output_ordering: None,
+                    infinite_source: false,
+                },
+                Some(predicate),
+                None,
+            )
+            .with_enable_bloom_filter(enable_bloom_filter),
+        );
+        let session_ctx = SessionContext::new();
+        let batches = collect(parquet_exec.clone(), session_ctx.task_ctx()).await?;
+        Ok((batches, parquet_exec.metrics().unwrap()))
+    }
+
+    #[tokio::test]
+    async fn parquet_bloom_filter_exec_metrics() -> Result<()> {
+        let c1: ArrayRef =
+            Arc::new(StringArray::from(vec!["a", "c", "e", "b", "d", "f"]));
+        let c2: ArrayRef = Arc::new(Int32Array::from(vec![1, 3, 5, 2, 4, 6]));
+        let batch = create_batch(vec![("c1", c1), ("c2", c2)]);
+
+        // write two row groups with overlapping min/max statistics, so
+        // that only the bloom filters can tell them apart
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let file = write_parquet_file(&batch, props)?;
+        let meta = local_unpartitioned_file(file.path());
+
+        // the second row group is read, as the bloom filter only prunes
+        // row groups and the predicate is not pushed down to the rows
+        let expected = vec![
+            "+----+----+",
+            "| c1 | c2 |",
+            "+----+----+",
+            "| b  | 2  |",
+            "| d  | 4  |",
+            "| f  | 6  |",
+            "+----+----+",
+        ];
+
+        let (batches, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("c1").eq(lit("d")), true)
+                .await?;
+        assert_batches_sorted_eq!(expected, &batches);
+        assert_eq!(get_value(&metrics, "row_groups_pruned"), 0);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+        assert_eq!(get_value(&metrics, "row_groups_matched_bloom_filter"), 1);
+
+        let filter = col("c2").in_list(vec![lit(4), lit(7)], false);
+        let (batches, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), filter, true).await?;
+        assert_batches_sorted_eq!(expected, &batches);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+        assert_eq!(get_value(&metrics, "row_groups_matched_bloom_filter"), 1);
+
+        // values absent from both row groups prune the whole file
+        let (batches, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("c1").eq(lit("cc")), true)
+                .await?;
+        assert!(batches.iter().all(|b| b.num_rows() == 0));
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 2);
+
+        // bloom filters are not read unless enabled
+        let (batches, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("c1").eq(lit("d")), false)
+                .await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 6);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_bloom_filter_float_zero_and_nan() -> Result<()> {
+        // equal to 0.0 and NaN, but with different bits
+        let nan = f64::from_bits(f64::NAN.to_bits() | 1);
+        let c1: ArrayRef = Arc::new(Float64Array::from(vec![-0.0, nan]));
+        let batch = create_batch(vec![("c1", c1)]);
+
+        // without statistics, only the bloom filter can prune
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_statistics_enabled(EnabledStatistics::None)
+            .build();
+        let file = write_parquet_file(&batch, props)?;
+        let meta = local_unpartitioned_file(file.path());
+
+        for value in [0.0, f64::NAN] {
+            let (batches, metrics) = run_bloom_filter_exec(
+                &meta,
+                batch.schema(),
+                col("c1").eq(lit(value)),
+                true,
+            )
+            .await?;
+            assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+            assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 0);
+        }
+
+        // other float values are checked
+        let (_, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("c1").eq(lit(7.5)), true)
+                .await?;
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_bloom_filter_nested_column_name() -> Result<()> {
+        // the leaf `s.a` comes before the top level column `a`
+        let nested: ArrayRef = Arc::new(StructArray::from(vec![(
+            Field::new("a", DataType::Int32, false),
+            Arc::new(Int32Array::from(vec![100, 101, 102])) as ArrayRef,
+        )]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = create_batch(vec![("s", nested), ("a", a)]);
+
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_statistics_enabled(EnabledStatistics::None)
+            .build();
+        let file = write_parquet_file(&batch, props)?;
+        let meta = local_unpartitioned_file(file.path());
+
+        // checked against the bloom filter of `a`, not of `s.a`
+        let (batches, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("a").eq(lit(2)), true)
+                .await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 0);
+        assert_eq!(get_value(&metrics, "row_groups_matched_bloom_filter"), 1);
+
+        let (_, metrics) =
+            run_bloom_filter_exec(&meta, batch.schema(), col("a").eq(lit(100)), true)
+                .await?;
+        assert_eq!(get_value(&metrics, "row_groups_pruned_bloom_filter"), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_dynamic_filter() -> Result<()> {
+        let c1: ArrayRef =
+            Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e", "f"]));
+        let c2: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 7, 8, 9]));
+        let batch = create_batch(vec![("c1", c1), ("c2", c2)]);
+
+        let mut file = tempfile::NamedTempFile::new()?;
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        let meta = local_unpartitioned_file(file.path());
+
+        let schema = batch.schema();
+        let filter = Arc::new(DynamicFilter::new());
+        let parquet_exec = ParquetExec::new(
+            FileScanConfig {
+                object_store_url: ObjectStoreUrl::local_filesystem(),
+                file_groups: vec![vec![meta.into()]],
+                file_schema: schema.clone(),
+                statistics: Statistics::default(),
+                projection: None,
+                limit: None,
+                table_partition_cols: vec![],
+                output_ordering: None,
+                infinite_source: false,
+            },
+            None,
+            None,
+        )
+        .with_additional_predicate(Arc::new(DynamicFilterExpr::new(
+            vec![physical_col("c2", &schema)?],
+            filter.clone(),
+        )));
+        assert!(parquet_exec.pruning_predicate().is_none());
+
+        // the bounds published before the file is opened prune the first
+        // row group
+        filter.update(&[Arc::new(Int32Array::from(vec![8, 7]))])?;
+        let parquet_exec = Arc::new(parquet_exec);
+        let session_ctx = SessionContext::new();
+        let batches = collect(parquet_exec.clone(), session_ctx.task_ctx()).await?;
+        let expected = vec![
+            "+----+----+",
+            "| c1 | c2 |",
+            "+----+----+",
+            "| d  | 7  |",
+            "| e  | 8  |",
+            "| f  | 9  |",
+            "+----+----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+        let metrics = parquet_exec.metrics().unwrap();
+        assert_eq!(get_value(&metrics, "row_groups_pruned"), 1);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn parquet_exec_metrics() {
         let c1: ArrayRef = Arc::new(StringArray::from(vec![
@@ -2295,6 +2595,110 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_parquet_results_with_options() -> Result<()> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::format::SortingColumn;
+
+        let tmp_dir = TempDir::new()?;
+        let ctx = SessionContext::new();
+        let schema = populate_csv_partitions(&tmp_dir, 4, ".csv")?;
+        ctx.register_csv(
+            "test",
+            tmp_dir.path().to_str().unwrap(),
+            CsvReadOptions::new().schema(&schema),
+        )
+        .await?;
+
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/out";
+        let mut options = ParquetWriterOptions::new();
+        options.set("max_row_group_size", "5")?;
+        options.set("statistics_enabled", "page")?;
+        options.set("bloom_filter_columns", "c2")?;
+        options.set("sorting_columns", "c1, c2 DESC")?;
+        let df = ctx
+            .sql("SELECT c1, c2 FROM test ORDER BY c1, c2 DESC")
+            .await?;
+        df.write_parquet_with_options(&out_dir, options).await?;
+
+        let file = File::open(format!("{out_dir}/part-0.parquet"))?;
+        let reader = SerializedFileReader::new(file)?;
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 8);
+        for row_group in metadata.row_groups() {
+            assert_eq!(row_group.num_rows(), 5);
+            assert!(row_group.column(0).bloom_filter_offset().is_none());
+            assert!(row_group.column(1).bloom_filter_offset().is_some());
+            assert!(row_group.column(1).column_index_offset().is_some());
+            assert_eq!(
+                row_group.sorting_columns(),
+                Some(&vec![
+                    SortingColumn {
+                        column_idx: 0,
+                        descending: false,
+                        nulls_first: false,
+                    },
+                    SortingColumn {
+                        column_idx: 1,
+                        descending: true,
+                        nulls_first: true,
+                    },
+                ])
+            );
+        }
+
+        // the written bloom filters can be used to prune row groups
+        let config = SessionConfig::new()
+            .set_bool("datafusion.execution.parquet.bloom_filter_enabled", true);
+        let ctx = SessionContext::with_config(config);
+        ctx.register_parquet("out", &out_dir, ParquetReadOptions::default())
+            .await?;
+        let results = ctx
+            .sql("SELECT c1, c2 FROM out WHERE c2 = 7")
+            .await?
+            .collect()
+            .await?;
+        let count: usize = results.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(count, 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_parquet_unsorted_with_sorting_columns() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let ctx = SessionContext::new();
+        let schema = populate_csv_partitions(&tmp_dir, 1, ".csv")?;
+        ctx.register_csv(
+            "test",
+            tmp_dir.path().to_str().unwrap(),
+            CsvReadOptions::new().schema(&schema),
+        )
+        .await?;
+
+        let mut options = ParquetWriterOptions::new();
+        options.set("sorting_columns", "c2 DESC")?;
+
+        // the input is sorted ascending, so it can't be declared descending
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/unsorted";
+        let df = ctx.sql("SELECT c1, c2 FROM test ORDER BY c2").await?;
+        let err = df
+            .write_parquet_with_options(&out_dir, options.clone())
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("not sorted by the sorting columns [c2]"),
+            "{err}"
+        );
+
+        let out_dir = tmp_dir.as_ref().to_str().unwrap().to_string() + "/sorted";
+        let df = ctx.sql("SELECT c1, c2 FROM test ORDER BY c2 DESC").await?;
+        df.write_parquet_with_options(&out_dir, options).await?;
+
+        Ok(())
+    }
+
     fn logical2physical(expr: &Expr, schema: &Schema) -> Arc<dyn PhysicalExpr> {
         let df_schema = schema.clone().to_dfschema().unwrap();
         let execution_props = ExecutionProps::new();
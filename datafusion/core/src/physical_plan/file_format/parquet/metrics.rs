@@ -31,6 +31,10 @@ pub struct ParquetFileMetrics {
     pub predicate_evaluation_errors: Count,
     /// Number of row groups pruned using
     pub row_groups_pruned: Count,
+    /// Number of row groups pruned using bloom filters
+    pub row_groups_pruned_bloom_filter: Count,
+    /// Number of row groups whose bloom filters were checked and not pruned
+    pub row_groups_matched_bloom_filter: Count,
     /// Total number of bytes scanned
     pub bytes_scanned: Count,
     /// Total rows filtered out by predicates pushed into parquet scan
@@ -58,6 +62,14 @@ impl ParquetFileMetrics {
             .with_new_label("filename", filename.to_string())
             .counter("row_groups_pruned", partition);
 
+        let row_groups_pruned_bloom_filter = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("row_groups_pruned_bloom_filter", partition);
+
+        let row_groups_matched_bloom_filter = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .counter("row_groups_matched_bloom_filter", partition);
+
         let bytes_scanned = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
             .counter("bytes_scanned", partition);
@@ -80,6 +92,8 @@ impl ParquetFileMetrics {
         Self {
             predicate_evaluation_errors,
             row_groups_pruned,
+            row_groups_pruned_bloom_filter,
+            row_groups_matched_bloom_filter,
             bytes_scanned,
             pushdown_rows_filtered,
             pushdown_eval_time,
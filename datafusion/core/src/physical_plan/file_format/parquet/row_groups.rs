@@ -15,16 +15,33 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
 use arrow::{
     array::ArrayRef,
     datatypes::{DataType, Schema},
 };
+use bytes::Bytes;
 use datafusion_common::Column;
 use datafusion_common::ScalarValue;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{
+    BinaryExpr, Column as PhysicalColumn, InListExpr, Literal,
+};
+use datafusion_physical_expr::{split_conjunction, PhysicalExpr};
 use log::debug;
 
+use parquet::arrow::async_reader::AsyncFileReader;
+use parquet::basic::Type as PhysicalType;
+use parquet::bloom_filter::Sbbf;
+use parquet::data_type::ByteArray;
+use parquet::errors::ParquetError;
 use parquet::file::{
-    metadata::RowGroupMetaData, statistics::Statistics as ParquetStatistics,
+    metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData},
+    reader::{ChunkReader, Length},
+    statistics::Statistics as ParquetStatistics,
 };
 
 use crate::physical_plan::file_format::parquet::{
@@ -86,6 +103,322 @@ pub(crate) fn prune_row_groups(
     filtered
 }
 
+/// Equality constraints extracted from a scan predicate that can be
+/// checked against the split block bloom filters stored in parquet files.
+///
+/// Each entry maps a column name to the set of literal values the
+/// column must contain one of for the predicate to be true, so a row
+/// group whose bloom filter rules out every value can be skipped.
+/// Only top level conjuncts of the form `col = literal` and
+/// `col IN (literal, ...)` are used.
+#[derive(Debug)]
+pub(crate) struct BloomFilterPredicate {
+    columns: Vec<(String, Vec<ScalarValue>)>,
+}
+
+impl BloomFilterPredicate {
+    /// Try to create a [`BloomFilterPredicate`] from `expr`, returning
+    /// `None` if no part of the predicate can be checked with a bloom filter
+    pub fn try_new(expr: &Arc<dyn PhysicalExpr>) -> Option<Self> {
+        let mut columns: HashMap<String, Vec<ScalarValue>> = HashMap::new();
+        for conjunct in split_conjunction(expr) {
+            if let Some((name, values)) = equality_values(conjunct) {
+                match columns.get_mut(&name) {
+                    // multiple conjuncts on the same column: only values
+                    // satisfying all of them can match
+                    Some(existing) => existing.retain(|v| values.contains(v)),
+                    None => {
+                        columns.insert(name, values);
+                    }
+                }
+            }
+        }
+
+        if columns.is_empty() {
+            return None;
+        }
+        let mut columns: Vec<_> = columns.into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        Some(Self { columns })
+    }
+
+    /// Returns true if `row_group` can be skipped because, for some
+    /// column, its bloom filter proves none of the required values are
+    /// present. Columns without a bloom filter never prune.
+    fn prune(
+        &self,
+        row_group: &RowGroupMetaData,
+        bloom_filters: &HashMap<String, Sbbf>,
+    ) -> bool {
+        self.columns.iter().any(|(name, values)| {
+            let (column, sbbf) = match (
+                find_column(row_group, name),
+                bloom_filters.get(name.as_str()),
+            ) {
+                (Some(column), Some(sbbf)) => (column, sbbf),
+                _ => return false,
+            };
+            let physical_type = column.column_descr().physical_type();
+            values.iter().all(|value| {
+                // values that can't be checked might be present
+                !check_bloom_filter(sbbf, value, physical_type).unwrap_or(true)
+            })
+        })
+    }
+}
+
+/// Returns the column name and literal values for a `col = literal`
+/// or `col IN (literal, ...)` expression
+fn equality_values(expr: &Arc<dyn PhysicalExpr>) -> Option<(String, Vec<ScalarValue>)> {
+    let expr_any = expr.as_any();
+    if let Some(binary) = expr_any.downcast_ref::<BinaryExpr>() {
+        if binary.op() != &Operator::Eq {
+            return None;
+        }
+        let left = binary.left().as_any();
+        let right = binary.right().as_any();
+        let (column, literal) = match (
+            left.downcast_ref::<PhysicalColumn>(),
+            right.downcast_ref::<Literal>(),
+        ) {
+            (Some(column), Some(literal)) => (column, literal),
+            _ => match (
+                right.downcast_ref::<PhysicalColumn>(),
+                left.downcast_ref::<Literal>(),
+            ) {
+                (Some(column), Some(literal)) => (column, literal),
+                _ => return None,
+            },
+        };
+        if literal.value().is_null() {
+            return None;
+        }
+        return Some((column.name().to_string(), vec![literal.value().clone()]));
+    }
+
+    if let Some(in_list) = expr_any.downcast_ref::<InListExpr>() {
+        if in_list.negated() {
+            return None;
+        }
+        let column = in_list.expr().as_any().downcast_ref::<PhysicalColumn>()?;
+        let values = in_list
+            .list()
+            .iter()
+            .map(|e| {
+                e.as_any()
+                    .downcast_ref::<Literal>()
+                    .map(|l| l.value().clone())
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .filter(|v| !v.is_null())
+            .collect();
+        return Some((column.name().to_string(), values));
+    }
+
+    None
+}
+
+/// Checks if `value` may be present according to `sbbf`. Values are
+/// hashed using their plain encoded representation, so the value must be
+/// converted to the physical type of the column.
+///
+/// Returns `None` if the value can not be checked against a column of
+/// `physical_type`.
+fn check_bloom_filter(
+    sbbf: &Sbbf,
+    value: &ScalarValue,
+    physical_type: PhysicalType,
+) -> Option<bool> {
+    let result = match (physical_type, value) {
+        (PhysicalType::BOOLEAN, ScalarValue::Boolean(Some(v))) => sbbf.check(v),
+        (PhysicalType::INT32, ScalarValue::Int8(Some(v))) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Int16(Some(v))) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Int32(Some(v))) => sbbf.check(v),
+        (PhysicalType::INT32, ScalarValue::UInt8(Some(v))) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::UInt16(Some(v))) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::UInt32(Some(v))) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Date32(Some(v))) => sbbf.check(v),
+        (PhysicalType::INT64, ScalarValue::Int64(Some(v))) => sbbf.check(v),
+        (PhysicalType::INT64, ScalarValue::UInt64(Some(v))) => sbbf.check(&(*v as i64)),
+        // Equal floats can have different bits (0.0 and -0.0, and NaNs with
+        // different payloads), so those values can not be checked
+        (PhysicalType::FLOAT, ScalarValue::Float32(Some(v))) => {
+            if *v == 0.0 || v.is_nan() {
+                return None;
+            }
+            sbbf.check(v)
+        }
+        (PhysicalType::DOUBLE, ScalarValue::Float64(Some(v))) => {
+            if *v == 0.0 || v.is_nan() {
+                return None;
+            }
+            sbbf.check(v)
+        }
+        (PhysicalType::BYTE_ARRAY, ScalarValue::Utf8(Some(v)))
+        | (PhysicalType::BYTE_ARRAY, ScalarValue::LargeUtf8(Some(v))) => {
+            sbbf.check(&ByteArray::from(v.as_str()))
+        }
+        (PhysicalType::BYTE_ARRAY, ScalarValue::Binary(Some(v)))
+        | (PhysicalType::BYTE_ARRAY, ScalarValue::LargeBinary(Some(v))) => {
+            sbbf.check(&ByteArray::from(v.clone()))
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Finds the column chunk of the top level column `name`, ignoring the
+/// leaves of nested columns with the same name
+fn find_column<'a>(
+    row_group: &'a RowGroupMetaData,
+    name: &str,
+) -> Option<&'a ColumnChunkMetaData> {
+    row_group
+        .columns()
+        .iter()
+        .find(|c| c.column_descr().path().parts() == [name])
+}
+
+/// Prunes the row groups in `row_groups` (indexes into the row groups of
+/// `metadata`) using the bloom filters of the columns referenced by
+/// `predicate`, returning the indexes that still need to be scanned.
+///
+/// Bloom filters that can not be read are treated as absent.
+pub(crate) async fn prune_row_groups_by_bloom_filters(
+    reader: &mut dyn AsyncFileReader,
+    metadata: &ParquetMetaData,
+    file_size: usize,
+    row_groups: &[usize],
+    predicate: &BloomFilterPredicate,
+    metrics: &ParquetFileMetrics,
+) -> Vec<usize> {
+    let mut filtered = Vec::with_capacity(row_groups.len());
+    for idx in row_groups {
+        let row_group = metadata.row_group(*idx);
+
+        let mut bloom_filters = HashMap::new();
+        for (name, _) in &predicate.columns {
+            let column = match find_column(row_group, name) {
+                Some(column) => column,
+                None => continue,
+            };
+            match read_bloom_filter(reader, metadata, file_size, column).await {
+                Ok(Some(sbbf)) => {
+                    bloom_filters.insert(name.clone(), sbbf);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    debug!("Ignoring error reading bloom filter for column {name}: {e}");
+                    metrics.predicate_evaluation_errors.add(1);
+                }
+            }
+        }
+
+        if bloom_filters.is_empty() {
+            filtered.push(*idx);
+            continue;
+        }
+
+        if predicate.prune(row_group, &bloom_filters) {
+            metrics.row_groups_pruned_bloom_filter.add(1);
+        } else {
+            metrics.row_groups_matched_bloom_filter.add(1);
+            filtered.push(*idx);
+        }
+    }
+    filtered
+}
+
+/// Fetches and decodes the bloom filter of `column`, if it has one.
+///
+/// The parquet metadata only records where a bloom filter starts, so
+/// the bytes up to the next known structure in the file (or the
+/// footer) are fetched.
+async fn read_bloom_filter(
+    reader: &mut dyn AsyncFileReader,
+    metadata: &ParquetMetaData,
+    file_size: usize,
+    column: &ColumnChunkMetaData,
+) -> Result<Option<Sbbf>, ParquetError> {
+    let start = match column.bloom_filter_offset() {
+        Some(offset) if offset >= 0 => offset as usize,
+        _ => return Ok(None),
+    };
+
+    // the footer is the 4 byte metadata length followed by the magic bytes
+    let footer_start = file_size.saturating_sub(8);
+    let end = metadata
+        .row_groups()
+        .iter()
+        .flat_map(|rg| rg.columns())
+        .flat_map(|c| {
+            [
+                c.bloom_filter_offset(),
+                c.column_index_offset(),
+                c.offset_index_offset(),
+                Some(
+                    c.dictionary_page_offset()
+                        .unwrap_or_else(|| c.data_page_offset()),
+                ),
+            ]
+        })
+        .flatten()
+        .filter_map(|offset| usize::try_from(offset).ok())
+        .filter(|offset| *offset > start)
+        .fold(footer_start, usize::min);
+
+    if end <= start {
+        return Err(ParquetError::General(format!(
+            "Invalid bloom filter offset {start} for file of size {file_size}"
+        )));
+    }
+
+    let data = reader.get_bytes(start..end).await?;
+    let chunk = BloomFilterChunk {
+        offset: start as u64,
+        data,
+    };
+    Sbbf::read_from_column_chunk(column, Arc::new(chunk))
+}
+
+/// A [`ChunkReader`] over a range of bytes fetched from a file, addressed
+/// by absolute file offsets
+struct BloomFilterChunk {
+    offset: u64,
+    data: Bytes,
+}
+
+impl Length for BloomFilterChunk {
+    fn len(&self) -> u64 {
+        self.offset + self.data.len() as u64
+    }
+}
+
+impl ChunkReader for BloomFilterChunk {
+    type T = Cursor<Bytes>;
+
+    fn get_read(&self, start: u64, length: usize) -> parquet::errors::Result<Self::T> {
+        Ok(Cursor::new(self.get_bytes(start, length)?))
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        let relative_start = start
+            .checked_sub(self.offset)
+            .map(|s| s as usize)
+            .filter(|s| *s <= self.data.len())
+            .ok_or_else(|| {
+                ParquetError::General(format!(
+                    "Bloom filter read at offset {start} is outside of fetched range"
+                ))
+            })?;
+        // the bloom filter header is read with a size estimate that can
+        // exceed the fetched range
+        let end = (relative_start + length).min(self.data.len());
+        Ok(self.data.slice(relative_start..end))
+    }
+}
+
 /// Wraps parquet statistics in a way
 /// that implements [`PruningStatistics`]
 struct RowGroupPruningStatistics<'a> {
@@ -290,6 +623,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bloom_filter_predicate_columns() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Int32, false),
+            Field::new("c2", DataType::Utf8, false),
+        ]);
+        let expr = col("c1")
+            .in_list(vec![lit(1), lit(2), lit(3)], false)
+            .and(lit("x").eq(col("c2")))
+            .and(col("c1").eq(lit(2)).or(col("c1").eq(lit(3))))
+            .and(col("c1").not_eq(lit(5)))
+            .and(col("c1").eq(lit(3)));
+        let expr = logical2physical(&expr, &schema);
+        let predicate = BloomFilterPredicate::try_new(&expr).unwrap();
+        assert_eq!(
+            predicate.columns,
+            vec![
+                ("c1".to_string(), vec![ScalarValue::Int32(Some(3))]),
+                (
+                    "c2".to_string(),
+                    vec![ScalarValue::Utf8(Some("x".to_string()))]
+                ),
+            ]
+        );
+
+        // predicates without equalities can't use bloom filters
+        let expr = logical2physical(&col("c1").gt(lit(15)), &schema);
+        assert!(BloomFilterPredicate::try_new(&expr).is_none());
+        let expr = col("c1").in_list(vec![lit(1)], true);
+        let expr = logical2physical(&expr, &schema);
+        assert!(BloomFilterPredicate::try_new(&expr).is_none());
+    }
+
     #[test]
     fn row_group_pruning_predicate_missing_stats() {
         use datafusion_expr::{col, lit};
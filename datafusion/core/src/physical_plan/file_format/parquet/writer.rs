@@ -0,0 +1,593 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Options controlling the layout of written parquet files
+
+use arrow::array::ArrayRef;
+use arrow::compute::SortOptions;
+use arrow::datatypes::Schema;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use parquet::arrow::arrow_to_parquet_schema;
+use parquet::basic::Compression;
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
+use parquet::format::SortingColumn;
+use parquet::schema::types::{ColumnPath, SchemaDescriptor};
+
+use crate::error::{DataFusionError, Result};
+
+/// Options for writing parquet files, used by `DataFrame::write_parquet_with_options`
+/// and `COPY ... TO`.
+///
+/// Unlike [`WriterProperties`], columns are referred to by name, and
+/// the options can be set from string key / value pairs with
+/// [`Self::set`]. The supported keys are:
+///
+/// * `compression`: `uncompressed`, `snappy`, `gzip`, `lzo`, `brotli`,
+///   `lz4`, `lz4_raw` or `zstd`
+/// * `max_row_group_size`: maximum number of rows in each row group
+/// * `statistics_enabled`: `none`, `chunk` or `page` level statistics
+/// * `bloom_filter_columns`: comma separated list of columns to write
+///   bloom filters for
+/// * `bloom_filter_fpp`: false positive probability of the bloom filters
+///   of `bloom_filter_columns`, strictly between 0 and 1
+/// * `bloom_filter_ndv`: expected number of distinct values per row group
+///   used to size the bloom filters of `bloom_filter_columns`
+///
+/// `bloom_filter_fpp` and `bloom_filter_ndv` require `bloom_filter_columns`.
+/// * `sorting_columns`: comma separated list of `column [ASC|DESC]
+///   [NULLS FIRST|NULLS LAST]` recorded as the sort order of each row group.
+///   The written rows must already be in this order, otherwise the write
+///   fails
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParquetWriterOptions {
+    compression: Option<Compression>,
+    max_row_group_size: Option<usize>,
+    statistics_enabled: Option<EnabledStatistics>,
+    bloom_filter_columns: Vec<String>,
+    bloom_filter_fpp: Option<f64>,
+    bloom_filter_ndv: Option<u64>,
+    sorting_columns: Vec<ParquetSortingColumn>,
+}
+
+/// A column the written row groups are declared to be sorted by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParquetSortingColumn {
+    /// Name of the column
+    pub name: String,
+    /// If true, the column is sorted in descending order
+    pub descending: bool,
+    /// If true, nulls sort before non-null values
+    pub nulls_first: bool,
+}
+
+impl ParquetWriterOptions {
+    /// Create options using the defaults of the parquet writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression codec of the written column chunks
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Set the maximum number of rows written to each row group
+    pub fn with_max_row_group_size(mut self, max_row_group_size: usize) -> Self {
+        self.max_row_group_size = Some(max_row_group_size);
+        self
+    }
+
+    /// Set the level of statistics written for each column. Page level
+    /// statistics also write the page index used by `ParquetExec` to
+    /// skip individual pages
+    pub fn with_statistics_enabled(mut self, statistics: EnabledStatistics) -> Self {
+        self.statistics_enabled = Some(statistics);
+        self
+    }
+
+    /// Write bloom filters for the column `name`
+    pub fn with_bloom_filter_column(mut self, name: impl Into<String>) -> Self {
+        self.bloom_filter_columns.push(name.into());
+        self
+    }
+
+    /// Set the false positive probability of the written bloom filters,
+    /// which must be strictly between 0 and 1
+    pub fn with_bloom_filter_fpp(mut self, fpp: f64) -> Result<Self> {
+        self.bloom_filter_fpp = Some(check_bloom_filter_fpp(fpp)?);
+        Ok(self)
+    }
+
+    /// Set the number of distinct values the bloom filters are sized for
+    pub fn with_bloom_filter_ndv(mut self, ndv: u64) -> Self {
+        self.bloom_filter_ndv = Some(ndv);
+        self
+    }
+
+    /// Declare that the rows of each row group are sorted by `column`,
+    /// after any previously added sorting columns
+    pub fn with_sorting_column(mut self, column: ParquetSortingColumn) -> Self {
+        self.sorting_columns.push(column);
+        self
+    }
+
+    /// Set the option `key` from its string representation
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key.to_lowercase().as_str() {
+            "compression" => {
+                self.compression = Some(match value.to_lowercase().as_str() {
+                    "uncompressed" => Compression::UNCOMPRESSED,
+                    "snappy" => Compression::SNAPPY,
+                    "gzip" => Compression::GZIP,
+                    "lzo" => Compression::LZO,
+                    "brotli" => Compression::BROTLI,
+                    "lz4" => Compression::LZ4,
+                    "lz4_raw" => Compression::LZ4_RAW,
+                    "zstd" => Compression::ZSTD,
+                    _ => {
+                        return Err(DataFusionError::Plan(format!(
+                            "Invalid value '{value}' for parquet writer option '{key}'"
+                        )))
+                    }
+                });
+            }
+            "max_row_group_size" => {
+                self.max_row_group_size = Some(parse_value(key, value)?);
+            }
+            "statistics_enabled" => {
+                self.statistics_enabled = Some(match value.to_lowercase().as_str() {
+                    "none" => EnabledStatistics::None,
+                    "chunk" => EnabledStatistics::Chunk,
+                    "page" => EnabledStatistics::Page,
+                    _ => {
+                        return Err(DataFusionError::Plan(format!(
+                            "Invalid value '{value}' for parquet writer option '{key}', \
+                             expected one of none, chunk or page"
+                        )))
+                    }
+                });
+            }
+            "bloom_filter_columns" => {
+                self.bloom_filter_columns =
+                    split_list(value).map(|s| s.to_string()).collect();
+            }
+            "bloom_filter_fpp" => {
+                self.bloom_filter_fpp =
+                    Some(check_bloom_filter_fpp(parse_value(key, value)?)?);
+            }
+            "bloom_filter_ndv" => {
+                self.bloom_filter_ndv = Some(parse_value(key, value)?);
+            }
+            "sorting_columns" => {
+                self.sorting_columns = split_list(value)
+                    .map(ParquetSortingColumn::parse)
+                    .collect::<Result<_>>()?;
+            }
+            _ => {
+                return Err(DataFusionError::Plan(format!(
+                    "Unknown parquet writer option '{key}'"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Create the [`WriterProperties`] for writing files with `schema`
+    pub fn writer_properties(&self, schema: &Schema) -> Result<WriterProperties> {
+        let parquet_schema = arrow_to_parquet_schema(schema)?;
+        let mut builder = WriterProperties::builder();
+
+        if let Some(compression) = self.compression {
+            builder = builder.set_compression(compression);
+        }
+        if let Some(max_row_group_size) = self.max_row_group_size {
+            builder = builder.set_max_row_group_size(max_row_group_size);
+        }
+        if let Some(statistics) = self.statistics_enabled {
+            builder = builder.set_statistics_enabled(statistics);
+        }
+        // the writer's global bloom filter settings enable bloom filters
+        // for all columns, so they are only applied per column
+        if self.bloom_filter_columns.is_empty()
+            && (self.bloom_filter_fpp.is_some() || self.bloom_filter_ndv.is_some())
+        {
+            return Err(DataFusionError::Plan(
+                "Parquet writer options 'bloom_filter_fpp' and 'bloom_filter_ndv' \
+                 require 'bloom_filter_columns'"
+                    .to_string(),
+            ));
+        }
+        for name in &self.bloom_filter_columns {
+            let column = column_path(&parquet_schema, name)?;
+            builder = builder.set_column_bloom_filter_enabled(column.clone(), true);
+            if let Some(fpp) = self.bloom_filter_fpp {
+                builder = builder.set_column_bloom_filter_fpp(column.clone(), fpp);
+            }
+            if let Some(ndv) = self.bloom_filter_ndv {
+                builder = builder.set_column_bloom_filter_ndv(column, ndv);
+            }
+        }
+
+        if !self.sorting_columns.is_empty() {
+            let sorting_columns = self
+                .sorting_columns
+                .iter()
+                .map(|c| {
+                    Ok(SortingColumn {
+                        column_idx: column_index(&parquet_schema, &c.name)? as i32,
+                        descending: c.descending,
+                        nulls_first: c.nulls_first,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            builder = builder.set_sorting_columns(Some(sorting_columns));
+        }
+
+        Ok(builder.build())
+    }
+}
+
+impl ParquetSortingColumn {
+    /// Create an ascending sorting column with nulls last
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            descending: false,
+            nulls_first: false,
+        }
+    }
+
+    /// Parse `column [ASC|DESC] [NULLS FIRST|NULLS LAST]`. As in SQL,
+    /// nulls sort first for descending columns unless specified.
+    fn parse(s: &str) -> Result<Self> {
+        let invalid = || {
+            DataFusionError::Plan(format!(
+                "Invalid parquet sorting column '{s}', expected \
+                 'column [ASC|DESC] [NULLS FIRST|NULLS LAST]'"
+            ))
+        };
+
+        let tokens: Vec<_> = s.split_whitespace().collect();
+        let (name, rest) = tokens.split_first().ok_or_else(invalid)?;
+        let rest: Vec<_> = rest.iter().map(|t| t.to_uppercase()).collect();
+        let rest: Vec<_> = rest.iter().map(|t| t.as_str()).collect();
+
+        let (descending, rest) = match rest.as_slice() {
+            ["DESC", rest @ ..] => (true, rest),
+            ["ASC", rest @ ..] => (false, rest),
+            rest => (false, rest),
+        };
+        let nulls_first = match rest {
+            [] => descending,
+            ["NULLS", "FIRST"] => true,
+            ["NULLS", "LAST"] => false,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            descending,
+            nulls_first,
+        })
+    }
+}
+
+/// Checks that the batches passed to a parquet writer are sorted by the
+/// sorting columns of its [`WriterProperties`], so that a written file
+/// never declares a sort order its rows don't have. The order is checked
+/// across batches, as a row group may span several of them.
+pub(crate) struct SortOrderValidator {
+    columns: Vec<usize>,
+    converter: RowConverter,
+    last_row: Option<OwnedRow>,
+}
+
+impl SortOrderValidator {
+    /// Create a validator for batches of `schema` written with `props`,
+    /// or `None` if `props` declare no sorting columns
+    pub(crate) fn try_new(
+        schema: &Schema,
+        props: &WriterProperties,
+    ) -> Result<Option<Self>> {
+        let sorting_columns = match props.sorting_columns() {
+            Some(sorting_columns) if !sorting_columns.is_empty() => sorting_columns,
+            _ => return Ok(None),
+        };
+        let parquet_schema = arrow_to_parquet_schema(schema)?;
+
+        let mut columns = Vec::with_capacity(sorting_columns.len());
+        let mut fields = Vec::with_capacity(sorting_columns.len());
+        for sorting_column in sorting_columns {
+            let column_idx = sorting_column.column_idx as usize;
+            if column_idx >= parquet_schema.num_columns() {
+                return Err(DataFusionError::Plan(format!(
+                    "Parquet sorting column index {column_idx} is out of bounds"
+                )));
+            }
+            // only top level columns can be checked against the arrow schema
+            let parts = parquet_schema.column(column_idx).path().parts();
+            if parts.len() != 1 {
+                return Err(DataFusionError::NotImplemented(format!(
+                    "Parquet sorting column '{}' is not a top level column",
+                    parts.join(".")
+                )));
+            }
+            let idx = schema.index_of(&parts[0])?;
+            columns.push(idx);
+            fields.push(SortField::new_with_options(
+                schema.field(idx).data_type().clone(),
+                SortOptions {
+                    descending: sorting_column.descending,
+                    nulls_first: sorting_column.nulls_first,
+                },
+            ));
+        }
+
+        Ok(Some(Self {
+            columns,
+            converter: RowConverter::new(fields)?,
+            last_row: None,
+        }))
+    }
+
+    /// Returns an error if the rows of `batch` are out of order, either
+    /// among themselves or with respect to the previously validated batches
+    pub(crate) fn validate(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        let arrays: Vec<ArrayRef> = self
+            .columns
+            .iter()
+            .map(|idx| batch.column(*idx).clone())
+            .collect();
+        let rows = self.converter.convert_columns(&arrays)?;
+
+        let first_row_sorted = self
+            .last_row
+            .as_ref()
+            .map(|last| last.row() <= rows.row(0))
+            .unwrap_or(true);
+        let sorted = first_row_sorted
+            && (1..rows.num_rows()).all(|i| rows.row(i - 1) <= rows.row(i));
+        if !sorted {
+            let names: Vec<_> = self
+                .columns
+                .iter()
+                .map(|idx| batch.schema().field(*idx).name().clone())
+                .collect();
+            return Err(DataFusionError::Execution(format!(
+                "Rows written to parquet are not sorted by the sorting columns [{}], \
+                 the input must be sorted in the same order",
+                names.join(", ")
+            )));
+        }
+
+        self.last_row = Some(rows.row(rows.num_rows() - 1).owned());
+        Ok(())
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(|s| s.trim()).filter(|s| !s.is_empty())
+}
+
+fn parse_value<T: std::str::FromStr>(key: &str, value: &str) -> Result<T> {
+    value.trim().parse().map_err(|_| {
+        DataFusionError::Plan(format!(
+            "Invalid value '{value}' for parquet writer option '{key}'"
+        ))
+    })
+}
+
+/// Checks that `fpp` is a valid false positive probability, as the parquet
+/// writer panics otherwise
+fn check_bloom_filter_fpp(fpp: f64) -> Result<f64> {
+    if fpp > 0.0 && fpp < 1.0 {
+        Ok(fpp)
+    } else {
+        Err(DataFusionError::Plan(format!(
+            "Invalid value '{fpp}' for parquet writer option 'bloom_filter_fpp', \
+             expected a probability strictly between 0 and 1"
+        )))
+    }
+}
+
+/// Returns the index of the leaf column `name` in `schema`
+fn column_index(schema: &SchemaDescriptor, name: &str) -> Result<usize> {
+    schema
+        .columns()
+        .iter()
+        .position(|c| c.path().string() == name)
+        .ok_or_else(|| {
+            DataFusionError::Plan(format!(
+                "Parquet writer option references unknown column '{name}'"
+            ))
+        })
+}
+
+fn column_path(schema: &SchemaDescriptor, name: &str) -> Result<ColumnPath> {
+    let idx = column_index(schema, name)?;
+    Ok(schema.column(idx).path().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use std::sync::Arc;
+
+    #[test]
+    fn set_options() -> Result<()> {
+        let mut options = ParquetWriterOptions::new();
+        options.set("compression", "ZSTD")?;
+        options.set("max_row_group_size", "1024")?;
+        options.set("STATISTICS_ENABLED", "Page")?;
+        options.set("bloom_filter_columns", "a, b")?;
+        options.set("bloom_filter_fpp", "0.01")?;
+        options.set("bloom_filter_ndv", "100")?;
+        options.set("sorting_columns", "a DESC, b nulls first,c asc nulls last")?;
+
+        let expected = ParquetWriterOptions::new()
+            .with_compression(Compression::ZSTD)
+            .with_max_row_group_size(1024)
+            .with_statistics_enabled(EnabledStatistics::Page)
+            .with_bloom_filter_column("a")
+            .with_bloom_filter_column("b")
+            .with_bloom_filter_fpp(0.01)?
+            .with_bloom_filter_ndv(100)
+            .with_sorting_column(ParquetSortingColumn {
+                name: "a".to_string(),
+                descending: true,
+                nulls_first: true,
+            })
+            .with_sorting_column(ParquetSortingColumn {
+                name: "b".to_string(),
+                descending: false,
+                nulls_first: true,
+            })
+            .with_sorting_column(ParquetSortingColumn::new("c"));
+        assert_eq!(options, expected);
+
+        let err = options.set("max_row_group_size", "many").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error during planning: Invalid value 'many' for parquet writer option 'max_row_group_size'"
+        );
+        let err = options.set("sorting_columns", "a DESC NULLS").unwrap_err();
+        assert!(err.to_string().contains("Invalid parquet sorting column"));
+        let err = options.set("compression", "zip").unwrap_err();
+        assert!(err.to_string().contains("Invalid value 'zip'"));
+        let err = options.set("row_group_size", "10").unwrap_err();
+        assert!(err.to_string().contains("Unknown parquet writer option"));
+        for fpp in ["1.5", "0", "1", "-0.1", "NaN"] {
+            let err = options.set("bloom_filter_fpp", fpp).unwrap_err();
+            assert!(
+                err.to_string().contains("strictly between 0 and 1"),
+                "{fpp}: {err}"
+            );
+        }
+        let err = ParquetWriterOptions::new()
+            .with_bloom_filter_fpp(0.0)
+            .unwrap_err();
+        assert!(err.to_string().contains("strictly between 0 and 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn writer_properties() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let options = ParquetWriterOptions::new()
+            .with_compression(Compression::SNAPPY)
+            .with_max_row_group_size(10)
+            .with_statistics_enabled(EnabledStatistics::Chunk)
+            .with_bloom_filter_column("b")
+            .with_sorting_column(ParquetSortingColumn::new("b"));
+        let props = options.writer_properties(&schema)?;
+
+        let a = ColumnPath::from("a");
+        let b = ColumnPath::from("b");
+        assert_eq!(props.compression(&a), Compression::SNAPPY);
+        assert_eq!(props.max_row_group_size(), 10);
+        assert_eq!(props.statistics_enabled(&a), EnabledStatistics::Chunk);
+        assert!(props.bloom_filter_properties(&a).is_none());
+        assert!(props.bloom_filter_properties(&b).is_some());
+        assert_eq!(
+            props.sorting_columns(),
+            Some(&vec![SortingColumn {
+                column_idx: 1,
+                descending: false,
+                nulls_first: false,
+            }])
+        );
+
+        let err = ParquetWriterOptions::new()
+            .with_bloom_filter_column("c")
+            .writer_properties(&schema)
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown column 'c'"));
+
+        let err = ParquetWriterOptions::new()
+            .with_bloom_filter_ndv(100)
+            .writer_properties(&schema)
+            .unwrap_err();
+        assert!(err.to_string().contains("require 'bloom_filter_columns'"));
+        Ok(())
+    }
+
+    #[test]
+    fn validate_sort_order() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = |a: Vec<Option<i32>>, b: Vec<Option<&str>>| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from(a)),
+                    Arc::new(StringArray::from(b)),
+                ],
+            )
+            .unwrap()
+        };
+        let props = ParquetWriterOptions::new()
+            .with_sorting_column(ParquetSortingColumn::new("a"))
+            .with_sorting_column(ParquetSortingColumn {
+                name: "b".to_string(),
+                descending: true,
+                nulls_first: true,
+            })
+            .writer_properties(&schema)?;
+
+        let mut validator = SortOrderValidator::try_new(&schema, &props)?.unwrap();
+        validator.validate(&batch(
+            vec![Some(1), Some(1), Some(2)],
+            vec![None, Some("b"), Some("z")],
+        ))?;
+        validator.validate(&batch(vec![Some(2), None], vec![Some("x"), None]))?;
+
+        // unsorted within a batch
+        let mut validator = SortOrderValidator::try_new(&schema, &props)?.unwrap();
+        let err = validator
+            .validate(&batch(vec![Some(1), Some(1)], vec![Some("a"), Some("b")]))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("not sorted by the sorting columns [a, b]"));
+
+        // unsorted across batches
+        let mut validator = SortOrderValidator::try_new(&schema, &props)?.unwrap();
+        validator.validate(&batch(vec![Some(2)], vec![Some("a")]))?;
+        validator.validate(&batch(vec![], vec![]))?;
+        let err = validator
+            .validate(&batch(vec![Some(1)], vec![Some("a")]))
+            .unwrap_err();
+        assert!(err.to_string().contains("not sorted"));
+
+        // nothing to validate without sorting columns
+        let props = ParquetWriterOptions::new().writer_properties(&schema)?;
+        assert!(SortOrderValidator::try_new(&schema, &props)?.is_none());
+        Ok(())
+    }
+}
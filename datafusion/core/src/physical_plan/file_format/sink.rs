@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for writing the output of a plan to files
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use futures::StreamExt;
+use object_store::path::Path;
+
+use crate::datasource::file_format::write::{write_files, write_stream, FileWriteConfig};
+use crate::datasource::file_format::FileFormat;
+use crate::datasource::listing::ListingTableUrl;
+use crate::datasource::object_store::ObjectStoreUrl;
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+use crate::physical_plan::coalesce_partitions::CoalescePartitionsExec;
+use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::insert::{make_count_batch, make_count_schema};
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
+use crate::physical_plan::{
+    DisplayFormatType, Distribution, ExecutionPlan, Partitioning,
+    SendableRecordBatchStream, Statistics,
+};
+
+/// Execution plan that writes the output of its input to files in an
+/// object store, for `COPY ... TO`.
+///
+/// The output is either a single file, or, if the output URL ends in
+/// `/` or partition columns are given, a directory containing one file
+/// per input partition (and partition value). The plan produces a
+/// single row with the number of rows written.
+#[derive(Debug)]
+pub struct FileSinkExec {
+    /// The plan whose output is written
+    input: Arc<dyn ExecutionPlan>,
+    /// The URL the output is written to, as specified
+    output_url: String,
+    /// The object store the files are written to
+    object_store_url: ObjectStoreUrl,
+    /// The directory the files are written to
+    base_path: Path,
+    /// The name of the file to write, if writing a single file
+    file_name: Option<String>,
+    /// The format of the written files
+    format: Arc<dyn FileFormat>,
+    /// The extension of the written files when writing a directory
+    file_extension: String,
+    /// Columns of the input used to write Hive style partitions
+    partition_by: Vec<String>,
+    /// The output schema, containing the number of rows written
+    count_schema: SchemaRef,
+}
+
+impl FileSinkExec {
+    /// Create a plan writing the output of `input` to `output_url`
+    ///
+    /// The output directory does not need to exist: it is created by the
+    /// object store when the files are written.
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        output_url: impl Into<String>,
+        format: Arc<dyn FileFormat>,
+        file_extension: impl Into<String>,
+        partition_by: Vec<String>,
+    ) -> Result<Self> {
+        let output_url = output_url.into();
+
+        let input_schema = input.schema();
+        for column in &partition_by {
+            input_schema.index_of(column)?;
+        }
+
+        let (dir, file_name) = if output_url.ends_with('/') || !partition_by.is_empty() {
+            (output_url.clone(), None)
+        } else {
+            match output_url.rsplit_once('/') {
+                Some((dir, file_name)) => (format!("{dir}/"), Some(file_name)),
+                None => ("./".to_string(), Some(output_url.as_str())),
+            }
+        };
+        if file_name == Some("") {
+            return Err(DataFusionError::Plan(format!(
+                "Invalid output file '{output_url}'"
+            )));
+        }
+
+        let dir = if is_local_path(&dir) {
+            local_directory_url(&dir)?
+        } else {
+            ListingTableUrl::parse(&dir)?
+        };
+
+        Ok(Self {
+            input,
+            object_store_url: dir.object_store(),
+            base_path: dir.prefix().clone(),
+            file_name: file_name.map(|s| s.to_string()),
+            output_url,
+            format,
+            file_extension: file_extension.into(),
+            partition_by,
+            count_schema: make_count_schema(),
+        })
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The URL the output is written to
+    pub fn output_url(&self) -> &str {
+        &self.output_url
+    }
+
+    /// The format of the written files
+    pub fn format(&self) -> &Arc<dyn FileFormat> {
+        &self.format
+    }
+
+    /// The columns used to write Hive style partitions
+    pub fn partition_by(&self) -> &[String] {
+        &self.partition_by
+    }
+}
+
+impl ExecutionPlan for FileSinkExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.count_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        // a single file is written from a single stream
+        if self.file_name.is_some() {
+            vec![Distribution::SinglePartition]
+        } else {
+            vec![Distribution::UnspecifiedDistribution]
+        }
+    }
+
+    fn benefits_from_input_partitioning(&self) -> bool {
+        self.file_name.is_none()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(Self {
+            input: children[0].clone(),
+            output_url: self.output_url.clone(),
+            object_store_url: self.object_store_url.clone(),
+            base_path: self.base_path.clone(),
+            file_name: self.file_name.clone(),
+            format: self.format.clone(),
+            file_extension: self.file_extension.clone(),
+            partition_by: self.partition_by.clone(),
+            count_schema: self.count_schema.clone(),
+        }))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "FileSinkExec invalid partition {partition}"
+            )));
+        }
+
+        let config = FileWriteConfig {
+            store: context.runtime_env().object_store(&self.object_store_url)?,
+            base_path: self.base_path.clone(),
+            format: self.format.clone(),
+            file_extension: self.file_extension.clone(),
+            partition_cols: self.partition_by.clone(),
+            list_files_cache: context.runtime_env().cache_manager.list_files_cache(),
+        };
+
+        let input = self.input.clone();
+        let file_name = self.file_name.clone();
+        let count_schema = self.count_schema.clone();
+        let stream = futures::stream::once(async move {
+            let count = write(input, context, config, file_name).await?;
+            make_count_batch(count_schema, count)
+        })
+        .boxed();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.count_schema.clone(),
+            stream,
+        )))
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(
+                    f,
+                    "FileSinkExec: output_url={}, partition_by=[{}]",
+                    self.output_url,
+                    self.partition_by.join(", ")
+                )
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+/// Writes the output of `input` as a single file named `file_name`, or
+/// as one file per partition if `None`, returning the number of rows
+async fn write(
+    input: Arc<dyn ExecutionPlan>,
+    context: Arc<TaskContext>,
+    config: FileWriteConfig,
+    file_name: Option<String>,
+) -> Result<u64> {
+    match file_name {
+        Some(file_name) => {
+            let input: Arc<dyn ExecutionPlan> =
+                if input.output_partitioning().partition_count() == 1 {
+                    input
+                } else {
+                    Arc::new(CoalescePartitionsExec::new(input))
+                };
+            let stream = input.execute(0, context)?;
+            write_stream(stream, config, file_name).await
+        }
+        None => {
+            let streams = (0..input.output_partitioning().partition_count())
+                .map(|i| input.execute(i, context.clone()))
+                .collect::<Result<Vec<_>>>()?;
+            write_files(streams, config).await
+        }
+    }
+}
+
+/// Returns true if `s` is interpreted as a local file system path by
+/// [`ListingTableUrl::parse`]
+fn is_local_path(s: &str) -> bool {
+    std::path::Path::new(s).is_absolute()
+        || matches!(
+            url::Url::parse(s),
+            Err(url::ParseError::RelativeUrlWithoutBase)
+        )
+}
+
+/// Resolves the local directory `dir` to an absolute [`ListingTableUrl`]
+/// without requiring it to exist, unlike [`ListingTableUrl::parse`]
+fn local_directory_url(dir: &str) -> Result<ListingTableUrl> {
+    let path = std::env::current_dir()?.join(dir);
+    let url = url::Url::from_directory_path(&path).map_err(|_| {
+        DataFusionError::Plan(format!("Invalid output directory '{dir}'"))
+    })?;
+    ListingTableUrl::parse(url)
+}
@@ -0,0 +1,259 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for writing data to [`DataSink`]s
+
+use std::any::Any;
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use arrow::array::UInt64Array;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
+use futures::StreamExt;
+
+use super::expressions::PhysicalSortExpr;
+use super::stream::RecordBatchStreamAdapter;
+use super::{
+    DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+};
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+
+/// `DataSink` implements writing streams of [`RecordBatch`]es to
+/// user defined destinations.
+///
+/// The `Display` impl is used to format the sink for explain plan
+/// output.
+#[async_trait]
+pub trait DataSink: Display + Debug + Send + Sync {
+    /// Writes the data from the streams to the destination, returning
+    /// the number of rows written.
+    ///
+    /// `data` contains one stream per partition of the input plan,
+    /// which can be written concurrently.
+    async fn write_all(
+        &self,
+        data: Vec<SendableRecordBatchStream>,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64>;
+}
+
+/// Execution plan for writing the output of its input to a
+/// [`DataSink`], such as the target table of an `INSERT INTO`.
+///
+/// The plan produces a single row with the number of rows written,
+/// in a `UInt64` column named `count`.
+#[derive(Debug)]
+pub struct InsertExec {
+    /// Input plan that produces the record batches to be written
+    input: Arc<dyn ExecutionPlan>,
+    /// Sink to which to write
+    sink: Arc<dyn DataSink>,
+    /// Schema describing the structure of the output
+    count_schema: SchemaRef,
+}
+
+impl InsertExec {
+    /// Create a plan to write the output of `input` to `sink`
+    pub fn new(input: Arc<dyn ExecutionPlan>, sink: Arc<dyn DataSink>) -> Self {
+        Self {
+            input,
+            sink,
+            count_schema: make_count_schema(),
+        }
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The sink the input is written to
+    pub fn sink(&self) -> &Arc<dyn DataSink> {
+        &self.sink
+    }
+}
+
+impl ExecutionPlan for InsertExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.count_schema.clone()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn benefits_from_input_partitioning(&self) -> bool {
+        // the sink chooses the partitioning of the input, so it is not
+        // changed by the optimizer
+        false
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(Self::new(children[0].clone(), self.sink.clone())))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "InsertExec invalid partition {partition}"
+            )));
+        }
+
+        let data = (0..self.input.output_partitioning().partition_count())
+            .map(|i| self.input.execute(i, context.clone()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let sink = self.sink.clone();
+        let count_schema = self.count_schema.clone();
+        let stream = futures::stream::once(async move {
+            let count = sink.write_all(data, &context).await?;
+            make_count_batch(count_schema, count)
+        })
+        .boxed();
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.count_schema.clone(),
+            stream,
+        )))
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(f, "InsertExec: sink={}", self.sink)
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+/// Returns the schema of the output of [`InsertExec`]: a single
+/// `UInt64` column named `count`
+pub(crate) fn make_count_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![Field::new(
+        "count",
+        DataType::UInt64,
+        false,
+    )]))
+}
+
+/// Returns a batch of `count_schema` containing `count`
+pub(crate) fn make_count_batch(
+    count_schema: SchemaRef,
+    count: u64,
+) -> Result<RecordBatch> {
+    RecordBatch::try_new(count_schema, vec![Arc::new(UInt64Array::from(vec![count]))])
+        .map_err(DataFusionError::ArrowError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_batches_eq;
+    use crate::physical_plan::{collect, memory::MemoryExec};
+    use crate::prelude::SessionContext;
+    use arrow::array::Int32Array;
+    use futures::TryStreamExt;
+    use parking_lot::Mutex;
+
+    /// Collects all written batches
+    #[derive(Debug, Default)]
+    struct TestSink {
+        batches: Mutex<Vec<RecordBatch>>,
+    }
+
+    impl Display for TestSink {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "TestSink")
+        }
+    }
+
+    #[async_trait]
+    impl DataSink for TestSink {
+        async fn write_all(
+            &self,
+            data: Vec<SendableRecordBatchStream>,
+            _context: &Arc<TaskContext>,
+        ) -> Result<u64> {
+            let mut count = 0;
+            for stream in data {
+                let batches: Vec<RecordBatch> = stream.try_collect().await?;
+                count += batches.iter().map(|b| b.num_rows() as u64).sum::<u64>();
+                self.batches.lock().extend(batches);
+            }
+            Ok(count)
+        }
+    }
+
+    #[tokio::test]
+    async fn insert_exec() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let input = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch]],
+            schema,
+            None,
+        )?);
+
+        let sink = Arc::new(TestSink::default());
+        let exec = Arc::new(InsertExec::new(input, sink.clone()));
+        let actual = collect(exec, session_ctx.task_ctx()).await?;
+        let expected = vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 6     |",
+            "+-------+",
+        ];
+        assert_batches_eq!(expected, &actual);
+        assert_eq!(sink.batches.lock().len(), 2);
+        Ok(())
+    }
+}
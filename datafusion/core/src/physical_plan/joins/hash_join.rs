@@ -38,6 +38,7 @@ use smallvec::{smallvec, SmallVec};
 use std::sync::Arc;
 use std::{any::Any, usize, vec};
 
+use futures::future::{BoxFuture, FutureExt};
 use futures::{ready, Stream, StreamExt, TryStreamExt};
 
 use arrow::array::Array;
@@ -58,7 +59,9 @@ use hashbrown::raw::RawTable;
 use crate::physical_plan::{
     coalesce_batches::concat_batches,
     coalesce_partitions::CoalescePartitionsExec,
+    common::{read_spill_as_stream, IPCWriter},
     expressions::Column,
+    expressions::DynamicFilter,
     expressions::PhysicalSortExpr,
     hash_utils::create_hashes,
     joins::utils::{
@@ -68,6 +71,7 @@ use crate::physical_plan::{
         JoinFilter, JoinOn,
     },
     metrics::{ExecutionPlanMetricsSet, MetricsSet},
+    stream::RecordBatchStreamAdapter,
     DisplayFormatType, Distribution, EquivalenceProperties, ExecutionPlan, Partitioning,
     PhysicalExpr, RecordBatchStream, SendableRecordBatchStream, Statistics,
 };
@@ -79,6 +83,7 @@ use crate::arrow::array::BooleanBufferBuilder;
 use crate::arrow::datatypes::TimeUnit;
 use crate::execution::{
     context::TaskContext,
+    disk_manager::DiskManager,
     memory_pool::{
         MemoryConsumer, SharedMemoryReservation, SharedOptionalMemoryReservation, TryGrow,
     },
@@ -92,8 +97,11 @@ use crate::physical_plan::joins::utils::{
     adjust_indices_by_join_type, apply_join_filter_to_indices, build_batch_from_indices,
     get_final_indices_from_bit_map, need_produce_result_in_final, JoinSide,
 };
+use arrow::compute::take;
+use log::debug;
 use std::fmt;
 use std::task::Poll;
+use tempfile::NamedTempFile;
 
 // Maps a `u64` hash value based on the build side ["on" values] to a list of indices with this key's value.
 //
@@ -123,6 +131,15 @@ type JoinLeftData = (JoinHashMap, RecordBatch);
 /// Filter expression expected to contain non-equality predicates that can not be pushed
 /// down to any of join inputs.
 /// In case of outer join, filter applied to only matched rows.
+///
+/// In [`PartitionMode::Partitioned`] mode, a partition whose build side does
+/// not fit into its memory reservation is spilled to disk and joined as a
+/// grace hash join. In [`PartitionMode::CollectLeft`] mode, the build side is
+/// shared by all output partitions and exceeding the memory limit is an error.
+///
+/// In [`PartitionMode::CollectLeft`] mode, the join can publish the bounds of
+/// its build side join keys to a [`DynamicFilter`] once the build side is
+/// collected, so that the probe side scans can skip the rows without a match.
 #[derive(Debug)]
 pub struct HashJoinExec {
     /// left (build) side which gets hashed
@@ -151,6 +168,8 @@ pub struct HashJoinExec {
     column_indices: Vec<ColumnIndex>,
     /// If null_equals_null is true, null == null else null != null
     pub(crate) null_equals_null: bool,
+    /// Filter on the probe side join keys, updated once the build side is collected
+    dynamic_filter: Option<Arc<DynamicFilter>>,
 }
 
 impl HashJoinExec {
@@ -195,9 +214,18 @@ impl HashJoinExec {
             metrics: ExecutionPlanMetricsSet::new(),
             column_indices,
             null_equals_null,
+            dynamic_filter: None,
         })
     }
 
+    /// Publish the bounds of the build side join keys to the given filter
+    /// once the build side is collected. Only applies to the
+    /// [`PartitionMode::CollectLeft`] mode.
+    pub fn with_dynamic_filter(mut self, dynamic_filter: Arc<DynamicFilter>) -> Self {
+        self.dynamic_filter = Some(dynamic_filter);
+        self
+    }
+
     /// left (build) side which gets hashed
     pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
         &self.left
@@ -232,6 +260,11 @@ impl HashJoinExec {
     pub fn null_equals_null(&self) -> bool {
         self.null_equals_null
     }
+
+    /// Filter the bounds of the build side join keys are published to
+    pub fn dynamic_filter(&self) -> Option<&Arc<DynamicFilter>> {
+        self.dynamic_filter.as_ref()
+    }
 }
 
 impl ExecutionPlan for HashJoinExec {
@@ -359,7 +392,7 @@ impl ExecutionPlan for HashJoinExec {
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(HashJoinExec::try_new(
+        let mut join = HashJoinExec::try_new(
             children[0].clone(),
             children[1].clone(),
             self.on.clone(),
@@ -367,7 +400,9 @@ impl ExecutionPlan for HashJoinExec {
             &self.join_type,
             self.mode,
             self.null_equals_null,
-        )?))
+        )?;
+        join.dynamic_filter = self.dynamic_filter.clone();
+        Ok(Arc::new(join))
     }
 
     fn execute(
@@ -390,6 +425,18 @@ impl ExecutionPlan for HashJoinExec {
                 .register(context.memory_pool()),
         );
 
+        let template = HashJoinStreamTemplate {
+            schema: self.schema(),
+            on_left: on_left.clone(),
+            on_right,
+            filter: self.filter.clone(),
+            join_type: self.join_type,
+            column_indices: self.column_indices.clone(),
+            random_state: self.random_state.clone(),
+            join_metrics: join_metrics.clone(),
+            null_equals_null: self.null_equals_null,
+        };
+
         // Memory reservation for left-side data depends on PartitionMode:
         // - operator-level for `CollectLeft` mode
         // - stream-level for partitioned mode
@@ -397,55 +444,53 @@ impl ExecutionPlan for HashJoinExec {
         // This approach allows to avoid cases when left data could potentially
         // outlive its memory reservation and rely on `MemoryReservation` destructors
         // for releasing memory in pool.
-        let left_fut = match self.mode {
-            PartitionMode::CollectLeft => self.left_fut.once(|| {
-                collect_left_input(
-                    None,
-                    self.random_state.clone(),
-                    self.left.clone(),
-                    on_left.clone(),
-                    context.clone(),
-                    join_metrics.clone(),
-                    Arc::new(self.reservation.clone()),
-                )
-            }),
-            PartitionMode::Partitioned => OnceFut::new(collect_left_input(
-                Some(partition),
-                self.random_state.clone(),
-                self.left.clone(),
-                on_left.clone(),
-                context.clone(),
-                join_metrics.clone(),
-                Arc::new(reservation.clone()),
-            )),
-            PartitionMode::Auto => {
-                return Err(DataFusionError::Plan(format!(
-                    "Invalid HashJoinExec, unsupported PartitionMode {:?} in execute()",
-                    PartitionMode::Auto
-                )));
-            }
-        };
+        match self.mode {
+            PartitionMode::CollectLeft => {
+                let left_fut = self.left_fut.once(|| {
+                    collect_left_input(
+                        self.random_state.clone(),
+                        self.left.clone(),
+                        on_left,
+                        context.clone(),
+                        join_metrics,
+                        Arc::new(self.reservation.clone()),
+                        self.dynamic_filter.clone(),
+                    )
+                });
 
-        // we have the batches and the hash map with their keys. We can how create a stream
-        // over the right that uses this information to issue new batches.
-        let right_stream = self.right.execute(partition, context)?;
+                // we have the batches and the hash map with their keys. We can how create a stream
+                // over the right that uses this information to issue new batches.
+                let right_stream = self.right.execute(partition, context)?;
 
-        Ok(Box::pin(HashJoinStream {
-            schema: self.schema(),
-            on_left,
-            on_right,
-            filter: self.filter.clone(),
-            join_type: self.join_type,
-            left_fut,
-            visited_left_side: None,
-            right: right_stream,
-            column_indices: self.column_indices.clone(),
-            random_state: self.random_state.clone(),
-            join_metrics,
-            null_equals_null: self.null_equals_null,
-            is_exhausted: false,
-            reservation,
-        }))
+                Ok(Box::pin(template.create_stream(
+                    left_fut,
+                    right_stream,
+                    reservation,
+                )))
+            }
+            PartitionMode::Partitioned => {
+                let left_stream = self.left.execute(partition, context.clone())?;
+                let right_stream = self.right.execute(partition, context.clone())?;
+
+                Ok(Box::pin(RecordBatchStreamAdapter::new(
+                    self.schema(),
+                    futures::stream::once(partitioned_join(
+                        template,
+                        left_stream,
+                        right_stream,
+                        reservation,
+                        context,
+                        partition,
+                        0,
+                    ))
+                    .try_flatten(),
+                )))
+            }
+            PartitionMode::Auto => Err(DataFusionError::Plan(format!(
+                "Invalid HashJoinExec, unsupported PartitionMode {:?} in execute()",
+                PartitionMode::Auto
+            ))),
+        }
     }
 
     fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
@@ -482,32 +527,55 @@ impl ExecutionPlan for HashJoinExec {
 }
 
 async fn collect_left_input(
-    partition: Option<usize>,
     random_state: RandomState,
     left: Arc<dyn ExecutionPlan>,
     on_left: Vec<Column>,
     context: Arc<TaskContext>,
     metrics: BuildProbeJoinMetrics,
     reservation: Arc<dyn TryGrow>,
+    dynamic_filter: Option<Arc<DynamicFilter>>,
 ) -> Result<JoinLeftData> {
-    let schema = left.schema();
+    let merge = {
+        if left.output_partitioning().partition_count() != 1 {
+            Arc::new(CoalescePartitionsExec::new(left))
+        } else {
+            left
+        }
+    };
 
-    let (left_input, left_input_partition) = if let Some(partition) = partition {
-        (left, partition)
-    } else {
-        let merge = {
-            if left.output_partitioning().partition_count() != 1 {
-                Arc::new(CoalescePartitionsExec::new(left))
-            } else {
-                left
-            }
-        };
+    // Load the whole left side in memory
+    let stream = merge.execute(0, context)?;
 
-        (merge, 0)
-    };
+    let left_data =
+        collect_left_stream(stream, random_state, on_left.clone(), metrics, reservation)
+            .await?;
+
+    if let Some(dynamic_filter) = dynamic_filter {
+        let batch = &left_data.1;
+        let keys = on_left
+            .iter()
+            .map(|c| Ok(c.evaluate(batch)?.into_array(batch.num_rows())))
+            .collect::<Result<Vec<_>>>()?;
+        // The filter keeps accepting all rows if the bounds of the keys
+        // can't be computed
+        if let Err(e) = dynamic_filter.update(&keys) {
+            debug!("Could not update the dynamic filter of the hash join: {e}");
+        }
+    }
+
+    Ok(left_data)
+}
 
-    // Depending on partition argument load single partition or whole left side in memory
-    let stream = left_input.execute(left_input_partition, context.clone())?;
+/// Collects all batches of the build-side `stream` in memory and creates the
+/// [JoinHashMap] over them
+async fn collect_left_stream(
+    stream: SendableRecordBatchStream,
+    random_state: RandomState,
+    on_left: Vec<Column>,
+    metrics: BuildProbeJoinMetrics,
+    reservation: Arc<dyn TryGrow>,
+) -> Result<JoinLeftData> {
+    let schema = stream.schema();
 
     // This operation performs 2 steps at once:
     // 1. creates a [JoinHashMap] of all batches from the stream
@@ -530,9 +598,16 @@ async fn collect_left_input(
         })
         .await?;
 
-    // Estimation of memory size, required for hashtable, prior to allocation.
-    // Final result can be verified using `RawTable.allocation_info()`
-    //
+    let estimated_hastable_size = estimate_hashtable_size(num_rows)?;
+    reservation.try_grow(estimated_hastable_size)?;
+    metrics.build_mem_used.add(estimated_hastable_size);
+
+    build_left_data(&schema, &batches, num_rows, &on_left, &random_state)
+}
+
+/// Estimation of memory size, required for hashtable, prior to allocation.
+/// Final result can be verified using `RawTable.allocation_info()`
+fn estimate_hashtable_size(num_rows: usize) -> Result<usize> {
     // For majority of cases hashbrown overestimates buckets qty to keep ~1/8 of them empty.
     // This formula leads to overallocation for small tables (< 8 elements) but fine overall.
     let estimated_buckets = (num_rows.checked_mul(8).ok_or_else(|| {
@@ -544,11 +619,18 @@ async fn collect_left_input(
     // 32 bytes per `(u64, SmallVec<[u64; 1]>)`
     // + 1 byte for each bucket
     // + 16 bytes fixed
-    let estimated_hastable_size = 32 * estimated_buckets + estimated_buckets + 16;
-
-    reservation.try_grow(estimated_hastable_size)?;
-    metrics.build_mem_used.add(estimated_hastable_size);
+    Ok(32 * estimated_buckets + estimated_buckets + 16)
+}
 
+/// Creates the [JoinHashMap] over the build-side `batches`, whose memory
+/// must already be reserved, and concatenates them into a single batch
+fn build_left_data(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+    num_rows: usize,
+    on_left: &[Column],
+    random_state: &RandomState,
+) -> Result<JoinLeftData> {
     let mut hashmap = JoinHashMap(RawTable::with_capacity(num_rows));
     let mut hashes_buffer = Vec::new();
     let mut offset = 0;
@@ -556,22 +638,265 @@ async fn collect_left_input(
         hashes_buffer.clear();
         hashes_buffer.resize(batch.num_rows(), 0);
         update_hash(
-            &on_left,
+            on_left,
             batch,
             &mut hashmap,
             offset,
-            &random_state,
+            random_state,
             &mut hashes_buffer,
         )?;
         offset += batch.num_rows();
     }
     // Merge all batches into a single batch, so we
     // can directly index into the arrays
-    let single_batch = concat_batches(&schema, &batches, num_rows)?;
+    let single_batch = concat_batches(schema, batches, num_rows)?;
 
     Ok((hashmap, single_batch))
 }
 
+/// Joins one output partition in [`PartitionMode::Partitioned`] mode.
+///
+/// The build side of the partition is collected in memory as long as its
+/// memory reservation can grow. Otherwise, and if the [`DiskManager`] allows
+/// temporary files, the join falls back to a grace hash join: both inputs are
+/// split by the hash of their join keys into [`GRACE_PARTITION_COUNT`] spill
+/// files each, and the resulting pairs of files are joined one at a time, so
+/// only the build side of a single grace partition is held in memory.
+///
+/// A grace partition whose build side still does not fit is joined the same
+/// way, splitting it again with a different hash, up to [`MAX_GRACE_DEPTH`]
+/// times. Build sides that cannot be split by hashing, e.g. because all of
+/// their rows have the same join key, therefore fail with the memory error.
+fn partitioned_join(
+    template: HashJoinStreamTemplate,
+    mut left: SendableRecordBatchStream,
+    mut right: SendableRecordBatchStream,
+    reservation: SharedMemoryReservation,
+    context: Arc<TaskContext>,
+    partition: usize,
+    depth: usize,
+) -> BoxFuture<'static, Result<SendableRecordBatchStream>> {
+    async move {
+        let left_schema = left.schema();
+        let metrics = template.join_metrics.clone();
+
+        // Build-side metrics are only recorded once the partition is known to
+        // fit in memory, as the grace partitions record their own ones
+        let mut batches = vec![];
+        let mut num_rows = 0;
+        let mut mem_used = 0;
+        let mut exhausted = None;
+        while let Some(batch) = left.next().await {
+            let batch = batch?;
+            let batch_size = batch.get_array_memory_size();
+            num_rows += batch.num_rows();
+            batches.push(batch);
+            // Reserve memory for incoming batch
+            if let Err(e) = reservation.try_grow(batch_size) {
+                exhausted = Some(e);
+                break;
+            }
+            mem_used += batch_size;
+        }
+
+        let exhausted = match exhausted {
+            Some(e) => e,
+            None => {
+                let estimated_hastable_size = estimate_hashtable_size(num_rows)?;
+                match reservation.try_grow(estimated_hastable_size) {
+                    Ok(()) => {
+                        metrics.build_input_batches.add(batches.len());
+                        metrics.build_input_rows.add(num_rows);
+                        metrics
+                            .build_mem_used
+                            .add(mem_used + estimated_hastable_size);
+                        let left_data = build_left_data(
+                            &left_schema,
+                            &batches,
+                            num_rows,
+                            &template.on_left,
+                            &template.random_state,
+                        )?;
+                        let left_fut = OnceFut::new(async move { Ok(left_data) });
+                        let stream: SendableRecordBatchStream = Box::pin(
+                            template.create_stream(left_fut, right, reservation),
+                        );
+                        return Ok(stream);
+                    }
+                    Err(e) => e,
+                }
+            }
+        };
+
+        let runtime = context.runtime_env();
+        if depth >= MAX_GRACE_DEPTH || !runtime.disk_manager.tmp_files_enabled() {
+            return Err(exhausted);
+        }
+
+        debug!(
+            "Build side of HashJoinStream[{partition}] exceeds its memory reservation, \
+            falling back to grace hash join with {GRACE_PARTITION_COUNT} partitions \
+            at depth {depth}"
+        );
+
+        // Spill the build side, releasing the memory of the buffered batches
+        let mut left_partitioner = GracePartitioner::try_new(
+            &runtime.disk_manager,
+            left_schema.clone(),
+            template.on_left.clone(),
+            depth,
+        )?;
+        for batch in batches.drain(..) {
+            left_partitioner.write(&batch)?;
+        }
+        reservation.free();
+        while let Some(batch) = left.next().await {
+            left_partitioner.write(&batch?)?;
+        }
+        let left_files = left_partitioner.finish(&metrics)?;
+
+        // Spill the probe side with the same partitioning
+        let right_schema = right.schema();
+        let mut right_partitioner = GracePartitioner::try_new(
+            &runtime.disk_manager,
+            right_schema.clone(),
+            template.on_right.clone(),
+            depth,
+        )?;
+        while let Some(batch) = right.next().await {
+            right_partitioner.write(&batch?)?;
+        }
+        let right_files = right_partitioner.finish(&metrics)?;
+
+        // Join the grace partitions one after another, each one with its own
+        // memory reservation that is released once the partition is done
+        let schema = template.schema.clone();
+        let pool = context.memory_pool().clone();
+        let stream = futures::stream::iter(left_files.into_iter().zip(right_files))
+            .then(move |(left_file, right_file)| {
+                let reservation = SharedMemoryReservation::from(
+                    MemoryConsumer::new(format!("HashJoinStream[{partition}]"))
+                        .register(&pool),
+                );
+                let left = read_spill_as_stream(left_file, left_schema.clone());
+                let right = read_spill_as_stream(right_file, right_schema.clone());
+                let template = template.clone();
+                let context = context.clone();
+                async move {
+                    partitioned_join(
+                        template,
+                        left?,
+                        right?,
+                        reservation,
+                        context,
+                        partition,
+                        depth + 1,
+                    )
+                    .await
+                }
+            })
+            .try_flatten();
+
+        let stream: SendableRecordBatchStream =
+            Box::pin(RecordBatchStreamAdapter::new(schema, stream));
+        Ok(stream)
+    }
+    .boxed()
+}
+
+/// Number of partitions the inputs of a [`HashJoinExec`] are split into when
+/// its build side does not fit in memory
+const GRACE_PARTITION_COUNT: usize = 16;
+
+/// Number of times the inputs of a [`HashJoinExec`] are split at most when
+/// the build side of a grace partition does not fit in memory either
+const MAX_GRACE_DEPTH: usize = 3;
+
+/// Splits batches by the hash of their join keys into one spill file per
+/// grace partition
+struct GracePartitioner {
+    /// columns used to compute the hash
+    on: Vec<Column>,
+    /// Random state used for the partitioning hash. This must differ from
+    /// the one of the join, whose hashes already determine the partition of
+    /// the inputs (and the buckets of the hash table), and from the ones of
+    /// the grace partitioners at other depths
+    random_state: RandomState,
+    /// One spill file per grace partition
+    files: Vec<NamedTempFile>,
+    /// Writers for `files`
+    writers: Vec<IPCWriter>,
+    /// Scratch space for the hashes of a batch
+    hashes_buffer: Vec<u64>,
+}
+
+impl GracePartitioner {
+    fn try_new(
+        disk_manager: &DiskManager,
+        schema: SchemaRef,
+        on: Vec<Column>,
+        depth: usize,
+    ) -> Result<Self> {
+        let files = (0..GRACE_PARTITION_COUNT)
+            .map(|_| disk_manager.create_tmp_file("HashJoinExec grace partitioning"))
+            .collect::<Result<Vec<_>>>()?;
+        let writers = files
+            .iter()
+            .map(|file| IPCWriter::new(file.path(), &schema))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            on,
+            random_state: RandomState::with_seeds(1, 2, 3, 4 + depth as u64),
+            files,
+            writers,
+            hashes_buffer: vec![],
+        })
+    }
+
+    /// Writes the rows of `batch` to the spill files of their grace partitions
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let keys_values = self
+            .on
+            .iter()
+            .map(|c| Ok(c.evaluate(batch)?.into_array(batch.num_rows())))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.hashes_buffer.clear();
+        self.hashes_buffer.resize(batch.num_rows(), 0);
+        create_hashes(&keys_values, &self.random_state, &mut self.hashes_buffer)?;
+
+        let mut indices = vec![vec![]; self.writers.len()];
+        for (row, hash) in self.hashes_buffer.iter().enumerate() {
+            indices[(*hash % self.writers.len() as u64) as usize].push(row as u32);
+        }
+
+        for (writer, indices) in self.writers.iter_mut().zip(indices) {
+            if indices.is_empty() {
+                continue;
+            }
+            let indices = UInt32Array::from(indices);
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|c| take(c.as_ref(), &indices, None))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            writer.write(&RecordBatch::try_new(batch.schema(), columns)?)?;
+        }
+        Ok(())
+    }
+
+    /// Finishes all spill files and returns them, recording the spill in `metrics`
+    fn finish(mut self, metrics: &BuildProbeJoinMetrics) -> Result<Vec<NamedTempFile>> {
+        for writer in self.writers.iter_mut() {
+            writer.finish()?;
+            metrics.spilled_bytes.add(writer.num_bytes as usize);
+        }
+        metrics.spill_count.add(1);
+        Ok(self.files)
+    }
+}
+
 /// Updates `hash` with new entries from [RecordBatch] evaluated against the expressions `on`,
 /// assuming that the [RecordBatch] corresponds to the `index`th
 pub fn update_hash(
@@ -609,6 +934,58 @@ pub fn update_hash(
     Ok(())
 }
 
+/// The parts of a [`HashJoinStream`] that do not depend on its inputs. A grace
+/// hash join creates one stream per grace partition from it.
+#[derive(Clone)]
+struct HashJoinStreamTemplate {
+    /// Input schema
+    schema: Arc<Schema>,
+    /// columns from the left
+    on_left: Vec<Column>,
+    /// columns from the right used to compute the hash
+    on_right: Vec<Column>,
+    /// join filter
+    filter: Option<JoinFilter>,
+    /// type of the join
+    join_type: JoinType,
+    /// Information of index and left / right placement of columns
+    column_indices: Vec<ColumnIndex>,
+    /// Random state used for hashing initialization
+    random_state: RandomState,
+    /// Metrics
+    join_metrics: BuildProbeJoinMetrics,
+    /// If null_equals_null is true, null == null else null != null
+    null_equals_null: bool,
+}
+
+impl HashJoinStreamTemplate {
+    /// Creates a [`HashJoinStream`] probing the build side from `left_fut`
+    /// with the batches of `right`
+    fn create_stream(
+        &self,
+        left_fut: OnceFut<JoinLeftData>,
+        right: SendableRecordBatchStream,
+        reservation: SharedMemoryReservation,
+    ) -> HashJoinStream {
+        HashJoinStream {
+            schema: self.schema.clone(),
+            on_left: self.on_left.clone(),
+            on_right: self.on_right.clone(),
+            filter: self.filter.clone(),
+            join_type: self.join_type,
+            left_fut,
+            visited_left_side: None,
+            right,
+            column_indices: self.column_indices.clone(),
+            random_state: self.random_state.clone(),
+            join_metrics: self.join_metrics.clone(),
+            null_equals_null: self.null_equals_null,
+            is_exhausted: false,
+            reservation,
+        }
+    }
+}
+
 /// A stream that issues [RecordBatch]es as they arrive from the right  of the join.
 struct HashJoinStream {
     /// Input schema
@@ -1294,7 +1671,10 @@ mod tests {
     use crate::{
         assert_batches_sorted_eq,
         common::assert_contains,
-        execution::runtime_env::{RuntimeConfig, RuntimeEnv},
+        execution::{
+            disk_manager::DiskManagerConfig,
+            runtime_env::{RuntimeConfig, RuntimeEnv},
+        },
         physical_plan::{
             common,
             expressions::Column,
@@ -1308,6 +1688,7 @@ mod tests {
     };
     use arrow::array::{ArrayRef, Date32Array, Int32Array, UInt32Builder, UInt64Builder};
     use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::util::pretty::pretty_format_batches;
     use datafusion_expr::Operator;
 
     use datafusion_common::ScalarValue;
@@ -3122,7 +3503,10 @@ mod tests {
         ];
 
         for join_type in join_types {
-            let runtime_config = RuntimeConfig::new().with_memory_limit(100, 1.0);
+            // Disable spilling, so the grace hash join fallback can't kick in
+            let runtime_config = RuntimeConfig::new()
+                .with_memory_limit(100, 1.0)
+                .with_disk_manager(DiskManagerConfig::Disabled);
             let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
             let session_config = SessionConfig::default().with_batch_size(50);
             let session_ctx = SessionContext::with_config_rt(session_config, runtime);
@@ -3141,9 +3525,16 @@ mod tests {
             let stream = join.execute(1, task_ctx)?;
             let err = common::collect(stream).await.unwrap_err();
 
+            // The build side is collected by the partition's stream itself
+            // rather than through a shared `OnceFut`, so the error is not
+            // wrapped into `DataFusionError::External`
+            assert!(
+                matches!(err, DataFusionError::ResourcesExhausted(_)),
+                "{err:?}"
+            );
             assert_contains!(
                 err.to_string(),
-                "External error: Resources exhausted: Failed to allocate additional"
+                "Resources exhausted: Failed to allocate additional"
             );
 
             // Asserting that stream-level reservation attempting to overallocate
@@ -3152,4 +3543,231 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn partitioned_join_spill() -> Result<()> {
+        // Build side of 1000 rows split into 10 batches, which doesn't fit
+        // into the memory limit together with its hash table
+        let left_batches = (0..10)
+            .map(|i| {
+                let a1 = (i * 100..(i + 1) * 100).collect::<Vec<_>>();
+                let b1 = a1.iter().map(|v| v % 300).collect::<Vec<_>>();
+                build_table_i32(("a1", &a1), ("b1", &b1), ("c1", &a1))
+            })
+            .collect::<Vec<_>>();
+        let left_schema = left_batches[0].schema();
+        let left = Arc::new(MemoryExec::try_new(&[left_batches], left_schema, None)?);
+
+        let a2 = (0..200).collect::<Vec<_>>();
+        let b2 = a2.iter().map(|v| v * 2).collect::<Vec<_>>();
+        let right_batch = build_table_i32(("a2", &a2), ("b2", &b2), ("c2", &a2));
+        let right = Arc::new(MemoryExec::try_new(
+            &[vec![right_batch.clone()]],
+            right_batch.schema(),
+            None,
+        )?);
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+        ];
+
+        for join_type in join_types {
+            let join = Arc::new(HashJoinExec::try_new(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                None,
+                &join_type,
+                PartitionMode::Partitioned,
+                false,
+            )?);
+
+            let session_ctx = SessionContext::new();
+            let batches =
+                common::collect(join.execute(0, session_ctx.task_ctx())?).await?;
+            let mut expected = pretty_format_batches(&batches)?
+                .to_string()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            expected.sort();
+
+            let runtime_config = RuntimeConfig::new().with_memory_limit(32 * 1024, 1.0);
+            let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+            let session_ctx =
+                SessionContext::with_config_rt(SessionConfig::default(), runtime);
+            let task_ctx = session_ctx.task_ctx();
+
+            let batches = common::collect(join.execute(0, task_ctx.clone())?).await?;
+            let mut actual = pretty_format_batches(&batches)?
+                .to_string()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            actual.sort();
+
+            assert_eq!(expected, actual, "{join_type:?}");
+
+            let metrics = join.metrics().unwrap();
+            assert!(metrics.spill_count().unwrap() > 0, "{join_type:?}");
+            assert!(metrics.spilled_bytes().unwrap() > 0, "{join_type:?}");
+            assert_eq!(task_ctx.memory_pool().reserved(), 0);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partitioned_join_recursive_spill() -> Result<()> {
+        // Build side of 16384 rows, whose grace partitions of ~1024 rows each
+        // still don't fit into the memory limit together with their hash
+        // tables, while the ones of ~64 rows after splitting them again do
+        let a1 = (0..16384).collect::<Vec<_>>();
+        let b1 = a1.iter().map(|v| v % 5000).collect::<Vec<_>>();
+        let left_batch = build_table_i32(("a1", &a1), ("b1", &b1), ("c1", &a1));
+        let left = Arc::new(MemoryExec::try_new(
+            &[vec![left_batch.clone()]],
+            left_batch.schema(),
+            None,
+        )?);
+
+        let a2 = (0..2000).collect::<Vec<_>>();
+        let b2 = a2.iter().map(|v| v * 2).collect::<Vec<_>>();
+        let right_batch = build_table_i32(("a2", &a2), ("b2", &b2), ("c2", &a2));
+        let right = Arc::new(MemoryExec::try_new(
+            &[vec![right_batch.clone()]],
+            right_batch.schema(),
+            None,
+        )?);
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+        ];
+
+        for join_type in join_types {
+            let join = Arc::new(HashJoinExec::try_new(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                None,
+                &join_type,
+                PartitionMode::Partitioned,
+                false,
+            )?);
+
+            let session_ctx = SessionContext::new();
+            let batches =
+                common::collect(join.execute(0, session_ctx.task_ctx())?).await?;
+            let mut expected = pretty_format_batches(&batches)?
+                .to_string()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            expected.sort();
+
+            let runtime_config = RuntimeConfig::new().with_memory_limit(16 * 1024, 1.0);
+            let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+            let session_ctx =
+                SessionContext::with_config_rt(SessionConfig::default(), runtime);
+            let task_ctx = session_ctx.task_ctx();
+
+            let batches = common::collect(join.execute(0, task_ctx.clone())?).await?;
+            let mut actual = pretty_format_batches(&batches)?
+                .to_string()
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            actual.sort();
+
+            assert_eq!(expected, actual, "{join_type:?}");
+
+            // Both inputs are spilled once by the partition itself, and once
+            // more by each of its grace partitions that gets split again
+            let metrics = join.metrics().unwrap();
+            assert!(metrics.spill_count().unwrap() > 2, "{join_type:?}");
+            assert_eq!(task_ctx.memory_pool().reserved(), 0);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partitioned_join_skewed_spill() -> Result<()> {
+        // All rows of the build side share the same join key, so splitting it
+        // into grace partitions never reduces the size of the largest one
+        let a1 = (0..1000).collect::<Vec<_>>();
+        let b1 = vec![1; 1000];
+        let left_batch = build_table_i32(("a1", &a1), ("b1", &b1), ("c1", &a1));
+        let left = Arc::new(MemoryExec::try_new(
+            &[vec![left_batch.clone()]],
+            left_batch.schema(),
+            None,
+        )?);
+        let right_batch = build_table_i32(
+            ("a2", &vec![1, 2]),
+            ("b2", &vec![1, 2]),
+            ("c2", &vec![1, 2]),
+        );
+        let right = Arc::new(MemoryExec::try_new(
+            &[vec![right_batch.clone()]],
+            right_batch.schema(),
+            None,
+        )?);
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        let join = Arc::new(HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            &JoinType::Inner,
+            PartitionMode::Partitioned,
+            false,
+        )?);
+
+        let runtime_config = RuntimeConfig::new().with_memory_limit(16 * 1024, 1.0);
+        let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+        let session_ctx =
+            SessionContext::with_config_rt(SessionConfig::default(), runtime);
+
+        // The partition is split `MAX_GRACE_DEPTH` times before giving up
+        let err = common::collect(join.execute(0, session_ctx.task_ctx())?)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, DataFusionError::ResourcesExhausted(_)),
+            "{err:?}"
+        );
+        assert_contains!(err.to_string(), "HashJoinStream[0]");
+
+        let metrics = join.metrics().unwrap();
+        assert_eq!(metrics.spill_count().unwrap(), 2 * MAX_GRACE_DEPTH);
+
+        Ok(())
+    }
 }
@@ -19,11 +19,18 @@
 //! A Sort-Merge join plan consumes two sorted children plan and produces
 //! joined output by given join type and other options.
 //! Sort-Merge join feature is currently experimental.
+//!
+//! The rows of the buffered side sharing the current join key are held in
+//! memory, tracked by the memory pool. When the pool can't accommodate
+//! them, the buffered batches are spilled to disk and read back when
+//! producing output.
 
 use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::fmt::Formatter;
+use std::fs::File;
+use std::io::BufReader;
 use std::mem;
 use std::ops::Range;
 use std::pin::Pin;
@@ -34,14 +41,19 @@ use arrow::array::*;
 use arrow::compute::{concat_batches, take, SortOptions};
 use arrow::datatypes::{DataType, SchemaRef, TimeUnit};
 use arrow::error::ArrowError;
+use arrow::ipc::reader::FileReader;
 use arrow::record_batch::RecordBatch;
 use futures::{Stream, StreamExt};
+use log::debug;
+use tempfile::NamedTempFile;
 
 use crate::error::DataFusionError;
 use crate::error::Result;
 use crate::execution::context::TaskContext;
 use crate::execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use crate::execution::runtime_env::RuntimeEnv;
 use crate::logical_expr::JoinType;
+use crate::physical_plan::common::spill_record_batches;
 use crate::physical_plan::expressions::Column;
 use crate::physical_plan::expressions::PhysicalSortExpr;
 use crate::physical_plan::joins::utils::{
@@ -100,12 +112,6 @@ impl SortMergeJoinExec {
         let left_schema = left.schema();
         let right_schema = right.schema();
 
-        if join_type == JoinType::RightSemi {
-            return Err(DataFusionError::NotImplemented(
-                "SortMergeJoinExec does not support JoinType::RightSemi".to_string(),
-            ));
-        }
-
         check_join_is_valid(&left_schema, &right_schema, &on)?;
         if sort_options.len() != on.len() {
             return Err(DataFusionError::Plan(format!(
@@ -197,6 +203,31 @@ impl SortMergeJoinExec {
     pub fn on(&self) -> &[(Column, Column)] {
         &self.on
     }
+
+    /// Left sorted input
+    pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.left
+    }
+
+    /// Right sorted input
+    pub fn right(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.right
+    }
+
+    /// How the join is performed
+    pub fn join_type(&self) -> JoinType {
+        self.join_type
+    }
+
+    /// Sort options of the join columns, one per join key
+    pub fn sort_options(&self) -> &[SortOptions] {
+        &self.sort_options
+    }
+
+    /// Whether null == null in the join keys
+    pub fn null_equals_null(&self) -> bool {
+        self.null_equals_null
+    }
 }
 
 impl ExecutionPlan for SortMergeJoinExec {
@@ -311,6 +342,7 @@ impl ExecutionPlan for SortMergeJoinExec {
 
         // create memory reservation
         let reservation = MemoryConsumer::new(format!("SMJStream[{partition}]"))
+            .with_can_spill(true)
             .register(context.memory_pool());
 
         // create join stream
@@ -326,6 +358,7 @@ impl ExecutionPlan for SortMergeJoinExec {
             batch_size,
             SortMergeJoinMetrics::new(partition, &self.metrics),
             reservation,
+            context.runtime_env(),
         )?))
     }
 
@@ -374,6 +407,10 @@ struct SortMergeJoinMetrics {
     /// Peak memory used for buffered data.
     /// Calculated as sum of peak memory values across partitions
     peak_mem_used: metrics::Gauge,
+    /// Number of buffered batches spilled to disk
+    spill_count: metrics::Count,
+    /// Total size in bytes of the buffered batches spilled to disk
+    spilled_bytes: metrics::Count,
 }
 
 impl SortMergeJoinMetrics {
@@ -387,6 +424,8 @@ impl SortMergeJoinMetrics {
             MetricBuilder::new(metrics).counter("output_batches", partition);
         let output_rows = MetricBuilder::new(metrics).output_rows(partition);
         let peak_mem_used = MetricBuilder::new(metrics).gauge("peak_mem_used", partition);
+        let spill_count = MetricBuilder::new(metrics).spill_count(partition);
+        let spilled_bytes = MetricBuilder::new(metrics).spilled_bytes(partition);
 
         Self {
             join_time,
@@ -395,6 +434,8 @@ impl SortMergeJoinMetrics {
             output_batches,
             output_rows,
             peak_mem_used,
+            spill_count,
+            spilled_bytes,
         }
     }
 }
@@ -511,8 +552,10 @@ impl StreamedBatch {
 /// A buffered batch that contains contiguous rows with same join key
 #[derive(Debug)]
 struct BufferedBatch {
-    /// The buffered record batch
-    pub batch: RecordBatch,
+    /// The buffered record batch, `None` once it has been spilled to disk
+    pub batch: Option<RecordBatch>,
+    /// Number of rows in the buffered record batch
+    pub num_rows: usize,
     /// The range in which the rows share the same join key
     pub range: Range<usize>,
     /// Array refs of the join key
@@ -521,6 +564,8 @@ struct BufferedBatch {
     pub null_joined: Vec<usize>,
     /// Size estimation used for reserving / releasing memory
     pub size_estimation: usize,
+    /// The file the record batch was spilled to, if it didn't fit in memory
+    pub spill_file: Option<NamedTempFile>,
 }
 impl BufferedBatch {
     fn new(batch: RecordBatch, range: Range<usize>, on_column: &[Column]) -> Self {
@@ -542,11 +587,29 @@ impl BufferedBatch {
             + mem::size_of::<usize>();
 
         BufferedBatch {
-            batch,
+            num_rows: batch.num_rows(),
+            batch: Some(batch),
             range,
             join_arrays,
             null_joined: vec![],
             size_estimation,
+            spill_file: None,
+        }
+    }
+
+    /// Reads the record batch back from the file it was spilled to
+    fn read_spilled(&self) -> Result<RecordBatch> {
+        match &self.spill_file {
+            Some(spill_file) => {
+                let file = BufReader::new(File::open(spill_file.path())?);
+                let reader = FileReader::try_new(file, None)?;
+                let schema = reader.schema();
+                let batches = reader.collect::<Result<Vec<_>, ArrowError>>()?;
+                Ok(concat_batches(&schema, &batches)?)
+            }
+            None => Err(DataFusionError::Internal(
+                "Buffered batch is neither in memory nor spilled".to_string(),
+            )),
         }
     }
 }
@@ -600,6 +663,8 @@ struct SMJStream {
     pub join_metrics: SortMergeJoinMetrics,
     /// Memory reservation
     pub reservation: MemoryReservation,
+    /// Runtime environment, used to spill buffered batches to disk
+    pub runtime_env: Arc<RuntimeEnv>,
 }
 
 impl RecordBatchStream for SMJStream {
@@ -718,6 +783,7 @@ impl SMJStream {
         batch_size: usize,
         join_metrics: SortMergeJoinMetrics,
         reservation: MemoryReservation,
+        runtime_env: Arc<RuntimeEnv>,
     ) -> Result<Self> {
         let streamed_schema = streamed.schema();
         let buffered_schema = buffered.schema();
@@ -745,6 +811,7 @@ impl SMJStream {
             join_type,
             join_metrics,
             reservation,
+            runtime_env,
         })
     }
 
@@ -798,7 +865,7 @@ impl SMJStream {
                     // pop previous buffered batches
                     while !self.buffered_data.batches.is_empty() {
                         let head_batch = self.buffered_data.head_batch();
-                        if head_batch.range.end == head_batch.batch.num_rows() {
+                        if head_batch.range.end == head_batch.num_rows {
                             self.freeze_dequeuing_buffered()?;
                             if let Some(buffered_batch) =
                                 self.buffered_data.batches.pop_front()
@@ -830,13 +897,9 @@ impl SMJStream {
                         self.join_metrics.input_batches.add(1);
                         self.join_metrics.input_rows.add(batch.num_rows());
                         if batch.num_rows() > 0 {
-                            let buffered_batch =
-                                BufferedBatch::new(batch, 0..1, &self.on_buffered);
-                            self.reservation.try_grow(buffered_batch.size_estimation)?;
-                            self.join_metrics
-                                .peak_mem_used
-                                .set_max(self.reservation.size());
-
+                            let buffered_batch = self.allocate_reservation(
+                                BufferedBatch::new(batch, 0..1, &self.on_buffered),
+                            )?;
                             self.buffered_data.batches.push_back(buffered_batch);
                             self.buffered_state = BufferedState::PollingRest;
                         }
@@ -844,10 +907,10 @@ impl SMJStream {
                 },
                 BufferedState::PollingRest => {
                     if self.buffered_data.tail_batch().range.end
-                        < self.buffered_data.tail_batch().batch.num_rows()
+                        < self.buffered_data.tail_batch().num_rows
                     {
                         while self.buffered_data.tail_batch().range.end
-                            < self.buffered_data.tail_batch().batch.num_rows()
+                            < self.buffered_data.tail_batch().num_rows
                         {
                             if is_join_arrays_equal(
                                 &self.buffered_data.head_batch().join_arrays,
@@ -873,16 +936,12 @@ impl SMJStream {
                                 self.join_metrics.input_batches.add(1);
                                 self.join_metrics.input_rows.add(batch.num_rows());
                                 if batch.num_rows() > 0 {
-                                    let buffered_batch = BufferedBatch::new(
-                                        batch,
-                                        0..0,
-                                        &self.on_buffered,
-                                    );
-                                    self.reservation
-                                        .try_grow(buffered_batch.size_estimation)?;
-                                    self.join_metrics
-                                        .peak_mem_used
-                                        .set_max(self.reservation.size());
+                                    let buffered_batch =
+                                        self.allocate_reservation(BufferedBatch::new(
+                                            batch,
+                                            0..0,
+                                            &self.on_buffered,
+                                        ))?;
                                     self.buffered_data.batches.push_back(buffered_batch);
                                 }
                             }
@@ -899,6 +958,71 @@ impl SMJStream {
         }
     }
 
+    /// Reserve memory for a new buffered batch. If the memory pool can't
+    /// accommodate it, the record batch is spilled to disk and only its
+    /// join keys are kept in memory, as they're needed to find the rows
+    /// sharing the current join key.
+    fn allocate_reservation(
+        &mut self,
+        mut buffered_batch: BufferedBatch,
+    ) -> Result<BufferedBatch> {
+        match self.reservation.try_grow(buffered_batch.size_estimation) {
+            Ok(_) => {}
+            Err(_) if self.runtime_env.disk_manager.tmp_files_enabled() => {
+                if let Some(batch) = buffered_batch.batch.take() {
+                    debug!(
+                        "Spilling buffered batch of {} rows of SMJStream to disk",
+                        batch.num_rows()
+                    );
+                    let batch_size = batch.get_array_memory_size();
+                    let spill_file = self
+                        .runtime_env
+                        .disk_manager
+                        .create_tmp_file("SortMergeJoinExec spill")?;
+                    let spilled_bytes = spill_record_batches(
+                        vec![batch],
+                        spill_file.path().into(),
+                        self.buffered_schema.clone(),
+                    )?;
+                    self.join_metrics.spill_count.add(1);
+                    self.join_metrics.spilled_bytes.add(spilled_bytes);
+
+                    buffered_batch.size_estimation -= batch_size;
+                    buffered_batch.spill_file = Some(spill_file);
+                }
+                // the join keys must stay in memory
+                self.reservation.try_grow(buffered_batch.size_estimation)?;
+            }
+            Err(e) => return Err(e),
+        }
+        self.join_metrics
+            .peak_mem_used
+            .set_max(self.reservation.size());
+        Ok(buffered_batch)
+    }
+
+    /// Returns the record batch of the buffered batch at `idx`. A spilled
+    /// batch is read back from disk, and kept in memory until it is
+    /// dequeued if the memory pool can accommodate it, so that it is only
+    /// read once for all the output of its key group.
+    fn buffered_record_batch(&mut self, idx: usize) -> Result<RecordBatch> {
+        let buffered_batch = &mut self.buffered_data.batches[idx];
+        if let Some(batch) = &buffered_batch.batch {
+            return Ok(batch.clone());
+        }
+
+        let batch = buffered_batch.read_spilled()?;
+        let batch_size = batch.get_array_memory_size();
+        if self.reservation.try_grow(batch_size).is_ok() {
+            buffered_batch.batch = Some(batch.clone());
+            buffered_batch.size_estimation += batch_size;
+            self.join_metrics
+                .peak_mem_used
+                .set_max(self.reservation.size());
+        }
+        Ok(batch)
+    }
+
     /// Get comparison result of streamed row and buffered batches
     fn compare_streamed_buffered(&self) -> Result<Ordering> {
         if self.streamed_state == StreamedState::Exhausted {
@@ -931,15 +1055,15 @@ impl SMJStream {
                     self.join_type,
                     JoinType::Left
                         | JoinType::Right
-                        | JoinType::RightSemi
                         | JoinType::Full
                         | JoinType::LeftAnti
+                        | JoinType::RightAnti
                 ) {
                     join_streamed = !self.streamed_joined;
                 }
             }
             Ordering::Equal => {
-                if matches!(self.join_type, JoinType::LeftSemi) {
+                if matches!(self.join_type, JoinType::LeftSemi | JoinType::RightSemi) {
                     join_streamed = !self.streamed_joined;
                 }
                 if matches!(
@@ -1028,7 +1152,8 @@ impl SMJStream {
         if !matches!(self.join_type, JoinType::Full) {
             return Ok(());
         }
-        for buffered_batch in self.buffered_data.batches.range_mut(..batch_count) {
+        for idx in 0..batch_count {
+            let buffered_batch = &mut self.buffered_data.batches[idx];
             let buffered_indices = UInt64Array::from_iter_values(
                 buffered_batch.null_joined.iter().map(|&index| index as u64),
             );
@@ -1037,8 +1162,8 @@ impl SMJStream {
             }
             buffered_batch.null_joined.clear();
 
-            let buffered_columns = buffered_batch
-                .batch
+            let buffered_columns = self
+                .buffered_record_batch(idx)?
                 .columns()
                 .iter()
                 .map(|column| take(column, &buffered_indices, None))
@@ -1064,7 +1189,8 @@ impl SMJStream {
     // Produces and stages record batch for all output indices found
     // for current streamed batch and clears staged output indices.
     fn freeze_streamed(&mut self) -> Result<()> {
-        for chunk in self.streamed_batch.output_indices.iter_mut() {
+        let output_indices = mem::take(&mut self.streamed_batch.output_indices);
+        for mut chunk in output_indices {
             let streamed_indices = chunk.streamed_indices.finish();
 
             if streamed_indices.is_empty() {
@@ -1081,23 +1207,27 @@ impl SMJStream {
 
             let buffered_indices: UInt64Array = chunk.buffered_indices.finish();
 
-            let mut buffered_columns =
-                if matches!(self.join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
-                    vec![]
-                } else if let Some(buffered_idx) = chunk.buffered_batch_idx {
-                    self.buffered_data.batches[buffered_idx]
-                        .batch
-                        .columns()
-                        .iter()
-                        .map(|column| take(column, &buffered_indices, None))
-                        .collect::<Result<Vec<_>, ArrowError>>()?
-                } else {
-                    self.buffered_schema
-                        .fields()
-                        .iter()
-                        .map(|f| new_null_array(f.data_type(), buffered_indices.len()))
-                        .collect::<Vec<_>>()
-                };
+            let mut buffered_columns = if matches!(
+                self.join_type,
+                JoinType::LeftSemi
+                    | JoinType::LeftAnti
+                    | JoinType::RightSemi
+                    | JoinType::RightAnti
+            ) {
+                vec![]
+            } else if let Some(buffered_idx) = chunk.buffered_batch_idx {
+                self.buffered_record_batch(buffered_idx)?
+                    .columns()
+                    .iter()
+                    .map(|column| take(column, &buffered_indices, None))
+                    .collect::<Result<Vec<_>, ArrowError>>()?
+            } else {
+                self.buffered_schema
+                    .fields()
+                    .iter()
+                    .map(|f| new_null_array(f.data_type(), buffered_indices.len()))
+                    .collect::<Vec<_>>()
+            };
 
             let columns = if matches!(self.join_type, JoinType::Right) {
                 buffered_columns.extend(streamed_columns);
@@ -1111,8 +1241,6 @@ impl SMJStream {
                 .push(RecordBatch::try_new(self.schema.clone(), columns)?);
         }
 
-        self.streamed_batch.output_indices.clear();
-
         Ok(())
     }
 
@@ -1362,15 +1490,19 @@ mod tests {
     use arrow::compute::SortOptions;
     use arrow::datatypes::{DataType, Field, Schema};
     use arrow::record_batch::RecordBatch;
+    use arrow::util::pretty::pretty_format_batches;
 
     use crate::common::assert_contains;
     use crate::error::Result;
+    use crate::execution::disk_manager::DiskManagerConfig;
     use crate::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
     use crate::logical_expr::JoinType;
     use crate::physical_plan::expressions::Column;
+    use crate::physical_plan::joins::sort_merge_join::BufferedBatch;
     use crate::physical_plan::joins::utils::JoinOn;
     use crate::physical_plan::joins::SortMergeJoinExec;
     use crate::physical_plan::memory::MemoryExec;
+    use crate::physical_plan::metrics::MetricsSet;
     use crate::physical_plan::{common, ExecutionPlan};
     use crate::prelude::{SessionConfig, SessionContext};
     use crate::test::{build_table_i32, columns};
@@ -1946,6 +2078,69 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_right_anti() -> Result<()> {
+        let left = build_table(
+            ("a1", &vec![10, 20, 30]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![70, 80, 90]),
+        );
+        let right = build_table(
+            ("a2", &vec![1, 2, 2, 3, 5]),
+            ("b1", &vec![4, 5, 5, 7, 7]), // 7 does not exist on the left
+            ("c2", &vec![7, 8, 8, 9, 11]),
+        );
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b1", &right.schema())?,
+        )];
+
+        let (_, batches) = join_collect(left, right, on, JoinType::RightAnti).await?;
+        let expected = vec![
+            "+----+----+----+",
+            "| a2 | b1 | c2 |",
+            "+----+----+----+",
+            "| 3  | 7  | 9  |",
+            "| 5  | 7  | 11 |",
+            "+----+----+----+",
+        ];
+        // The output order is important as SMJ preserves sortedness
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_right_semi() -> Result<()> {
+        let left = build_table(
+            ("a1", &vec![10, 20, 30, 40]),
+            ("b1", &vec![4, 5, 5, 6]), // 5 is double on the left
+            ("c1", &vec![70, 80, 90, 100]),
+        );
+        let right = build_table(
+            ("a2", &vec![1, 2, 2, 3]),
+            ("b1", &vec![4, 5, 5, 7]), // 7 does not exist on the left
+            ("c2", &vec![7, 8, 8, 9]),
+        );
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b1", &right.schema())?,
+        )];
+
+        let (_, batches) = join_collect(left, right, on, JoinType::RightSemi).await?;
+        let expected = vec![
+            "+----+----+----+",
+            "| a2 | b1 | c2 |",
+            "+----+----+----+",
+            "| 1  | 4  | 7  |",
+            "| 2  | 5  | 8  |",
+            "| 2  | 5  | 8  |",
+            "+----+----+----+",
+        ];
+        // The output order is important as SMJ preserves sortedness
+        assert_batches_eq!(expected, &batches);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn join_with_duplicated_column_names() -> Result<()> {
         let left = build_table(
@@ -2288,10 +2483,14 @@ mod tests {
             JoinType::Full,
             JoinType::LeftSemi,
             JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
         ];
 
         for join_type in join_types {
-            let runtime_config = RuntimeConfig::new().with_memory_limit(100, 1.0);
+            let runtime_config = RuntimeConfig::new()
+                .with_memory_limit(100, 1.0)
+                .with_disk_manager(DiskManagerConfig::Disabled);
             let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
             let session_config = SessionConfig::default().with_batch_size(50);
             let session_ctx = SessionContext::with_config_rt(session_config, runtime);
@@ -2364,10 +2563,14 @@ mod tests {
             JoinType::Full,
             JoinType::LeftSemi,
             JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
         ];
 
         for join_type in join_types {
-            let runtime_config = RuntimeConfig::new().with_memory_limit(100, 1.0);
+            let runtime_config = RuntimeConfig::new()
+                .with_memory_limit(100, 1.0)
+                .with_disk_manager(DiskManagerConfig::Disabled);
             let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
             let session_config = SessionConfig::default().with_batch_size(50);
             let session_ctx = SessionContext::with_config_rt(session_config, runtime);
@@ -2393,4 +2596,204 @@ mod tests {
 
         Ok(())
     }
+
+    async fn join_collect_with_runtime(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: JoinOn,
+        join_type: JoinType,
+        runtime_config: RuntimeConfig,
+    ) -> Result<(Vec<RecordBatch>, MetricsSet)> {
+        let runtime = Arc::new(RuntimeEnv::new(runtime_config)?);
+        let session_config = SessionConfig::default().with_batch_size(50);
+        let session_ctx = SessionContext::with_config_rt(session_config, runtime);
+        let task_ctx = session_ctx.task_ctx();
+        let join = join(left, right, on, join_type)?;
+
+        let stream = join.execute(0, task_ctx)?;
+        let batches = common::collect(stream).await?;
+        Ok((batches, join.metrics().unwrap()))
+    }
+
+    /// Returns a memory limit large enough to keep the join keys of all the
+    /// batches of either input in memory, but too small for any of the
+    /// batches themselves, so that every buffered batch is spilled
+    async fn spilling_memory_limit(
+        left: &Arc<dyn ExecutionPlan>,
+        right: &Arc<dyn ExecutionPlan>,
+        on: &JoinOn,
+    ) -> Result<usize> {
+        let task_ctx = SessionContext::new().task_ctx();
+        let inputs = [
+            (left, on.iter().map(|(l, _)| l.clone()).collect::<Vec<_>>()),
+            (right, on.iter().map(|(_, r)| r.clone()).collect::<Vec<_>>()),
+        ];
+
+        let mut limit = 0;
+        let mut min_size_estimation = usize::MAX;
+        for (input, on_columns) in inputs {
+            let batches = common::collect(input.execute(0, task_ctx.clone())?).await?;
+            let mut join_keys_size = 0;
+            for batch in batches {
+                let batch_size = batch.get_array_memory_size();
+                let buffered_batch = BufferedBatch::new(batch, 0..1, &on_columns);
+                join_keys_size += buffered_batch.size_estimation - batch_size;
+                min_size_estimation =
+                    min_size_estimation.min(buffered_batch.size_estimation);
+            }
+            limit = limit.max(join_keys_size);
+        }
+        assert!(limit < min_size_estimation);
+        Ok(limit)
+    }
+
+    /// Runs the join of `left` and `right` for every join type with a memory
+    /// limit too small to hold any buffered batch, and checks that the
+    /// buffered batches were spilled and the output matches the unlimited join
+    async fn assert_spilled_joins(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: JoinOn,
+    ) -> Result<()> {
+        let memory_limit = spilling_memory_limit(&left, &right, &on).await?;
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+        ];
+
+        for join_type in join_types {
+            let (expected, _) = join_collect_with_runtime(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                join_type,
+                RuntimeConfig::new(),
+            )
+            .await?;
+            let (batches, metrics) = join_collect_with_runtime(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                join_type,
+                RuntimeConfig::new().with_memory_limit(memory_limit, 1.0),
+            )
+            .await?;
+
+            assert!(
+                metrics.spill_count().unwrap() > 0,
+                "{join_type} didn't spill"
+            );
+            assert!(metrics.spilled_bytes().unwrap() > 0);
+            assert_eq!(
+                pretty_format_batches(&expected)?.to_string(),
+                pretty_format_batches(&batches)?.to_string(),
+                "{join_type} output differs after spilling"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spill_single_batch() -> Result<()> {
+        let left = build_table(
+            ("a1", &vec![0, 1, 2, 3, 4, 5]),
+            ("b1", &vec![1, 2, 3, 4, 5, 6]),
+            ("c1", &vec![4, 5, 6, 7, 8, 9]),
+        );
+        let right = build_table(
+            ("a2", &vec![0, 10, 20, 30, 40]),
+            ("b2", &vec![1, 3, 4, 6, 8]),
+            ("c2", &vec![50, 60, 70, 80, 90]),
+        );
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        assert_spilled_joins(left, right, on).await
+    }
+
+    #[tokio::test]
+    async fn spill_multi_batch() -> Result<()> {
+        // the key group of b = 1 spans several buffered batches
+        let left_batch_1 = build_table_i32(
+            ("a1", &vec![0, 1]),
+            ("b1", &vec![0, 1]),
+            ("c1", &vec![4, 5]),
+        );
+        let left_batch_2 = build_table_i32(
+            ("a1", &vec![2, 3]),
+            ("b1", &vec![1, 1]),
+            ("c1", &vec![6, 7]),
+        );
+        let left_batch_3 = build_table_i32(
+            ("a1", &vec![4, 5]),
+            ("b1", &vec![1, 3]),
+            ("c1", &vec![8, 9]),
+        );
+        let right_batch_1 = build_table_i32(
+            ("a2", &vec![0, 10]),
+            ("b2", &vec![1, 1]),
+            ("c2", &vec![50, 60]),
+        );
+        let right_batch_2 = build_table_i32(
+            ("a2", &vec![20, 30]),
+            ("b2", &vec![1, 2]),
+            ("c2", &vec![70, 80]),
+        );
+        let right_batch_3 =
+            build_table_i32(("a2", &vec![40]), ("b2", &vec![2]), ("c2", &vec![90]));
+        let left =
+            build_table_from_batches(vec![left_batch_1, left_batch_2, left_batch_3]);
+        let right =
+            build_table_from_batches(vec![right_batch_1, right_batch_2, right_batch_3]);
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        assert_spilled_joins(left, right, on).await
+    }
+
+    #[tokio::test]
+    async fn spill_join_keys_exceed_memory_limit() -> Result<()> {
+        let left = build_table(
+            ("a1", &vec![0, 1, 2]),
+            ("b1", &vec![1, 2, 3]),
+            ("c1", &vec![4, 5, 6]),
+        );
+        let right = build_table(
+            ("a2", &vec![0, 10, 20]),
+            ("b2", &vec![1, 2, 3]),
+            ("c2", &vec![50, 60, 70]),
+        );
+        let on = vec![(
+            Column::new_with_schema("b1", &left.schema())?,
+            Column::new_with_schema("b2", &right.schema())?,
+        )];
+
+        // the join keys of spilled batches stay in memory, so spilling
+        // fails if they don't fit either
+        let err = join_collect_with_runtime(
+            left,
+            right,
+            on,
+            JoinType::Inner,
+            RuntimeConfig::new().with_memory_limit(1, 1.0),
+        )
+        .await
+        .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Resources exhausted: Failed to allocate additional"
+        );
+        Ok(())
+    }
 }
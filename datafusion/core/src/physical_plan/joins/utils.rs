@@ -637,7 +637,10 @@ fn estimate_inner_join_cardinality(
 /// If distinct_count is available, uses it directly. If the column numeric, and
 /// has min/max values, then they might be used as a fallback option. Otherwise,
 /// returns None.
-fn max_distinct_count(num_rows: usize, stats: ColumnStatistics) -> Option<usize> {
+pub(crate) fn max_distinct_count(
+    num_rows: usize,
+    stats: ColumnStatistics,
+) -> Option<usize> {
     match (stats.distinct_count, stats.max_value, stats.min_value) {
         (Some(_), _, _) => stats.distinct_count,
         (_, Some(max), Some(min)) => {
@@ -1022,6 +1025,10 @@ pub(crate) struct BuildProbeJoinMetrics {
     pub(crate) output_batches: metrics::Count,
     /// Number of rows produced by this operator
     pub(crate) output_rows: metrics::Count,
+    /// Number of times the inputs were spilled to disk
+    pub(crate) spill_count: metrics::Count,
+    /// Total bytes of input data spilled to disk
+    pub(crate) spilled_bytes: metrics::Count,
 }
 
 impl BuildProbeJoinMetrics {
@@ -1049,6 +1056,10 @@ impl BuildProbeJoinMetrics {
 
         let output_rows = MetricBuilder::new(metrics).output_rows(partition);
 
+        let spill_count = MetricBuilder::new(metrics).spill_count(partition);
+
+        let spilled_bytes = MetricBuilder::new(metrics).spilled_bytes(partition);
+
         Self {
             build_time,
             build_input_batches,
@@ -1059,6 +1070,8 @@ impl BuildProbeJoinMetrics {
             input_rows,
             output_batches,
             output_rows,
+            spill_count,
+            spilled_bytes,
         }
     }
 }
@@ -150,6 +150,21 @@ impl MemoryExec {
         })
     }
 
+    /// The in-memory partitions, before the projection is applied
+    pub fn partitions(&self) -> &[Vec<RecordBatch>] {
+        &self.partitions
+    }
+
+    /// Schema of the partitions, before the projection is applied
+    pub fn original_schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Optional projection applied to the partitions
+    pub fn projection(&self) -> &Option<Vec<usize>> {
+        &self.projection
+    }
+
     /// Set sort information
     pub fn with_sort_information(
         mut self,
@@ -647,12 +647,14 @@ pub mod empty;
 pub mod explain;
 pub mod file_format;
 pub mod filter;
+pub mod insert;
 pub mod joins;
 pub mod limit;
 pub mod memory;
 pub mod metrics;
 pub mod planner;
 pub mod projection;
+pub mod recursive_query;
 pub mod repartition;
 pub mod sorts;
 pub mod stream;
@@ -663,6 +665,7 @@ pub mod union;
 pub mod unnest;
 pub mod values;
 pub mod windows;
+pub mod work_table;
 
 use crate::execution::context::TaskContext;
 use crate::physical_plan::repartition::RepartitionExec;
@@ -18,17 +18,23 @@
 //! Physical query planner
 
 use super::analyze::AnalyzeExec;
+use super::recursive_query::RecursiveQueryExec;
 use super::unnest::UnnestExec;
 use super::{
     aggregates, empty::EmptyExec, joins::PartitionMode, udaf, union::UnionExec,
     values::ValuesExec, windows,
 };
+use crate::datasource::file_format::csv::CsvFormat;
+use crate::datasource::file_format::file_type::{FileCompressionType, FileType};
+use crate::datasource::file_format::json::JsonFormat;
+use crate::datasource::file_format::parquet::{ParquetFormat, DEFAULT_PARQUET_EXTENSION};
+use crate::datasource::file_format::FileFormat;
 use crate::datasource::source_as_provider;
 use crate::execution::context::{ExecutionProps, SessionState};
 use crate::logical_expr::utils::generate_sort_key;
 use crate::logical_expr::{
-    Aggregate, EmptyRelation, Join, Projection, Sort, SubqueryAlias, TableScan, Unnest,
-    Window,
+    Aggregate, CopyTo, DmlStatement, EmptyRelation, Join, Projection, RecursiveQuery,
+    Sort, SubqueryAlias, TableScan, Unnest, Window, WriteOp,
 };
 use crate::logical_expr::{
     CrossJoin, Expr, LogicalPlan, Partitioning as LogicalPartitioning, PlanType,
@@ -40,6 +46,7 @@ use crate::physical_optimizer::optimizer::PhysicalOptimizerRule;
 use crate::physical_plan::aggregates::{AggregateExec, AggregateMode, PhysicalGroupBy};
 use crate::physical_plan::explain::ExplainExec;
 use crate::physical_plan::expressions::{Column, PhysicalSortExpr};
+use crate::physical_plan::file_format::{FileSinkExec, ParquetWriterOptions};
 use crate::physical_plan::filter::FilterExec;
 use crate::physical_plan::joins::HashJoinExec;
 use crate::physical_plan::joins::SortMergeJoinExec;
@@ -63,11 +70,11 @@ use datafusion_expr::expr::{
     self, AggregateFunction, Between, BinaryExpr, Cast, GetIndexedField, GroupingSet,
     Like, TryCast, WindowFunction,
 };
-use datafusion_expr::expr_rewriter::unnormalize_cols;
+use datafusion_expr::expr_rewriter::{unnormalize_col, unnormalize_cols};
 use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessary;
 use datafusion_expr::{logical_plan, StringifiedPlan};
 use datafusion_expr::{WindowFrame, WindowFrameBound};
-use datafusion_optimizer::utils::unalias;
+use datafusion_optimizer::utils::{split_conjunction, unalias};
 use datafusion_physical_expr::expressions::Literal;
 use datafusion_sql::utils::window_expr_common_partition_keys;
 use futures::future::BoxFuture;
@@ -76,6 +83,7 @@ use itertools::Itertools;
 use log::{debug, trace};
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::str::FromStr;
 use std::sync::Arc;
 
 fn create_function_physical_name(
@@ -1110,6 +1118,23 @@ impl DefaultPhysicalPlanner {
                     let schema = SchemaRef::new(schema.as_ref().to_owned().into());
                     Ok(Arc::new(UnnestExec::new(input, column_exec, schema)))
                 }
+                LogicalPlan::RecursiveQuery(RecursiveQuery {
+                    name,
+                    static_term,
+                    recursive_term,
+                    is_distinct,
+                }) => {
+                    let static_term =
+                        self.create_initial_plan(static_term, session_state).await?;
+                    let recursive_term =
+                        self.create_initial_plan(recursive_term, session_state).await?;
+                    Ok(Arc::new(RecursiveQueryExec::try_new(
+                        name.clone(),
+                        static_term,
+                        recursive_term,
+                        *is_distinct,
+                    )?))
+                }
                 LogicalPlan::CreateExternalTable(_) => {
                     // There is no default plan for "CREATE EXTERNAL
                     // TABLE" -- it must be handled at a higher level (so
@@ -1181,11 +1206,78 @@ impl DefaultPhysicalPlanner {
                         "Unsupported logical plan: CreateView".to_string(),
                     ))
                 }
-                LogicalPlan::Dml(_) => {
+                LogicalPlan::Copy(CopyTo {
+                    input,
+                    output_url,
+                    file_format,
+                    partition_by,
+                    options,
+                    ..
+                }) => {
+                    let input = self.create_initial_plan(input, session_state).await?;
+                    let (format, file_extension) =
+                        create_copy_format(file_format, options)?;
+                    Ok(Arc::new(FileSinkExec::try_new(
+                        input,
+                        output_url,
+                        format,
+                        file_extension,
+                        partition_by.clone(),
+                    )?))
+                }
+                LogicalPlan::Dml(DmlStatement {
+                    table_name,
+                    op: WriteOp::Insert,
+                    input,
+                    ..
+                }) => {
+                    let name = table_name.table();
+                    let schema = session_state.schema_for_ref(table_name)?;
+                    if let Some(provider) = schema.table(name).await {
+                        let input_exec = self.create_initial_plan(input, session_state).await?;
+                        provider.insert_into(session_state, input_exec).await
+                    } else {
+                        Err(DataFusionError::Execution(format!(
+                            "Table '{table_name}' does not exist"
+                        )))
+                    }
+                }
+                LogicalPlan::Dml(DmlStatement {
+                    table_name,
+                    op: op @ (WriteOp::Delete | WriteOp::Update),
+                    input,
+                    ..
+                }) => {
+                    let name = table_name.table();
+                    let schema = session_state.schema_for_ref(table_name)?;
+                    let provider = match schema.table(name).await {
+                        Some(provider) => provider,
+                        None => {
+                            return Err(DataFusionError::Execution(format!(
+                                "Table '{table_name}' does not exist"
+                            )))
+                        }
+                    };
+                    match (op, input.as_ref()) {
+                        (WriteOp::Delete, input) => {
+                            let filters = dml_filters(input, op)?;
+                            provider.delete_from(session_state, filters).await
+                        }
+                        (_, LogicalPlan::Projection(projection)) => {
+                            let assignments = update_assignments(projection);
+                            let filters = dml_filters(&projection.input, op)?;
+                            provider.update(session_state, assignments, filters).await
+                        }
+                        (_, input) => Err(DataFusionError::Internal(format!(
+                            "Expected a projection as input of UPDATE, got {input:?}"
+                        ))),
+                    }
+                }
+                LogicalPlan::Dml(DmlStatement { op, .. }) => {
                     // DataFusion is a read-only query engine, but also a library, so consumers may implement this
-                    Err(DataFusionError::NotImplemented(
-                        "Unsupported logical plan: Dml".to_string(),
-                    ))
+                    Err(DataFusionError::NotImplemented(format!(
+                        "Unsupported logical plan: Dml({op})"
+                    )))
                 }
                 LogicalPlan::SetVariable(_) => {
                     Err(DataFusionError::Internal(
@@ -1853,6 +1945,126 @@ impl DefaultPhysicalPlanner {
     }
 }
 
+/// Creates the [`FileFormat`] written by `COPY ... TO` for `file_format`,
+/// configured with the remaining `options` of the statement, and the
+/// extension of the written files
+fn create_copy_format(
+    file_format: &str,
+    options: &[(String, String)],
+) -> Result<(Arc<dyn FileFormat>, String)> {
+    let unsupported = |key: &str| {
+        Err(DataFusionError::Plan(format!(
+            "Unsupported option '{key}' for COPY TO {file_format}"
+        )))
+    };
+    let invalid = |key: &str, value: &str| {
+        DataFusionError::Plan(format!(
+            "Invalid value '{value}' for option '{key}' of COPY TO {file_format}"
+        ))
+    };
+
+    match file_format {
+        "PARQUET" => {
+            let mut writer_options = ParquetWriterOptions::new();
+            for (key, value) in options {
+                writer_options.set(key, value)?;
+            }
+            let format = ParquetFormat::new().with_writer_options(writer_options);
+            Ok((Arc::new(format), DEFAULT_PARQUET_EXTENSION.to_string()))
+        }
+        "CSV" => {
+            let mut format = CsvFormat::default();
+            let mut compression = FileCompressionType::UNCOMPRESSED;
+            for (key, value) in options {
+                format = match key.as_str() {
+                    "header" => format
+                        .with_has_header(value.parse().map_err(|_| invalid(key, value))?),
+                    "delimiter" => match value.as_bytes() {
+                        [delimiter] => format.with_delimiter(*delimiter),
+                        _ => return Err(invalid(key, value)),
+                    },
+                    "compression" => {
+                        compression = FileCompressionType::from_str(value)
+                            .map_err(|_| invalid(key, value))?;
+                        format.with_file_compression_type(compression.clone())
+                    }
+                    _ => return unsupported(key),
+                };
+            }
+            let file_extension = FileType::CSV.get_ext_with_compression(compression)?;
+            Ok((Arc::new(format), file_extension))
+        }
+        "JSON" => {
+            let mut compression = FileCompressionType::UNCOMPRESSED;
+            for (key, value) in options {
+                match key.as_str() {
+                    "compression" => {
+                        compression = FileCompressionType::from_str(value)
+                            .map_err(|_| invalid(key, value))?;
+                    }
+                    _ => return unsupported(key),
+                }
+            }
+            let format =
+                JsonFormat::default().with_file_compression_type(compression.clone());
+            let file_extension = FileType::JSON.get_ext_with_compression(compression)?;
+            Ok((Arc::new(format), file_extension))
+        }
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "COPY TO is not supported for file format {file_format}"
+        ))),
+    }
+}
+
+/// Returns the unqualified filters selecting the rows affected by a
+/// `DELETE` or `UPDATE`, whose `input` must be a (filtered) scan of
+/// the target table
+fn dml_filters(input: &LogicalPlan, op: &WriteOp) -> Result<Vec<Expr>> {
+    let mut filters = vec![];
+    let mut plan = input;
+    loop {
+        match plan {
+            LogicalPlan::Filter(filter) => {
+                filters.extend(split_conjunction(&filter.predicate).into_iter().cloned());
+                plan = filter.input.as_ref();
+            }
+            LogicalPlan::SubqueryAlias(alias) => plan = alias.input.as_ref(),
+            LogicalPlan::TableScan(scan) => {
+                filters.extend(scan.filters.iter().cloned());
+                break;
+            }
+            _ => {
+                return Err(DataFusionError::NotImplemented(format!(
+                    "Unsupported input of {op}: {plan:?}"
+                )))
+            }
+        }
+    }
+    Ok(unnormalize_cols(filters))
+}
+
+/// Returns the unqualified assignments of an `UPDATE` from the
+/// projection of the new values of all columns, skipping the columns
+/// that keep their values
+fn update_assignments(projection: &Projection) -> Vec<(String, Expr)> {
+    projection
+        .expr
+        .iter()
+        .zip(projection.schema.fields())
+        .filter_map(|(expr, field)| {
+            let name = field.name();
+            let expr = match expr {
+                Expr::Alias(expr, _) => expr.as_ref(),
+                expr => expr,
+            };
+            match expr {
+                Expr::Column(column) if column.name == *name => None,
+                expr => Some((name.clone(), unnormalize_col(expr.clone()))),
+            }
+        })
+        .collect()
+}
+
 fn tuple_err<T, R>(value: (Result<T>, Result<R>)) -> Result<(T, R)> {
     match value {
         (Ok(e), Ok(e1)) => Ok((e, e1)),
@@ -0,0 +1,479 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the recursive query plan
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow::array::BooleanArray;
+use arrow::compute::filter_record_batch;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use futures::{ready, Stream, StreamExt};
+
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+use crate::execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use crate::physical_plan::{
+    expressions::PhysicalSortExpr,
+    metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet},
+    work_table::{WorkTable, WorkTableExec},
+    DisplayFormatType, Distribution, ExecutionPlan, Partitioning, RecordBatchStream,
+    SendableRecordBatchStream, Statistics,
+};
+
+/// Recursive query execution plan.
+///
+/// This plan has two components: a base part (the static term) and
+/// a dynamic part (the recursive term). The execution will start from
+/// the base, and as long as the previous iteration produced at least
+/// a single new row (taking care of the distinction) the recursive
+/// part will be continuously executed.
+///
+/// Before each execution of the dynamic part, the rows from the previous
+/// iteration will be available in a "working table" (not a real table,
+/// can be only accessed using a [`WorkTableExec`] with the same name
+/// inside the recursive term).
+#[derive(Debug)]
+pub struct RecursiveQueryExec {
+    /// Name of the query handler
+    name: String,
+    /// The working table of cte
+    work_table: Arc<WorkTable>,
+    /// The base part (static term)
+    static_term: Arc<dyn ExecutionPlan>,
+    /// The dynamic part (recursive term)
+    recursive_term: Arc<dyn ExecutionPlan>,
+    /// Distinction
+    is_distinct: bool,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl RecursiveQueryExec {
+    /// Create a new RecursiveQueryExec
+    pub fn try_new(
+        name: String,
+        static_term: Arc<dyn ExecutionPlan>,
+        recursive_term: Arc<dyn ExecutionPlan>,
+        is_distinct: bool,
+    ) -> Result<Self> {
+        // Each recursive query needs its own work table
+        let work_table = Arc::new(WorkTable::new());
+        // Use the same work table for both the WorkTableExec and the recursive term
+        let recursive_term =
+            assign_work_table(recursive_term, &name, work_table.clone())?;
+        Ok(RecursiveQueryExec {
+            name,
+            static_term,
+            recursive_term,
+            is_distinct,
+            work_table,
+            metrics: ExecutionPlanMetricsSet::new(),
+        })
+    }
+
+    /// Name of the query handler
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The base part (static term)
+    pub fn static_term(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.static_term
+    }
+
+    /// The dynamic part (recursive term)
+    pub fn recursive_term(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.recursive_term
+    }
+
+    /// Whether rows that were already produced are discarded (`UNION`)
+    pub fn is_distinct(&self) -> bool {
+        self.is_distinct
+    }
+}
+
+impl ExecutionPlan for RecursiveQueryExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.static_term.schema()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.static_term.clone(), self.recursive_term.clone()]
+    }
+
+    // Distribution on a recursive query is really tricky to handle.
+    // For now, we are going to use a single partition but in the
+    // future we might find a better way to handle this.
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        vec![Distribution::SinglePartition, Distribution::SinglePartition]
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(RecursiveQueryExec::try_new(
+            self.name.clone(),
+            children[0].clone(),
+            children[1].clone(),
+            self.is_distinct,
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        // TODO: we might be able to handle multiple partitions in the future.
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "RecursiveQueryExec got an invalid partition {partition} (expected 0)"
+            )));
+        }
+
+        let static_stream = self.static_term.execute(partition, context.clone())?;
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        RecursiveQueryStream::try_new(
+            context,
+            self.work_table.clone(),
+            self.recursive_term.clone(),
+            static_stream,
+            self.is_distinct,
+            baseline_metrics,
+        )
+        .map(|s| Box::pin(s) as SendableRecordBatchStream)
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(
+                    f,
+                    "RecursiveQueryExec: name={}, is_distinct={}",
+                    self.name, self.is_distinct
+                )
+            }
+        }
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
+
+/// The actual logic of the recursive queries happens during the streaming
+/// process. A simplified version of the algorithm is the following:
+///
+/// ```text
+/// buffer = []
+///
+/// while batch := static_stream.next():
+///    buffer.push(batch)
+///    yield batch
+///
+/// while buffer.len() > 0:
+///    work_table.update(buffer.drain())
+///    recursive_stream = recursive_term.execute()
+///    while batch := recursive_stream.next():
+///        buffer.push(batch)
+///        yield batch
+/// ```
+struct RecursiveQueryStream {
+    /// The context to be used for managing handlers & executing new tasks
+    task_context: Arc<TaskContext>,
+    /// The working table state, representing the self referencing cte table
+    work_table: Arc<WorkTable>,
+    /// The dynamic part (recursive term) as is (without being executed)
+    recursive_term: Arc<dyn ExecutionPlan>,
+    /// The static part (static term) as a stream. If the processing of this
+    /// part is completed, then it will be None.
+    static_stream: Option<SendableRecordBatchStream>,
+    /// The dynamic part (recursive term) as a stream. If the processing of this
+    /// part has not started yet, or has been completed, then it will be None.
+    recursive_stream: Option<SendableRecordBatchStream>,
+    /// The schema of the output.
+    schema: SchemaRef,
+    /// In-memory buffer for storing a copy of the current results. Will be
+    /// cleared after each iteration.
+    buffer: Vec<RecordBatch>,
+    /// Tracks the memory used by the buffer and the work table
+    reservation: MemoryReservation,
+    /// Memory used by the batches currently stored in the work table
+    work_table_size: usize,
+    /// Rows that were already produced, if duplicates are discarded
+    distinct: Option<DistinctRows>,
+    /// Metrics.
+    baseline_metrics: BaselineMetrics,
+}
+
+impl RecursiveQueryStream {
+    /// Create a new recursive query stream
+    fn try_new(
+        task_context: Arc<TaskContext>,
+        work_table: Arc<WorkTable>,
+        recursive_term: Arc<dyn ExecutionPlan>,
+        static_stream: SendableRecordBatchStream,
+        is_distinct: bool,
+        baseline_metrics: BaselineMetrics,
+    ) -> Result<Self> {
+        let schema = static_stream.schema();
+        let reservation =
+            MemoryConsumer::new("RecursiveQuery").register(task_context.memory_pool());
+        let distinct = if is_distinct {
+            Some(DistinctRows::try_new(&schema)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            task_context,
+            work_table,
+            recursive_term,
+            static_stream: Some(static_stream),
+            recursive_stream: None,
+            schema,
+            buffer: vec![],
+            reservation,
+            work_table_size: 0,
+            distinct,
+            baseline_metrics,
+        })
+    }
+
+    /// Push a clone of the given batch to the in memory buffer, and then
+    /// return a poll with it.
+    fn push_batch(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        batch: RecordBatch,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        // The recursive term may only differ from the static term in the
+        // names of its columns
+        let batch =
+            match RecordBatch::try_new(self.schema.clone(), batch.columns().to_vec()) {
+                Ok(batch) => batch,
+                Err(e) => return Poll::Ready(Some(Err(e.into()))),
+            };
+
+        let this = &mut *self;
+        let batch = match this.distinct.as_mut() {
+            Some(distinct) => match distinct.filter(&batch, &mut this.reservation) {
+                Ok(batch) => batch,
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            },
+            None => batch,
+        };
+        if batch.num_rows() == 0 {
+            return self.poll_next(cx);
+        }
+
+        if let Err(e) = self.reservation.try_grow(batch.get_array_memory_size()) {
+            return Poll::Ready(Some(Err(e)));
+        }
+        self.buffer.push(batch.clone());
+        Poll::Ready(Some(Ok(batch)))
+    }
+
+    /// Start polling for the next iteration, will be called either after the static term
+    /// is completed or another term is completed. It will follow the algorithm above on
+    /// to check whether the recursion has ended.
+    fn poll_next_iteration(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        let total_length = self
+            .buffer
+            .iter()
+            .fold(0, |acc, batch| acc + batch.num_rows());
+
+        if total_length == 0 {
+            return Poll::Ready(None);
+        }
+
+        // Update the work table with the current buffer, the batches of the
+        // previous iteration are released
+        let batches = std::mem::take(&mut self.buffer);
+        let new_size = batches.iter().map(|b| b.get_array_memory_size()).sum();
+        let old_size = std::mem::replace(&mut self.work_table_size, new_size);
+        self.reservation.shrink(old_size);
+        self.work_table.update(batches);
+
+        // We always execute (and re-execute iteratively) the first partition.
+        // Downstream plans should not expect any partitioning.
+        let partition = 0;
+
+        let recursive_plan = match reset_plan_states(self.recursive_term.clone()) {
+            Ok(plan) => plan,
+            Err(e) => return Poll::Ready(Some(Err(e))),
+        };
+        match recursive_plan.execute(partition, self.task_context.clone()) {
+            Ok(stream) => self.recursive_stream = Some(stream),
+            Err(e) => return Poll::Ready(Some(Err(e))),
+        }
+        self.poll_next(cx)
+    }
+}
+
+/// Tracks the rows a recursive query produced when it discards duplicates
+struct DistinctRows {
+    /// Converts the rows of the output batches to a comparable format
+    converter: RowConverter,
+    /// Rows that were already produced
+    seen: HashSet<OwnedRow>,
+}
+
+impl DistinctRows {
+    fn try_new(schema: &SchemaRef) -> Result<Self> {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| SortField::new(f.data_type().clone()))
+            .collect();
+        Ok(Self {
+            converter: RowConverter::new(fields)?,
+            seen: HashSet::new(),
+        })
+    }
+
+    /// Removes the rows of `batch` that were already produced, including
+    /// the ones that are repeated within the batch
+    fn filter(
+        &mut self,
+        batch: &RecordBatch,
+        reservation: &mut MemoryReservation,
+    ) -> Result<RecordBatch> {
+        let rows = self.converter.convert_columns(batch.columns())?;
+        let mut new_rows_size = 0;
+        let mask = rows
+            .iter()
+            .map(|row| {
+                let is_new = !self.seen.contains(&row.owned());
+                if is_new {
+                    new_rows_size += row.as_ref().len();
+                    self.seen.insert(row.owned());
+                }
+                Some(is_new)
+            })
+            .collect::<BooleanArray>();
+        reservation.try_grow(new_rows_size)?;
+        Ok(filter_record_batch(batch, &mask)?)
+    }
+}
+
+/// Points the [`WorkTableExec`]s named `name` in `plan` to `work_table`
+fn assign_work_table(
+    plan: Arc<dyn ExecutionPlan>,
+    name: &str,
+    work_table: Arc<WorkTable>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    plan.transform_down(
+        &|plan| match plan.as_any().downcast_ref::<WorkTableExec>() {
+            Some(exec) if exec.name() == name => Ok(Transformed::Yes(Arc::new(
+                exec.with_work_table(work_table.clone()),
+            ))),
+            _ => Ok(Transformed::No(plan)),
+        },
+    )
+}
+
+/// Some plans will change their internal states after execution, making them unable to be executed again.
+/// This function uses `with_new_children` to recreate each node of the plan, so that
+/// the recursive term can be executed once per iteration.
+fn reset_plan_states(plan: Arc<dyn ExecutionPlan>) -> Result<Arc<dyn ExecutionPlan>> {
+    plan.transform_up(&|plan| {
+        // WorkTableExec's states have already been updated correctly.
+        if plan.as_any().is::<WorkTableExec>() {
+            Ok(Transformed::No(plan))
+        } else {
+            let new_plan = plan.clone().with_new_children(plan.children())?;
+            Ok(Transformed::Yes(new_plan))
+        }
+    })
+}
+
+impl Stream for RecursiveQueryStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // TODO: we should use this poll to record some metrics!
+        let poll = if let Some(static_stream) = &mut self.static_stream {
+            // While the static term's stream is available, we'll be forwarding the batches from it (also
+            // saving them for the initial iteration of the recursive term).
+            let batch_result = ready!(static_stream.poll_next_unpin(cx));
+            match batch_result {
+                // Static stream is done, start polling for the next iteration
+                None => {
+                    self.static_stream = None;
+                    self.as_mut().poll_next_iteration(cx)
+                }
+                Some(Ok(batch)) => self.as_mut().push_batch(cx, batch),
+                _ => Poll::Ready(batch_result),
+            }
+        } else if let Some(recursive_stream) = &mut self.recursive_stream {
+            let batch_result = ready!(recursive_stream.poll_next_unpin(cx));
+            match batch_result {
+                None => {
+                    self.recursive_stream = None;
+                    self.as_mut().poll_next_iteration(cx)
+                }
+                Some(Ok(batch)) => self.as_mut().push_batch(cx, batch),
+                _ => Poll::Ready(batch_result),
+            }
+        } else {
+            Poll::Ready(None)
+        };
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for RecursiveQueryStream {
+    /// Get the schema
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
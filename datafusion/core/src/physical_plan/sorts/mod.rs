@@ -24,6 +24,7 @@ mod cursor;
 mod index;
 pub mod sort;
 pub mod sort_preserving_merge;
+mod topk;
 
 pub use cursor::SortKeyCursor;
 pub use index::RowIndex;
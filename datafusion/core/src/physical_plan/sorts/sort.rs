@@ -25,14 +25,17 @@ use crate::execution::memory_pool::{
     human_readable_size, MemoryConsumer, MemoryReservation,
 };
 use crate::execution::runtime_env::RuntimeEnv;
-use crate::physical_plan::common::{batch_byte_size, IPCWriter, SizedRecordBatchStream};
+use crate::physical_plan::common::{
+    batch_byte_size, read_spill_as_stream, IPCWriter, SizedRecordBatchStream,
+};
 use crate::physical_plan::expressions::PhysicalSortExpr;
 use crate::physical_plan::metrics::{
     BaselineMetrics, CompositeMetricsSet, MemTrackingMetrics, MetricsSet,
 };
 use crate::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeStream;
+use crate::physical_plan::sorts::topk::TopK;
 use crate::physical_plan::sorts::SortedStream;
-use crate::physical_plan::stream::{RecordBatchReceiverStream, RecordBatchStreamAdapter};
+use crate::physical_plan::stream::RecordBatchStreamAdapter;
 use crate::physical_plan::{
     DisplayFormatType, Distribution, EmptyRecordBatchStream, ExecutionPlan, Partitioning,
     RecordBatchStream, SendableRecordBatchStream, Statistics,
@@ -43,22 +46,19 @@ pub use arrow::compute::SortOptions;
 use arrow::compute::{concat, lexsort_to_indices, take, SortColumn, TakeOptions};
 use arrow::datatypes::SchemaRef;
 use arrow::error::ArrowError;
-use arrow::ipc::reader::FileReader;
 use arrow::record_batch::RecordBatch;
 use datafusion_physical_expr::EquivalenceProperties;
 use futures::{Stream, StreamExt, TryStreamExt};
-use log::{debug, error};
+use log::debug;
 use std::any::Any;
 use std::cmp::{min, Ordering};
 use std::fmt;
 use std::fmt::{Debug, Formatter};
-use std::fs::File;
-use std::io::BufReader;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use tempfile::NamedTempFile;
-use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::sync::mpsc::Receiver;
 use tokio::task;
 
 /// Sort arbitrary size of data to get a total order (may spill several times during sorting based on free memory available).
@@ -571,24 +571,6 @@ async fn spill_partial_sorted_stream(
     }
 }
 
-fn read_spill_as_stream(
-    path: NamedTempFile,
-    schema: SchemaRef,
-) -> Result<SendableRecordBatchStream> {
-    let (sender, receiver): (Sender<Result<RecordBatch>>, Receiver<Result<RecordBatch>>) =
-        tokio::sync::mpsc::channel(2);
-    let join_handle = task::spawn_blocking(move || {
-        if let Err(e) = read_spill(sender, path.path()) {
-            error!("Failure while reading spill file: {:?}. Error: {}", path, e);
-        }
-    });
-    Ok(RecordBatchReceiverStream::create(
-        &schema,
-        receiver,
-        join_handle,
-    ))
-}
-
 fn write_sorted(
     mut receiver: Receiver<Result<RecordBatch>>,
     path: PathBuf,
@@ -608,18 +590,10 @@ fn write_sorted(
     Ok(())
 }
 
-fn read_spill(sender: Sender<Result<RecordBatch>>, path: &Path) -> Result<()> {
-    let file = BufReader::new(File::open(path)?);
-    let reader = FileReader::try_new(file, None)?;
-    for batch in reader {
-        sender
-            .blocking_send(batch.map_err(Into::into))
-            .map_err(|e| DataFusionError::Execution(format!("{e}")))?;
-    }
-    Ok(())
-}
-
 /// External Sort execution plan
+///
+/// With a `fetch`, only the first `fetch` rows of the input are kept in a
+/// heap while it is read, instead of buffering and spilling the whole input.
 #[derive(Debug)]
 pub struct SortExec {
     /// Input schema
@@ -767,6 +741,37 @@ impl ExecutionPlan for SortExec {
 
         debug!("End SortExec's input.execute for partition: {}", partition);
 
+        if let Some(fetch) = self.fetch {
+            // The row format used by TopK does not support all the data types
+            // of the sort keys, in which case the whole input is sorted
+            match TopK::try_new(
+                partition,
+                input.schema(),
+                self.expr.clone(),
+                fetch,
+                context.session_config().batch_size(),
+                context.runtime_env(),
+                &self.metrics_set,
+            ) {
+                Ok(topk) => {
+                    return Ok(Box::pin(RecordBatchStreamAdapter::new(
+                        self.schema(),
+                        futures::stream::once(do_topk(
+                            input,
+                            topk,
+                            partition,
+                            self.expr.clone(),
+                            self.metrics_set.clone(),
+                            context,
+                            fetch,
+                        ))
+                        .try_flatten(),
+                    )))
+                }
+                Err(e) => debug!("Not using TopK for SortExec: {e}"),
+            }
+        }
+
         Ok(Box::pin(RecordBatchStreamAdapter::new(
             self.schema(),
             futures::stream::once(do_sort(
@@ -905,6 +910,47 @@ async fn do_sort(
     result
 }
 
+/// Keeps the first rows of the input in a [`TopK`], whose memory use is
+/// bounded by the number of rows to fetch.
+///
+/// If the rows to fetch don't fit in memory, they are sorted along with the
+/// rest of the input by an [`ExternalSorter`], which can spill to disk.
+async fn do_topk(
+    mut input: SendableRecordBatchStream,
+    mut topk: TopK,
+    partition_id: usize,
+    expr: Vec<PhysicalSortExpr>,
+    metrics_set: CompositeMetricsSet,
+    context: Arc<TaskContext>,
+    fetch: usize,
+) -> Result<SendableRecordBatchStream> {
+    while let Some(batch) = input.next().await {
+        match topk.insert_batch(batch?) {
+            Ok(()) => {}
+            Err(DataFusionError::ResourcesExhausted(e)) => {
+                debug!("Falling back to an external sort for TopK: {e}");
+                // the rows not kept by the TopK are not among the rows to fetch
+                let kept = topk.take_sorted_batches()?;
+                drop(topk);
+                let schema = input.schema();
+                let input = futures::stream::iter(kept.into_iter().map(Ok)).chain(input);
+                let input = Box::pin(RecordBatchStreamAdapter::new(schema, input));
+                return do_sort(
+                    input,
+                    partition_id,
+                    expr,
+                    metrics_set,
+                    context,
+                    Some(fetch),
+                )
+                .await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    topk.emit()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -919,7 +965,7 @@ mod tests {
     use crate::test::assert_is_pending;
     use crate::test::exec::{assert_strong_count_converges_to_zero, BlockingExec};
     use arrow::array::*;
-    use arrow::compute::SortOptions;
+    use arrow::compute::{concat_batches, SortOptions};
     use arrow::datatypes::*;
     use datafusion_common::cast::{as_primitive_array, as_string_array};
     use futures::FutureExt;
@@ -1268,6 +1314,244 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sort_topk() -> Result<()> {
+        // a small batch size to compact the retained batches
+        let session_ctx =
+            SessionContext::with_config(SessionConfig::new().with_batch_size(3));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batches = (0..50)
+            .map(|batch| {
+                let rows = batch * 20..(batch + 1) * 20;
+                let a: Int32Array = rows
+                    .clone()
+                    .map(|i| (i % 13 != 0).then_some(i * 37 % 101))
+                    .collect();
+                let b: StringArray = rows.map(|i| Some(i.to_string())).collect();
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let expr = vec![
+            PhysicalSortExpr {
+                expr: col("a", &schema)?,
+                options: SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                },
+            },
+            PhysicalSortExpr {
+                expr: col("b", &schema)?,
+                options: SortOptions::default(),
+            },
+        ];
+
+        let sort = |fetch| -> Result<Arc<SortExec>> {
+            let input = MemoryExec::try_new(&[batches.clone()], schema.clone(), None)?;
+            Ok(Arc::new(SortExec::try_new(
+                expr.clone(),
+                Arc::new(input),
+                fetch,
+            )?))
+        };
+        let sorted = concat_batches(
+            &schema,
+            &collect(sort(None)?, session_ctx.task_ctx()).await?,
+        )?;
+
+        for fetch in [0, 1, 7, 100] {
+            let sort_exec = sort(Some(fetch))?;
+            let result = collect(sort_exec.clone(), session_ctx.task_ctx()).await?;
+            assert!(result.iter().all(|batch| batch.num_rows() <= 3));
+            let result = concat_batches(&schema, &result)?;
+            assert_eq!(result, sorted.slice(0, fetch), "with fetch: {fetch}");
+
+            let metrics = sort_exec.metrics().unwrap();
+            assert_eq!(metrics.output_rows().unwrap(), fetch);
+            assert_eq!(metrics.spill_count().unwrap(), 0);
+        }
+
+        assert_eq!(
+            session_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The sort should have returned all memory used back to the memory manager"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sort_topk_ties() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let batches = (0..10)
+            .map(|batch| {
+                let rows = batch * 10..(batch + 1) * 10;
+                let a: Int32Array = rows.clone().map(|i| Some(i % 4)).collect();
+                let b: Int32Array = rows.map(Some).collect();
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let expr = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+
+        let sort = |fetch| -> Result<Arc<SortExec>> {
+            let input = MemoryExec::try_new(&[batches.clone()], schema.clone(), None)?;
+            Ok(Arc::new(SortExec::try_new(
+                expr.clone(),
+                Arc::new(input),
+                fetch,
+            )?))
+        };
+        let sorted = concat_batches(
+            &schema,
+            &collect(sort(None)?, session_ctx.task_ctx()).await?,
+        )?;
+
+        // each value of `a` is in 25 rows, so the last rows to fetch are
+        // among tied rows, any of which may be returned
+        for fetch in [1, 13, 25, 26, 60] {
+            let result = concat_batches(
+                &schema,
+                &collect(sort(Some(fetch))?, session_ctx.task_ctx()).await?,
+            )?;
+            assert_eq!(result.num_rows(), fetch, "with fetch: {fetch}");
+            assert_eq!(
+                result.column(0),
+                &sorted.column(0).slice(0, fetch),
+                "with fetch: {fetch}"
+            );
+            let b = as_primitive_array::<Int32Type>(result.column(1))?;
+            let mut b = b.values().to_vec();
+            b.sort_unstable();
+            b.dedup();
+            assert_eq!(b.len(), fetch, "rows returned twice with fetch: {fetch}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sort_topk_memory_limited() -> Result<()> {
+        // the rows to fetch don't fit in memory, so the input is sorted by
+        // an external sort which spills to disk
+        let config = RuntimeConfig::new().with_memory_limit(12288, 1.0);
+        let runtime = Arc::new(RuntimeEnv::new(config)?);
+        let session_ctx = SessionContext::with_config_rt(SessionConfig::new(), runtime);
+
+        let csv = test::scan_partitioned_csv(4)?;
+        let schema = csv.schema();
+        let expr = vec![
+            PhysicalSortExpr {
+                expr: col("c1", &schema)?,
+                options: SortOptions::default(),
+            },
+            PhysicalSortExpr {
+                expr: col("c2", &schema)?,
+                options: SortOptions::default(),
+            },
+            PhysicalSortExpr {
+                expr: col("c7", &schema)?,
+                options: SortOptions::default(),
+            },
+        ];
+
+        let fetch = 350;
+        let sort_exec = Arc::new(SortExec::try_new(
+            expr.clone(),
+            Arc::new(CoalescePartitionsExec::new(csv.clone())),
+            Some(fetch),
+        )?);
+        let result = concat_batches(
+            &schema,
+            &collect(sort_exec.clone(), session_ctx.task_ctx()).await?,
+        )?;
+        let metrics = sort_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert!(metrics.spilled_bytes().unwrap() > 0);
+        assert_eq!(
+            session_ctx.runtime_env().memory_pool.reserved(),
+            0,
+            "The sort should have returned all memory used back to the memory manager"
+        );
+
+        let sort_exec = Arc::new(SortExec::try_new(
+            expr,
+            Arc::new(CoalescePartitionsExec::new(csv)),
+            None,
+        )?);
+        let sorted = concat_batches(
+            &schema,
+            &collect(sort_exec, SessionContext::new().task_ctx()).await?,
+        )?;
+
+        // rows with the same sort keys may be returned in any order
+        assert_eq!(result.num_rows(), fetch);
+        let key_columns = [0, 1, 6];
+        assert_eq!(
+            result.project(&key_columns)?,
+            sorted.slice(0, fetch).project(&key_columns)?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sort_fetch_unsupported_row_format() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let batches = [
+            vec![Some(vec![Some(3)]), Some(vec![Some(1), Some(2)])],
+            vec![Some(vec![Some(2)]), Some(vec![Some(1)]), Some(vec![])],
+        ]
+        .into_iter()
+        .map(|values| {
+            let a = ListArray::from_iter_primitive::<Int32Type, _, _>(values);
+            RecordBatch::try_from_iter(vec![("a", Arc::new(a) as ArrayRef)])
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        let schema = batches[0].schema();
+        let expr = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+
+        // the row format used by TopK does not support lists, in which case
+        // the whole input is sorted
+        assert!(TopK::try_new(
+            0,
+            schema.clone(),
+            expr.clone(),
+            2,
+            session_ctx.copied_config().batch_size(),
+            session_ctx.runtime_env(),
+            &CompositeMetricsSet::new(),
+        )
+        .is_err());
+
+        let sort = |fetch| -> Result<Arc<SortExec>> {
+            let input = MemoryExec::try_new(&[batches.clone()], schema.clone(), None)?;
+            Ok(Arc::new(SortExec::try_new(
+                expr.clone(),
+                Arc::new(input),
+                fetch,
+            )?))
+        };
+        let sorted = concat_batches(
+            &schema,
+            &collect(sort(None)?, session_ctx.task_ctx()).await?,
+        )?;
+        let result = concat_batches(
+            &schema,
+            &collect(sort(Some(2))?, session_ctx.task_ctx()).await?,
+        )?;
+        assert_eq!(result, sorted.slice(0, 2));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_drop_cancel() -> Result<()> {
         let session_ctx = SessionContext::new();
@@ -0,0 +1,376 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! TopK: sort that only keeps the first K rows of its input in memory
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef};
+use arrow::compute::{interleave, lexsort_to_indices, take, SortColumn};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{OwnedRow, RowConverter, SortField};
+
+use crate::error::Result;
+use crate::execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use crate::execution::runtime_env::RuntimeEnv;
+use crate::physical_plan::common::batch_byte_size;
+use crate::physical_plan::expressions::PhysicalSortExpr;
+use crate::physical_plan::memory::MemoryStream;
+use crate::physical_plan::metrics::{BaselineMetrics, CompositeMetricsSet};
+use crate::physical_plan::SendableRecordBatchStream;
+
+/// Keeps the first `k` rows of its input according to the sort expressions,
+/// with memory use proportional to `k` rather than to the input size.
+///
+/// The sort keys of each input batch are encoded with the arrow row format
+/// and compared with the last of the current top `k` rows, kept in a max
+/// heap. Rows sorting after it are discarded right away, and so are the
+/// batches none of the rows of which make it into the heap. Once the heap is
+/// full, the sort keys of a batch are only encoded if its first row in sort
+/// order sorts before the last row of the heap. The batches
+/// holding rows of the heap are retained until none of their rows are left
+/// in it, and compacted into a single batch once they hold many more rows
+/// than the heap.
+pub(crate) struct TopK {
+    schema: SchemaRef,
+    expr: Vec<PhysicalSortExpr>,
+    k: usize,
+    batch_size: usize,
+    row_converter: RowConverter,
+    /// The current top `k` rows, the last of which is at the top of the heap
+    heap: BinaryHeap<TopKRow>,
+    /// Total size of the encoded rows of the heap
+    rows_size: usize,
+    store: RecordBatchStore,
+    reservation: MemoryReservation,
+    metrics: BaselineMetrics,
+}
+
+impl TopK {
+    pub(crate) fn try_new(
+        partition_id: usize,
+        schema: SchemaRef,
+        expr: Vec<PhysicalSortExpr>,
+        k: usize,
+        batch_size: usize,
+        runtime: Arc<RuntimeEnv>,
+        metrics_set: &CompositeMetricsSet,
+    ) -> Result<Self> {
+        let sort_fields = expr
+            .iter()
+            .map(|e| {
+                Ok(SortField::new_with_options(
+                    e.expr.data_type(&schema)?,
+                    e.options,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let row_converter = RowConverter::new(sort_fields)?;
+
+        let reservation = MemoryConsumer::new(format!("TopK[{partition_id}]"))
+            .register(&runtime.memory_pool);
+
+        Ok(Self {
+            schema,
+            expr,
+            k,
+            batch_size,
+            row_converter,
+            heap: BinaryHeap::new(),
+            rows_size: 0,
+            store: RecordBatchStore::default(),
+            reservation,
+            metrics: metrics_set.new_final_baseline(partition_id),
+        })
+    }
+
+    /// Inserts the rows of the batch that are among the first `k` rows seen
+    /// so far into the heap, evicting the rows they replace
+    pub(crate) fn insert_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        if self.k == 0 || batch.num_rows() == 0 {
+            return Ok(());
+        }
+        // NB timer records time taken on drop
+        let _timer = self.metrics.elapsed_compute().timer();
+
+        let sort_keys = self
+            .expr
+            .iter()
+            .map(|e| Ok(e.expr.evaluate(&batch)?.into_array(batch.num_rows())))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        if self.heap.len() == self.k && !self.may_qualify(&sort_keys)? {
+            return Ok(());
+        }
+        let rows = self.row_converter.convert_columns(&sort_keys)?;
+
+        let batch_id = self.store.next_batch_id();
+        let mut uses = 0;
+        for index in 0..rows.num_rows() {
+            let row = rows.row(index);
+            if self.heap.len() == self.k {
+                // The heap is full: only keep rows sorting before its last row
+                match self.heap.peek() {
+                    Some(last) if row < last.row.row() => {}
+                    _ => continue,
+                }
+                if let Some(evicted) = self.heap.pop() {
+                    self.rows_size -= evicted.size();
+                    if evicted.batch_id == batch_id {
+                        uses -= 1;
+                    } else {
+                        self.store.unuse(evicted.batch_id);
+                    }
+                }
+            }
+            let row = TopKRow {
+                row: row.owned(),
+                batch_id,
+                index,
+            };
+            self.rows_size += row.size();
+            self.heap.push(row);
+            uses += 1;
+        }
+
+        if uses > 0 {
+            self.store.insert(batch_id, batch, uses);
+        }
+        self.maybe_compact()?;
+        self.update_reservation()
+    }
+
+    /// Returns whether the first row of a batch with the given sort keys, in
+    /// sort order, sorts before the last row of the heap, only encoding that
+    /// row
+    fn may_qualify(&mut self, sort_keys: &[ArrayRef]) -> Result<bool> {
+        let columns = sort_keys
+            .iter()
+            .zip(&self.expr)
+            .map(|(values, e)| SortColumn {
+                values: values.clone(),
+                options: Some(e.options),
+            })
+            .collect::<Vec<_>>();
+        let indices = lexsort_to_indices(&columns, Some(1))?;
+        let first = sort_keys
+            .iter()
+            .map(|values| Ok(take(values.as_ref(), &indices, None)?))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        let rows = self.row_converter.convert_columns(&first)?;
+        Ok(match self.heap.peek() {
+            Some(last) => rows.row(0) < last.row.row(),
+            None => true,
+        })
+    }
+
+    /// Returns the rows of the heap, in sort order
+    pub(crate) fn emit(mut self) -> Result<SendableRecordBatchStream> {
+        let batches = self.take_sorted_batches()?;
+        let num_rows = batches.iter().map(|batch| batch.num_rows()).sum();
+        self.metrics.record_output(num_rows);
+        self.metrics.done();
+        Ok(Box::pin(MemoryStream::try_new(
+            batches,
+            self.schema.clone(),
+            None,
+        )?))
+    }
+
+    /// Removes the rows of the heap, returning them in sort order as
+    /// batches of at most `batch_size` rows
+    pub(crate) fn take_sorted_batches(&mut self) -> Result<Vec<RecordBatch>> {
+        let rows = std::mem::take(&mut self.heap).into_sorted_vec();
+        self.rows_size = 0;
+        let _timer = self.metrics.elapsed_compute().timer();
+        rows.chunks(self.batch_size)
+            .filter_map(|rows| self.take_rows(rows).transpose())
+            .collect()
+    }
+
+    /// Builds a batch of the given rows of the heap, in their order
+    fn take_rows(&self, rows: &[TopKRow]) -> Result<Option<RecordBatch>> {
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let mut batch_indices = HashMap::new();
+        let mut batches = vec![];
+        let indices = rows
+            .iter()
+            .map(|row| {
+                let batch_index =
+                    *batch_indices.entry(row.batch_id).or_insert_with(|| {
+                        batches.push(self.store.get(row.batch_id));
+                        batches.len() - 1
+                    });
+                (batch_index, row.index)
+            })
+            .collect::<Vec<_>>();
+
+        let columns = (0..self.schema.fields().len())
+            .map(|i| {
+                let arrays = batches
+                    .iter()
+                    .map(|batch| batch.column(i).as_ref())
+                    .collect::<Vec<&dyn Array>>();
+                Ok(interleave(&arrays, &indices)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), columns)?))
+    }
+
+    /// Replaces the retained batches with a single batch of the rows of the
+    /// heap, once they hold many more rows than the heap
+    fn maybe_compact(&mut self) -> Result<()> {
+        let max_rows = 2 * self.k.max(self.batch_size);
+        if self.store.len() <= 2 || self.store.num_rows() <= max_rows {
+            return Ok(());
+        }
+        let _timer = self.metrics.elapsed_compute().timer();
+
+        let heap_rows = std::mem::take(&mut self.heap).into_vec();
+        let batch = match self.take_rows(&heap_rows)? {
+            Some(batch) => batch,
+            None => return Ok(()),
+        };
+        self.store.clear();
+        let batch_id = self.store.next_batch_id();
+        self.store.insert(batch_id, batch, heap_rows.len());
+        self.heap = heap_rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| TopKRow {
+                batch_id,
+                index,
+                ..row
+            })
+            .collect();
+        Ok(())
+    }
+
+    fn update_reservation(&mut self) -> Result<()> {
+        let size = self.rows_size + self.store.memory_size();
+        let reserved = self.reservation.size();
+        if size > reserved {
+            self.reservation.try_grow(size - reserved)?;
+        } else {
+            self.reservation.shrink(reserved - size);
+        }
+        self.metrics.mem_used().set(size);
+        Ok(())
+    }
+}
+
+/// A row of the heap, ordered by its encoded sort keys
+struct TopKRow {
+    row: OwnedRow,
+    /// Id of the batch holding the row in the [`RecordBatchStore`]
+    batch_id: usize,
+    /// Index of the row in its batch
+    index: usize,
+}
+
+impl TopKRow {
+    fn size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.row.row().as_ref().len()
+    }
+}
+
+impl PartialEq for TopKRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.row.row() == other.row.row()
+    }
+}
+
+impl Eq for TopKRow {}
+
+impl PartialOrd for TopKRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TopKRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.row.row().cmp(&other.row.row())
+    }
+}
+
+/// Batches holding the rows of the heap, with the number of their rows
+/// left in the heap
+#[derive(Default)]
+struct RecordBatchStore {
+    next_batch_id: usize,
+    batches: HashMap<usize, (RecordBatch, usize)>,
+    memory_size: usize,
+}
+
+impl RecordBatchStore {
+    fn next_batch_id(&mut self) -> usize {
+        let batch_id = self.next_batch_id;
+        self.next_batch_id += 1;
+        batch_id
+    }
+
+    fn insert(&mut self, batch_id: usize, batch: RecordBatch, uses: usize) {
+        self.memory_size += batch_byte_size(&batch);
+        self.batches.insert(batch_id, (batch, uses));
+    }
+
+    fn get(&self, batch_id: usize) -> &RecordBatch {
+        &self.batches[&batch_id].0
+    }
+
+    /// Records that a row of the batch left the heap, dropping the batch
+    /// once none of its rows are left
+    fn unuse(&mut self, batch_id: usize) {
+        let uses = match self.batches.get_mut(&batch_id) {
+            Some((_, uses)) => {
+                *uses -= 1;
+                *uses
+            }
+            None => return,
+        };
+        if uses == 0 {
+            if let Some((batch, _)) = self.batches.remove(&batch_id) {
+                self.memory_size -= batch_byte_size(&batch);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.batches.clear();
+        self.memory_size = 0;
+    }
+
+    fn len(&self) -> usize {
+        self.batches.len()
+    }
+
+    fn num_rows(&self) -> usize {
+        self.batches
+            .values()
+            .map(|(batch, _)| batch.num_rows())
+            .sum()
+    }
+
+    fn memory_size(&self) -> usize {
+        self.memory_size
+    }
+}
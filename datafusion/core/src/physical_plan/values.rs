@@ -88,8 +88,29 @@ impl ValuesExec {
         Ok(Self { schema, data })
     }
 
+    /// create a new values exec from already evaluated record batches
+    pub fn try_new_from_batches(
+        schema: SchemaRef,
+        batches: Vec<RecordBatch>,
+    ) -> Result<Self> {
+        if batches.is_empty() {
+            return Err(DataFusionError::Plan("Values list cannot be empty".into()));
+        }
+        if let Some(batch) = batches.iter().find(|b| b.schema() != schema) {
+            return Err(DataFusionError::Plan(format!(
+                "Values batch schema {:?} does not match the expected schema {:?}",
+                batch.schema(),
+                schema
+            )));
+        }
+        Ok(Self {
+            schema,
+            data: batches,
+        })
+    }
+
     /// provides the data
-    fn data(&self) -> Vec<RecordBatch> {
+    pub fn data(&self) -> Vec<RecordBatch> {
         self.data.clone()
     }
 }
@@ -175,4 +196,16 @@ mod tests {
         assert!(empty.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn values_from_batches() -> Result<()> {
+        let schema = test_util::aggr_test_schema();
+        let empty = ValuesExec::try_new_from_batches(schema.clone(), vec![]);
+        assert!(empty.is_err());
+
+        let batch = RecordBatch::new_empty(schema.clone());
+        let values = ValuesExec::try_new_from_batches(schema, vec![batch])?;
+        assert_eq!(values.data().len(), 1);
+        Ok(())
+    }
 }
@@ -28,14 +28,15 @@ use crate::physical_plan::{
     udaf, PhysicalExpr,
 };
 use crate::scalar::ScalarValue;
-use arrow::datatypes::Schema;
+use arrow::datatypes::{DataType, Field, Schema};
 use datafusion_expr::{
     window_function::{signature_for_built_in, BuiltInWindowFunction, WindowFunction},
-    WindowFrame,
+    PartitionEvaluator, WindowFrame, WindowUDF,
 };
 use datafusion_physical_expr::window::{
     BuiltInWindowFunctionExpr, SlidingAggregateWindowExpr,
 };
+use std::any::Any;
 use std::convert::TryInto;
 use std::sync::Arc;
 
@@ -90,6 +91,12 @@ pub fn create_window_expr(
             order_by,
             window_frame,
         )),
+        WindowFunction::WindowUDF(fun) => Arc::new(BuiltInWindowExpr::new(
+            create_udwf_window_expr(fun, args, input_schema, name)?,
+            partition_by,
+            order_by,
+            window_frame,
+        )),
     })
 }
 
@@ -187,6 +194,77 @@ fn create_built_in_window_expr(
     })
 }
 
+/// Creates a physical expression of the UDWF, that includes all necessary type coercion.
+/// This function errors when `args`' can't be coerced to a valid argument type of the UDWF.
+fn create_udwf_window_expr(
+    fun: &Arc<WindowUDF>,
+    args: &[Arc<dyn PhysicalExpr>],
+    input_schema: &Schema,
+    name: String,
+) -> Result<Arc<dyn BuiltInWindowFunctionExpr>> {
+    let coerced_args = coerce(args, input_schema, &fun.signature)?;
+    let input_types = coerced_args
+        .iter()
+        .map(|arg| arg.data_type(input_schema))
+        .collect::<Result<Vec<_>>>()?;
+    let data_type = (fun.return_type)(&input_types)?.as_ref().clone();
+
+    Ok(Arc::new(WindowUDFExpr {
+        fun: fun.clone(),
+        args: coerced_args,
+        name,
+        data_type,
+    }))
+}
+
+/// Implements [`BuiltInWindowFunctionExpr`] for a user-defined window
+/// function, evaluated by the partition evaluators the UDWF creates
+#[derive(Debug)]
+struct WindowUDFExpr {
+    fun: Arc<WindowUDF>,
+    args: Vec<Arc<dyn PhysicalExpr>>,
+    /// Display name
+    name: String,
+    /// Type of the window function result
+    data_type: DataType,
+}
+
+impl BuiltInWindowFunctionExpr for WindowUDFExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn field(&self) -> Result<Field> {
+        Ok(Field::new(&self.name, self.data_type.clone(), true))
+    }
+
+    fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.args.clone()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn create_evaluator(&self) -> Result<Box<dyn PartitionEvaluator>> {
+        (self.fun.partition_evaluator_factory)(&self.data_type)
+    }
+
+    // The properties of the UDWF are the ones reported by its evaluators
+
+    fn supports_bounded_execution(&self) -> bool {
+        self.create_evaluator()
+            .map(|evaluator| evaluator.supports_bounded_execution())
+            .unwrap_or(false)
+    }
+
+    fn uses_window_frame(&self) -> bool {
+        self.create_evaluator()
+            .map(|evaluator| evaluator.uses_window_frame())
+            .unwrap_or(false)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,180 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the work table query plan
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use parking_lot::Mutex;
+
+use crate::error::{DataFusionError, Result};
+use crate::execution::context::TaskContext;
+use crate::physical_plan::{
+    expressions::PhysicalSortExpr, memory::MemoryStream, project_schema,
+    DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
+    Statistics,
+};
+
+/// The name is from PostgreSQL's terminology.
+/// See <https://wiki.postgresql.org/wiki/CTEReadme#How_Recursion_Works>
+/// This table serves as a mirror or buffer between each iteration of a recursive query.
+#[derive(Debug, Default)]
+pub(super) struct WorkTable {
+    batches: Mutex<Option<Vec<RecordBatch>>>,
+}
+
+impl WorkTable {
+    /// Create a new work table.
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the batches of the previous iteration
+    fn batches(&self) -> Result<Vec<RecordBatch>> {
+        self.batches.lock().clone().ok_or_else(|| {
+            DataFusionError::Execution("Unexpected empty work table".to_string())
+        })
+    }
+
+    /// Replaces the batches of the previous iteration with `batches`
+    pub(super) fn update(&self, batches: Vec<RecordBatch>) {
+        self.batches.lock().replace(batches);
+    }
+}
+
+/// Reads the rows of the previous iteration of a recursive query.
+///
+/// The underlying relation does not exist when the plan is created: the
+/// enclosing [`RecursiveQueryExec`] fills the shared [`WorkTable`] with the
+/// output of each iteration before it executes the recursive term again.
+///
+/// [`RecursiveQueryExec`]: super::recursive_query::RecursiveQueryExec
+#[derive(Debug)]
+pub struct WorkTableExec {
+    /// Name of the relation handler
+    name: String,
+    /// The schema of the stream
+    schema: SchemaRef,
+    /// Optional projection
+    projection: Option<Vec<usize>>,
+    /// The schema after the projection is applied
+    projected_schema: SchemaRef,
+    /// The work table
+    work_table: Arc<WorkTable>,
+}
+
+impl WorkTableExec {
+    /// Create a new execution plan for a worktable exec.
+    pub fn new(
+        name: String,
+        schema: SchemaRef,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self> {
+        let projected_schema = project_schema(&schema, projection.as_ref())?;
+        Ok(Self {
+            name,
+            schema,
+            projection,
+            projected_schema,
+            work_table: Arc::new(WorkTable::new()),
+        })
+    }
+
+    /// Name of the relation handler
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns a copy of this plan that reads from `work_table`
+    pub(super) fn with_work_table(&self, work_table: Arc<WorkTable>) -> Self {
+        Self {
+            name: self.name.clone(),
+            schema: self.schema.clone(),
+            projection: self.projection.clone(),
+            projected_schema: self.projected_schema.clone(),
+            work_table,
+        }
+    }
+}
+
+impl ExecutionPlan for WorkTableExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.projected_schema.clone()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![]
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        Partitioning::UnknownPartitioning(1)
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        None
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        _: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // keep reading from the same work table
+        Ok(self)
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        // WorkTable streams must be the plan base.
+        if partition != 0 {
+            return Err(DataFusionError::Internal(format!(
+                "WorkTableExec got an invalid partition {partition} (expected 0)"
+            )));
+        }
+
+        Ok(Box::pin(MemoryStream::try_new(
+            self.work_table.batches()?,
+            self.projected_schema.clone(),
+            self.projection.clone(),
+        )?))
+    }
+
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default => {
+                write!(f, "WorkTableExec: name={}", self.name)
+            }
+        }
+    }
+
+    fn statistics(&self) -> Statistics {
+        Statistics::default()
+    }
+}
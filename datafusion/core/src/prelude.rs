@@ -28,7 +28,8 @@
 pub use crate::dataframe::DataFrame;
 pub use crate::execution::context::{SessionConfig, SessionContext};
 pub use crate::execution::options::{
-    AvroReadOptions, CsvReadOptions, NdJsonReadOptions, ParquetReadOptions,
+    AvroReadOptions, CsvReadOptions, CsvWriteOptions, NdJsonReadOptions,
+    NdJsonWriteOptions, ParquetReadOptions,
 };
 
 pub use datafusion_common::Column;
@@ -338,6 +338,45 @@ async fn csv_grouping_by_partition() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn csv_partition_cols_are_nullable() -> Result<()> {
+    let ctx = SessionContext::new();
+
+    register_partitioned_aggregate_csv(
+        &ctx,
+        &[
+            "mytable/date=2021-10-26/file.csv",
+            "mytable/date=2021-10-27/file.csv",
+        ],
+        &[("date", DataType::Date32)],
+        "mirror:///mytable/",
+    );
+
+    // the partition columns of existing tables are nullable, though only
+    // directories named `__HIVE_DEFAULT_PARTITION__` are read as nulls
+    let df = ctx.table("t").await?;
+    assert!(df
+        .schema()
+        .field_with_unqualified_name("date")?
+        .is_nullable());
+
+    let result = ctx
+        .sql("SELECT count(*) FROM t WHERE date IS NULL")
+        .await?
+        .collect()
+        .await?;
+    let expected = vec![
+        "+-----------------+",
+        "| COUNT(UInt8(1)) |",
+        "+-----------------+",
+        "| 0               |",
+        "+-----------------+",
+    ];
+    assert_batches_sorted_eq!(expected, &result);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn parquet_multiple_partitions() -> Result<()> {
     let ctx = SessionContext::new();
@@ -0,0 +1,325 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::datasource::file_format::file_type::FileCompressionType;
+
+use super::*;
+
+const VALUES: &str = "SELECT column1 AS id, column2 AS name \
+                      FROM (VALUES (1, 'a'), (2, 'b'), (3, 'a'))";
+
+#[tokio::test]
+async fn copy_query_to_parquet_file() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let path = tmp_dir.path().join("output.parquet");
+
+    let sql = format!(
+        "COPY ({VALUES}) TO '{}' (COMPRESSION zstd, MAX_ROW_GROUP_SIZE 2)",
+        path.display()
+    );
+    let actual = execute_to_batches(&ctx, &sql).await;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    assert!(path.is_file());
+
+    ctx.register_parquet(
+        "output",
+        path.to_str().unwrap(),
+        ParquetReadOptions::default(),
+    )
+    .await?;
+    let actual = execute_to_batches(&ctx, "SELECT * FROM output ORDER BY id").await;
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 1  | a    |",
+        "| 2  | b    |",
+        "| 3  | a    |",
+        "+----+------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_table_to_partitioned_csv() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.sql(&format!("CREATE TABLE t AS {VALUES}"))
+        .await?
+        .collect()
+        .await?;
+    let tmp_dir = TempDir::new()?;
+    let dir = tmp_dir.path().join("output");
+
+    let sql = format!(
+        "COPY t TO '{}' (FORMAT csv, PARTITION_BY (name), HEADER true)",
+        dir.display()
+    );
+    let actual = execute_to_batches(&ctx, &sql).await;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    assert!(dir.join("name=a").is_dir());
+    assert!(dir.join("name=b").is_dir());
+
+    ctx.register_csv(
+        "output",
+        dir.join("name=a").to_str().unwrap(),
+        CsvReadOptions::new(),
+    )
+    .await?;
+    let actual = execute_to_batches(&ctx, "SELECT * FROM output ORDER BY id").await;
+    let expected = vec!["+----+", "| id |", "+----+", "| 1  |", "| 3  |", "+----+"];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_to_json_directory() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let dir = format!("{}/output/", tmp_dir.path().display());
+
+    let sql = format!("COPY ({VALUES}) TO '{dir}' (FORMAT json)");
+    let actual = execute_to_batches(&ctx, &sql).await;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+
+    ctx.register_json("output", &dir, NdJsonReadOptions::default())
+        .await?;
+    let actual = execute_to_batches(&ctx, "SELECT * FROM output ORDER BY id").await;
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 1  | a    |",
+        "| 2  | b    |",
+        "| 3  | a    |",
+        "+----+------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_creates_output_directory_on_execution() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let dir = tmp_dir.path().join("nested").join("output");
+
+    let sql = format!("COPY ({VALUES}) TO '{}/' (FORMAT csv)", dir.display());
+    let plan = ctx.sql(&sql).await?.create_physical_plan().await?;
+    // planning does not touch the file system
+    assert!(!tmp_dir.path().join("nested").exists());
+
+    let actual = collect(plan, ctx.task_ctx()).await?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    assert!(dir.is_dir());
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_empty_query_to_file() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 0     |",
+        "+-------+",
+    ];
+
+    // the file is written, with the header, even though no rows are
+    let csv_path = tmp_dir.path().join("output.csv");
+    let sql = format!(
+        "COPY ({VALUES} WHERE false) TO '{}' (HEADER true)",
+        csv_path.display()
+    );
+    let actual = execute_to_batches(&ctx, &sql).await;
+    assert_batches_eq!(expected, &actual);
+    assert_eq!(std::fs::read_to_string(&csv_path)?, "id,name\n");
+
+    let parquet_path = tmp_dir.path().join("output.parquet");
+    let sql = format!(
+        "COPY ({VALUES} WHERE false) TO '{}'",
+        parquet_path.display()
+    );
+    let actual = execute_to_batches(&ctx, &sql).await;
+    assert_batches_eq!(expected, &actual);
+
+    ctx.register_parquet(
+        "output",
+        parquet_path.to_str().unwrap(),
+        ParquetReadOptions::default(),
+    )
+    .await?;
+    let actual = execute_to_batches(&ctx, "SELECT count(*) FROM output").await;
+    let expected = vec![
+        "+-----------------+",
+        "| COUNT(UInt8(1)) |",
+        "+-----------------+",
+        "| 0               |",
+        "+-----------------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_to_compressed_csv_directory() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let dir = format!("{}/output/", tmp_dir.path().display());
+
+    let sql = format!("COPY ({VALUES}) TO '{dir}' (FORMAT csv, COMPRESSION gzip)");
+    let actual = execute_to_batches(&ctx, &sql).await;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+
+    ctx.register_csv(
+        "output",
+        &dir,
+        CsvReadOptions::new()
+            .file_extension(".csv.gz")
+            .file_compression_type(FileCompressionType::GZIP),
+    )
+    .await?;
+    let actual = execute_to_batches(&ctx, "SELECT * FROM output ORDER BY id").await;
+    let expected = vec![
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "| 1  | a    |",
+        "| 2  | b    |",
+        "| 3  | a    |",
+        "+----+------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_to_invalid_options() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+    let path = tmp_dir.path().join("output");
+
+    let sql = format!("COPY ({VALUES}) TO '{}'", path.display());
+    let err = ctx.sql(&sql).await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Format not explicitly set and unable to get file extension"
+    );
+
+    let sql = format!(
+        "COPY ({VALUES}) TO '{}' (FORMAT json, DELIMITER ';')",
+        path.display()
+    );
+    let err = ctx.sql(&sql).await?.collect().await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Unsupported option 'delimiter' for COPY TO JSON"
+    );
+
+    let sql = format!(
+        "COPY ({VALUES}) TO '{}' (FORMAT csv, COMPRESSION snappy)",
+        path.display()
+    );
+    let err = ctx.sql(&sql).await?.collect().await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Invalid value 'snappy' for option 'compression' of COPY TO CSV"
+    );
+
+    let sql = format!(
+        "COPY ({VALUES}) TO '{}' (FORMAT parquet, ROW_GROUPS 2)",
+        path.display()
+    );
+    let err = ctx.sql(&sql).await?.collect().await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Unknown parquet writer option 'row_groups'"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn copy_to_parquet_with_sorting_columns() -> Result<()> {
+    let ctx = SessionContext::new();
+    let tmp_dir = TempDir::new()?;
+
+    // the declared order must match the order of the written rows
+    let path = tmp_dir.path().join("unsorted.parquet");
+    let sql = format!(
+        "COPY ({VALUES}) TO '{}' (SORTING_COLUMNS 'name')",
+        path.display()
+    );
+    let err = ctx.sql(&sql).await?.collect().await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "Rows written to parquet are not sorted by the sorting columns [name]"
+    );
+
+    let path = tmp_dir.path().join("sorted.parquet");
+    let sql = format!(
+        "COPY ({VALUES} ORDER BY name, id) TO '{}' (SORTING_COLUMNS 'name, id')",
+        path.display()
+    );
+    let actual = execute_to_batches(&ctx, &sql).await;
+    let expected = vec![
+        "+-------+",
+        "| count |",
+        "+-------+",
+        "| 3     |",
+        "+-------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
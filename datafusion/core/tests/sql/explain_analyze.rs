@@ -781,3 +781,40 @@ async fn explain_physical_plan_only() {
     ]];
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn explain_insert() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.sql("CREATE TABLE t1(a INT, b VARCHAR)")
+        .await?
+        .collect()
+        .await?;
+    ctx.sql("CREATE TABLE t2(a INT, b VARCHAR)")
+        .await?
+        .collect()
+        .await?;
+    ctx.sql("INSERT INTO t2 VALUES (1, 'one'), (2, 'two')")
+        .await?
+        .collect()
+        .await?;
+
+    let sql = "EXPLAIN INSERT INTO t1 SELECT * FROM t2";
+    let actual = execute_to_batches(&ctx, sql).await;
+    let formatted = arrow::util::pretty::pretty_format_batches(&actual)
+        .unwrap()
+        .to_string();
+    assert_contains!(&formatted, "Dml: op=[Insert] table=[t1]");
+    assert_contains!(&formatted, "InsertExec: sink=MemoryTable");
+
+    // explaining the insert doesn't write to the table
+    let actual = execute_to_batches(&ctx, "SELECT count(*) FROM t1").await;
+    let expected = vec![
+        "+-----------------+",
+        "| COUNT(UInt8(1)) |",
+        "+-----------------+",
+        "| 0               |",
+        "+-----------------+",
+    ];
+    assert_batches_eq!(expected, &actual);
+    Ok(())
+}
@@ -81,6 +81,7 @@ pub mod aggregates;
 #[cfg(feature = "avro")]
 pub mod avro;
 pub mod cast;
+pub mod copy;
 pub mod create_drop;
 pub mod errors;
 pub mod explain_analyze;
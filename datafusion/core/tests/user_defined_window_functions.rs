@@ -0,0 +1,258 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! This module contains end to end demonstrations of creating
+//! user defined window functions
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use datafusion::{
+    arrow::{
+        array::{Array, ArrayRef, Int64Array, StringArray},
+        datatypes::{DataType, Field, Schema},
+        record_batch::RecordBatch,
+    },
+    assert_batches_eq,
+    datasource::MemTable,
+    error::Result,
+    logical_expr::{
+        create_udwf, window_state::WindowAggState, PartitionEvaluator, Volatility,
+        WindowFrame, WindowUDF,
+    },
+    physical_plan::displayable,
+    prelude::{col, SessionContext},
+    scalar::ScalarValue,
+};
+use datafusion_common::cast::as_int64_array;
+
+const RUNNING_SUM_SQL: &str = "SELECT grp, id, \
+    running_sum(x) OVER (PARTITION BY grp ORDER BY id) AS sum_x \
+    FROM t ORDER BY grp, id";
+
+#[tokio::test]
+/// Evaluates the udwf over whole partitions with `WindowAggExec`
+async fn test_udwf() -> Result<()> {
+    let ctx = udwf_context(false)?;
+
+    let plan = physical_plan(&ctx, RUNNING_SUM_SQL).await?;
+    assert!(plan.contains("WindowAggExec"), "{plan}");
+    assert!(!plan.contains("BoundedWindowAggExec"), "{plan}");
+
+    assert_batches_eq!(
+        expected_running_sum(),
+        &execute(&ctx, RUNNING_SUM_SQL).await?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+/// Evaluates the udwf incrementally with `BoundedWindowAggExec`
+async fn test_udwf_bounded() -> Result<()> {
+    let ctx = udwf_context(true)?;
+
+    let plan = physical_plan(&ctx, RUNNING_SUM_SQL).await?;
+    assert!(plan.contains("BoundedWindowAggExec"), "{plan}");
+
+    assert_batches_eq!(
+        expected_running_sum(),
+        &execute(&ctx, RUNNING_SUM_SQL).await?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+/// Calls the udwf without going through the registry
+async fn test_udwf_dataframe() -> Result<()> {
+    let ctx = udwf_context(true)?;
+    let running_sum = running_sum_udwf(true).call(
+        vec![col("x")],
+        vec![col("grp")],
+        vec![col("id").sort(true, false)],
+        WindowFrame::new(true),
+    );
+    let df = ctx
+        .table("t")
+        .await?
+        .select(vec![col("grp"), col("id"), running_sum.alias("sum_x")])?
+        .sort(vec![
+            col("grp").sort(true, false),
+            col("id").sort(true, false),
+        ])?;
+
+    assert_batches_eq!(expected_running_sum(), &df.collect().await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_udwf_unknown() -> Result<()> {
+    let ctx = udwf_context(false)?;
+    let err = ctx
+        .sql("SELECT unknown_udwf(x) OVER (ORDER BY id) FROM t")
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("There is no window function named unknown_udwf"),
+        "{err}"
+    );
+    Ok(())
+}
+
+fn expected_running_sum() -> Vec<&'static str> {
+    vec![
+        "+-----+----+-------+",
+        "| grp | id | sum_x |",
+        "+-----+----+-------+",
+        "| a   | 1  | 1     |",
+        "| a   | 2  | 3     |",
+        "| a   | 5  | 8     |",
+        "| a   | 6  | 8     |",
+        "| b   | 3  | 3     |",
+        "| b   | 4  | 7     |",
+        "| b   | 7  | 14    |",
+        "+-----+----+-------+",
+    ]
+}
+
+async fn execute(ctx: &SessionContext, sql: &str) -> Result<Vec<RecordBatch>> {
+    ctx.sql(sql).await?.collect().await
+}
+
+async fn physical_plan(ctx: &SessionContext, sql: &str) -> Result<String> {
+    let plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    Ok(displayable(plan.as_ref()).indent().to_string())
+}
+
+/// Returns a context with a table "t" and the "running_sum" window
+/// function registered.
+///
+/// "t" contains this data, split into two batches:
+///
+/// ```text
+/// grp | id | x
+/// a   | 1  | 1
+/// a   | 2  | 2
+/// b   | 3  | 3
+/// b   | 4  | 4
+/// a   | 5  | 5
+/// a   | 6  | NULL
+/// b   | 7  | 7
+/// ```
+fn udwf_context(bounded: bool) -> Result<SessionContext> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("grp", DataType::Utf8, false),
+        Field::new("id", DataType::Int64, false),
+        Field::new("x", DataType::Int64, true),
+    ]));
+
+    let batch1 = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(StringArray::from(vec!["a", "a", "b", "b"])),
+            Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+            Arc::new(Int64Array::from(vec![Some(1), Some(2), Some(3), Some(4)])),
+        ],
+    )?;
+    let batch2 = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(StringArray::from(vec!["a", "a", "b"])),
+            Arc::new(Int64Array::from(vec![5, 6, 7])),
+            Arc::new(Int64Array::from(vec![Some(5), None, Some(7)])),
+        ],
+    )?;
+
+    let ctx = SessionContext::new();
+    let provider = MemTable::try_new(schema, vec![vec![batch1, batch2]])?;
+    ctx.register_table("t", Arc::new(provider))?;
+    ctx.register_udwf(running_sum_udwf(bounded));
+    Ok(ctx)
+}
+
+/// Creates the "running_sum" window function, which sums the values of
+/// the partition up to and including the current row.
+fn running_sum_udwf(bounded: bool) -> WindowUDF {
+    create_udwf(
+        "running_sum",
+        DataType::Int64,
+        Arc::new(DataType::Int64),
+        Volatility::Immutable,
+        Arc::new(move |_return_type: &DataType| {
+            let evaluator: Box<dyn PartitionEvaluator> = Box::new(RunningSumEvaluator {
+                bounded,
+                ..Default::default()
+            });
+            Ok(evaluator)
+        }),
+    )
+}
+
+#[derive(Debug, Default)]
+struct RunningSumEvaluator {
+    /// Whether to evaluate incrementally with `evaluate_stateful`
+    bounded: bool,
+    /// Index of the row being evaluated, in the current partition batch
+    idx: usize,
+    /// Sum of the values seen so far
+    sum: i64,
+}
+
+impl PartitionEvaluator for RunningSumEvaluator {
+    fn supports_bounded_execution(&self) -> bool {
+        self.bounded
+    }
+
+    fn get_range(&self, idx: usize, _n_rows: usize) -> Result<Range<usize>> {
+        Ok(Range {
+            start: idx,
+            end: idx + 1,
+        })
+    }
+
+    fn update_state(
+        &mut self,
+        _state: &WindowAggState,
+        idx: usize,
+        _range_columns: &[ArrayRef],
+        _sort_partition_points: &[Range<usize>],
+    ) -> Result<()> {
+        self.idx = idx;
+        Ok(())
+    }
+
+    fn evaluate_stateful(&mut self, values: &[ArrayRef]) -> Result<ScalarValue> {
+        let values = as_int64_array(&values[0])?;
+        if values.is_valid(self.idx) {
+            self.sum += values.value(self.idx);
+        }
+        Ok(ScalarValue::Int64(Some(self.sum)))
+    }
+
+    fn evaluate(&self, values: &[ArrayRef], _num_rows: usize) -> Result<ArrayRef> {
+        let values = as_int64_array(&values[0])?;
+        let mut sum = 0;
+        let result = values
+            .iter()
+            .map(|value| {
+                sum += value.unwrap_or_default();
+                Some(sum)
+            })
+            .collect::<Int64Array>();
+        Ok(Arc::new(result))
+    }
+}
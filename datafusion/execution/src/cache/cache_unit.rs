@@ -0,0 +1,435 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Default [`CacheAccessor`] implementations
+
+use super::{CacheAccessor, FileStatisticsKey};
+use arrow::datatypes::SchemaRef;
+use datafusion_common::Statistics;
+use object_store::path::Path;
+use object_store::ObjectMeta;
+use parking_lot::Mutex;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The default cache of object listings, keyed by path prefix.
+///
+/// Listings can't be validated against the object store without listing
+/// it again, so a time to live should be set if objects are added or
+/// removed while the cache is in use.
+#[derive(Debug)]
+pub struct DefaultListFilesCache {
+    entries: LruMap<Path, Arc<Vec<ObjectMeta>>>,
+}
+
+impl DefaultListFilesCache {
+    /// Create a cache holding the listings of up to `max_entries` prefixes
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: LruMap::new(max_entries),
+        }
+    }
+
+    /// Expire the cached listings `ttl` after they were added
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.entries.ttl = Some(ttl);
+        self
+    }
+}
+
+impl CacheAccessor<Path, Arc<Vec<ObjectMeta>>> for DefaultListFilesCache {
+    fn get(&self, k: &Path) -> Option<Arc<Vec<ObjectMeta>>> {
+        self.entries.get(k, None)
+    }
+
+    fn put(&self, k: &Path, v: Arc<Vec<ObjectMeta>>) -> Option<Arc<Vec<ObjectMeta>>> {
+        self.entries.put(k.clone(), None, v)
+    }
+
+    fn remove(&self, k: &Path) -> Option<Arc<Vec<ObjectMeta>>> {
+        self.entries.remove(k)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&self) {
+        self.entries.clear()
+    }
+
+    fn name(&self) -> String {
+        "DefaultListFilesCache".to_string()
+    }
+}
+
+/// The default cache of per file values, such as metadata.
+///
+/// Entries are keyed by file location, and are invalidated when the
+/// size or last modification time of the file changes.
+#[derive(Debug)]
+pub struct DefaultFileCache<V> {
+    entries: LruMap<Path, V>,
+}
+
+impl<V: Clone> DefaultFileCache<V> {
+    /// Create a cache holding the values of up to `max_entries` files
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: LruMap::new(max_entries),
+        }
+    }
+
+    /// Expire the cached values `ttl` after they were added
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.entries.ttl = Some(ttl);
+        self
+    }
+}
+
+impl<V: Clone + Send + Sync> CacheAccessor<ObjectMeta, V> for DefaultFileCache<V> {
+    fn get(&self, k: &ObjectMeta) -> Option<V> {
+        self.entries.get(&k.location, Some(k))
+    }
+
+    fn put(&self, k: &ObjectMeta, v: V) -> Option<V> {
+        self.entries.put(k.location.clone(), Some(k.clone()), v)
+    }
+
+    fn remove(&self, k: &ObjectMeta) -> Option<V> {
+        self.entries.remove(&k.location)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&self) {
+        self.entries.clear()
+    }
+
+    fn name(&self) -> String {
+        "DefaultFileCache".to_string()
+    }
+}
+
+/// The default [`FileStatisticsCache`](super::FileStatisticsCache).
+///
+/// Entries are keyed by file location and table schema, and are
+/// invalidated when the size or last modification time of the file
+/// changes.
+#[derive(Debug)]
+pub struct DefaultFileStatisticsCache {
+    entries: LruMap<(Path, SchemaRef), Arc<Statistics>>,
+}
+
+impl DefaultFileStatisticsCache {
+    /// Create a cache holding the statistics of up to `max_entries` files
+    /// and schemas
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: LruMap::new(max_entries),
+        }
+    }
+
+    /// Expire the cached statistics `ttl` after they were added
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.entries.ttl = Some(ttl);
+        self
+    }
+}
+
+impl CacheAccessor<FileStatisticsKey, Arc<Statistics>> for DefaultFileStatisticsCache {
+    fn get(&self, k: &FileStatisticsKey) -> Option<Arc<Statistics>> {
+        let key = (k.meta.location.clone(), k.schema.clone());
+        self.entries.get(&key, Some(&k.meta))
+    }
+
+    fn put(&self, k: &FileStatisticsKey, v: Arc<Statistics>) -> Option<Arc<Statistics>> {
+        let key = (k.meta.location.clone(), k.schema.clone());
+        self.entries.put(key, Some(k.meta.clone()), v)
+    }
+
+    fn remove(&self, k: &FileStatisticsKey) -> Option<Arc<Statistics>> {
+        let key = (k.meta.location.clone(), k.schema.clone());
+        self.entries.remove(&key)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&self) {
+        self.entries.clear()
+    }
+
+    fn name(&self) -> String {
+        "DefaultFileStatisticsCache".to_string()
+    }
+}
+
+/// The default [`FileMetadataCache`](super::FileMetadataCache)
+pub type DefaultFileMetadataCache = DefaultFileCache<Arc<dyn Any + Send + Sync>>;
+
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    /// The metadata of the file the value was computed from, if any
+    meta: Option<ObjectMeta>,
+    inserted: Instant,
+    /// The last access of the entry, its key in [`LruMapState::accesses`]
+    last_access: u64,
+}
+
+#[derive(Debug)]
+struct LruMapState<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    /// The keys of the entries by last access, least recent first
+    accesses: BTreeMap<u64, K>,
+    /// Logical clock ordering the accesses to the entries
+    clock: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> LruMapState<K, V> {
+    /// Records an access to the entry of `key`, returning it
+    fn touch(&mut self, key: &K) -> Option<&mut Entry<V>> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(key)?;
+        self.accesses.remove(&entry.last_access);
+        self.accesses.insert(self.clock, key.clone());
+        entry.last_access = self.clock;
+        Some(entry)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<Entry<V>> {
+        let entry = self.entries.remove(key)?;
+        self.accesses.remove(&entry.last_access);
+        Some(entry)
+    }
+
+    /// Removes the least recently used entry
+    fn remove_lru(&mut self) -> bool {
+        let lru = match self.accesses.keys().next() {
+            Some(access) => *access,
+            None => return false,
+        };
+        if let Some(key) = self.accesses.remove(&lru) {
+            self.entries.remove(&key);
+        }
+        true
+    }
+}
+
+/// A map evicting its least recently used entries beyond `max_entries`,
+/// and the entries older than `ttl`
+#[derive(Debug)]
+struct LruMap<K, V> {
+    max_entries: usize,
+    ttl: Option<Duration>,
+    state: Mutex<LruMapState<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> LruMap<K, V> {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            ttl: None,
+            state: Mutex::new(LruMapState {
+                entries: HashMap::new(),
+                accesses: BTreeMap::new(),
+                clock: 0,
+            }),
+        }
+    }
+
+    /// Returns the value of `key`, unless it expired or the file
+    /// changed since it was added
+    fn get(&self, key: &K, meta: Option<&ObjectMeta>) -> Option<V> {
+        let mut state = self.state.lock();
+        let entry = state.touch(key)?;
+        let expired = match self.ttl {
+            Some(ttl) => entry.inserted.elapsed() > ttl,
+            None => false,
+        };
+        let changed = match (meta, &entry.meta) {
+            (Some(meta), Some(saved)) => {
+                meta.size != saved.size || meta.last_modified != saved.last_modified
+            }
+            _ => false,
+        };
+        if expired || changed {
+            state.remove(key);
+            return None;
+        }
+        Some(entry.value.clone())
+    }
+
+    fn put(&self, key: K, meta: Option<ObjectMeta>, value: V) -> Option<V> {
+        if self.max_entries == 0 {
+            return None;
+        }
+
+        let mut state = self.state.lock();
+        let previous = state.remove(&key).map(|e| e.value);
+        state.clock += 1;
+        let last_access = state.clock;
+        state.accesses.insert(last_access, key.clone());
+        state.entries.insert(
+            key,
+            Entry {
+                value,
+                meta,
+                inserted: Instant::now(),
+                last_access,
+            },
+        );
+
+        while state.entries.len() > self.max_entries {
+            if !state.remove_lru() {
+                break;
+            }
+        }
+        previous
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        self.state.lock().remove(key).map(|e| e.value)
+    }
+
+    fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock();
+        state.entries.clear();
+        state.accesses.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    fn object_meta(location: &str, size: usize) -> ObjectMeta {
+        ObjectMeta {
+            location: Path::from(location),
+            last_modified: Default::default(),
+            size,
+        }
+    }
+
+    #[test]
+    fn file_cache_invalidated_on_change() {
+        let cache = DefaultFileCache::new(10);
+        let meta = object_meta("a.parquet", 100);
+        assert!(cache.get(&meta).is_none());
+
+        cache.put(&meta, Arc::new(1));
+        assert_eq!(cache.get(&meta), Some(Arc::new(1)));
+
+        // the file was rewritten with a different size
+        let changed = object_meta("a.parquet", 200);
+        assert!(cache.get(&changed).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let cache = DefaultListFilesCache::new(2);
+        let listing = Arc::new(vec![object_meta("a/1.parquet", 1)]);
+        let (a, b, c) = (Path::from("a"), Path::from("b"), Path::from("c"));
+
+        cache.put(&a, listing.clone());
+        cache.put(&b, listing.clone());
+        // access `a`, so that `b` is the least recently used
+        assert!(cache.get(&a).is_some());
+        cache.put(&c, listing);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+    }
+
+    #[test]
+    fn evict_in_access_order() {
+        let cache = DefaultFileCache::new(3);
+        let metas = (0..5)
+            .map(|i| object_meta(&format!("{i}.parquet"), i))
+            .collect::<Vec<_>>();
+        for (i, meta) in metas.iter().enumerate().take(3) {
+            cache.put(meta, i);
+        }
+        // access the entries in reverse insertion order
+        for meta in metas.iter().take(3).rev() {
+            assert!(cache.get(meta).is_some());
+        }
+        cache.put(&metas[3], 3);
+        cache.put(&metas[4], 4);
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&metas[0]), Some(0));
+        assert!(cache.get(&metas[1]).is_none());
+        assert!(cache.get(&metas[2]).is_none());
+        assert_eq!(cache.get(&metas[3]), Some(3));
+        assert_eq!(cache.get(&metas[4]), Some(4));
+    }
+
+    #[test]
+    fn statistics_keyed_by_schema() {
+        let cache = DefaultFileStatisticsCache::new(10);
+        let meta = object_meta("a.parquet", 100);
+        let schema = |names: &[&str]| {
+            Arc::new(Schema::new(
+                names
+                    .iter()
+                    .map(|name| Field::new(*name, DataType::Int64, true))
+                    .collect(),
+            ))
+        };
+        let ab = FileStatisticsKey::new(meta.clone(), schema(&["a", "b"]));
+        let ba = FileStatisticsKey::new(meta, schema(&["b", "a"]));
+
+        let statistics = Arc::new(Statistics {
+            num_rows: Some(1),
+            ..Default::default()
+        });
+        cache.put(&ab, statistics.clone());
+        assert_eq!(cache.get(&ab), Some(statistics));
+        assert!(cache.get(&ba).is_none());
+
+        // an equal schema shares the entry
+        let ab2 = FileStatisticsKey::new(ab.meta.clone(), schema(&["a", "b"]));
+        assert!(cache.get(&ab2).is_some());
+    }
+
+    #[test]
+    fn expire_after_ttl() {
+        let cache = DefaultListFilesCache::new(10).with_ttl(Duration::from_millis(10));
+        let prefix = Path::from("a");
+        cache.put(&prefix, Arc::new(vec![]));
+        assert!(cache.get(&prefix).is_some());
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(cache.get(&prefix).is_none());
+    }
+}
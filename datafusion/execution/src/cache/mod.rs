@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Caches of object store listings and file metadata shared across queries
+//!
+//! Caching is disabled by default: each [`CacheAccessor`] must be
+//! registered with [`CacheManagerConfig`]. Default implementations with
+//! an entry limit and an optional time to live are provided in
+//! [`cache_unit`].
+
+use arrow::datatypes::SchemaRef;
+use datafusion_common::Statistics;
+use object_store::path::Path;
+use object_store::ObjectMeta;
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+pub mod cache_unit;
+
+/// A cache of values of type `V` keyed by `K`, safe to share between
+/// concurrent queries
+pub trait CacheAccessor<K, V>: Send + Sync {
+    /// Returns the cached value for `k`, if any and still valid
+    fn get(&self, k: &K) -> Option<V>;
+
+    /// Caches `v` for `k`, returning the previously cached value, if any
+    fn put(&self, k: &K, v: V) -> Option<V>;
+
+    /// Removes the cached value for `k`, returning it if any
+    fn remove(&self, k: &K) -> Option<V>;
+
+    /// Returns the number of cached entries
+    fn len(&self) -> usize;
+
+    /// Returns true if nothing is cached
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all the cached entries
+    fn clear(&self);
+
+    /// Name of the cache, for debugging
+    fn name(&self) -> String;
+}
+
+/// Caches the objects listed under a path prefix
+pub type ListFilesCache = Arc<dyn CacheAccessor<Path, Arc<Vec<ObjectMeta>>>>;
+
+/// Key of the [`FileStatisticsCache`]
+///
+/// Statistics are computed for the schema of the table reading the file,
+/// their column statistics following the order of its fields, so they are
+/// keyed by that schema as well as by the file.
+#[derive(Debug, Clone)]
+pub struct FileStatisticsKey {
+    /// The file the statistics were collected from
+    pub meta: ObjectMeta,
+    /// The schema of the table the statistics were collected for
+    pub schema: SchemaRef,
+}
+
+impl FileStatisticsKey {
+    /// Create the key of the statistics of `meta` for a table of `schema`
+    pub fn new(meta: ObjectMeta, schema: SchemaRef) -> Self {
+        Self { meta, schema }
+    }
+}
+
+/// Caches the statistics of files for the schemas of the tables reading
+/// them. Entries are only valid as long as the size and last modification
+/// time of the file are unchanged.
+pub type FileStatisticsCache = Arc<dyn CacheAccessor<FileStatisticsKey, Arc<Statistics>>>;
+
+/// Caches the parsed metadata of files, such as the `ParquetMetaData` of
+/// parquet files, which file formats downcast to their own type. Entries
+/// are only valid as long as the size and last modification time of the
+/// file are unchanged.
+pub type FileMetadataCache =
+    Arc<dyn CacheAccessor<ObjectMeta, Arc<dyn Any + Send + Sync>>>;
+
+/// Configuration of the caches of a [`CacheManager`], all disabled by default
+#[derive(Clone, Default)]
+pub struct CacheManagerConfig {
+    /// Cache of the files listed by listing tables
+    pub list_files_cache: Option<ListFilesCache>,
+    /// Cache of the file statistics collected by listing tables
+    pub file_statistics_cache: Option<FileStatisticsCache>,
+    /// Cache of the file metadata read by file formats
+    pub file_metadata_cache: Option<FileMetadataCache>,
+}
+
+impl CacheManagerConfig {
+    /// Set the cache of the files listed by listing tables
+    pub fn with_list_files_cache(mut self, cache: Option<ListFilesCache>) -> Self {
+        self.list_files_cache = cache;
+        self
+    }
+
+    /// Set the cache of the file statistics collected by listing tables
+    pub fn with_file_statistics_cache(
+        mut self,
+        cache: Option<FileStatisticsCache>,
+    ) -> Self {
+        self.file_statistics_cache = cache;
+        self
+    }
+
+    /// Set the cache of the file metadata read by file formats
+    pub fn with_file_metadata_cache(mut self, cache: Option<FileMetadataCache>) -> Self {
+        self.file_metadata_cache = cache;
+        self
+    }
+}
+
+/// Holds the caches shared by all the queries of a
+/// [`RuntimeEnv`](crate::runtime_env::RuntimeEnv)
+#[derive(Default)]
+pub struct CacheManager {
+    list_files_cache: Option<ListFilesCache>,
+    file_statistics_cache: Option<FileStatisticsCache>,
+    file_metadata_cache: Option<FileMetadataCache>,
+}
+
+impl Debug for CacheManager {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let name = |cache: Option<String>| cache.unwrap_or_else(|| "None".to_string());
+        f.debug_struct("CacheManager")
+            .field(
+                "list_files_cache",
+                &name(self.list_files_cache.as_ref().map(|c| c.name())),
+            )
+            .field(
+                "file_statistics_cache",
+                &name(self.file_statistics_cache.as_ref().map(|c| c.name())),
+            )
+            .field(
+                "file_metadata_cache",
+                &name(self.file_metadata_cache.as_ref().map(|c| c.name())),
+            )
+            .finish()
+    }
+}
+
+impl CacheManager {
+    /// Create a cache manager with the caches of `config`
+    pub fn new(config: &CacheManagerConfig) -> Arc<Self> {
+        Arc::new(Self {
+            list_files_cache: config.list_files_cache.clone(),
+            file_statistics_cache: config.file_statistics_cache.clone(),
+            file_metadata_cache: config.file_metadata_cache.clone(),
+        })
+    }
+
+    /// The cache of the files listed by listing tables, if enabled
+    pub fn list_files_cache(&self) -> Option<ListFilesCache> {
+        self.list_files_cache.clone()
+    }
+
+    /// The cache of the file statistics collected by listing tables, if enabled
+    pub fn file_statistics_cache(&self) -> Option<FileStatisticsCache> {
+        self.file_statistics_cache.clone()
+    }
+
+    /// The cache of the file metadata read by file formats, if enabled
+    pub fn file_metadata_cache(&self) -> Option<FileMetadataCache> {
+        self.file_metadata_cache.clone()
+    }
+}
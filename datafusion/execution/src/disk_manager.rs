@@ -102,6 +102,12 @@ impl DiskManager {
         }
     }
 
+    /// Return true if this disk manager supports creating temporary
+    /// files, i.e. it was not created with [`DiskManagerConfig::Disabled`]
+    pub fn tmp_files_enabled(&self) -> bool {
+        self.local_dirs.lock().is_some()
+    }
+
     /// Return a temporary file from a randomized choice in the configured locations
     ///
     /// If the file can not be created for some reason, returns an
@@ -162,6 +168,7 @@ mod tests {
         // A default configuration should not create temp files until requested
         let config = DiskManagerConfig::new();
         let dm = DiskManager::try_new(config)?;
+        assert!(dm.tmp_files_enabled());
 
         assert_eq!(0, local_dir_snapshot(&dm).len());
 
@@ -210,6 +217,7 @@ mod tests {
     fn test_disabled_disk_manager() {
         let config = DiskManagerConfig::Disabled;
         let manager = DiskManager::try_new(config).unwrap();
+        assert!(!manager.tmp_files_enabled());
         assert_eq!(
             manager.create_tmp_file("Testing").unwrap_err().to_string(),
             "Resources exhausted: Memory Exhausted while Testing (DiskManager is disabled)",
@@ -17,6 +17,7 @@
 
 //! DataFusion execution configuration and runtime structures
 
+pub mod cache;
 pub mod config;
 pub mod disk_manager;
 pub mod memory_pool;
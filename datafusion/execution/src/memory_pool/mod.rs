@@ -179,6 +179,13 @@ impl From<MemoryReservation> for SharedMemoryReservation {
     }
 }
 
+impl SharedMemoryReservation {
+    /// Frees all bytes from this reservation returning the number of bytes freed
+    pub fn free(&self) -> usize {
+        self.0.lock().free()
+    }
+}
+
 impl TryGrow for SharedMemoryReservation {
     /// Try to increase the size of this reservation by `capacity` bytes
     fn try_grow(&self, capacity: usize) -> Result<()> {
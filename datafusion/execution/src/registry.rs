@@ -17,8 +17,8 @@
 
 //! FunctionRegistry trait
 
-use datafusion_common::Result;
-use datafusion_expr::{AggregateUDF, ScalarUDF};
+use datafusion_common::{DataFusionError, Result};
+use datafusion_expr::{AggregateUDF, ScalarUDF, WindowUDF};
 use std::{collections::HashSet, sync::Arc};
 
 /// A registry knows how to build logical expressions out of user-defined function' names
@@ -31,4 +31,12 @@ pub trait FunctionRegistry {
 
     /// Returns a reference to the udaf named `name`.
     fn udaf(&self, name: &str) -> Result<Arc<AggregateUDF>>;
+
+    /// Returns a reference to the udwf named `name`. Registries without
+    /// user-defined window functions don't need to implement it.
+    fn udwf(&self, name: &str) -> Result<Arc<WindowUDF>> {
+        Err(DataFusionError::NotImplemented(format!(
+            "Function registry does not support user-defined window function '{name}'"
+        )))
+    }
 }
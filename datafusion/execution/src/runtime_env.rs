@@ -19,6 +19,7 @@
 //! and various system level components that are used during physical plan execution.
 
 use crate::{
+    cache::{CacheManager, CacheManagerConfig},
     disk_manager::{DiskManager, DiskManagerConfig},
     memory_pool::{GreedyMemoryPool, MemoryPool, UnboundedMemoryPool},
     object_store::{DefaultObjectStoreRegistry, ObjectStoreRegistry},
@@ -40,6 +41,8 @@ pub struct RuntimeEnv {
     pub disk_manager: Arc<DiskManager>,
     /// Object Store Registry
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// Caches of object listings and file metadata shared across queries
+    pub cache_manager: Arc<CacheManager>,
 }
 
 impl Debug for RuntimeEnv {
@@ -55,6 +58,7 @@ impl RuntimeEnv {
             memory_pool,
             disk_manager,
             object_store_registry,
+            cache_manager,
         } = config;
 
         let memory_pool =
@@ -64,6 +68,7 @@ impl RuntimeEnv {
             memory_pool,
             disk_manager: DiskManager::try_new(disk_manager)?,
             object_store_registry,
+            cache_manager: CacheManager::new(&cache_manager),
         })
     }
 
@@ -110,6 +115,8 @@ pub struct RuntimeConfig {
     pub memory_pool: Option<Arc<dyn MemoryPool>>,
     /// ObjectStoreRegistry to get object store based on url
     pub object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    /// Caches of object listings and file metadata, all disabled by default
+    pub cache_manager: CacheManagerConfig,
 }
 
 impl Default for RuntimeConfig {
@@ -125,6 +132,7 @@ impl RuntimeConfig {
             disk_manager: Default::default(),
             memory_pool: Default::default(),
             object_store_registry: Arc::new(DefaultObjectStoreRegistry::default()),
+            cache_manager: Default::default(),
         }
     }
 
@@ -149,6 +157,12 @@ impl RuntimeConfig {
         self
     }
 
+    /// Customize the caches shared across queries
+    pub fn with_cache_manager(mut self, cache_manager: CacheManagerConfig) -> Self {
+        self.cache_manager = cache_manager;
+        self
+    }
+
     /// Specify the total memory to use while running the DataFusion
     /// plan to `max_memory * memory_fraction` in bytes.
     ///
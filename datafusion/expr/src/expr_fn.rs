@@ -21,8 +21,10 @@ use crate::expr::{AggregateFunction, BinaryExpr, Cast, GroupingSet, TryCast};
 use crate::{
     aggregate_function, built_in_function, conditional_expressions::CaseBuilder,
     logical_plan::Subquery, AccumulatorFunctionImplementation, AggregateUDF,
-    BuiltinScalarFunction, Expr, LogicalPlan, Operator, ReturnTypeFunction,
+    BuiltinScalarFunction, Expr, LogicalPlan, Operator,
+    PartitionEvaluatorFunctionImplementation, ReturnTypeFunction,
     ScalarFunctionImplementation, ScalarUDF, Signature, StateTypeFunction, Volatility,
+    WindowUDF,
 };
 use arrow::datatypes::DataType;
 use datafusion_common::{Column, Result};
@@ -675,6 +677,24 @@ pub fn create_udaf(
     )
 }
 
+/// Creates a new UDWF with a specific signature and return type.
+/// The signature and return type must match the `PartitionEvaluator's implementation`.
+pub fn create_udwf(
+    name: &str,
+    input_type: DataType,
+    return_type: Arc<DataType>,
+    volatility: Volatility,
+    partition_evaluator_factory: PartitionEvaluatorFunctionImplementation,
+) -> WindowUDF {
+    let return_type: ReturnTypeFunction = Arc::new(move |_| Ok(return_type.clone()));
+    WindowUDF::new(
+        name,
+        &Signature::exact(vec![input_type], volatility),
+        &return_type,
+        &partition_evaluator_factory,
+    )
+}
+
 /// Calls a named built in function
 /// ```
 /// use datafusion_expr::{col, lit, call_fn};
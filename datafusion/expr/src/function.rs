@@ -22,7 +22,7 @@ use crate::type_coercion::functions::data_types;
 use crate::ColumnarValue;
 use crate::{
     array_expressions, conditional_expressions, struct_expressions, Accumulator,
-    BuiltinScalarFunction, Signature, TypeSignature,
+    BuiltinScalarFunction, PartitionEvaluator, Signature, TypeSignature,
 };
 use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
 use datafusion_common::{DataFusionError, Result};
@@ -48,6 +48,11 @@ pub type ReturnTypeFunction =
 pub type AccumulatorFunctionImplementation =
     Arc<dyn Fn(&DataType) -> Result<Box<dyn Accumulator>> + Send + Sync>;
 
+/// Factory that returns a partition evaluator for the given user-defined
+/// window function, given its return datatype.
+pub type PartitionEvaluatorFunctionImplementation =
+    Arc<dyn Fn(&DataType) -> Result<Box<dyn PartitionEvaluator>> + Send + Sync>;
+
 /// Factory that returns the types used by an aggregator to serialize
 /// its state, given its return datatype.
 pub type StateTypeFunction =
@@ -29,8 +29,8 @@ use crate::{
     logical_plan::{
         Aggregate, Analyze, CrossJoin, Distinct, EmptyRelation, Explain, Filter, Join,
         JoinConstraint, JoinType, Limit, LogicalPlan, Partitioning, PlanType, Prepare,
-        Projection, Repartition, Sort, SubqueryAlias, TableScan, ToStringifiedPlan,
-        Union, Unnest, Values, Window,
+        Projection, RecursiveQuery, Repartition, Sort, SubqueryAlias, TableScan,
+        ToStringifiedPlan, Union, Unnest, Values, Window,
     },
     utils::{
         can_hash, expand_qualified_wildcard, expand_wildcard,
@@ -576,6 +576,35 @@ impl LogicalPlanBuilder {
         })))
     }
 
+    /// Convert a regular plan into a recursive query, using it as the static
+    /// term and `recursive_term` as the term that is evaluated repeatedly
+    /// against the work table `name`. If `is_distinct` is true, rows that
+    /// were already produced are discarded (`UNION` instead of `UNION ALL`).
+    pub fn to_recursive_query(
+        self,
+        name: String,
+        recursive_term: LogicalPlan,
+        is_distinct: bool,
+    ) -> Result<Self> {
+        // Ensure that the static term and the recursive term have the same number of fields
+        let static_fields_len = self.plan.schema().fields().len();
+        let recursive_fields_len = recursive_term.schema().fields().len();
+        if static_fields_len != recursive_fields_len {
+            return Err(DataFusionError::Plan(format!(
+                "Non-recursive term and recursive term must have the same number of columns ({static_fields_len} != {recursive_fields_len})"
+            )));
+        }
+        // Ensure that the recursive term has the same field types as the static term
+        let coerced_recursive_term =
+            coerce_plan_expr_for_schema(&recursive_term, self.plan.schema())?;
+        Ok(Self::from(LogicalPlan::RecursiveQuery(RecursiveQuery {
+            name,
+            static_term: Arc::new(self.plan),
+            recursive_term: Arc::new(coerced_recursive_term),
+            is_distinct,
+        })))
+    }
+
     /// Apply deduplication: Only distinct (different) values are returned)
     pub fn distinct(self) -> Result<Self> {
         Ok(Self::from(LogicalPlan::Distinct(Distinct {
@@ -33,8 +33,8 @@ use datafusion_common::tree_node::{
     Transformed, TreeNode, TreeNodeVisitor, VisitRecursion,
 };
 use datafusion_common::{
-    plan_err, Column, DFSchema, DFSchemaRef, DataFusionError, OwnedTableReference,
-    Result, ScalarValue, TableReference,
+    plan_err, Column, DFField, DFSchema, DFSchemaRef, DataFusionError,
+    OwnedTableReference, Result, ScalarValue, TableReference,
 };
 use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug, Display, Formatter};
@@ -122,10 +122,14 @@ pub enum LogicalPlan {
     Prepare(Prepare),
     /// Insert / Update / Delete
     Dml(DmlStatement),
+    /// Write the output of a plan to files (`COPY ... TO`)
+    Copy(CopyTo),
     /// Describe the schema of table
     DescribeTable(DescribeTable),
     /// Unnest a column that contains a nested list type.
     Unnest(Unnest),
+    /// A variadic query (e.g. "Recursive CTEs")
+    RecursiveQuery(RecursiveQuery),
 }
 
 impl LogicalPlan {
@@ -170,7 +174,12 @@ impl LogicalPlan {
                 dummy_schema
             }
             LogicalPlan::Dml(DmlStatement { table_schema, .. }) => table_schema,
+            LogicalPlan::Copy(CopyTo { schema, .. }) => schema,
             LogicalPlan::Unnest(Unnest { schema, .. }) => schema,
+            LogicalPlan::RecursiveQuery(RecursiveQuery { static_term, .. }) => {
+                // we take the schema of the static term as the schema of the entire recursive query
+                static_term.schema()
+            }
         }
     }
 
@@ -217,9 +226,11 @@ impl LogicalPlan {
             | LogicalPlan::CreateCatalogSchema(_)
             | LogicalPlan::CreateCatalog(_)
             | LogicalPlan::Dml(_)
+            | LogicalPlan::Copy(_)
             | LogicalPlan::Values(_)
             | LogicalPlan::SubqueryAlias(_)
             | LogicalPlan::Union(_)
+            | LogicalPlan::RecursiveQuery(_)
             | LogicalPlan::Extension(_)
             | LogicalPlan::TableScan(_) => {
                 vec![self.schema()]
@@ -366,8 +377,10 @@ impl LogicalPlan {
             | LogicalPlan::Analyze(_)
             | LogicalPlan::Explain(_)
             | LogicalPlan::Union(_)
+            | LogicalPlan::RecursiveQuery(_)
             | LogicalPlan::Distinct(_)
             | LogicalPlan::Dml(_)
+            | LogicalPlan::Copy(_)
             | LogicalPlan::DescribeTable(_)
             | LogicalPlan::Prepare(_) => Ok(()),
         }
@@ -396,12 +409,18 @@ impl LogicalPlan {
             LogicalPlan::Explain(explain) => vec![&explain.plan],
             LogicalPlan::Analyze(analyze) => vec![&analyze.input],
             LogicalPlan::Dml(write) => vec![&write.input],
+            LogicalPlan::Copy(copy) => vec![&copy.input],
             LogicalPlan::CreateMemoryTable(CreateMemoryTable { input, .. })
             | LogicalPlan::CreateView(CreateView { input, .. })
             | LogicalPlan::Prepare(Prepare { input, .. }) => {
                 vec![input]
             }
             LogicalPlan::Unnest(Unnest { input, .. }) => vec![input],
+            LogicalPlan::RecursiveQuery(RecursiveQuery {
+                static_term,
+                recursive_term,
+                ..
+            }) => vec![static_term, recursive_term],
             // plans without inputs
             LogicalPlan::TableScan { .. }
             | LogicalPlan::EmptyRelation { .. }
@@ -914,6 +933,22 @@ impl LogicalPlan {
                     LogicalPlan::Dml(DmlStatement { table_name, op, .. }) => {
                         write!(f, "Dml: op=[{op}] table=[{table_name}]")
                     }
+                    LogicalPlan::Copy(CopyTo {
+                        output_url,
+                        file_format,
+                        partition_by,
+                        options,
+                        ..
+                    }) => {
+                        let options: Vec<_> =
+                            options.iter().map(|(k, v)| format!("{k} {v}")).collect();
+                        write!(
+                            f,
+                            "CopyTo: format={file_format} output_url={output_url} partition_by=[{}] options: ({})",
+                            partition_by.join(", "),
+                            options.join(", ")
+                        )
+                    }
                     LogicalPlan::Filter(Filter {
                         predicate: ref expr,
                         ..
@@ -1085,6 +1120,16 @@ impl LogicalPlan {
                     LogicalPlan::Unnest(Unnest { column, .. }) => {
                         write!(f, "Unnest: {column}")
                     }
+                    LogicalPlan::RecursiveQuery(RecursiveQuery {
+                        name,
+                        is_distinct,
+                        ..
+                    }) => {
+                        write!(
+                            f,
+                            "RecursiveQuery: name={name}, is_distinct={is_distinct}"
+                        )
+                    }
                 }
             }
         }
@@ -1459,6 +1504,26 @@ pub struct Union {
     pub schema: DFSchemaRef,
 }
 
+/// A variadic query operation, Recursive CTE.
+///
+/// The `static_term` is evaluated once, and the `recursive_term` is then
+/// evaluated repeatedly against a work table named `name`, which holds the
+/// rows produced by the previous iteration, until an iteration produces no
+/// rows. The result is the union of the rows of all iterations.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RecursiveQuery {
+    /// Name of the query
+    pub name: String,
+    /// The static term (initial contents of the working table)
+    pub static_term: Arc<LogicalPlan>,
+    /// The recursive term (evaluated on the contents of the working table until
+    /// it returns an empty set)
+    pub recursive_term: Arc<LogicalPlan>,
+    /// Should the output of the recursive term be deduplicated (`UNION`) or
+    /// not (`UNION ALL`).
+    pub is_distinct: bool,
+}
+
 /// Creates an in memory table.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CreateMemoryTable {
@@ -1565,6 +1630,50 @@ pub struct DmlStatement {
     pub input: Arc<LogicalPlan>,
 }
 
+/// Writes the output of `input` to files at `output_url`, producing a
+/// single row with the number of rows written
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CopyTo {
+    /// The relation whose output is written
+    pub input: Arc<LogicalPlan>,
+    /// The path or URL the output is written to. A path ending in `/`,
+    /// or any output with `partition_by` columns, is written as a
+    /// directory of files.
+    pub output_url: String,
+    /// The format of the written files (e.g. `PARQUET`, `CSV`, `JSON`)
+    pub file_format: String,
+    /// Columns of `input` used to write Hive style `col=value` directories
+    pub partition_by: Vec<String>,
+    /// Format specific options, such as compression
+    pub options: Vec<(String, String)>,
+    /// The output schema: a single `count` column
+    pub schema: DFSchemaRef,
+}
+
+impl CopyTo {
+    /// Create a new `CopyTo` writing the output of `input`
+    pub fn try_new(
+        input: Arc<LogicalPlan>,
+        output_url: String,
+        file_format: String,
+        partition_by: Vec<String>,
+        options: Vec<(String, String)>,
+    ) -> Result<Self> {
+        let schema = DFSchema::new_with_metadata(
+            vec![DFField::new_unqualified("count", DataType::UInt64, false)],
+            HashMap::new(),
+        )?;
+        Ok(Self {
+            input,
+            output_url,
+            file_format,
+            partition_by,
+            options,
+            schema: Arc::new(schema),
+        })
+    }
+}
+
 /// Prepare a statement but do not execute it. Prepare statements can have 0 or more
 /// `Expr::Placeholder` expressions that are filled in during execution
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -17,21 +17,55 @@
 
 //! partition evaluation module
 
-use crate::window::window_expr::BuiltinWindowState;
-use crate::window::WindowAggState;
+use crate::window_state::{BuiltinWindowState, WindowAggState};
 use arrow::array::ArrayRef;
 use datafusion_common::Result;
 use datafusion_common::{DataFusionError, ScalarValue};
 use std::fmt::Debug;
 use std::ops::Range;
 
-/// Partition evaluator
+/// Partition evaluator, computing the results of a window function over
+/// the rows of a partition.
+///
+/// Built-in window functions such as `rank` or `lag`, as well as
+/// user-defined window functions ([`WindowUDF`]), are evaluated by
+/// partition evaluators. Depending on the properties of the window
+/// function, the results are computed by:
+///
+/// * [`Self::evaluate_inside_range`] for each row, if it
+///   [uses the window frame](Self::uses_window_frame)
+/// * [`Self::evaluate_with_rank`], if it [includes rank](Self::include_rank)
+/// * [`Self::evaluate`] otherwise
+///
+/// When the evaluator [supports bounded execution](Self::supports_bounded_execution),
+/// the results may instead be computed incrementally, as the rows of
+/// the partition arrive, with [`Self::evaluate_stateful`]. The range of
+/// the rows each result depends on is then given by [`Self::get_range`]
+/// unless the evaluator uses the window frame.
+///
+/// The properties of user-defined window functions are the ones reported
+/// by their evaluators, while built-in window functions report them
+/// through their physical expressions.
+///
+/// [`WindowUDF`]: crate::WindowUDF
 pub trait PartitionEvaluator: Debug + Send {
     /// Whether the evaluator should be evaluated with rank
     fn include_rank(&self) -> bool {
         false
     }
 
+    /// Whether the result of the window function depends on the window
+    /// frame of each row, rather than on the whole partition
+    fn uses_window_frame(&self) -> bool {
+        false
+    }
+
+    /// Whether the evaluator can compute its results incrementally, using
+    /// bounded memory, with [`Self::evaluate_stateful`]
+    fn supports_bounded_execution(&self) -> bool {
+        false
+    }
+
     /// Returns state of the Built-in Window Function
     fn state(&self) -> Result<BuiltinWindowState> {
         // If we do not use state we just return Default
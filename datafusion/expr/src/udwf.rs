@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Udwf module contains functions and structs supporting user-defined window functions.
+
+use crate::expr::WindowFunction;
+use crate::window_function;
+use crate::{
+    Expr, PartitionEvaluatorFunctionImplementation, ReturnTypeFunction, Signature,
+    WindowFrame,
+};
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+/// Logical representation of a user-defined window function (UDWF).
+/// A UDWF is evaluated over the rows of each partition by a
+/// [`PartitionEvaluator`](crate::PartitionEvaluator), in the same way
+/// as the built-in window functions such as `rank` or `lag`.
+#[derive(Clone)]
+pub struct WindowUDF {
+    /// name
+    pub name: String,
+    /// signature
+    pub signature: Signature,
+    /// Return type
+    pub return_type: ReturnTypeFunction,
+    /// actual implementation
+    pub partition_evaluator_factory: PartitionEvaluatorFunctionImplementation,
+}
+
+impl Debug for WindowUDF {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("WindowUDF")
+            .field("name", &self.name)
+            .field("signature", &self.signature)
+            .field("fun", &"<FUNC>")
+            .finish()
+    }
+}
+
+impl PartialEq for WindowUDF {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.signature == other.signature
+    }
+}
+
+impl Eq for WindowUDF {}
+
+impl std::hash::Hash for WindowUDF {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+    }
+}
+
+impl WindowUDF {
+    /// Create a new WindowUDF
+    pub fn new(
+        name: &str,
+        signature: &Signature,
+        return_type: &ReturnTypeFunction,
+        partition_evaluator_factory: &PartitionEvaluatorFunctionImplementation,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            signature: signature.clone(),
+            return_type: return_type.clone(),
+            partition_evaluator_factory: partition_evaluator_factory.clone(),
+        }
+    }
+
+    /// creates a logical expression with a call of the UDWF
+    /// This utility allows using the UDWF without requiring access to the registry.
+    pub fn call(
+        &self,
+        args: Vec<Expr>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        window_frame: WindowFrame,
+    ) -> Expr {
+        Expr::WindowFunction(WindowFunction::new(
+            window_function::WindowFunction::WindowUDF(Arc::new(self.clone())),
+            args,
+            partition_by,
+            order_by,
+            window_frame,
+        ))
+    }
+}
@@ -21,12 +21,12 @@ use crate::expr::{Sort, WindowFunction};
 use crate::logical_plan::builder::build_join_schema;
 use crate::logical_plan::{
     Aggregate, Analyze, CreateMemoryTable, CreateView, Distinct, Extension, Filter, Join,
-    Limit, Partitioning, Prepare, Projection, Repartition, Sort as SortPlan, Subquery,
-    SubqueryAlias, Union, Unnest, Values, Window,
+    Limit, Partitioning, Prepare, Projection, RecursiveQuery, Repartition,
+    Sort as SortPlan, Subquery, SubqueryAlias, Union, Unnest, Values, Window,
 };
 use crate::{
-    BinaryExpr, Cast, DmlStatement, Expr, ExprSchemable, GroupingSet, LogicalPlan,
-    LogicalPlanBuilder, Operator, TableScan, TryCast,
+    BinaryExpr, Cast, CopyTo, DmlStatement, Expr, ExprSchemable, GroupingSet,
+    LogicalPlan, LogicalPlanBuilder, Operator, TableScan, TryCast,
 };
 use arrow::datatypes::{DataType, TimeUnit};
 use datafusion_common::tree_node::{
@@ -673,6 +673,21 @@ pub fn from_plan(
             op: op.clone(),
             input: Arc::new(inputs[0].clone()),
         })),
+        LogicalPlan::Copy(CopyTo {
+            output_url,
+            file_format,
+            partition_by,
+            options,
+            schema,
+            ..
+        }) => Ok(LogicalPlan::Copy(CopyTo {
+            input: Arc::new(inputs[0].clone()),
+            output_url: output_url.clone(),
+            file_format: file_format.clone(),
+            partition_by: partition_by.clone(),
+            options: options.clone(),
+            schema: schema.clone(),
+        })),
         LogicalPlan::Values(Values { schema, .. }) => Ok(LogicalPlan::Values(Values {
             schema: schema.clone(),
             values: expr
@@ -922,6 +937,14 @@ pub fn from_plan(
             Ok(plan.clone())
         }
         LogicalPlan::DescribeTable(_) => Ok(plan.clone()),
+        LogicalPlan::RecursiveQuery(RecursiveQuery {
+            name, is_distinct, ..
+        }) => Ok(LogicalPlan::RecursiveQuery(RecursiveQuery {
+            name: name.clone(),
+            static_term: Arc::new(inputs[0].clone()),
+            recursive_term: Arc::new(inputs[1].clone()),
+            is_distinct: *is_distinct,
+        })),
         LogicalPlan::Unnest(Unnest { column, schema, .. }) => {
             // Update schema with unnested column type.
             let input = Arc::new(inputs[0].clone());
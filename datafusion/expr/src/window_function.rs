@@ -23,7 +23,9 @@
 
 use crate::aggregate_function::AggregateFunction;
 use crate::type_coercion::functions::data_types;
-use crate::{aggregate_function, AggregateUDF, Signature, TypeSignature, Volatility};
+use crate::{
+    aggregate_function, AggregateUDF, Signature, TypeSignature, Volatility, WindowUDF,
+};
 use arrow::datatypes::DataType;
 use datafusion_common::{DataFusionError, Result};
 use std::sync::Arc;
@@ -37,6 +39,8 @@ pub enum WindowFunction {
     /// window function that leverages a built-in window function
     BuiltInWindowFunction(BuiltInWindowFunction),
     AggregateUDF(Arc<AggregateUDF>),
+    /// window function that leverages a user-defined window function
+    WindowUDF(Arc<WindowUDF>),
 }
 
 /// Find DataFusion's built-in window function by name.
@@ -75,6 +79,7 @@ impl fmt::Display for WindowFunction {
             WindowFunction::AggregateFunction(fun) => fun.fmt(f),
             WindowFunction::BuiltInWindowFunction(fun) => fun.fmt(f),
             WindowFunction::AggregateUDF(fun) => std::fmt::Debug::fmt(fun, f),
+            WindowFunction::WindowUDF(fun) => fun.name.fmt(f),
         }
     }
 }
@@ -151,6 +156,9 @@ pub fn return_type(
         WindowFunction::AggregateUDF(fun) => {
             Ok((*(fun.return_type)(input_expr_types)?).clone())
         }
+        WindowFunction::WindowUDF(fun) => {
+            Ok((*(fun.return_type)(input_expr_types)?).clone())
+        }
     }
 }
 
@@ -187,6 +195,7 @@ pub fn signature(fun: &WindowFunction) -> Signature {
         WindowFunction::AggregateFunction(fun) => aggregate_function::signature(fun),
         WindowFunction::BuiltInWindowFunction(fun) => signature_for_built_in(fun),
         WindowFunction::AggregateUDF(fun) => fun.signature.clone(),
+        WindowFunction::WindowUDF(fun) => fun.signature.clone(),
     }
 }
 
@@ -130,9 +130,40 @@ impl PhysicalExpr for CastExpr {
         let child_interval = children[0];
         // Get child's datatype:
         let cast_type = child_interval.get_datatype();
-        Ok(vec![Some(
-            interval.cast_to(&cast_type, &self.cast_options)?,
-        )])
+        // Bounds can only be carried back through casts that preserve the
+        // order of and distinguish between all values of the child's type:
+        if !is_order_preserving_cast(&cast_type, &self.cast_type) {
+            return Ok(vec![Some(child_interval.clone())]);
+        }
+        // Bounds of the parent's interval beyond the child's type exclude no
+        // value of the child, so they must become unbounded rather than fail:
+        let cast_options = CastOptions { safe: true };
+        Ok(vec![interval
+            .cast_to(&cast_type, &cast_options)?
+            .intersect(child_interval)?])
+    }
+}
+
+/// Decides whether casting values of type `from` to type `to` is injective
+/// and order preserving, so that bounds on the cast values are also valid
+/// bounds on the original values.
+fn is_order_preserving_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    let is_integer = |data_type: &DataType| {
+        matches!(
+            data_type,
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
+        )
+    };
+    match (from, to) {
+        (from, to) if is_integer(from) && is_integer(to) => true,
+        (from, Timestamp(_, _)) if is_integer(from) => true,
+        (Timestamp(_, _), to) if is_integer(to) => true,
+        (Timestamp(from_unit, _), Timestamp(to_unit, _)) => from_unit == to_unit,
+        (Float32, Float64) => true,
+        (Int8 | Int16 | UInt8 | UInt16, Float32 | Float64) => true,
+        (Int32 | UInt32, Float64) => true,
+        _ => false,
     }
 }
 
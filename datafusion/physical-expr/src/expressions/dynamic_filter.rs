@@ -0,0 +1,337 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Dynamic filter expression, whose predicate is only known at runtime
+
+use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use crate::expressions::{BinaryExpr, Literal, MaxAccumulator, MinAccumulator};
+use crate::physical_expr::down_cast_any_ref;
+use crate::PhysicalExpr;
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::tree_node::{Transformed, TreeNode, VisitRecursion};
+use datafusion_common::{DataFusionError, Result, ScalarValue};
+use datafusion_expr::{Accumulator, ColumnarValue, Operator};
+
+/// Bounds of the join keys of a hash join build side
+#[derive(Debug, Clone, PartialEq)]
+enum KeyBounds {
+    /// The build side has not been collected yet
+    Unknown,
+    /// Some join key has no non-null values, so that no row can match
+    Empty,
+    /// Minimum and maximum values of each join key
+    Range(Vec<(ScalarValue, ScalarValue)>),
+}
+
+/// Filter on the join keys, published by a hash join once it has collected
+/// its build side, and shared with the [`DynamicFilterExpr`]s pushed down
+/// to its probe side.
+///
+/// Until the build side is collected, the filter accepts all rows.
+#[derive(Debug)]
+pub struct DynamicFilter {
+    bounds: RwLock<KeyBounds>,
+}
+
+impl Default for DynamicFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DynamicFilter {
+    /// Create a new filter, accepting all rows until it is updated
+    pub fn new() -> Self {
+        Self {
+            bounds: RwLock::new(KeyBounds::Unknown),
+        }
+    }
+
+    /// Update the filter to only accept the rows whose join keys are within
+    /// the bounds of the given build side join keys
+    pub fn update(&self, keys: &[ArrayRef]) -> Result<()> {
+        let mut bounds = Vec::with_capacity(keys.len());
+        for key in keys {
+            let mut min = MinAccumulator::try_new(key.data_type())?;
+            let mut max = MaxAccumulator::try_new(key.data_type())?;
+            min.update_batch(&[key.clone()])?;
+            max.update_batch(&[key.clone()])?;
+            bounds.push((min.evaluate()?, max.evaluate()?));
+        }
+
+        let bounds = if bounds
+            .iter()
+            .any(|(min, max)| min.is_null() || max.is_null())
+        {
+            KeyBounds::Empty
+        } else {
+            KeyBounds::Range(bounds)
+        };
+        *self.bounds.write().map_err(|e| {
+            DataFusionError::Internal(format!("Poisoned dynamic filter: {e}"))
+        })? = bounds;
+        Ok(())
+    }
+
+    /// Whether the filter has been updated with the build side join keys
+    pub fn is_complete(&self) -> bool {
+        self.bounds()
+            .map(|bounds| bounds != KeyBounds::Unknown)
+            .unwrap_or(false)
+    }
+
+    fn bounds(&self) -> Result<KeyBounds> {
+        self.bounds
+            .read()
+            .map(|bounds| bounds.clone())
+            .map_err(|e| {
+                DataFusionError::Internal(format!("Poisoned dynamic filter: {e}"))
+            })
+    }
+}
+
+/// Physical expression applying a [`DynamicFilter`] to the given join keys.
+///
+/// The predicate of the expression is derived from the filter each time
+/// it is evaluated, see [`DynamicFilterExpr::current`].
+#[derive(Debug)]
+pub struct DynamicFilterExpr {
+    /// Join keys, in the same order as the build side keys of the filter
+    keys: Vec<Arc<dyn PhysicalExpr>>,
+    filter: Arc<DynamicFilter>,
+}
+
+impl DynamicFilterExpr {
+    /// Create a new dynamic filter expression
+    pub fn new(keys: Vec<Arc<dyn PhysicalExpr>>, filter: Arc<DynamicFilter>) -> Self {
+        Self { keys, filter }
+    }
+
+    /// Join keys the filter applies to
+    pub fn keys(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.keys
+    }
+
+    /// Filter shared with the hash join
+    pub fn filter(&self) -> &Arc<DynamicFilter> {
+        &self.filter
+    }
+
+    /// Returns the predicate currently published by the filter: `true` until
+    /// the build side is collected, then a range check of each join key.
+    pub fn current(&self) -> Result<Arc<dyn PhysicalExpr>> {
+        let bounds = match self.filter.bounds()? {
+            KeyBounds::Unknown => return Ok(lit_bool(true)),
+            KeyBounds::Empty => return Ok(lit_bool(false)),
+            KeyBounds::Range(bounds) => bounds,
+        };
+        Ok(self
+            .keys
+            .iter()
+            .zip(bounds)
+            .map(|(key, (min, max))| {
+                let min = Arc::new(BinaryExpr::new(
+                    key.clone(),
+                    Operator::GtEq,
+                    Arc::new(Literal::new(min)),
+                ));
+                let max = Arc::new(BinaryExpr::new(
+                    key.clone(),
+                    Operator::LtEq,
+                    Arc::new(Literal::new(max)),
+                ));
+                Arc::new(BinaryExpr::new(min, Operator::And, max))
+                    as Arc<dyn PhysicalExpr>
+            })
+            .reduce(|left, right| Arc::new(BinaryExpr::new(left, Operator::And, right)))
+            .unwrap_or_else(|| lit_bool(true)))
+    }
+}
+
+fn lit_bool(value: bool) -> Arc<dyn PhysicalExpr> {
+    Arc::new(Literal::new(ScalarValue::Boolean(Some(value))))
+}
+
+impl fmt::Display for DynamicFilterExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.current() {
+            Ok(current) => write!(f, "DynamicFilter [{current}]"),
+            Err(_) => write!(f, "DynamicFilter [?]"),
+        }
+    }
+}
+
+impl PhysicalExpr for DynamicFilterExpr {
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        self.current()?.evaluate(batch)
+    }
+
+    fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        self.keys.clone()
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Ok(Arc::new(DynamicFilterExpr::new(
+            children,
+            self.filter.clone(),
+        )))
+    }
+}
+
+impl PartialEq<dyn Any> for DynamicFilterExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        down_cast_any_ref(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                self.keys.len() == x.keys.len()
+                    && self
+                        .keys
+                        .iter()
+                        .zip(x.keys.iter())
+                        .all(|(this_key, other_key)| this_key.eq(other_key))
+                    && Arc::ptr_eq(&self.filter, &x.filter)
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// Whether the expression contains any [`DynamicFilterExpr`]
+pub fn has_dynamic_filter(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    let mut found = false;
+    expr.apply(&mut |expr| {
+        if expr.as_any().is::<DynamicFilterExpr>() {
+            found = true;
+            return Ok(VisitRecursion::Stop);
+        }
+        Ok(VisitRecursion::Continue)
+    })
+    .ok();
+    found
+}
+
+/// Replaces the [`DynamicFilterExpr`]s of the expression with the predicates
+/// they currently publish
+pub fn snapshot_dynamic_filters(
+    expr: Arc<dyn PhysicalExpr>,
+) -> Result<Arc<dyn PhysicalExpr>> {
+    expr.transform_up(&|expr| {
+        Ok(match expr.as_any().downcast_ref::<DynamicFilterExpr>() {
+            Some(dynamic_filter) => Transformed::Yes(dynamic_filter.current()?),
+            None => Transformed::No(expr),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::col;
+    use arrow::array::{BooleanArray, Int32Array};
+    use arrow::datatypes::Field;
+    use datafusion_common::cast::as_boolean_array;
+
+    fn evaluate(expr: &DynamicFilterExpr, batch: &RecordBatch) -> Result<BooleanArray> {
+        let result = expr.evaluate(batch)?.into_array(batch.num_rows());
+        Ok(as_boolean_array(&result)?.clone())
+    }
+
+    #[test]
+    fn dynamic_filter() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(Int32Array::from(vec![
+                Some(1),
+                Some(5),
+                None,
+                Some(9),
+            ]))],
+        )?;
+
+        let filter = Arc::new(DynamicFilter::new());
+        let expr = DynamicFilterExpr::new(vec![col("a", &schema)?], filter.clone());
+        assert!(!filter.is_complete());
+        assert_eq!(expr.to_string(), "DynamicFilter [true]");
+        assert_eq!(
+            evaluate(&expr, &batch)?,
+            BooleanArray::from(vec![true, true, true, true])
+        );
+
+        filter.update(&[Arc::new(Int32Array::from(vec![Some(6), None, Some(4)]))])?;
+        assert!(filter.is_complete());
+        assert_eq!(expr.to_string(), "DynamicFilter [a@0 >= 4 AND a@0 <= 6]");
+        assert_eq!(
+            evaluate(&expr, &batch)?,
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false)])
+        );
+
+        filter.update(&[Arc::new(Int32Array::from(vec![None, None]))])?;
+        assert_eq!(expr.to_string(), "DynamicFilter [false]");
+        assert_eq!(
+            evaluate(&expr, &batch)?,
+            BooleanArray::from(vec![false, false, false, false])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn snapshot() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let filter = Arc::new(DynamicFilter::new());
+        let positive = Arc::new(BinaryExpr::new(
+            col("a", &schema)?,
+            Operator::GtEq,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(0)))),
+        ));
+        let expr: Arc<dyn PhysicalExpr> = Arc::new(BinaryExpr::new(
+            positive,
+            Operator::And,
+            Arc::new(DynamicFilterExpr::new(
+                vec![col("a", &schema)?],
+                filter.clone(),
+            )),
+        ));
+        assert!(has_dynamic_filter(&expr));
+
+        filter.update(&[Arc::new(Int32Array::from(vec![1, 2]))])?;
+        let snapshot = snapshot_dynamic_filters(expr)?;
+        assert!(!has_dynamic_filter(&snapshot));
+        assert_eq!(snapshot.to_string(), "a@0 >= 0 AND a@0 >= 1 AND a@0 <= 2");
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ mod case;
 mod cast;
 mod column;
 mod datetime;
+mod dynamic_filter;
 mod get_indexed_field;
 mod in_list;
 mod is_not_null;
@@ -79,6 +80,9 @@ pub use cast::{
 };
 pub use column::{col, Column, UnKnownColumn};
 pub use datetime::DateTimeIntervalExpr;
+pub use dynamic_filter::{
+    has_dynamic_filter, snapshot_dynamic_filters, DynamicFilter, DynamicFilterExpr,
+};
 pub use get_indexed_field::GetIndexedFieldExpr;
 pub use in_list::{in_list, InListExpr};
 pub use is_not_null::{is_not_null, IsNotNullExpr};
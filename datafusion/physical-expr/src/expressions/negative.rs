@@ -28,6 +28,7 @@ use arrow::{
     record_batch::RecordBatch,
 };
 
+use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::PhysicalExpr;
 use datafusion_common::{DataFusionError, Result};
@@ -122,6 +123,23 @@ impl PhysicalExpr for NegativeExpr {
     ) -> Result<Arc<dyn PhysicalExpr>> {
         Ok(Arc::new(NegativeExpr::new(children[0].clone())))
     }
+
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        // Negate the child's interval:
+        children[0].arithmetic_negate()
+    }
+
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        children: &[&Interval],
+    ) -> Result<Vec<Option<Interval>>> {
+        // The child is the negation of the current node:
+        let child_interval = children[0];
+        Ok(vec![interval
+            .arithmetic_negate()?
+            .intersect(child_interval)?])
+    }
 }
 
 impl PartialEq<dyn Any> for NegativeExpr {
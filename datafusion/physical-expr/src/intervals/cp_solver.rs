@@ -190,6 +190,8 @@ fn get_inverse_op(op: Operator) -> Operator {
     match op {
         Operator::Plus => Operator::Minus,
         Operator::Minus => Operator::Plus,
+        Operator::Multiply => Operator::Divide,
+        Operator::Divide => Operator::Multiply,
         _ => unreachable!(),
     }
 }
@@ -206,6 +208,19 @@ fn get_inverse_op(op: Operator) -> Operator {
 /// - For minus operation, specifically, we would first do
 ///     - [xL, xU] <- ([yL, yU] + [pL, pU]) ∩ [xL, xU], and then
 ///     - [yL, yU] <- ([xL, xU] - [pL, pU]) ∩ [yL, yU].
+/// - For multiplication operation, specifically, we would first do
+///     - [xL, xU] <- ([pL, pU] / [yL, yU]) ∩ [xL, xU], and then
+///     - [yL, yU] <- ([pL, pU] / [xL, xU]) ∩ [yL, yU],
+///   where a step is skipped if its divisor contains zero.
+/// - For division operation, specifically, we would first do
+///     - [xL, xU] <- ([pL, pU] * [yL, yU]) ∩ [xL, xU], and then
+///     - [yL, yU] <- ([xL, xU] / [pL, pU]) ∩ [yL, yU],
+///   where a step is skipped if its divisor contains zero. Since integer
+///   division truncates, we only propagate division of floating point values.
+///
+/// A child with a singleton interval (e.g. a literal) is left as is. So is the
+/// interval child of a timestamp shifted by an interval, as the difference of
+/// timestamps is not an interval.
 pub fn propagate_arithmetic(
     op: &Operator,
     parent: &Interval,
@@ -213,17 +228,52 @@ pub fn propagate_arithmetic(
     right_child: &Interval,
 ) -> Result<(Option<Interval>, Option<Interval>)> {
     let inverse_op = get_inverse_op(*op);
+    let is_float = matches!(parent.get_datatype(), DataType::Float32 | DataType::Float64);
+    let is_shift = |child: &Interval| {
+        matches!(parent.get_datatype(), DataType::Timestamp(_, _))
+            && matches!(child.get_datatype(), DataType::Interval(_))
+    };
     // First, propagate to the left:
-    match apply_operator(&inverse_op, parent, right_child)?.intersect(left_child)? {
+    let left = match op {
+        Operator::Plus | Operator::Minus if is_shift(left_child) => None,
+        Operator::Plus | Operator::Minus => {
+            Some(apply_operator(&inverse_op, parent, right_child)?)
+        }
+        Operator::Multiply if !right_child.contains_zero()? => {
+            Some(apply_operator(&inverse_op, parent, right_child)?)
+        }
+        Operator::Divide if is_float => {
+            Some(apply_operator(&inverse_op, parent, right_child)?)
+        }
+        _ => None,
+    };
+    let left = match left {
+        Some(left) => left.intersect(left_child)?,
+        None => Some(left_child.clone()),
+    };
+    match left {
         // Left is feasible:
         Some(value) => {
+            if is_singleton(right_child) {
+                return Ok((Some(value), Some(right_child.clone())));
+            }
             // Propagate to the right using the new left.
             let right = match op {
-                Operator::Minus => apply_operator(op, &value, parent),
-                Operator::Plus => apply_operator(&inverse_op, parent, &value),
-                _ => unreachable!(),
-            }?
-            .intersect(right_child)?;
+                Operator::Plus | Operator::Minus if is_shift(right_child) => None,
+                Operator::Minus => Some(apply_operator(op, &value, parent)?),
+                Operator::Plus => Some(apply_operator(&inverse_op, parent, &value)?),
+                Operator::Multiply if !value.contains_zero()? => {
+                    Some(apply_operator(&inverse_op, parent, &value)?)
+                }
+                Operator::Divide if is_float && !parent.contains_zero()? => {
+                    Some(apply_operator(op, &value, parent)?)
+                }
+                _ => None,
+            };
+            let right = match right {
+                Some(right) => right.intersect(right_child)?,
+                None => Some(right_child.clone()),
+            };
             // Return intervals for both children:
             Ok((Some(value), right))
         }
@@ -232,41 +282,60 @@ pub fn propagate_arithmetic(
     }
 }
 
-/// This function provides a target parent interval for comparison operators.
-/// If we have expression > 0, expression must have the range [0, ∞].
-/// If we have expression < 0, expression must have the range [-∞, 0].
-/// Currently, we only support strict inequalities since open/closed intervals
-/// are not implemented yet.
-fn comparison_operator_target(datatype: &DataType, op: &Operator) -> Result<Interval> {
-    let unbounded = ScalarValue::try_from(datatype)?;
-    let zero = ScalarValue::new_zero(datatype)?;
-    Ok(match *op {
-        Operator::Gt => Interval {
-            lower: zero,
-            upper: unbounded,
-        },
-        Operator::Lt => Interval {
-            lower: unbounded,
-            upper: zero,
-        },
-        _ => unreachable!(),
-    })
+/// Decides whether the interval consists of a single value.
+fn is_singleton(interval: &Interval) -> bool {
+    !interval.lower.is_null() && interval.lower == interval.upper
 }
 
 /// This function propagates constraints arising from comparison operators.
-/// The main idea is that we can analyze an inequality like x > y through the
-/// equivalent inequality x - y > 0. Assuming that x and y has ranges [xL, xU]
-/// and [yL, yU], we simply apply constraint propagation across [xL, xU],
-/// [yL, yH] and [0, ∞]. Specifically, we would first do
-///     - [xL, xU] <- ([yL, yU] + [0, ∞]) ∩ [xL, xU], and then
-///     - [yL, yU] <- ([xL, xU] - [0, ∞]) ∩ [yL, yU].
+/// The main idea is that an inequality like x > y bounds each side by the
+/// other one. Assuming that x and y has ranges [xL, xU] and [yL, yU], we
+/// would first do
+///     - [xL, xU] <- [yL, ∞] ∩ [xL, xU], and then
+///     - [yL, yU] <- [-∞, xU] ∩ [yL, yU].
+/// For x < y, the bounds are swapped accordingly. Currently, we only support
+/// strict inequalities since open/closed intervals are not implemented yet.
+/// Since the children are not combined arithmetically, this works for any
+/// ordered data type.
 pub fn propagate_comparison(
     op: &Operator,
     left_child: &Interval,
     right_child: &Interval,
 ) -> Result<(Option<Interval>, Option<Interval>)> {
-    let parent = comparison_operator_target(&left_child.get_datatype(), op)?;
-    propagate_arithmetic(&Operator::Minus, &parent, left_child, right_child)
+    let unbounded = ScalarValue::try_from(left_child.get_datatype())?;
+    let left_target = match *op {
+        Operator::Gt => Interval {
+            lower: right_child.lower.clone(),
+            upper: unbounded,
+        },
+        Operator::Lt => Interval {
+            lower: unbounded,
+            upper: right_child.upper.clone(),
+        },
+        _ => unreachable!(),
+    };
+    // First, propagate to the left:
+    match left_target.intersect(left_child)? {
+        // Left is feasible:
+        Some(value) => {
+            // Propagate to the right using the new left.
+            let unbounded = ScalarValue::try_from(right_child.get_datatype())?;
+            let right_target = match *op {
+                Operator::Gt => Interval {
+                    lower: unbounded,
+                    upper: value.upper.clone(),
+                },
+                _ => Interval {
+                    lower: value.lower.clone(),
+                    upper: unbounded,
+                },
+            };
+            let right = right_target.intersect(right_child)?;
+            Ok((Some(value), right))
+        }
+        // If the left child is infeasible, short-circuit.
+        None => Ok((None, None)),
+    }
 }
 
 impl ExprIntervalGraph {
@@ -527,7 +596,9 @@ mod tests {
     use crate::intervals::test_utils::gen_conjunctive_numeric_expr;
     use itertools::Itertools;
 
-    use crate::expressions::{BinaryExpr, Column};
+    use crate::expressions::{
+        BinaryExpr, CastExpr, Column, NegativeExpr, DEFAULT_DATAFUSION_CAST_OPTIONS,
+    };
     use datafusion_common::ScalarValue;
     use rand::rngs::StdRng;
     use rand::{Rng, SeedableRng};
@@ -541,38 +612,34 @@ mod tests {
         left_waited: (Option<i32>, Option<i32>),
         right_waited: (Option<i32>, Option<i32>),
         result: PropagationResult,
+    ) -> Result<()> {
+        let interval = |(lower, upper): (Option<i32>, Option<i32>)| Interval {
+            lower: ScalarValue::Int32(lower),
+            upper: ScalarValue::Int32(upper),
+        };
+        experiment_with_intervals(
+            expr,
+            exprs_with_interval,
+            (interval(left_interval), interval(right_interval)),
+            (interval(left_waited), interval(right_waited)),
+            result,
+        )
+    }
+
+    fn experiment_with_intervals(
+        expr: Arc<dyn PhysicalExpr>,
+        exprs_with_interval: (Arc<dyn PhysicalExpr>, Arc<dyn PhysicalExpr>),
+        intervals: (Interval, Interval),
+        waited: (Interval, Interval),
+        result: PropagationResult,
     ) -> Result<()> {
         let col_stats = vec![
-            (
-                exprs_with_interval.0.clone(),
-                Interval {
-                    lower: ScalarValue::Int32(left_interval.0),
-                    upper: ScalarValue::Int32(left_interval.1),
-                },
-            ),
-            (
-                exprs_with_interval.1.clone(),
-                Interval {
-                    lower: ScalarValue::Int32(right_interval.0),
-                    upper: ScalarValue::Int32(right_interval.1),
-                },
-            ),
+            (exprs_with_interval.0.clone(), intervals.0),
+            (exprs_with_interval.1.clone(), intervals.1),
         ];
         let expected = vec![
-            (
-                exprs_with_interval.0.clone(),
-                Interval {
-                    lower: ScalarValue::Int32(left_waited.0),
-                    upper: ScalarValue::Int32(left_waited.1),
-                },
-            ),
-            (
-                exprs_with_interval.1.clone(),
-                Interval {
-                    lower: ScalarValue::Int32(right_waited.0),
-                    upper: ScalarValue::Int32(right_waited.1),
-                },
-            ),
+            (exprs_with_interval.0.clone(), waited.0),
+            (exprs_with_interval.1.clone(), waited.1),
         ];
         let mut graph = ExprIntervalGraph::try_new(expr)?;
         let expr_indexes = graph
@@ -681,6 +748,201 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn testing_multiplication() -> Result<()> {
+        let left_col = Arc::new(Column::new("left_watermark", 0));
+        let right_col = Arc::new(Column::new("right_watermark", 0));
+        // left_watermark * 2 > right_watermark
+        let left_mul_2 = Arc::new(BinaryExpr::new(
+            left_col.clone(),
+            Operator::Multiply,
+            Arc::new(Literal::new(ScalarValue::Int32(Some(2)))),
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_mul_2, Operator::Gt, right_col.clone()));
+        experiment(
+            expr,
+            (left_col, right_col),
+            (Some(0), Some(10)),
+            (Some(5), Some(30)),
+            (Some(2), Some(10)),
+            (Some(5), Some(20)),
+            PropagationResult::Success,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn testing_negation() -> Result<()> {
+        let left_col = Arc::new(Column::new("left_watermark", 0));
+        let right_col = Arc::new(Column::new("right_watermark", 0));
+        // -left_watermark > right_watermark
+        let negated_left = Arc::new(NegativeExpr::new(left_col.clone()));
+        let expr = Arc::new(BinaryExpr::new(
+            negated_left,
+            Operator::Gt,
+            right_col.clone(),
+        ));
+        experiment(
+            expr,
+            (left_col, right_col),
+            (Some(0), Some(10)),
+            (Some(-5), Some(5)),
+            (Some(0), Some(5)),
+            (Some(-5), Some(0)),
+            PropagationResult::Success,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn testing_cast() -> Result<()> {
+        let left_col = Arc::new(Column::new("left_watermark", 0));
+        let right_col = Arc::new(Column::new("right_watermark", 0));
+        // CAST(left_watermark AS BIGINT) * 3 < CAST(right_watermark AS BIGINT)
+        let left_mul_3 = Arc::new(BinaryExpr::new(
+            Arc::new(CastExpr::new(
+                left_col.clone(),
+                DataType::Int64,
+                DEFAULT_DATAFUSION_CAST_OPTIONS,
+            )),
+            Operator::Multiply,
+            Arc::new(Literal::new(ScalarValue::Int64(Some(3)))),
+        ));
+        let right_cast = Arc::new(CastExpr::new(
+            right_col.clone(),
+            DataType::Int64,
+            DEFAULT_DATAFUSION_CAST_OPTIONS,
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_mul_3, Operator::Lt, right_cast));
+        experiment(
+            expr,
+            (left_col, right_col),
+            (Some(1), Some(10)),
+            (None, Some(15)),
+            (Some(1), Some(5)),
+            (Some(3), Some(15)),
+            PropagationResult::Success,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn testing_float() -> Result<()> {
+        let left_col = Arc::new(Column::new("left_watermark", 0));
+        let right_col = Arc::new(Column::new("right_watermark", 0));
+        // left_watermark + 1.5 > right_watermark
+        let left_plus = Arc::new(BinaryExpr::new(
+            left_col.clone(),
+            Operator::Plus,
+            Arc::new(Literal::new(ScalarValue::Float64(Some(1.5)))),
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_plus, Operator::Gt, right_col.clone()));
+        // Computed bounds are rounded outwards to the next floating point value:
+        let below = |value: f64| f64::from_bits(value.to_bits() - 1);
+        let above = |value: f64| f64::from_bits(value.to_bits() + 1);
+        let interval = |lower: f64, upper: f64| Interval {
+            lower: ScalarValue::Float64(Some(lower)),
+            upper: ScalarValue::Float64(Some(upper)),
+        };
+        experiment_with_intervals(
+            expr,
+            (left_col, right_col),
+            (interval(0.0, 10.0), interval(5.0, 20.0)),
+            (interval(below(3.5), 10.0), interval(5.0, above(11.5))),
+            PropagationResult::Success,
+        )
+    }
+
+    #[test]
+    fn testing_timestamp_interval() -> Result<()> {
+        let left_col = Arc::new(Column::new("left_watermark", 0));
+        let right_col = Arc::new(Column::new("right_watermark", 0));
+        let one_second = || Arc::new(Literal::new(ScalarValue::new_interval_dt(0, 1000)));
+        let interval = |lower: i64, upper: i64| Interval {
+            lower: ScalarValue::TimestampMillisecond(Some(lower), None),
+            upper: ScalarValue::TimestampMillisecond(Some(upper), None),
+        };
+        // left_watermark + INTERVAL '1' SECOND > right_watermark
+        let left_plus = Arc::new(BinaryExpr::new(
+            left_col.clone(),
+            Operator::Plus,
+            one_second(),
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_plus, Operator::Gt, right_col.clone()));
+        experiment_with_intervals(
+            expr,
+            (left_col.clone(), right_col.clone()),
+            (interval(0, 10_000), interval(5_000, 20_000)),
+            (interval(4_000, 10_000), interval(5_000, 11_000)),
+            PropagationResult::Success,
+        )?;
+        // left_watermark - INTERVAL '1' SECOND < right_watermark
+        let left_minus = Arc::new(BinaryExpr::new(
+            left_col.clone(),
+            Operator::Minus,
+            one_second(),
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_minus, Operator::Lt, right_col.clone()));
+        experiment_with_intervals(
+            expr,
+            (left_col, right_col),
+            (interval(0, 10_000), interval(0, 5_000)),
+            (interval(0, 6_000), interval(0, 5_000)),
+            PropagationResult::Success,
+        )
+    }
+
+    #[test]
+    fn testing_interval_column() -> Result<()> {
+        let left_col: Arc<dyn PhysicalExpr> = Arc::new(Column::new("left_watermark", 0));
+        let shift_col: Arc<dyn PhysicalExpr> = Arc::new(Column::new("shift", 1));
+        let right_col: Arc<dyn PhysicalExpr> =
+            Arc::new(Column::new("right_watermark", 2));
+        let timestamps = |lower: i64, upper: i64| Interval {
+            lower: ScalarValue::TimestampMillisecond(Some(lower), None),
+            upper: ScalarValue::TimestampMillisecond(Some(upper), None),
+        };
+        let shifts = |lower: i32, upper: i32| Interval {
+            lower: ScalarValue::new_interval_dt(0, lower),
+            upper: ScalarValue::new_interval_dt(0, upper),
+        };
+        // left_watermark + shift > right_watermark
+        let left_plus = Arc::new(BinaryExpr::new(
+            left_col.clone(),
+            Operator::Plus,
+            shift_col.clone(),
+        ));
+        let expr = Arc::new(BinaryExpr::new(left_plus, Operator::Gt, right_col.clone()));
+
+        let mut graph = ExprIntervalGraph::try_new(expr)?;
+        let indices = graph.gather_node_indices(&[left_col, shift_col, right_col]);
+        let mut leaf_bounds = indices
+            .iter()
+            .map(|(_, index)| *index)
+            .zip([
+                timestamps(0, 10_000),
+                shifts(1000, 2000),
+                timestamps(5_000, 20_000),
+            ])
+            .collect_vec();
+        assert_eq!(
+            graph.update_ranges(&mut leaf_bounds)?,
+            PropagationResult::Success
+        );
+        // the shift is not bounded by the difference of timestamps
+        let expected = vec![
+            timestamps(3_000, 10_000),
+            shifts(1000, 2000),
+            timestamps(5_000, 12_000),
+        ];
+        let result = leaf_bounds
+            .into_iter()
+            .map(|(_, interval)| interval)
+            .collect_vec();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
     #[rstest]
     #[test]
     fn case_1(
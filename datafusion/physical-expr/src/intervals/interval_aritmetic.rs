@@ -18,19 +18,21 @@
 //! Interval arithmetic library
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
 use arrow::compute::{cast_with_options, CastOptions};
-use arrow::datatypes::DataType;
+use arrow::datatypes::{DataType, IntervalDayTimeType, IntervalMonthDayNanoType};
 use datafusion_common::{DataFusionError, Result, ScalarValue};
 use datafusion_expr::Operator;
 
 use crate::aggregate::min_max::{max, min};
 
 /// This type represents an interval, which is used to calculate reliable
-/// bounds for expressions. Currently, we only support addition and
-/// subtraction, but more capabilities will be added in the future.
+/// bounds for expressions. Currently, we support addition, subtraction,
+/// multiplication, division, negation and casts, but more capabilities will
+/// be added in the future.
 /// Upper/lower bounds having NULL values indicate an unbounded side. For
 /// example; [10, 20], [10, ∞], [-∞, 100] and [-∞, ∞] are all valid intervals.
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
@@ -55,6 +57,9 @@ impl Display for Interval {
 }
 
 impl Interval {
+    /// Cast the bounds of the interval to the given data type with the given
+    /// options. Bounds that can't be represented in the target type become
+    /// unbounded with safe casts, and are errors otherwise.
     pub(crate) fn cast_to(
         &self,
         data_type: &DataType,
@@ -169,14 +174,14 @@ impl Interval {
         } else if other.lower.is_null() {
             self.lower.clone()
         } else {
-            max(&self.lower, &other.lower)?
+            greater(&self.lower, &other.lower)?
         };
         let upper = if self.upper.is_null() {
             other.upper.clone()
         } else if other.upper.is_null() {
             self.upper.clone()
         } else {
-            min(&self.upper, &other.upper)?
+            lesser(&self.upper, &other.upper)?
         };
         Ok(if !lower.is_null() && !upper.is_null() && lower > upper {
             // This None value signals an empty interval.
@@ -186,30 +191,94 @@ impl Interval {
         })
     }
 
-    // Compute the negation of the interval.
-    #[allow(dead_code)]
+    /// Compute the negation of the interval. Say we have interval [a, b],
+    /// then its negation is [-b, -a].
     pub(crate) fn arithmetic_negate(&self) -> Result<Interval> {
         Ok(Interval {
-            lower: self.upper.arithmetic_negate()?,
-            upper: self.lower.arithmetic_negate()?,
+            lower: negate_bound(&self.upper)?,
+            upper: negate_bound(&self.lower)?,
         })
     }
 
+    /// Returns the data type of the sum or difference of this interval and
+    /// `other`, which is a timestamp if either of them is, e.g. when shifting
+    /// timestamps by interval values.
+    fn sum_datatype(&self, other: &Interval) -> DataType {
+        match other.get_datatype() {
+            data_type @ DataType::Timestamp(_, _) => data_type,
+            _ => self.get_datatype(),
+        }
+    }
+
+    /// Decide if adding the values of the interval to other values preserves
+    /// their order, so that the bounds of sums are the sums of the bounds.
+    ///
+    /// Values of interval types are ordered by their raw values, as when they
+    /// are sorted or compared, i.e. by their months, then days and then
+    /// sub-day parts, the latter two compared as unsigned values. This is
+    /// not the order of the durations they add, unless the bounds only differ
+    /// in their sub-day parts, of the same sign. Other intervals are
+    /// unbounded in sums.
+    fn is_monotonic(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (
+                ScalarValue::IntervalDayTime(Some(lower)),
+                ScalarValue::IntervalDayTime(Some(upper)),
+            ) => {
+                let (lower_days, lower_millis) = IntervalDayTimeType::to_parts(*lower);
+                let (upper_days, upper_millis) = IntervalDayTimeType::to_parts(*upper);
+                lower == upper
+                    || (lower_days == upper_days
+                        && (lower_millis < 0) == (upper_millis < 0)
+                        && lower_millis <= upper_millis)
+            }
+            (
+                ScalarValue::IntervalMonthDayNano(Some(lower)),
+                ScalarValue::IntervalMonthDayNano(Some(upper)),
+            ) => {
+                let (lower_months, lower_days, lower_nanos) =
+                    IntervalMonthDayNanoType::to_parts(*lower);
+                let (upper_months, upper_days, upper_nanos) =
+                    IntervalMonthDayNanoType::to_parts(*upper);
+                lower == upper
+                    || (lower_months == upper_months
+                        && lower_days == upper_days
+                        && (lower_nanos < 0) == (upper_nanos < 0)
+                        && lower_nanos <= upper_nanos)
+            }
+            (ScalarValue::IntervalDayTime(_), _)
+            | (ScalarValue::IntervalMonthDayNano(_), _) => false,
+            _ => true,
+        }
+    }
+
+    /// Decide if the interval contains zero. Unbounded sides are assumed to
+    /// extend across zero.
+    pub(crate) fn contains_zero(&self) -> Result<bool> {
+        let zero = ScalarValue::new_zero(&self.get_datatype())?;
+        Ok((self.lower.is_null() || self.lower <= zero)
+            && (self.upper.is_null() || self.upper >= zero))
+    }
+
     /// Add the given interval (`other`) to this interval. Say we have
     /// intervals [a1, b1] and [a2, b2], then their sum is [a1 + a2, b1 + b2].
     /// Note that this represents all possible values the sum can take if
     /// one can choose single values arbitrarily from each of the operands.
     pub fn add<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
         let rhs = other.borrow();
+        let data_type = self.sum_datatype(rhs);
+        if !self.is_monotonic() || !rhs.is_monotonic() {
+            return Bound::span(vec![], &data_type);
+        }
         let lower = if self.lower.is_null() || rhs.lower.is_null() {
-            ScalarValue::try_from(self.lower.get_datatype())
+            ScalarValue::try_from(&data_type)
         } else {
-            self.lower.add(&rhs.lower)
+            self.lower.add(&rhs.lower).map(|v| next_float(v, false))
         }?;
         let upper = if self.upper.is_null() || rhs.upper.is_null() {
-            ScalarValue::try_from(self.upper.get_datatype())
+            ScalarValue::try_from(&data_type)
         } else {
-            self.upper.add(&rhs.upper)
+            self.upper.add(&rhs.upper).map(|v| next_float(v, true))
         }?;
         Ok(Interval { lower, upper })
     }
@@ -220,18 +289,176 @@ impl Interval {
     /// if one can choose single values arbitrarily from each of the operands.
     pub fn sub<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
         let rhs = other.borrow();
+        let data_type = self.sum_datatype(rhs);
+        if !self.is_monotonic() || !rhs.is_monotonic() {
+            return Bound::span(vec![], &data_type);
+        }
         let lower = if self.lower.is_null() || rhs.upper.is_null() {
-            ScalarValue::try_from(self.lower.get_datatype())
+            ScalarValue::try_from(&data_type)
         } else {
-            self.lower.sub(&rhs.upper)
+            self.lower.sub(&rhs.upper).map(|v| next_float(v, false))
         }?;
         let upper = if self.upper.is_null() || rhs.lower.is_null() {
-            ScalarValue::try_from(self.upper.get_datatype())
+            ScalarValue::try_from(&data_type)
         } else {
-            self.upper.sub(&rhs.lower)
+            self.upper.sub(&rhs.lower).map(|v| next_float(v, true))
         }?;
         Ok(Interval { lower, upper })
     }
+
+    /// Multiply this interval by the given interval (`other`). Say we have
+    /// intervals [a1, b1] and [a2, b2], then their product is the interval
+    /// spanning a1 * a2, a1 * b2, b1 * a2 and b1 * b2. Unbounded sides are
+    /// treated as infinities, and products overflowing the data type make
+    /// the corresponding side of the result unbounded.
+    pub fn mul<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
+        let rhs = other.borrow();
+        let mut products = Vec::with_capacity(4);
+        for lhs_bound in [Bound::lower(&self.lower), Bound::upper(&self.upper)] {
+            for rhs_bound in [Bound::lower(&rhs.lower), Bound::upper(&rhs.upper)] {
+                products.push(lhs_bound.mul(&rhs_bound)?);
+            }
+        }
+        Bound::span(products, &self.get_datatype())
+    }
+
+    /// Divide this interval by the given interval (`other`). Say we have
+    /// intervals [a1, b1] and [a2, b2], then their quotient is the interval
+    /// spanning a1 / a2, a1 / b2, b1 / a2 and b1 / b2, provided that the
+    /// divisor does not contain zero. Otherwise, the quotient is unbounded.
+    pub fn div<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
+        let rhs = other.borrow();
+        let data_type = self.get_datatype();
+        if rhs.contains_zero()? {
+            return Bound::span(vec![], &data_type);
+        }
+        let mut quotients = Vec::with_capacity(4);
+        for lhs_bound in [Bound::lower(&self.lower), Bound::upper(&self.upper)] {
+            for rhs_bound in [Bound::lower(&rhs.lower), Bound::upper(&rhs.upper)] {
+                if let Some(quotient) = lhs_bound.div(&rhs_bound)? {
+                    quotients.push(quotient);
+                }
+            }
+        }
+        Bound::span(quotients, &data_type)
+    }
+}
+
+/// A bound of an interval, where the unbounded sides are infinite.
+#[derive(Debug)]
+enum Bound {
+    NegInf,
+    Finite(ScalarValue),
+    PosInf,
+}
+
+impl Bound {
+    fn lower(value: &ScalarValue) -> Self {
+        if value.is_null() {
+            Bound::NegInf
+        } else {
+            Bound::Finite(value.clone())
+        }
+    }
+
+    fn upper(value: &ScalarValue) -> Self {
+        if value.is_null() {
+            Bound::PosInf
+        } else {
+            Bound::Finite(value.clone())
+        }
+    }
+
+    fn infinite(sign: Ordering) -> Self {
+        match sign {
+            Ordering::Less => Bound::NegInf,
+            _ => Bound::PosInf,
+        }
+    }
+
+    fn sign(&self) -> Result<Ordering> {
+        Ok(match self {
+            Bound::NegInf => Ordering::Less,
+            Bound::PosInf => Ordering::Greater,
+            Bound::Finite(value) => {
+                let zero = ScalarValue::new_zero(&value.get_datatype())?;
+                value.partial_cmp(&zero).unwrap_or(Ordering::Equal)
+            }
+        })
+    }
+
+    fn mul(&self, other: &Bound) -> Result<Bound> {
+        let sign = mul_signs(self.sign()?, other.sign()?);
+        Ok(match (self, other) {
+            (Bound::Finite(lhs), Bound::Finite(rhs)) => match checked_mul(lhs, rhs)? {
+                Some(product) => Bound::Finite(product),
+                None => Bound::infinite(sign),
+            },
+            // Zero times an unbounded side is zero, as the actual values are finite
+            (Bound::Finite(value), _) | (_, Bound::Finite(value))
+                if sign == Ordering::Equal =>
+            {
+                Bound::Finite(ScalarValue::new_zero(&value.get_datatype())?)
+            }
+            _ => Bound::infinite(sign),
+        })
+    }
+
+    /// Divide by a bound of an interval not containing zero, returning `None`
+    /// for the quotient of two infinities. Such quotients can be skipped, as
+    /// the other corners of the intervals already span them.
+    fn div(&self, other: &Bound) -> Result<Option<Bound>> {
+        let sign = mul_signs(self.sign()?, other.sign()?);
+        Ok(match (self, other) {
+            (Bound::Finite(lhs), Bound::Finite(rhs)) => match checked_div(lhs, rhs)? {
+                Some(quotient) => Some(Bound::Finite(quotient)),
+                None => Some(Bound::infinite(sign)),
+            },
+            (Bound::Finite(value), _) => {
+                Some(Bound::Finite(ScalarValue::new_zero(&value.get_datatype())?))
+            }
+            (_, Bound::Finite(_)) => Some(Bound::infinite(sign)),
+            _ => None,
+        })
+    }
+
+    fn compare(&self, other: &Bound) -> Ordering {
+        match (self, other) {
+            (Bound::Finite(lhs), Bound::Finite(rhs)) => {
+                lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal)
+            }
+            (Bound::NegInf, Bound::NegInf) | (Bound::PosInf, Bound::PosInf) => {
+                Ordering::Equal
+            }
+            (Bound::NegInf, _) | (_, Bound::PosInf) => Ordering::Less,
+            (Bound::PosInf, _) | (_, Bound::NegInf) => Ordering::Greater,
+        }
+    }
+
+    /// Returns the interval spanning the given bounds, which is unbounded
+    /// if there are none
+    fn span(bounds: Vec<Bound>, data_type: &DataType) -> Result<Interval> {
+        let lower = bounds.iter().min_by(|a, b| a.compare(b));
+        let upper = bounds.iter().max_by(|a, b| a.compare(b));
+        Ok(Interval {
+            lower: match lower {
+                Some(Bound::Finite(value)) => next_float(value.clone(), false),
+                _ => ScalarValue::try_from(data_type)?,
+            },
+            upper: match upper {
+                Some(Bound::Finite(value)) => next_float(value.clone(), true),
+                _ => ScalarValue::try_from(data_type)?,
+            },
+        })
+    }
+}
+
+fn mul_signs(lhs: Ordering, rhs: Ordering) -> Ordering {
+    match (lhs, rhs) {
+        (Ordering::Equal, _) | (_, Ordering::Equal) => Ordering::Equal,
+        (lhs, rhs) if lhs == rhs => Ordering::Greater,
+        _ => Ordering::Less,
+    }
 }
 
 /// Indicates whether interval arithmetic is supported for the given operator.
@@ -240,6 +467,8 @@ pub fn is_operator_supported(op: &Operator) -> bool {
         op,
         &Operator::Plus
             | &Operator::Minus
+            | &Operator::Multiply
+            | &Operator::Divide
             | &Operator::And
             | &Operator::Gt
             | &Operator::Lt
@@ -247,6 +476,8 @@ pub fn is_operator_supported(op: &Operator) -> bool {
 }
 
 /// Indicates whether interval arithmetic is supported for the given data type.
+/// Values of interval types can be compared, added to each other and added
+/// to or subtracted from timestamps, but not multiplied or divided.
 pub fn is_datatype_supported(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -258,6 +489,10 @@ pub fn is_datatype_supported(data_type: &DataType) -> bool {
             | &DataType::UInt32
             | &DataType::UInt16
             | &DataType::UInt8
+            | &DataType::Float64
+            | &DataType::Float32
+            | &DataType::Timestamp(_, _)
+            | &DataType::Interval(_)
     )
 }
 
@@ -269,6 +504,8 @@ pub fn apply_operator(op: &Operator, lhs: &Interval, rhs: &Interval) -> Result<I
         Operator::And => lhs.and(rhs),
         Operator::Plus => lhs.add(rhs),
         Operator::Minus => lhs.sub(rhs),
+        Operator::Multiply => lhs.mul(rhs),
+        Operator::Divide => lhs.div(rhs),
         _ => Ok(Interval {
             lower: ScalarValue::Null,
             upper: ScalarValue::Null,
@@ -276,6 +513,24 @@ pub fn apply_operator(op: &Operator, lhs: &Interval, rhs: &Interval) -> Result<I
     }
 }
 
+/// Returns the greater of two non-null values. Values of interval types,
+/// which the `max` aggregate does not support, are compared by their raw
+/// values, as they are in expressions.
+fn greater(lhs: &ScalarValue, rhs: &ScalarValue) -> Result<ScalarValue> {
+    match lhs.get_datatype() {
+        DataType::Interval(_) => Ok(if lhs >= rhs { lhs } else { rhs }.clone()),
+        _ => max(lhs, rhs),
+    }
+}
+
+/// Returns the lesser of two non-null values, see [`greater`].
+fn lesser(lhs: &ScalarValue, rhs: &ScalarValue) -> Result<ScalarValue> {
+    match lhs.get_datatype() {
+        DataType::Interval(_) => Ok(if lhs <= rhs { lhs } else { rhs }.clone()),
+        _ => min(lhs, rhs),
+    }
+}
+
 /// Cast scalar value to the given data type using an arrow kernel.
 fn cast_scalar_value(
     value: &ScalarValue,
@@ -286,9 +541,131 @@ fn cast_scalar_value(
     ScalarValue::try_from_array(&cast_array, 0)
 }
 
+/// Negate a bound, which becomes unbounded if its negation overflows.
+fn negate_bound(value: &ScalarValue) -> Result<ScalarValue> {
+    let negated = match value {
+        _ if value.is_null() => None,
+        ScalarValue::Int8(Some(v)) => v.checked_neg().map(|v| ScalarValue::Int8(Some(v))),
+        ScalarValue::Int16(Some(v)) => {
+            v.checked_neg().map(|v| ScalarValue::Int16(Some(v)))
+        }
+        ScalarValue::Int32(Some(v)) => {
+            v.checked_neg().map(|v| ScalarValue::Int32(Some(v)))
+        }
+        ScalarValue::Int64(Some(v)) => {
+            v.checked_neg().map(|v| ScalarValue::Int64(Some(v)))
+        }
+        value => Some(value.arithmetic_negate()?),
+    };
+    match negated {
+        Some(negated) => Ok(negated),
+        None => ScalarValue::try_from(value.get_datatype()),
+    }
+}
+
+macro_rules! checked_arithmetic {
+    ($LHS:expr, $RHS:expr, $CHECKED:ident, $OPERATION:tt) => {
+        match ($LHS, $RHS) {
+            (ScalarValue::Float64(Some(lhs)), ScalarValue::Float64(Some(rhs))) => {
+                Some(ScalarValue::Float64(Some(lhs $OPERATION rhs)))
+            }
+            (ScalarValue::Float32(Some(lhs)), ScalarValue::Float32(Some(rhs))) => {
+                Some(ScalarValue::Float32(Some(lhs $OPERATION rhs)))
+            }
+            (ScalarValue::Int64(Some(lhs)), ScalarValue::Int64(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::Int64(Some(v)))
+            }
+            (ScalarValue::Int32(Some(lhs)), ScalarValue::Int32(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::Int32(Some(v)))
+            }
+            (ScalarValue::Int16(Some(lhs)), ScalarValue::Int16(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::Int16(Some(v)))
+            }
+            (ScalarValue::Int8(Some(lhs)), ScalarValue::Int8(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::Int8(Some(v)))
+            }
+            (ScalarValue::UInt64(Some(lhs)), ScalarValue::UInt64(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::UInt64(Some(v)))
+            }
+            (ScalarValue::UInt32(Some(lhs)), ScalarValue::UInt32(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::UInt32(Some(v)))
+            }
+            (ScalarValue::UInt16(Some(lhs)), ScalarValue::UInt16(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::UInt16(Some(v)))
+            }
+            (ScalarValue::UInt8(Some(lhs)), ScalarValue::UInt8(Some(rhs))) => {
+                lhs.$CHECKED(*rhs).map(|v| ScalarValue::UInt8(Some(v)))
+            }
+            (lhs, rhs) => {
+                return Err(DataFusionError::NotImplemented(format!(
+                    "Interval arithmetic does not support {lhs:?} {} {rhs:?}",
+                    stringify!($OPERATION)
+                )))
+            }
+        }
+    };
+}
+
+/// Multiply two non-null values of the same type, returning `None` on overflow.
+fn checked_mul(lhs: &ScalarValue, rhs: &ScalarValue) -> Result<Option<ScalarValue>> {
+    Ok(checked_arithmetic!(lhs, rhs, checked_mul, *))
+}
+
+/// Divide two non-null values of the same type, returning `None` on overflow.
+fn checked_div(lhs: &ScalarValue, rhs: &ScalarValue) -> Result<Option<ScalarValue>> {
+    Ok(checked_arithmetic!(lhs, rhs, checked_div, /))
+}
+
+/// Returns the next floating point value above (`up`) or below the given
+/// value, so that bounds computed with rounding errors still enclose the
+/// exact results. Values of other types are returned unchanged.
+fn next_float(value: ScalarValue, up: bool) -> ScalarValue {
+    match value {
+        ScalarValue::Float64(Some(v)) => ScalarValue::Float64(Some(next_f64(v, up))),
+        ScalarValue::Float32(Some(v)) => ScalarValue::Float32(Some(next_f32(v, up))),
+        value => value,
+    }
+}
+
+fn next_f64(value: f64, up: bool) -> f64 {
+    if value.is_nan() || value.is_infinite() {
+        value
+    } else if value == 0.0 {
+        let smallest = f64::from_bits(1);
+        if up {
+            smallest
+        } else {
+            -smallest
+        }
+    } else if (value > 0.0) == up {
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+fn next_f32(value: f32, up: bool) -> f32 {
+    if value.is_nan() || value.is_infinite() {
+        value
+    } else if value == 0.0 {
+        let smallest = f32::from_bits(1);
+        if up {
+            smallest
+        } else {
+            -smallest
+        }
+    } else if (value > 0.0) == up {
+        f32::from_bits(value.to_bits() + 1)
+    } else {
+        f32::from_bits(value.to_bits() - 1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::intervals::Interval;
+    use arrow::compute::CastOptions;
+    use arrow::datatypes::{DataType, TimeUnit};
     use datafusion_common::{Result, ScalarValue};
 
     #[test]
@@ -530,4 +907,255 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn mul_test() -> Result<()> {
+        let cases = vec![
+            (Some(2), Some(3), Some(4), Some(5), Some(8), Some(15)),
+            (Some(-2), Some(3), Some(4), Some(5), Some(-10), Some(15)),
+            (Some(-2), Some(3), Some(-5), Some(4), Some(-15), Some(12)),
+            (Some(-3), Some(-2), Some(-5), Some(-4), Some(8), Some(15)),
+            (Some(1), None, Some(2), Some(3), Some(2), None),
+            (Some(1), None, Some(-3), Some(-2), None, Some(-2)),
+            (Some(-1), None, Some(2), Some(3), Some(-3), None),
+            (Some(0), Some(0), None, None, Some(0), Some(0)),
+            (Some(0), Some(2), Some(1), None, Some(0), None),
+            (Some(i64::MAX), None, Some(2), Some(2), None, None),
+            (Some(i64::MIN), Some(0), Some(2), Some(2), None, Some(0)),
+            (None, None, None, None, None, None),
+        ];
+
+        for case in cases {
+            assert_eq!(
+                Interval {
+                    lower: ScalarValue::Int64(case.0),
+                    upper: ScalarValue::Int64(case.1)
+                }
+                .mul(&Interval {
+                    lower: ScalarValue::Int64(case.2),
+                    upper: ScalarValue::Int64(case.3)
+                })?,
+                Interval {
+                    lower: ScalarValue::Int64(case.4),
+                    upper: ScalarValue::Int64(case.5)
+                }
+            )
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn div_test() -> Result<()> {
+        let cases = vec![
+            (Some(10), Some(20), Some(2), Some(5), Some(2), Some(10)),
+            (Some(-10), Some(20), Some(2), Some(5), Some(-5), Some(10)),
+            (Some(-10), Some(20), Some(-5), Some(-2), Some(-10), Some(5)),
+            (Some(10), Some(20), Some(-2), Some(5), None, None),
+            (Some(10), Some(20), Some(0), Some(5), None, None),
+            (Some(10), None, Some(2), Some(5), Some(2), None),
+            (Some(10), Some(20), Some(2), None, Some(0), Some(10)),
+            (None, Some(-10), Some(2), None, None, Some(0)),
+            (Some(i64::MIN), Some(0), Some(-1), Some(-1), Some(0), None),
+        ];
+
+        for case in cases {
+            assert_eq!(
+                Interval {
+                    lower: ScalarValue::Int64(case.0),
+                    upper: ScalarValue::Int64(case.1)
+                }
+                .div(&Interval {
+                    lower: ScalarValue::Int64(case.2),
+                    upper: ScalarValue::Int64(case.3)
+                })?,
+                Interval {
+                    lower: ScalarValue::Int64(case.4),
+                    upper: ScalarValue::Int64(case.5)
+                }
+            )
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn negate_test() -> Result<()> {
+        let cases = vec![
+            (Some(1), Some(2), Some(-2), Some(-1)),
+            (Some(-1), None, None, Some(1)),
+            (None, Some(3), Some(-3), None),
+            (Some(i32::MIN), Some(0), Some(0), None),
+            (None, None, None, None),
+        ];
+
+        for case in cases {
+            assert_eq!(
+                Interval {
+                    lower: ScalarValue::Int32(case.0),
+                    upper: ScalarValue::Int32(case.1)
+                }
+                .arithmetic_negate()?,
+                Interval {
+                    lower: ScalarValue::Int32(case.2),
+                    upper: ScalarValue::Int32(case.3)
+                }
+            )
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn contains_zero_test() -> Result<()> {
+        let cases = vec![
+            (Some(-1), Some(1), true),
+            (Some(0), Some(1), true),
+            (Some(1), Some(2), false),
+            (Some(-2), Some(-1), false),
+            (None, Some(-1), false),
+            (Some(-1), None, true),
+            (None, None, true),
+        ];
+
+        for case in cases {
+            assert_eq!(
+                Interval {
+                    lower: ScalarValue::Int64(case.0),
+                    upper: ScalarValue::Int64(case.1)
+                }
+                .contains_zero()?,
+                case.2
+            )
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn float_rounding_test() -> Result<()> {
+        let interval = Interval {
+            lower: ScalarValue::Float64(Some(0.1)),
+            upper: ScalarValue::Float64(Some(0.2)),
+        };
+        let result = interval.add(&interval)?;
+        assert!(result.lower < ScalarValue::Float64(Some(0.1 + 0.1)));
+        assert!(result.upper > ScalarValue::Float64(Some(0.2 + 0.2)));
+
+        let result = interval.mul(&Interval {
+            lower: ScalarValue::Float64(Some(3.0)),
+            upper: ScalarValue::Float64(Some(3.0)),
+        })?;
+        assert!(result.lower < ScalarValue::Float64(Some(0.1 * 3.0)));
+        assert!(result.upper > ScalarValue::Float64(Some(0.2 * 3.0)));
+
+        let result = interval.div(&Interval {
+            lower: ScalarValue::Float64(Some(-1.0)),
+            upper: ScalarValue::Float64(Some(1.0)),
+        })?;
+        assert_eq!(
+            result,
+            Interval {
+                lower: ScalarValue::Float64(None),
+                upper: ScalarValue::Float64(None),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cast_test() -> Result<()> {
+        let interval = Interval {
+            lower: ScalarValue::Int64(Some(-1000)),
+            upper: ScalarValue::Int64(Some(100)),
+        };
+        let cast_options = CastOptions { safe: true };
+        assert_eq!(
+            interval.cast_to(&DataType::Int8, &cast_options)?,
+            Interval {
+                lower: ScalarValue::Int8(None),
+                upper: ScalarValue::Int8(Some(100)),
+            }
+        );
+        assert_eq!(
+            interval.cast_to(&DataType::Float64, &cast_options)?,
+            Interval {
+                lower: ScalarValue::Float64(Some(-1000.0)),
+                upper: ScalarValue::Float64(Some(100.0)),
+            }
+        );
+        assert_eq!(
+            interval.cast_to(
+                &DataType::Timestamp(TimeUnit::Millisecond, None),
+                &cast_options
+            )?,
+            Interval {
+                lower: ScalarValue::TimestampMillisecond(Some(-1000), None),
+                upper: ScalarValue::TimestampMillisecond(Some(100), None),
+            }
+        );
+        // bounds overflowing the target type are errors with unsafe casts
+        assert!(interval
+            .cast_to(&DataType::Int8, &CastOptions { safe: false })
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn interval_type_test() -> Result<()> {
+        let timestamps = |lower: i64, upper: i64| Interval {
+            lower: ScalarValue::TimestampMillisecond(Some(lower), None),
+            upper: ScalarValue::TimestampMillisecond(Some(upper), None),
+        };
+        let day_time = |lower: (i32, i32), upper: (i32, i32)| Interval {
+            lower: ScalarValue::new_interval_dt(lower.0, lower.1),
+            upper: ScalarValue::new_interval_dt(upper.0, upper.1),
+        };
+        let day = 86_400_000;
+
+        // timestamps shifted by intervals only differing in their milliseconds
+        let shift = day_time((1, 1000), (1, 2000));
+        let expected = timestamps(day + 1000, day + 12_000);
+        assert_eq!(timestamps(0, 10_000).add(&shift)?, expected);
+        assert_eq!(shift.add(timestamps(0, 10_000))?, expected);
+        assert_eq!(
+            timestamps(0, 10_000).sub(&shift)?,
+            timestamps(-day - 2000, -day + 9000)
+        );
+
+        // intervals differing in their days include intervals of any number
+        // of milliseconds, so shifted timestamps are unbounded
+        let shift = day_time((0, 1000), (1, 0));
+        assert_eq!(
+            timestamps(0, 10_000).add(&shift)?,
+            Interval {
+                lower: ScalarValue::TimestampMillisecond(None, None),
+                upper: ScalarValue::TimestampMillisecond(None, None),
+            }
+        );
+
+        // January and February 1970 have 31 and 28 days
+        let months = Interval {
+            lower: ScalarValue::new_interval_ym(0, 1),
+            upper: ScalarValue::new_interval_ym(0, 2),
+        };
+        assert_eq!(
+            timestamps(0, 0).add(&months)?,
+            timestamps(31 * day, 59 * day)
+        );
+
+        // intervals of interval values
+        assert_eq!(
+            day_time((0, 1000), (0, 2000)).add(day_time((1, 0), (1, 500)))?,
+            day_time((1, 1000), (1, 2500))
+        );
+        assert_eq!(
+            day_time((0, 1000), (0, 3000)).intersect(&day_time((0, 2000), (0, 4000)))?,
+            Some(day_time((0, 2000), (0, 3000)))
+        );
+        assert_eq!(
+            day_time((0, 1000), (0, 2000)).gt(&day_time((0, 3000), (0, 4000))),
+            Interval {
+                lower: ScalarValue::Boolean(Some(false)),
+                upper: ScalarValue::Boolean(Some(false)),
+            }
+        );
+        Ok(())
+    }
 }
@@ -38,6 +38,7 @@ use datafusion_common::Result;
 use datafusion_expr::BuiltinScalarFunction;
 use datafusion_expr::ColumnarValue;
 use datafusion_expr::ScalarFunctionImplementation;
+use datafusion_expr::ScalarUDF;
 use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::{self, Formatter};
@@ -49,6 +50,8 @@ pub struct ScalarFunctionExpr {
     name: String,
     args: Vec<Arc<dyn PhysicalExpr>>,
     return_type: DataType,
+    /// The user defined function `fun` was taken from, if any
+    udf: Option<ScalarUDF>,
 }
 
 impl Debug for ScalarFunctionExpr {
@@ -75,6 +78,22 @@ impl ScalarFunctionExpr {
             name: name.to_owned(),
             args,
             return_type: return_type.clone(),
+            udf: None,
+        }
+    }
+
+    /// Create a new scalar function that calls a user defined function
+    pub fn new_udf(
+        udf: &ScalarUDF,
+        args: Vec<Arc<dyn PhysicalExpr>>,
+        return_type: &DataType,
+    ) -> Self {
+        Self {
+            fun: udf.fun.clone(),
+            name: udf.name.clone(),
+            args,
+            return_type: return_type.clone(),
+            udf: Some(udf.clone()),
         }
     }
 
@@ -97,6 +116,12 @@ impl ScalarFunctionExpr {
     pub fn return_type(&self) -> &DataType {
         &self.return_type
     }
+
+    /// The user defined function called by this expression, if it was
+    /// created from one
+    pub fn udf(&self) -> Option<&ScalarUDF> {
+        self.udf.as_ref()
+    }
 }
 
 impl fmt::Display for ScalarFunctionExpr {
@@ -155,12 +180,13 @@ impl PhysicalExpr for ScalarFunctionExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(ScalarFunctionExpr::new(
-            &self.name,
-            self.fun.clone(),
-            children,
-            self.return_type(),
-        )))
+        Ok(Arc::new(ScalarFunctionExpr {
+            fun: self.fun.clone(),
+            name: self.name.clone(),
+            args: children,
+            return_type: self.return_type.clone(),
+            udf: self.udf.clone(),
+        }))
     }
 }
 
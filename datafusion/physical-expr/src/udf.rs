@@ -34,9 +34,8 @@ pub fn create_physical_expr(
         .map(|e| e.data_type(input_schema))
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(Arc::new(ScalarFunctionExpr::new(
-        &fun.name,
-        fun.fun.clone(),
+    Ok(Arc::new(ScalarFunctionExpr::new_udf(
+        fun,
         input_phy_exprs.to_vec(),
         (fun.return_type)(&input_exprs_types)?.as_ref(),
     )))
@@ -21,21 +21,20 @@ use std::any::Any;
 use std::ops::Range;
 use std::sync::Arc;
 
-use super::window_frame_state::WindowFrameContext;
 use super::BuiltInWindowFunctionExpr;
 use super::WindowExpr;
-use crate::window::window_expr::{
-    reverse_order_bys, BuiltinWindowState, NthValueKind, NthValueState, WindowFn,
-};
-use crate::window::{
-    PartitionBatches, PartitionWindowAggStates, WindowAggState, WindowState,
-};
+use crate::window::window_expr::{reverse_order_bys, WindowFn};
+use crate::window::{PartitionBatches, PartitionWindowAggStates, WindowState};
 use crate::{expressions::PhysicalSortExpr, PhysicalExpr};
 use arrow::array::{new_empty_array, Array, ArrayRef};
 use arrow::compute::SortOptions;
 use arrow::datatypes::Field;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::window_frame_state::WindowFrameContext;
+use datafusion_expr::window_state::{
+    BuiltinWindowState, NthValueKind, NthValueState, WindowAggState,
+};
 use datafusion_expr::WindowFrame;
 
 /// A window expr that takes the form of a built in window function
@@ -18,15 +18,15 @@
 //! Defines physical expression for `lead` and `lag` that can evaluated
 //! at runtime during query execution
 
-use crate::window::partition_evaluator::PartitionEvaluator;
-use crate::window::window_expr::{BuiltinWindowState, LeadLagState};
-use crate::window::{BuiltInWindowFunctionExpr, WindowAggState};
+use crate::window::BuiltInWindowFunctionExpr;
 use crate::PhysicalExpr;
 use arrow::array::ArrayRef;
 use arrow::compute::cast;
 use arrow::datatypes::{DataType, Field};
 use datafusion_common::ScalarValue;
 use datafusion_common::{DataFusionError, Result};
+use datafusion_expr::window_state::{BuiltinWindowState, LeadLagState, WindowAggState};
+use datafusion_expr::PartitionEvaluator;
 use std::any::Any;
 use std::cmp::min;
 use std::ops::{Neg, Range};
@@ -47,6 +47,11 @@ impl WindowShift {
     pub fn get_shift_offset(&self) -> i64 {
         self.shift_offset
     }
+
+    /// Get the default_value for window shift expression.
+    pub fn get_default_value(&self) -> Option<ScalarValue> {
+        self.default_value.clone()
+    }
 }
 
 /// lead() window function
@@ -40,6 +40,28 @@ pub fn byte_to_string(b: u8) -> Result<String> {
     Ok(b.to_owned())
 }
 
+/// Serializes an optional CSV character, `None` being the empty string
+pub fn optional_byte_to_string(b: Option<u8>) -> Result<String> {
+    b.map(byte_to_string)
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Deserializes an optional CSV character, the empty string being `None`
+pub fn str_to_optional_byte(s: &String) -> Result<Option<u8>> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        str_to_byte(s).map(Some)
+    }
+}
+
+/// Deserializes a CSV quote character, defaulting to `"` for plans
+/// serialized without one
+pub fn str_to_quote(s: &String) -> Result<u8> {
+    Ok(str_to_optional_byte(s)?.unwrap_or(b'"'))
+}
+
 #[macro_export]
 macro_rules! convert_required {
     ($PB:expr) => {{
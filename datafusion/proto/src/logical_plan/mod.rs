@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::common::{byte_to_string, proto_error, str_to_byte};
+use crate::common::{
+    byte_to_string, optional_byte_to_string, proto_error, str_to_byte,
+    str_to_optional_byte, str_to_quote,
+};
 use crate::protobuf::logical_plan_node::LogicalPlanType::CustomScan;
 use crate::protobuf::CustomTableScanNode;
 use crate::{
@@ -349,10 +352,21 @@ impl AsLogicalPlan for LogicalPlanNode {
                         FileFormatType::Csv(protobuf::CsvFormat {
                             has_header,
                             delimiter,
+                            quote,
+                            escape,
+                            comment,
+                            null_value,
                         }) => Arc::new(
                             CsvFormat::default()
                                 .with_has_header(*has_header)
-                                .with_delimiter(str_to_byte(delimiter)?),
+                                .with_delimiter(str_to_byte(delimiter)?)
+                                .with_quote(str_to_quote(quote)?)
+                                .with_escape(str_to_optional_byte(escape)?)
+                                .with_comment(str_to_optional_byte(comment)?)
+                                .with_null_value(
+                                    Some(null_value.clone())
+                                        .filter(|value| !value.is_empty()),
+                                ),
                         ),
                         FileFormatType::Avro(..) => Arc::new(AvroFormat::default()),
                     };
@@ -829,6 +843,10 @@ impl AsLogicalPlan for LogicalPlanNode {
                         FileFormatType::Csv(protobuf::CsvFormat {
                             delimiter: byte_to_string(csv.delimiter())?,
                             has_header: csv.has_header(),
+                            quote: byte_to_string(csv.quote())?,
+                            escape: optional_byte_to_string(csv.escape())?,
+                            comment: optional_byte_to_string(csv.comment())?,
+                            null_value: csv.null_value().unwrap_or_default().to_owned(),
                         })
                     } else if any.is::<AvroFormat>() {
                         FileFormatType::Avro(protobuf::AvroFormat {})
@@ -1366,9 +1384,15 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::Dml(_) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for Dml",
             )),
+            LogicalPlan::Copy(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for Copy",
+            )),
             LogicalPlan::DescribeTable(_) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DescribeTable",
             )),
+            LogicalPlan::RecursiveQuery(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for RecursiveQuery",
+            )),
         }
     }
 }
@@ -571,6 +571,11 @@ impl TryFrom<&Expr> for protobuf::LogicalExprNode {
                             "UDAF as window function in proto".to_string(),
                         ))
                     }
+                    WindowFunction::WindowUDF(_) => {
+                        return Err(Error::NotImplemented(
+                            "UDWF as window function in proto".to_string(),
+                        ))
+                    }
                 };
                 let arg_expr: Option<Box<Self>> = if !args.is_empty() {
                     let arg = &args[0];
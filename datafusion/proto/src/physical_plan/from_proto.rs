@@ -22,16 +22,20 @@ use arrow::datatypes::DataType;
 use chrono::TimeZone;
 use chrono::Utc;
 use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::ipc::reader::StreamReader;
+use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::datasource::listing::{FileRange, PartitionedFile};
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::execution::context::ExecutionProps;
 use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::window_function::WindowFunction;
+use datafusion::logical_expr::WindowFrame;
 use datafusion::physical_expr::expressions::DateTimeIntervalExpr;
 use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
 use datafusion::physical_plan::expressions::GetIndexedFieldExpr;
 use datafusion::physical_plan::expressions::LikeExpr;
 use datafusion::physical_plan::file_format::FileScanConfig;
+use datafusion::physical_plan::windows::create_window_expr;
 use datafusion::physical_plan::{
     expressions::{
         BinaryExpr, CaseExpr, CastExpr, Column, InListExpr, IsNotNullExpr, IsNullExpr,
@@ -39,7 +43,8 @@ use datafusion::physical_plan::{
     },
     functions, Partitioning,
 };
-use datafusion::physical_plan::{ColumnStatistics, PhysicalExpr, Statistics};
+use datafusion::physical_plan::{ColumnStatistics, PhysicalExpr, Statistics, WindowExpr};
+use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{DataFusionError, Result};
 use object_store::path::Path;
 use object_store::ObjectMeta;
@@ -50,8 +55,9 @@ use std::sync::Arc;
 use crate::common::proto_error;
 use crate::convert_required;
 use crate::logical_plan;
+use crate::physical_plan::PhysicalExtensionCodec;
 use crate::protobuf::physical_expr_node::ExprType;
-use datafusion::physical_plan::joins::utils::JoinSide;
+use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter, JoinSide};
 use datafusion::physical_plan::sorts::sort::SortOptions;
 
 impl From<&protobuf::PhysicalColumn> for Column {
@@ -68,10 +74,12 @@ impl From<&protobuf::PhysicalColumn> for Column {
 /// * `registry` - A registry knows how to build logical expressions out of user-defined function' names
 /// * `input_schema` - The Arrow schema for the input, used for determining expression data types
 ///                    when performing type coercion.
+/// * `codec` - An extension codec used to decode user-defined functions and custom expressions
 pub fn parse_physical_expr(
     proto: &protobuf::PhysicalExprNode,
     registry: &dyn FunctionRegistry,
     input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     let expr_type = proto
         .expr_type
@@ -90,6 +98,7 @@ pub fn parse_physical_expr(
                 registry,
                 "left",
                 input_schema,
+                codec,
             )?,
             logical_plan::from_proto::from_proto_binary_op(&binary_expr.op)?,
             parse_required_physical_expr(
@@ -97,6 +106,7 @@ pub fn parse_physical_expr(
                 registry,
                 "right",
                 input_schema,
+                codec,
             )?,
         )),
         ExprType::DateTimeIntervalExpr(expr) => Arc::new(DateTimeIntervalExpr::try_new(
@@ -105,6 +115,7 @@ pub fn parse_physical_expr(
                 registry,
                 "left",
                 input_schema,
+                codec,
             )?,
             logical_plan::from_proto::from_proto_binary_op(&expr.op)?,
             parse_required_physical_expr(
@@ -112,6 +123,7 @@ pub fn parse_physical_expr(
                 registry,
                 "right",
                 input_schema,
+                codec,
             )?,
             input_schema,
         )?),
@@ -136,6 +148,7 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?))
         }
         ExprType::IsNotNullExpr(e) => {
@@ -144,6 +157,7 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?))
         }
         ExprType::NotExpr(e) => Arc::new(NotExpr::new(parse_required_physical_expr(
@@ -151,6 +165,7 @@ pub fn parse_physical_expr(
             registry,
             "expr",
             input_schema,
+            codec,
         )?)),
         ExprType::Negative(e) => {
             Arc::new(NegativeExpr::new(parse_required_physical_expr(
@@ -158,6 +173,7 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?))
         }
         ExprType::InList(e) => Arc::new(InListExpr::new(
@@ -166,10 +182,11 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?,
             e.list
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr(x, registry, input_schema, codec))
                 .collect::<Result<Vec<_>, _>>()?,
             e.negated,
             input_schema,
@@ -177,7 +194,7 @@ pub fn parse_physical_expr(
         ExprType::Case(e) => Arc::new(CaseExpr::try_new(
             e.expr
                 .as_ref()
-                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema))
+                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema, codec))
                 .transpose()?,
             e.when_then_expr
                 .iter()
@@ -188,19 +205,21 @@ pub fn parse_physical_expr(
                             registry,
                             "when_expr",
                             input_schema,
+                            codec,
                         )?,
                         parse_required_physical_expr(
                             e.then_expr.as_ref(),
                             registry,
                             "then_expr",
                             input_schema,
+                            codec,
                         )?,
                     ))
                 })
                 .collect::<Result<Vec<_>>>()?,
             e.else_expr
                 .as_ref()
-                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema))
+                .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema, codec))
                 .transpose()?,
         )?),
         ExprType::Cast(e) => Arc::new(CastExpr::new(
@@ -209,6 +228,7 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?,
             convert_required!(e.arrow_type)?,
             DEFAULT_DATAFUSION_CAST_OPTIONS,
@@ -219,6 +239,7 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?,
             convert_required!(e.arrow_type)?,
         )),
@@ -233,7 +254,7 @@ pub fn parse_physical_expr(
             let args = e
                 .args
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr(x, registry, input_schema, codec))
                 .collect::<Result<Vec<_>, _>>()?;
 
             // TODO Do not create new the ExecutionProps
@@ -252,17 +273,20 @@ pub fn parse_physical_expr(
             ))
         }
         ExprType::ScalarUdf(e) => {
-            let scalar_fun = registry.udf(e.name.as_str())?.deref().clone().fun;
+            let udf = if e.fun_definition.is_empty() {
+                registry.udf(e.name.as_str())?
+            } else {
+                codec.try_decode_udf(&e.name, &e.fun_definition)?
+            };
 
             let args = e
                 .args
                 .iter()
-                .map(|x| parse_physical_expr(x, registry, input_schema))
+                .map(|x| parse_physical_expr(x, registry, input_schema, codec))
                 .collect::<Result<Vec<_>, _>>()?;
 
-            Arc::new(ScalarFunctionExpr::new(
-                e.name.as_str(),
-                scalar_fun,
+            Arc::new(ScalarFunctionExpr::new_udf(
+                udf.deref(),
                 args,
                 &convert_required!(e.return_type)?,
             ))
@@ -275,12 +299,14 @@ pub fn parse_physical_expr(
                 registry,
                 "expr",
                 input_schema,
+                codec,
             )?,
             parse_required_physical_expr(
                 like_expr.pattern.as_deref(),
                 registry,
                 "pattern",
                 input_schema,
+                codec,
             )?,
         )),
         ExprType::GetIndexedFieldExpr(get_indexed_field_expr) => {
@@ -290,10 +316,19 @@ pub fn parse_physical_expr(
                     registry,
                     "arg",
                     input_schema,
+                    codec,
                 )?,
                 convert_required!(get_indexed_field_expr.key)?,
             ))
         }
+        ExprType::Extension(extension) => {
+            let inputs = extension
+                .inputs
+                .iter()
+                .map(|e| parse_physical_expr(e, registry, input_schema, codec))
+                .collect::<Result<Vec<_>>>()?;
+            codec.try_decode_expr(&extension.expr, &inputs)?
+        }
     };
 
     Ok(pexpr)
@@ -304,8 +339,9 @@ fn parse_required_physical_expr(
     registry: &dyn FunctionRegistry,
     field: &str,
     input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<Arc<dyn PhysicalExpr>> {
-    expr.map(|e| parse_physical_expr(e, registry, input_schema))
+    expr.map(|e| parse_physical_expr(e, registry, input_schema, codec))
         .transpose()?
         .ok_or_else(|| {
             DataFusionError::Internal(format!("Missing required field {field:?}"))
@@ -342,17 +378,131 @@ impl TryFrom<&protobuf::physical_window_expr_node::WindowFunction> for WindowFun
     }
 }
 
+/// Parses a physical sort expression from a protobuf.
+pub fn parse_physical_sort_expr(
+    proto: &protobuf::PhysicalSortExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<PhysicalSortExpr> {
+    let expr = proto
+        .expr
+        .as_ref()
+        .ok_or_else(|| proto_error("Unexpected empty physical sort expression"))?;
+    Ok(PhysicalSortExpr {
+        expr: parse_physical_expr(expr.as_ref(), registry, input_schema, codec)?,
+        options: SortOptions {
+            descending: !proto.asc,
+            nulls_first: proto.nulls_first,
+        },
+    })
+}
+
+/// Parses a physical window expression from a protobuf.
+///
+/// `name` is the name of the output field of the window expression
+pub fn parse_physical_window_expr(
+    proto: &protobuf::PhysicalWindowExprNode,
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+    name: String,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<Arc<dyn WindowExpr>> {
+    // Older plans carry a single argument in `expr`
+    let args = if proto.args.is_empty() {
+        proto
+            .expr
+            .iter()
+            .map(|e| parse_physical_expr(e.as_ref(), registry, input_schema, codec))
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        proto
+            .args
+            .iter()
+            .map(|e| parse_physical_expr(e, registry, input_schema, codec))
+            .collect::<Result<Vec<_>>>()?
+    };
+    let partition_by = proto
+        .partition_by
+        .iter()
+        .map(|e| parse_physical_expr(e, registry, input_schema, codec))
+        .collect::<Result<Vec<_>>>()?;
+    let order_by = proto
+        .order_by
+        .iter()
+        .map(|e| parse_physical_sort_expr(e, registry, input_schema, codec))
+        .collect::<Result<Vec<_>>>()?;
+    let window_frame = match proto.window_frame.as_ref() {
+        Some(window_frame) => WindowFrame::try_from(window_frame.clone())?,
+        None => WindowFrame::new(!order_by.is_empty()),
+    };
+
+    create_window_expr(
+        &convert_required!(proto.window_function)?,
+        name,
+        &args,
+        &partition_by,
+        &order_by,
+        Arc::new(window_frame),
+        input_schema,
+    )
+}
+
+/// Parses a join filter from a protobuf.
+pub fn parse_join_filter(
+    proto: &protobuf::JoinFilter,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<JoinFilter> {
+    let schema: Schema = convert_required!(proto.schema)?;
+    let expression = parse_physical_expr(
+        proto
+            .expression
+            .as_ref()
+            .ok_or_else(|| proto_error("Unexpected empty filter expression"))?,
+        registry,
+        &schema,
+        codec,
+    )?;
+    let column_indices = proto
+        .column_indices
+        .iter()
+        .map(|i| {
+            let side = protobuf::JoinSide::from_i32(i.side).ok_or_else(|| {
+                proto_error(format!(
+                    "Received a JoinFilter message with unknown JoinSide {}",
+                    i.side
+                ))
+            })?;
+            Ok(ColumnIndex {
+                index: i.index as usize,
+                side: side.into(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(JoinFilter::new(expression, column_indices, schema))
+}
+
+/// Decodes record batches from an Arrow IPC stream.
+pub fn parse_record_batches(buf: &[u8]) -> Result<Vec<RecordBatch>> {
+    let reader = StreamReader::try_new(buf, None)?;
+    let batches = reader.collect::<Result<Vec<_>, _>>()?;
+    Ok(batches)
+}
+
 pub fn parse_protobuf_hash_partitioning(
     partitioning: Option<&protobuf::PhysicalHashRepartition>,
     registry: &dyn FunctionRegistry,
     input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<Option<Partitioning>> {
     match partitioning {
         Some(hash_part) => {
             let expr = hash_part
                 .hash_expr
                 .iter()
-                .map(|e| parse_physical_expr(e, registry, input_schema))
+                .map(|e| parse_physical_expr(e, registry, input_schema, codec))
                 .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
 
             Ok(Some(Partitioning::Hash(
@@ -367,6 +517,7 @@ pub fn parse_protobuf_hash_partitioning(
 pub fn parse_protobuf_file_scan_config(
     proto: &protobuf::FileScanExecConf,
     registry: &dyn FunctionRegistry,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<FileScanConfig> {
     let schema: Arc<Schema> = Arc::new(convert_required!(proto.schema)?);
     let projection = proto
@@ -411,7 +562,7 @@ pub fn parse_protobuf_file_scan_config(
             let expr = o
                 .expr
                 .as_ref()
-                .map(|e| parse_physical_expr(e.as_ref(), registry, &schema))
+                .map(|e| parse_physical_expr(e.as_ref(), registry, &schema, codec))
                 .unwrap()?;
             Ok(PhysicalSortExpr {
                 expr,
@@ -504,6 +655,18 @@ impl From<protobuf::JoinSide> for JoinSide {
     }
 }
 
+impl From<protobuf::CompressionTypeVariant> for CompressionTypeVariant {
+    fn from(value: protobuf::CompressionTypeVariant) -> Self {
+        match value {
+            protobuf::CompressionTypeVariant::Gzip => Self::GZIP,
+            protobuf::CompressionTypeVariant::Bzip2 => Self::BZIP2,
+            protobuf::CompressionTypeVariant::Xz => Self::XZ,
+            protobuf::CompressionTypeVariant::Zstd => Self::ZSTD,
+            protobuf::CompressionTypeVariant::Uncompressed => Self::UNCOMPRESSED,
+        }
+    }
+}
+
 impl TryFrom<&protobuf::Statistics> for Statistics {
     type Error = DataFusionError;
 
@@ -20,11 +20,11 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use datafusion::arrow::compute::SortOptions;
-use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
 use datafusion::datasource::file_format::file_type::FileCompressionType;
 use datafusion::execution::runtime_env::RuntimeEnv;
 use datafusion::execution::FunctionRegistry;
-use datafusion::logical_expr::WindowFrame;
+use datafusion::logical_expr::{AggregateUDF, ScalarUDF};
 use datafusion::physical_plan::aggregates::{create_aggregate_expr, AggregateMode};
 use datafusion::physical_plan::aggregates::{AggregateExec, PhysicalGroupBy};
 use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
@@ -32,30 +32,49 @@ use datafusion::physical_plan::coalesce_partitions::CoalescePartitionsExec;
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::explain::ExplainExec;
 use datafusion::physical_plan::expressions::{Column, PhysicalSortExpr};
-use datafusion::physical_plan::file_format::{AvroExec, CsvExec, ParquetExec};
+use datafusion::physical_plan::file_format::{
+    AvroExec, CsvExec, NdJsonExec, ParquetExec,
+};
 use datafusion::physical_plan::filter::FilterExec;
-use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter};
-use datafusion::physical_plan::joins::CrossJoinExec;
+use datafusion::physical_plan::insert::{DataSink, InsertExec};
+use datafusion::physical_plan::joins::{
+    CrossJoinExec, NestedLoopJoinExec, SortMergeJoinExec, SymmetricHashJoinExec,
+};
 use datafusion::physical_plan::joins::{HashJoinExec, PartitionMode};
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use datafusion::physical_plan::memory::MemoryExec;
 use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sorts::sort::SortExec;
 use datafusion::physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
+use datafusion::physical_plan::udaf;
 use datafusion::physical_plan::union::UnionExec;
-use datafusion::physical_plan::windows::{create_window_expr, WindowAggExec};
+use datafusion::physical_plan::unnest::UnnestExec;
+use datafusion::physical_plan::values::ValuesExec;
+use datafusion::physical_plan::windows::{BoundedWindowAggExec, WindowAggExec};
 use datafusion::physical_plan::{
     AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr, WindowExpr,
 };
+use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{DataFusionError, Result};
 use prost::bytes::BufMut;
 use prost::Message;
 
 use crate::common::proto_error;
-use crate::common::{csv_delimiter_to_string, str_to_byte};
+use crate::common::{
+    byte_to_string, csv_delimiter_to_string, optional_byte_to_string, str_to_byte,
+    str_to_optional_byte, str_to_quote,
+};
 use crate::physical_plan::from_proto::{
-    parse_physical_expr, parse_protobuf_file_scan_config,
+    parse_join_filter, parse_physical_expr, parse_physical_sort_expr,
+    parse_physical_window_expr, parse_protobuf_file_scan_config, parse_record_batches,
+};
+use crate::physical_plan::to_proto::{
+    serialize_file_scan_config, serialize_join_filter, serialize_physical_aggr_expr,
+    serialize_physical_expr, serialize_physical_sort_expr,
+    serialize_physical_window_expr, serialize_record_batches,
 };
+use crate::protobuf::physical_aggregate_expr_node::AggregateFunction;
 use crate::protobuf::physical_expr_node::ExprType;
 use crate::protobuf::physical_plan_node::PhysicalPlanType;
 use crate::protobuf::repartition_exec_node::PartitionMethod;
@@ -120,7 +139,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .zip(projection.expr_name.iter())
                     .map(|(expr, name)| {
                         Ok((
-                            parse_physical_expr(expr, registry, input.schema().as_ref())?,
+                            parse_physical_expr(
+                                expr,
+                                registry,
+                                input.schema().as_ref(),
+                                extension_codec,
+                            )?,
                             name.to_string(),
                         ))
                     })
@@ -138,7 +162,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .expr
                     .as_ref()
                     .map(|expr| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
+                        parse_physical_expr(
+                            expr,
+                            registry,
+                            input.schema().as_ref(),
+                            extension_codec,
+                        )
                     })
                     .transpose()?
                     .ok_or_else(|| {
@@ -149,19 +178,29 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     })?;
                 Ok(Arc::new(FilterExec::try_new(predicate, input)?))
             }
-            PhysicalPlanType::CsvScan(scan) => Ok(Arc::new(CsvExec::new(
-                parse_protobuf_file_scan_config(
-                    scan.base_conf.as_ref().unwrap(),
-                    registry,
-                )?,
-                scan.has_header,
-                str_to_byte(&scan.delimiter)?,
-                FileCompressionType::UNCOMPRESSED,
-            ))),
+            PhysicalPlanType::CsvScan(scan) => Ok(Arc::new(
+                CsvExec::new(
+                    parse_protobuf_file_scan_config(
+                        scan.base_conf.as_ref().unwrap(),
+                        registry,
+                        extension_codec,
+                    )?,
+                    scan.has_header,
+                    str_to_byte(&scan.delimiter)?,
+                    FileCompressionType::UNCOMPRESSED,
+                )
+                .with_quote(str_to_quote(&scan.quote)?)
+                .with_escape(str_to_optional_byte(&scan.escape)?)
+                .with_comment(str_to_optional_byte(&scan.comment)?)
+                .with_null_value(
+                    Some(scan.null_value.clone()).filter(|value| !value.is_empty()),
+                ),
+            )),
             PhysicalPlanType::ParquetScan(scan) => {
                 let base_config = parse_protobuf_file_scan_config(
                     scan.base_conf.as_ref().unwrap(),
                     registry,
+                    extension_codec,
                 )?;
                 let predicate = scan
                     .predicate
@@ -171,6 +210,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             expr,
                             registry,
                             base_config.file_schema.as_ref(),
+                            extension_codec,
                         )
                     })
                     .transpose()?;
@@ -180,6 +220,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 Ok(Arc::new(AvroExec::new(parse_protobuf_file_scan_config(
                     scan.base_conf.as_ref().unwrap(),
                     registry,
+                    extension_codec,
                 )?)))
             }
             PhysicalPlanType::CoalesceBatches(coalesce_batches) => {
@@ -212,7 +253,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                             .hash_expr
                             .iter()
                             .map(|e| {
-                                parse_physical_expr(e, registry, input.schema().as_ref())
+                                parse_physical_expr(
+                                    e,
+                                    registry,
+                                    input.schema().as_ref(),
+                                    extension_codec,
+                                )
                             })
                             .collect::<Result<Vec<Arc<dyn PhysicalExpr>>, _>>()?;
 
@@ -283,58 +329,91 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 let physical_schema: SchemaRef =
                     SchemaRef::new((&input_schema).try_into()?);
 
-                let physical_window_expr: Vec<Arc<dyn WindowExpr>> = window_agg
-                    .window_expr
+                let physical_window_expr = parse_physical_window_exprs(
+                    &window_agg.window_expr,
+                    &window_agg.window_expr_name,
+                    registry,
+                    &physical_schema,
+                    extension_codec,
+                )?;
+                let partition_keys = window_agg
+                    .partition_keys
                     .iter()
-                    .zip(window_agg.window_expr_name.iter())
-                    .map(|(expr, name)| {
-                        let expr_type = expr.expr_type.as_ref().ok_or_else(|| {
-                            proto_error("Unexpected empty window physical expression")
-                        })?;
-
-                        match expr_type {
-                            ExprType::WindowExpr(window_node) => {
-                                let window_node_expr = window_node
-                                    .expr
-                                    .as_ref()
-                                    .map(|e| {
-                                        parse_physical_expr(
-                                            e.as_ref(),
-                                            registry,
-                                            &physical_schema,
-                                        )
-                                    })
-                                    .transpose()?
-                                    .ok_or_else(|| {
-                                        proto_error(
-                                            "missing window_node expr expression"
-                                                .to_string(),
-                                        )
-                                    })?;
-
-                                Ok(create_window_expr(
-                                    &convert_required!(window_node.window_function)?,
-                                    name.to_owned(),
-                                    &[window_node_expr],
-                                    &[],
-                                    &[],
-                                    Arc::new(WindowFrame::new(false)),
-                                    &physical_schema,
-                                )?)
-                            }
-                            _ => Err(DataFusionError::Internal(
-                                "Invalid expression for WindowAggrExec".to_string(),
-                            )),
-                        }
+                    .map(|expr| {
+                        parse_physical_expr(
+                            expr,
+                            registry,
+                            &physical_schema,
+                            extension_codec,
+                        )
                     })
-                    .collect::<Result<Vec<_>, _>>()?;
-                //todo fill partition keys and sort keys
+                    .collect::<Result<Vec<_>>>()?;
+                let sort_keys = window_agg
+                    .sort_keys
+                    .iter()
+                    .map(|expr| {
+                        parse_physical_sort_expr(
+                            expr,
+                            registry,
+                            &physical_schema,
+                            extension_codec,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
                 Ok(Arc::new(WindowAggExec::try_new(
                     physical_window_expr,
                     input,
-                    Arc::new((&input_schema).try_into()?),
-                    vec![],
-                    None,
+                    physical_schema,
+                    partition_keys,
+                    (!sort_keys.is_empty()).then_some(sort_keys),
+                )?))
+            }
+            PhysicalPlanType::BoundedWindow(window_agg) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    window_agg.input,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let physical_schema: SchemaRef =
+                    Arc::new(convert_required!(window_agg.input_schema)?);
+                let physical_window_expr = parse_physical_window_exprs(
+                    &window_agg.window_expr,
+                    &window_agg.window_expr_name,
+                    registry,
+                    &physical_schema,
+                    extension_codec,
+                )?;
+                let partition_keys = window_agg
+                    .partition_keys
+                    .iter()
+                    .map(|expr| {
+                        parse_physical_expr(
+                            expr,
+                            registry,
+                            &physical_schema,
+                            extension_codec,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let sort_keys = window_agg
+                    .sort_keys
+                    .iter()
+                    .map(|expr| {
+                        parse_physical_sort_expr(
+                            expr,
+                            registry,
+                            &physical_schema,
+                            extension_codec,
+                        )
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Arc::new(BoundedWindowAggExec::try_new(
+                    physical_window_expr,
+                    input,
+                    physical_schema,
+                    partition_keys,
+                    (!sort_keys.is_empty()).then_some(sort_keys),
                 )?))
             }
             PhysicalPlanType::Aggregate(hash_agg) => {
@@ -367,8 +446,13 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .iter()
                     .zip(hash_agg.group_expr_name.iter())
                     .map(|(expr, name)| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
-                            .map(|expr| (expr, name.to_string()))
+                        parse_physical_expr(
+                            expr,
+                            registry,
+                            input.schema().as_ref(),
+                            extension_codec,
+                        )
+                        .map(|expr| (expr, name.to_string()))
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
@@ -377,8 +461,13 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .iter()
                     .zip(hash_agg.group_expr_name.iter())
                     .map(|(expr, name)| {
-                        parse_physical_expr(expr, registry, input.schema().as_ref())
-                            .map(|expr| (expr, name.to_string()))
+                        parse_physical_expr(
+                            expr,
+                            registry,
+                            input.schema().as_ref(),
+                            extension_codec,
+                        )
+                        .map(|expr| (expr, name.to_string()))
                     })
                     .collect::<Result<Vec<_>, _>>()?;
 
@@ -415,29 +504,63 @@ impl AsExecutionPlan for PhysicalPlanNode {
 
                         match expr_type {
                             ExprType::AggregateExpr(agg_node) => {
-                                let aggr_function =
-                                    protobuf::AggregateFunction::from_i32(
-                                        agg_node.aggr_function,
-                                    )
-                                        .ok_or_else(
-                                            || {
-                                                proto_error(format!(
-                                                    "Received an unknown aggregate function: {}",
-                                                    agg_node.aggr_function
-                                                ))
-                                            },
-                                        )?;
-
-                                let input_phy_expr: Vec<Arc<dyn PhysicalExpr>> = agg_node.expr.iter()
-                                    .map(|e| parse_physical_expr(e, registry, &physical_schema).unwrap()).collect();
-
-                                Ok(create_aggregate_expr(
-                                    &aggr_function.into(),
-                                    agg_node.distinct,
-                                    input_phy_expr.as_slice(),
-                                    &physical_schema,
-                                    name.to_string(),
-                                )?)
+                                let input_phy_expr: Vec<Arc<dyn PhysicalExpr>> = agg_node
+                                    .expr
+                                    .iter()
+                                    .map(|e| {
+                                        parse_physical_expr(
+                                            e,
+                                            registry,
+                                            &physical_schema,
+                                            extension_codec,
+                                        )
+                                    })
+                                    .collect::<Result<Vec<_>>>()?;
+
+                                match &agg_node.aggregate_function {
+                                    Some(AggregateFunction::AggrFunction(i)) => {
+                                        let aggr_function =
+                                            protobuf::AggregateFunction::from_i32(*i)
+                                                .ok_or_else(|| {
+                                                    proto_error(format!(
+                                                        "Received an unknown aggregate function: {i}"
+                                                    ))
+                                                })?;
+
+                                        create_aggregate_expr(
+                                            &aggr_function.into(),
+                                            agg_node.distinct,
+                                            input_phy_expr.as_slice(),
+                                            &physical_schema,
+                                            name.to_string(),
+                                        )
+                                    }
+                                    Some(AggregateFunction::UserDefinedAggrFunction(
+                                        udaf_name,
+                                    )) => {
+                                        let agg_udf = if agg_node
+                                            .fun_definition
+                                            .is_empty()
+                                        {
+                                            registry.udaf(udaf_name)?
+                                        } else {
+                                            extension_codec.try_decode_udaf(
+                                                udaf_name,
+                                                &agg_node.fun_definition,
+                                            )?
+                                        };
+
+                                        udaf::create_aggregate_expr(
+                                            agg_udf.as_ref(),
+                                            input_phy_expr.as_slice(),
+                                            &physical_schema,
+                                            name,
+                                        )
+                                    }
+                                    None => Err(proto_error(
+                                        "Missing aggregate function in aggregate expression",
+                                    )),
+                                }
                             }
                             _ => Err(DataFusionError::Internal(
                                 "Invalid aggregate expression for AggregateExec"
@@ -487,38 +610,8 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 let filter = hashjoin
                     .filter
                     .as_ref()
-                    .map(|f| {
-                        let schema = f
-                            .schema
-                            .as_ref()
-                            .ok_or_else(|| proto_error("Missing JoinFilter schema"))?
-                            .try_into()?;
-
-                        let expression = parse_physical_expr(
-                            f.expression.as_ref().ok_or_else(|| {
-                                proto_error("Unexpected empty filter expression")
-                            })?,
-                            registry, &schema
-                        )?;
-                        let column_indices = f.column_indices
-                            .iter()
-                            .map(|i| {
-                                let side = protobuf::JoinSide::from_i32(i.side)
-                                    .ok_or_else(|| proto_error(format!(
-                                        "Received a HashJoinNode message with JoinSide in Filter {}",
-                                        i.side))
-                                    )?;
-
-                                Ok(ColumnIndex{
-                                    index: i.index as usize,
-                                    side: side.into(),
-                                })
-                            })
-                            .collect::<Result<Vec<_>>>()?;
-
-                        Ok(JoinFilter::new(expression, column_indices, schema))
-                    })
-                    .map_or(Ok(None), |v: Result<JoinFilter>| v.map(Some))?;
+                    .map(|f| parse_join_filter(f, registry, extension_codec))
+                    .transpose()?;
 
                 let partition_mode =
                     protobuf::PartitionMode::from_i32(hashjoin.partition_mode)
@@ -596,7 +689,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                 })?
                                 .as_ref();
                             Ok(PhysicalSortExpr {
-                                expr: parse_physical_expr(expr,registry, input.schema().as_ref())?,
+                                expr: parse_physical_expr(
+                                    expr,
+                                    registry,
+                                    input.schema().as_ref(),
+                                    extension_codec,
+                                )?,
                                 options: SortOptions {
                                     descending: !sort_expr.asc,
                                     nulls_first: sort_expr.nulls_first,
@@ -644,7 +742,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                                 })?
                                 .as_ref();
                             Ok(PhysicalSortExpr {
-                                expr: parse_physical_expr(expr,registry, input.schema().as_ref())?,
+                                expr: parse_physical_expr(
+                                    expr,
+                                    registry,
+                                    input.schema().as_ref(),
+                                    extension_codec,
+                                )?,
                                 options: SortOptions {
                                     descending: !sort_expr.asc,
                                     nulls_first: sort_expr.nulls_first,
@@ -659,6 +762,193 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(Arc::new(SortPreservingMergeExec::new(exprs, input)))
             }
+            PhysicalPlanType::JsonScan(scan) => {
+                let compression_type =
+                    protobuf::CompressionTypeVariant::from_i32(scan.file_compression_type)
+                        .ok_or_else(|| {
+                            proto_error(format!(
+                                "Received a JsonScanExecNode message with unknown CompressionTypeVariant {}",
+                                scan.file_compression_type
+                            ))
+                        })?;
+                Ok(Arc::new(NdJsonExec::new(
+                    parse_protobuf_file_scan_config(
+                        scan.base_conf.as_ref().unwrap(),
+                        registry,
+                        extension_codec,
+                    )?,
+                    CompressionTypeVariant::from(compression_type).into(),
+                )))
+            }
+            PhysicalPlanType::SortMergeJoin(sort_join) => {
+                let left: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sort_join.left,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let right: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sort_join.right,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let on: Vec<(Column, Column)> = sort_join
+                    .on
+                    .iter()
+                    .map(|col| {
+                        let left = into_required!(col.left)?;
+                        let right = into_required!(col.right)?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<_>>()?;
+                let join_type = protobuf::JoinType::from_i32(sort_join.join_type)
+                    .ok_or_else(|| {
+                        proto_error(format!(
+                            "Received a SortMergeJoinExecNode message with unknown JoinType {}",
+                            sort_join.join_type
+                        ))
+                    })?;
+                let sort_options = sort_join
+                    .sort_options
+                    .iter()
+                    .map(|options| SortOptions {
+                        descending: !options.asc,
+                        nulls_first: options.nulls_first,
+                    })
+                    .collect();
+                Ok(Arc::new(SortMergeJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    join_type.into(),
+                    sort_options,
+                    sort_join.null_equals_null,
+                )?))
+            }
+            PhysicalPlanType::NestedLoopJoin(join) => {
+                let left: Arc<dyn ExecutionPlan> =
+                    into_physical_plan!(join.left, registry, runtime, extension_codec)?;
+                let right: Arc<dyn ExecutionPlan> =
+                    into_physical_plan!(join.right, registry, runtime, extension_codec)?;
+                let join_type =
+                    protobuf::JoinType::from_i32(join.join_type).ok_or_else(|| {
+                        proto_error(format!(
+                            "Received a NestedLoopJoinExecNode message with unknown JoinType {}",
+                            join.join_type
+                        ))
+                    })?;
+                let filter = join
+                    .filter
+                    .as_ref()
+                    .map(|f| parse_join_filter(f, registry, extension_codec))
+                    .transpose()?;
+                Ok(Arc::new(NestedLoopJoinExec::try_new(
+                    left,
+                    right,
+                    filter,
+                    &join_type.into(),
+                )?))
+            }
+            PhysicalPlanType::SymmetricHashJoin(sym_join) => {
+                let left: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sym_join.left,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let right: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    sym_join.right,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let on: Vec<(Column, Column)> = sym_join
+                    .on
+                    .iter()
+                    .map(|col| {
+                        let left = into_required!(col.left)?;
+                        let right = into_required!(col.right)?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<_>>()?;
+                let join_type = protobuf::JoinType::from_i32(sym_join.join_type)
+                    .ok_or_else(|| {
+                        proto_error(format!(
+                            "Received a SymmetricHashJoinExecNode message with unknown JoinType {}",
+                            sym_join.join_type
+                        ))
+                    })?;
+                let filter = sym_join.filter.as_ref().ok_or_else(|| {
+                    proto_error("Missing JoinFilter in SymmetricHashJoinExecNode")
+                })?;
+                Ok(Arc::new(SymmetricHashJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    parse_join_filter(filter, registry, extension_codec)?,
+                    &join_type.into(),
+                    sym_join.null_equals_null,
+                )?))
+            }
+            PhysicalPlanType::Unnest(unnest) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    unnest.input,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                Ok(Arc::new(UnnestExec::new(
+                    input,
+                    into_required!(unnest.column)?,
+                    Arc::new(convert_required!(unnest.schema)?),
+                )))
+            }
+            PhysicalPlanType::Values(values) => {
+                let schema = Arc::new(convert_required!(values.schema)?);
+                let batches = parse_record_batches(&values.data)?;
+                Ok(Arc::new(ValuesExec::try_new_from_batches(schema, batches)?))
+            }
+            PhysicalPlanType::Memory(memory) => {
+                let schema: SchemaRef = Arc::new(convert_required!(memory.schema)?);
+                let partitions = memory
+                    .partitions
+                    .iter()
+                    .map(|partition| parse_record_batches(partition))
+                    .collect::<Result<Vec<_>>>()?;
+                let projection = memory.projection.as_ref().map(|projection| {
+                    projection.indices.iter().map(|i| *i as usize).collect()
+                });
+                let exec = MemoryExec::try_new(&partitions, schema, projection)?;
+                if memory.sort_information.is_empty() {
+                    Ok(Arc::new(exec))
+                } else {
+                    // The sort information refers to the projected schema
+                    let sort_information = memory
+                        .sort_information
+                        .iter()
+                        .map(|expr| {
+                            parse_physical_sort_expr(
+                                expr,
+                                registry,
+                                &exec.schema(),
+                                extension_codec,
+                            )
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(Arc::new(exec.with_sort_information(sort_information)))
+                }
+            }
+            PhysicalPlanType::Insert(insert) => {
+                let input: Arc<dyn ExecutionPlan> = into_physical_plan!(
+                    insert.input,
+                    registry,
+                    runtime,
+                    extension_codec
+                )?;
+                let sink = extension_codec.try_decode_sink(&insert.sink)?;
+                Ok(Arc::new(InsertExec::new(input, sink)))
+            }
             PhysicalPlanType::Extension(extension) => {
                 let inputs: Vec<Arc<dyn ExecutionPlan>> = extension
                     .inputs
@@ -709,7 +999,7 @@ impl AsExecutionPlan for PhysicalPlanNode {
             let expr = exec
                 .expr()
                 .iter()
-                .map(|expr| expr.0.clone().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.clone(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
             let expr_name = exec.expr().iter().map(|expr| expr.1.clone()).collect();
             Ok(protobuf::PhysicalPlanNode {
@@ -730,7 +1020,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::Filter(Box::new(
                     protobuf::FilterExecNode {
                         input: Some(Box::new(input)),
-                        expr: Some(exec.predicate().clone().try_into()?),
+                        expr: Some(serialize_physical_expr(
+                            exec.predicate().clone(),
+                            extension_codec,
+                        )?),
                     },
                 ))),
             })
@@ -774,45 +1067,12 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 exec.right().to_owned(),
                 extension_codec,
             )?;
-            let on: Vec<protobuf::JoinOn> = exec
-                .on()
-                .iter()
-                .map(|tuple| protobuf::JoinOn {
-                    left: Some(protobuf::PhysicalColumn {
-                        name: tuple.0.name().to_string(),
-                        index: tuple.0.index() as u32,
-                    }),
-                    right: Some(protobuf::PhysicalColumn {
-                        name: tuple.1.name().to_string(),
-                        index: tuple.1.index() as u32,
-                    }),
-                })
-                .collect();
+            let on = join_on_to_proto(exec.on());
             let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
             let filter = exec
                 .filter()
-                .as_ref()
-                .map(|f| {
-                    let expression = f.expression().to_owned().try_into()?;
-                    let column_indices = f
-                        .column_indices()
-                        .iter()
-                        .map(|i| {
-                            let side: protobuf::JoinSide = i.side.to_owned().into();
-                            protobuf::ColumnIndex {
-                                index: i.index as u32,
-                                side: side.into(),
-                            }
-                        })
-                        .collect();
-                    let schema = f.schema().try_into()?;
-                    Ok(protobuf::JoinFilter {
-                        expression: Some(expression),
-                        column_indices,
-                        schema: Some(schema),
-                    })
-                })
-                .map_or(Ok(None), |v: Result<protobuf::JoinFilter>| v.map(Some))?;
+                .map(|f| serialize_join_filter(f, extension_codec))
+                .transpose()?;
 
             let partition_mode = match exec.partition_mode() {
                 PartitionMode::CollectLeft => protobuf::PartitionMode::CollectLeft,
@@ -869,7 +1129,9 @@ impl AsExecutionPlan for PhysicalPlanNode {
             let agg = exec
                 .aggr_expr()
                 .iter()
-                .map(|expr| expr.to_owned().try_into())
+                .map(|expr| {
+                    serialize_physical_aggr_expr(expr.to_owned(), extension_codec)
+                })
                 .collect::<Result<Vec<_>>>()?;
             let agg_names = exec
                 .aggr_expr()
@@ -897,14 +1159,14 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .group_expr()
                 .null_expr()
                 .iter()
-                .map(|expr| expr.0.to_owned().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.to_owned(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
 
             let group_expr = exec
                 .group_expr()
                 .expr()
                 .iter()
-                .map(|expr| expr.0.to_owned().try_into())
+                .map(|expr| serialize_physical_expr(expr.0.to_owned(), extension_codec))
                 .collect::<Result<Vec<_>>>()?;
 
             Ok(protobuf::PhysicalPlanNode {
@@ -950,21 +1212,31 @@ impl AsExecutionPlan for PhysicalPlanNode {
             Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::CsvScan(
                     protobuf::CsvScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                         has_header: exec.has_header(),
                         delimiter: csv_delimiter_to_string(exec.delimiter())?,
+                        quote: byte_to_string(exec.quote())?,
+                        escape: optional_byte_to_string(exec.escape())?,
+                        comment: optional_byte_to_string(exec.comment())?,
+                        null_value: exec.null_value().unwrap_or_default().to_owned(),
                     },
                 )),
             })
         } else if let Some(exec) = plan.downcast_ref::<ParquetExec>() {
             let predicate = exec
                 .predicate()
-                .map(|pred| pred.clone().try_into())
+                .map(|pred| serialize_physical_expr(pred.clone(), extension_codec))
                 .transpose()?;
             Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::ParquetScan(
                     protobuf::ParquetScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                         predicate,
                     },
                 )),
@@ -973,7 +1245,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
             Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::AvroScan(
                     protobuf::AvroScanExecNode {
-                        base_conf: Some(exec.base_config().try_into()?),
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
                     },
                 )),
             })
@@ -1000,7 +1275,9 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     PartitionMethod::Hash(protobuf::PhysicalHashRepartition {
                         hash_expr: exprs
                             .iter()
-                            .map(|expr| expr.clone().try_into())
+                            .map(|expr| {
+                                serialize_physical_expr(expr.clone(), extension_codec)
+                            })
                             .collect::<Result<Vec<_>>>()?,
                         partition_count: *partition_count as u64,
                     })
@@ -1031,7 +1308,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .iter()
                 .map(|expr| {
                     let sort_expr = Box::new(protobuf::PhysicalSortExprNode {
-                        expr: Some(Box::new(expr.expr.to_owned().try_into()?)),
+                        expr: Some(Box::new(serialize_physical_expr(
+                            expr.expr.to_owned(),
+                            extension_codec,
+                        )?)),
                         asc: !expr.options.descending,
                         nulls_first: expr.options.nulls_first,
                     });
@@ -1078,7 +1358,10 @@ impl AsExecutionPlan for PhysicalPlanNode {
                 .iter()
                 .map(|expr| {
                     let sort_expr = Box::new(protobuf::PhysicalSortExprNode {
-                        expr: Some(Box::new(expr.expr.to_owned().try_into()?)),
+                        expr: Some(Box::new(serialize_physical_expr(
+                            expr.expr.to_owned(),
+                            extension_codec,
+                        )?)),
                         asc: !expr.options.descending,
                         nulls_first: expr.options.nulls_first,
                     });
@@ -1097,42 +1380,327 @@ impl AsExecutionPlan for PhysicalPlanNode {
                     }),
                 )),
             })
-        } else {
-            let mut buf: Vec<u8> = vec![];
-            match extension_codec.try_encode(plan_clone.clone(), &mut buf) {
-                Ok(_) => {
-                    let inputs: Vec<protobuf::PhysicalPlanNode> = plan_clone
-                        .children()
-                        .into_iter()
-                        .map(|i| {
-                            protobuf::PhysicalPlanNode::try_from_physical_plan(
-                                i,
-                                extension_codec,
-                            )
-                        })
-                        .collect::<Result<_>>()?;
-
-                    Ok(protobuf::PhysicalPlanNode {
-                        physical_plan_type: Some(PhysicalPlanType::Extension(
-                            protobuf::PhysicalExtensionNode { node: buf, inputs },
-                        )),
-                    })
-                }
-                Err(e) => Err(DataFusionError::Internal(format!(
-                    "Unsupported plan and extension codec failed with [{e}]. Plan: {plan_clone:?}"
-                ))),
-            }
-        }
-    }
-}
-
-pub trait AsExecutionPlan: Debug + Send + Sync + Clone {
-    fn try_decode(buf: &[u8]) -> Result<Self>
-    where
-        Self: Sized;
-
-    fn try_encode<B>(&self, buf: &mut B) -> Result<()>
-    where
+        } else if let Some(exec) = plan.downcast_ref::<NdJsonExec>() {
+            let file_compression_type: protobuf::CompressionTypeVariant =
+                exec.file_compression_type().get_variant().into();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::JsonScan(
+                    protobuf::JsonScanExecNode {
+                        base_conf: Some(serialize_file_scan_config(
+                            exec.base_config(),
+                            extension_codec,
+                        )?),
+                        file_compression_type: file_compression_type.into(),
+                    },
+                )),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<SortMergeJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().into();
+            let sort_options = exec
+                .sort_options()
+                .iter()
+                .map(|options| protobuf::PhysicalSortOptions {
+                    asc: !options.descending,
+                    nulls_first: options.nulls_first,
+                })
+                .collect();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SortMergeJoin(Box::new(
+                    protobuf::SortMergeJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on: join_on_to_proto(exec.on()),
+                        join_type: join_type.into(),
+                        sort_options,
+                        null_equals_null: exec.null_equals_null(),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<NestedLoopJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
+            let filter = exec
+                .filter()
+                .map(|f| serialize_join_filter(f, extension_codec))
+                .transpose()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::NestedLoopJoin(Box::new(
+                    protobuf::NestedLoopJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        join_type: join_type.into(),
+                        filter,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<SymmetricHashJoinExec>() {
+            let left = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.left().to_owned(),
+                extension_codec,
+            )?;
+            let right = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.right().to_owned(),
+                extension_codec,
+            )?;
+            let join_type: protobuf::JoinType = exec.join_type().to_owned().into();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::SymmetricHashJoin(Box::new(
+                    protobuf::SymmetricHashJoinExecNode {
+                        left: Some(Box::new(left)),
+                        right: Some(Box::new(right)),
+                        on: join_on_to_proto(exec.on()),
+                        join_type: join_type.into(),
+                        null_equals_null: exec.null_equals_null(),
+                        filter: Some(serialize_join_filter(
+                            exec.filter(),
+                            extension_codec,
+                        )?),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<UnnestExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Unnest(Box::new(
+                    protobuf::UnnestExecNode {
+                        input: Some(Box::new(input)),
+                        column: Some(protobuf::PhysicalColumn {
+                            name: exec.column().name().to_string(),
+                            index: exec.column().index() as u32,
+                        }),
+                        schema: Some(exec.schema().as_ref().try_into()?),
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<ValuesExec>() {
+            let schema = exec.schema();
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Values(
+                    protobuf::ValuesExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        data: serialize_record_batches(&schema, &exec.data())?,
+                    },
+                )),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<MemoryExec>() {
+            let schema = exec.original_schema();
+            let partitions = exec
+                .partitions()
+                .iter()
+                .map(|partition| serialize_record_batches(&schema, partition))
+                .collect::<Result<Vec<_>>>()?;
+            let sort_information = exec
+                .output_ordering()
+                .unwrap_or_default()
+                .iter()
+                .map(|expr| serialize_physical_sort_expr(expr, extension_codec))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Memory(
+                    protobuf::MemoryExecNode {
+                        schema: Some(schema.as_ref().try_into()?),
+                        partitions,
+                        projection: exec.projection().as_ref().map(|projection| {
+                            protobuf::ProjectionIndices {
+                                indices: projection.iter().map(|i| *i as u32).collect(),
+                            }
+                        }),
+                        sort_information,
+                    },
+                )),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<WindowAggExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let (window_expr, window_expr_name) =
+                window_exprs_to_proto(exec.window_expr(), extension_codec)?;
+            let partition_keys = exec
+                .partition_keys
+                .iter()
+                .map(|expr| serialize_physical_expr(expr.clone(), extension_codec))
+                .collect::<Result<Vec<_>>>()?;
+            let sort_keys = exec
+                .sort_keys
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|expr| serialize_physical_sort_expr(expr, extension_codec))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Window(Box::new(
+                    protobuf::WindowAggExecNode {
+                        input: Some(Box::new(input)),
+                        window_expr,
+                        window_expr_name,
+                        input_schema: Some(exec.input_schema().as_ref().try_into()?),
+                        partition_keys,
+                        sort_keys,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<BoundedWindowAggExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let (window_expr, window_expr_name) =
+                window_exprs_to_proto(exec.window_expr(), extension_codec)?;
+            let partition_keys = exec
+                .partition_keys
+                .iter()
+                .map(|expr| serialize_physical_expr(expr.clone(), extension_codec))
+                .collect::<Result<Vec<_>>>()?;
+            let sort_keys = exec
+                .sort_keys
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|expr| serialize_physical_sort_expr(expr, extension_codec))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::BoundedWindow(Box::new(
+                    protobuf::BoundedWindowAggExecNode {
+                        input: Some(Box::new(input)),
+                        window_expr,
+                        window_expr_name,
+                        input_schema: Some(exec.input_schema().as_ref().try_into()?),
+                        partition_keys,
+                        sort_keys,
+                    },
+                ))),
+            })
+        } else if let Some(exec) = plan.downcast_ref::<InsertExec>() {
+            let input = protobuf::PhysicalPlanNode::try_from_physical_plan(
+                exec.input().to_owned(),
+                extension_codec,
+            )?;
+            let mut sink = vec![];
+            extension_codec.try_encode_sink(exec.sink(), &mut sink)?;
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Insert(Box::new(
+                    protobuf::InsertExecNode {
+                        input: Some(Box::new(input)),
+                        sink,
+                    },
+                ))),
+            })
+        } else {
+            let mut buf: Vec<u8> = vec![];
+            match extension_codec.try_encode(plan_clone.clone(), &mut buf) {
+                Ok(_) => {
+                    let inputs: Vec<protobuf::PhysicalPlanNode> = plan_clone
+                        .children()
+                        .into_iter()
+                        .map(|i| {
+                            protobuf::PhysicalPlanNode::try_from_physical_plan(
+                                i,
+                                extension_codec,
+                            )
+                        })
+                        .collect::<Result<_>>()?;
+
+                    Ok(protobuf::PhysicalPlanNode {
+                        physical_plan_type: Some(PhysicalPlanType::Extension(
+                            protobuf::PhysicalExtensionNode { node: buf, inputs },
+                        )),
+                    })
+                }
+                Err(e) => Err(DataFusionError::Internal(format!(
+                    "Unsupported plan and extension codec failed with [{e}]. Plan: {plan_clone:?}"
+                ))),
+            }
+        }
+    }
+}
+
+fn parse_physical_window_exprs(
+    window_expr: &[protobuf::PhysicalExprNode],
+    window_expr_name: &[String],
+    registry: &dyn FunctionRegistry,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<Vec<Arc<dyn WindowExpr>>> {
+    window_expr
+        .iter()
+        .zip(window_expr_name.iter())
+        .map(|(expr, name)| {
+            let expr_type = expr.expr_type.as_ref().ok_or_else(|| {
+                proto_error("Unexpected empty window physical expression")
+            })?;
+
+            match expr_type {
+                ExprType::WindowExpr(window_node) => parse_physical_window_expr(
+                    window_node,
+                    registry,
+                    input_schema,
+                    name.to_owned(),
+                    codec,
+                ),
+                _ => Err(DataFusionError::Internal(
+                    "Invalid expression for WindowAggrExec".to_string(),
+                )),
+            }
+        })
+        .collect()
+}
+
+fn window_exprs_to_proto(
+    window_expr: &[Arc<dyn WindowExpr>],
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<(Vec<protobuf::PhysicalExprNode>, Vec<String>)> {
+    let exprs = window_expr
+        .iter()
+        .map(|expr| serialize_physical_window_expr(expr.clone(), codec))
+        .collect::<Result<Vec<_>>>()?;
+    let names = window_expr
+        .iter()
+        .map(|expr| expr.name().to_string())
+        .collect();
+    Ok((exprs, names))
+}
+
+fn join_on_to_proto(on: &[(Column, Column)]) -> Vec<protobuf::JoinOn> {
+    on.iter()
+        .map(|(left, right)| protobuf::JoinOn {
+            left: Some(protobuf::PhysicalColumn {
+                name: left.name().to_string(),
+                index: left.index() as u32,
+            }),
+            right: Some(protobuf::PhysicalColumn {
+                name: right.name().to_string(),
+                index: right.index() as u32,
+            }),
+        })
+        .collect()
+}
+
+pub trait AsExecutionPlan: Debug + Send + Sync + Clone {
+    fn try_decode(buf: &[u8]) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn try_encode<B>(&self, buf: &mut B) -> Result<()>
+    where
         B: BufMut,
         Self: Sized;
 
@@ -1160,6 +1728,76 @@ pub trait PhysicalExtensionCodec: Debug + Send + Sync {
     ) -> Result<Arc<dyn ExecutionPlan>>;
 
     fn try_encode(&self, node: Arc<dyn ExecutionPlan>, buf: &mut Vec<u8>) -> Result<()>;
+
+    /// Decodes a scalar UDF that was encoded by [`Self::try_encode_udf`]
+    fn try_decode_udf(&self, name: &str, _buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+        Err(DataFusionError::NotImplemented(format!(
+            "PhysicalExtensionCodec is not provided for scalar function {name}"
+        )))
+    }
+
+    /// Encodes the state of a scalar UDF. Leaving `buf` empty makes the
+    /// function be looked up by name in the `FunctionRegistry` when decoding
+    fn try_encode_udf(&self, _node: &ScalarUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decodes an aggregate UDF that was encoded by [`Self::try_encode_udaf`]
+    fn try_decode_udaf(&self, name: &str, _buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+        Err(DataFusionError::NotImplemented(format!(
+            "PhysicalExtensionCodec is not provided for aggregate function {name}"
+        )))
+    }
+
+    /// Encodes the state of an aggregate UDF. Leaving `buf` empty makes the
+    /// function be looked up by name in the `FunctionRegistry` when decoding
+    fn try_encode_udaf(&self, _node: &AggregateUDF, _buf: &mut Vec<u8>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Decodes a custom physical expression from its encoded form and its
+    /// decoded children
+    fn try_decode_expr(
+        &self,
+        _buf: &[u8],
+        _inputs: &[Arc<dyn PhysicalExpr>],
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Err(DataFusionError::NotImplemented(
+            "PhysicalExtensionCodec is not provided".to_string(),
+        ))
+    }
+
+    /// Encodes a physical expression this crate has no protobuf
+    /// representation for. Its children are encoded separately
+    fn try_encode_expr(
+        &self,
+        _node: Arc<dyn PhysicalExpr>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        Err(DataFusionError::NotImplemented(
+            "PhysicalExtensionCodec is not provided".to_string(),
+        ))
+    }
+    /// Decodes the [`DataSink`] of an [`InsertExec`] encoded by
+    /// [`Self::try_encode_sink`]
+    fn try_decode_sink(&self, _buf: &[u8]) -> Result<Arc<dyn DataSink>> {
+        Err(DataFusionError::NotImplemented(
+            "PhysicalExtensionCodec is not provided for data sinks".to_string(),
+        ))
+    }
+
+    /// Encodes the [`DataSink`] of an [`InsertExec`]. Sinks have no
+    /// protobuf representation, as they usually refer to the state of
+    /// the target table
+    fn try_encode_sink(
+        &self,
+        _sink: &Arc<dyn DataSink>,
+        _buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        Err(DataFusionError::NotImplemented(
+            "PhysicalExtensionCodec is not provided for data sinks".to_string(),
+        ))
+    }
 }
 
 #[derive(Debug)]
@@ -1203,24 +1841,48 @@ macro_rules! into_physical_plan {
 
 #[cfg(test)]
 mod roundtrip_tests {
+    use std::any::Any;
+    use std::fmt::{self, Display, Formatter};
     use std::ops::Deref;
     use std::sync::Arc;
 
+    use async_trait::async_trait;
+
     use super::super::protobuf;
-    use crate::physical_plan::{AsExecutionPlan, DefaultPhysicalExtensionCodec};
-    use datafusion::arrow::array::ArrayRef;
+    use crate::physical_plan::{
+        AsExecutionPlan, DefaultPhysicalExtensionCodec, PhysicalExtensionCodec,
+    };
+    use datafusion::arrow::array::{ArrayRef, Int64Array};
     use datafusion::arrow::datatypes::IntervalUnit;
+    use datafusion::arrow::record_batch::RecordBatch;
+    use datafusion::datasource::file_format::file_type::FileCompressionType;
     use datafusion::datasource::object_store::ObjectStoreUrl;
     use datafusion::execution::context::ExecutionProps;
-    use datafusion::logical_expr::create_udf;
-    use datafusion::logical_expr::{BuiltinScalarFunction, Volatility};
+    use datafusion::execution::context::TaskContext;
+    use datafusion::execution::FunctionRegistry;
+    use datafusion::logical_expr::{
+        create_udaf, create_udf, Accumulator, AggregateUDF, ColumnarValue, ScalarUDF,
+    };
+    use datafusion::logical_expr::{
+        AggregateFunction, BuiltInWindowFunction, BuiltinScalarFunction, Volatility,
+        WindowFrame, WindowFunction,
+    };
     use datafusion::physical_expr::expressions::DateTimeIntervalExpr;
     use datafusion::physical_expr::ScalarFunctionExpr;
     use datafusion::physical_plan::aggregates::PhysicalGroupBy;
     use datafusion::physical_plan::expressions::{like, BinaryExpr, GetIndexedFieldExpr};
     use datafusion::physical_plan::functions;
     use datafusion::physical_plan::functions::make_scalar_function;
+    use datafusion::physical_plan::insert::{DataSink, InsertExec};
+    use datafusion::physical_plan::joins::utils::{ColumnIndex, JoinFilter, JoinSide};
+    use datafusion::physical_plan::memory::MemoryExec;
     use datafusion::physical_plan::projection::ProjectionExec;
+    use datafusion::physical_plan::unnest::UnnestExec;
+    use datafusion::physical_plan::values::ValuesExec;
+    use datafusion::physical_plan::windows::{
+        create_window_expr, BoundedWindowAggExec, WindowAggExec,
+    };
+    use datafusion::physical_plan::{udaf, udf};
     use datafusion::{
         arrow::{
             compute::kernels::sort::SortOptions,
@@ -1233,17 +1895,22 @@ mod roundtrip_tests {
             empty::EmptyExec,
             expressions::{binary, col, lit, InListExpr, NotExpr},
             expressions::{Avg, Column, DistinctCount, PhysicalSortExpr},
-            file_format::{FileScanConfig, ParquetExec},
+            file_format::{CsvExec, FileScanConfig, NdJsonExec, ParquetExec},
             filter::FilterExec,
-            joins::{HashJoinExec, PartitionMode},
+            joins::{
+                HashJoinExec, NestedLoopJoinExec, PartitionMode, SortMergeJoinExec,
+                SymmetricHashJoinExec,
+            },
             limit::{GlobalLimitExec, LocalLimitExec},
             sorts::sort::SortExec,
-            AggregateExpr, ExecutionPlan, PhysicalExpr, Statistics,
+            AggregateExpr, ExecutionPlan, PhysicalExpr, SendableRecordBatchStream,
+            Statistics,
         },
         prelude::SessionContext,
         scalar::ScalarValue,
     };
-    use datafusion_common::Result;
+    use datafusion_common::cast::as_int64_array;
+    use datafusion_common::{DataFusionError, Result};
 
     fn roundtrip_test(exec_plan: Arc<dyn ExecutionPlan>) -> Result<()> {
         let ctx = SessionContext::new();
@@ -1259,6 +1926,22 @@ mod roundtrip_tests {
         Ok(())
     }
 
+    fn roundtrip_test_with_codec(
+        exec_plan: Arc<dyn ExecutionPlan>,
+        codec: &dyn PhysicalExtensionCodec,
+    ) -> Result<()> {
+        let ctx = SessionContext::new();
+        let proto: protobuf::PhysicalPlanNode =
+            protobuf::PhysicalPlanNode::try_from_physical_plan(exec_plan.clone(), codec)
+                .expect("to proto");
+        let runtime = ctx.runtime_env();
+        let result_exec_plan: Arc<dyn ExecutionPlan> = proto
+            .try_into_physical_plan(&ctx, runtime.deref(), codec)
+            .expect("from proto");
+        assert_eq!(format!("{exec_plan:?}"), format!("{result_exec_plan:?}"));
+        Ok(())
+    }
+
     fn roundtrip_test_with_context(
         exec_plan: Arc<dyn ExecutionPlan>,
         ctx: SessionContext,
@@ -1656,4 +2339,670 @@ mod roundtrip_tests {
 
         roundtrip_test(plan)
     }
+
+    #[test]
+    fn roundtrip_json_exec() -> Result<()> {
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ])),
+            file_groups: vec![vec![PartitionedFile::new(
+                "/path/to/file.json.gz".to_string(),
+                1024,
+            )]],
+            statistics: Statistics::default(),
+            projection: Some(vec![1]),
+            limit: Some(10),
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        };
+
+        roundtrip_test(Arc::new(NdJsonExec::new(
+            scan_config,
+            FileCompressionType::GZIP,
+        )))
+    }
+
+    #[test]
+    fn roundtrip_csv_exec_with_parsing_options() -> Result<()> {
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ])),
+            file_groups: vec![vec![PartitionedFile::new(
+                "/path/to/file.csv".to_string(),
+                1024,
+            )]],
+            statistics: Statistics::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        };
+
+        roundtrip_test(Arc::new(
+            CsvExec::new(scan_config, true, b';', FileCompressionType::UNCOMPRESSED)
+                .with_quote(b'\'')
+                .with_escape(Some(b'\\'))
+                .with_comment(Some(b'#'))
+                .with_null_value(Some("NA".to_string())),
+        ))
+    }
+
+    #[test]
+    fn roundtrip_sort_merge_join() -> Result<()> {
+        let field_a = Field::new("col", DataType::Int64, false);
+        let schema_left = Schema::new(vec![field_a.clone()]);
+        let schema_right = Schema::new(vec![field_a]);
+        let on = vec![(
+            Column::new("col", schema_left.index_of("col")?),
+            Column::new("col", schema_right.index_of("col")?),
+        )];
+
+        let schema_left = Arc::new(schema_left);
+        let schema_right = Arc::new(schema_right);
+        for join_type in &[
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftAnti,
+            JoinType::RightAnti,
+            JoinType::LeftSemi,
+            JoinType::RightSemi,
+        ] {
+            roundtrip_test(Arc::new(SortMergeJoinExec::try_new(
+                Arc::new(EmptyExec::new(false, schema_left.clone())),
+                Arc::new(EmptyExec::new(false, schema_right.clone())),
+                on.clone(),
+                *join_type,
+                vec![SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }],
+                true,
+            )?))?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_nested_loop_join() -> Result<()> {
+        let schema_left =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let schema_right =
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int64, false)]));
+        let filter_schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]);
+        let filter = JoinFilter::new(
+            binary(
+                col("a", &filter_schema)?,
+                Operator::Lt,
+                col("b", &filter_schema)?,
+                &filter_schema,
+            )?,
+            vec![
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Left,
+                },
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Right,
+                },
+            ],
+            filter_schema,
+        );
+
+        for join_type in &[JoinType::Inner, JoinType::Left, JoinType::Full] {
+            roundtrip_test(Arc::new(NestedLoopJoinExec::try_new(
+                Arc::new(EmptyExec::new(false, schema_left.clone())),
+                Arc::new(EmptyExec::new(false, schema_right.clone())),
+                Some(filter.clone()),
+                join_type,
+            )?))?;
+        }
+        roundtrip_test(Arc::new(NestedLoopJoinExec::try_new(
+            Arc::new(EmptyExec::new(false, schema_left)),
+            Arc::new(EmptyExec::new(false, schema_right)),
+            None,
+            &JoinType::Inner,
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_symmetric_hash_join() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let sort_information = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: SortOptions::default(),
+        }];
+        let left = Arc::new(
+            MemoryExec::try_new(&[vec![]], schema.clone(), None)?
+                .with_sort_information(sort_information.clone()),
+        );
+        let right = Arc::new(
+            MemoryExec::try_new(&[vec![]], schema.clone(), None)?
+                .with_sort_information(sort_information),
+        );
+        let on = vec![(Column::new("b", 1), Column::new("b", 1))];
+
+        let filter_schema = Schema::new(vec![
+            Field::new("left_a", DataType::Int64, false),
+            Field::new("right_a", DataType::Int64, false),
+        ]);
+        let filter = JoinFilter::new(
+            binary(
+                col("left_a", &filter_schema)?,
+                Operator::Gt,
+                col("right_a", &filter_schema)?,
+                &filter_schema,
+            )?,
+            vec![
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Left,
+                },
+                ColumnIndex {
+                    index: 0,
+                    side: JoinSide::Right,
+                },
+            ],
+            filter_schema,
+        );
+
+        roundtrip_test(Arc::new(SymmetricHashJoinExec::try_new(
+            left,
+            right,
+            on,
+            filter,
+            &JoinType::Inner,
+            false,
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_unnest() -> Result<()> {
+        let input_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new(
+                "a",
+                DataType::List(Box::new(Field::new("item", DataType::Float64, true))),
+                true,
+            ),
+        ]));
+        let output_schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("a", DataType::Float64, true),
+        ]));
+        roundtrip_test(Arc::new(UnnestExec::new(
+            Arc::new(EmptyExec::new(false, input_schema)),
+            Column::new("a", 1),
+            output_schema,
+        )))
+    }
+
+    #[test]
+    fn roundtrip_values() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        roundtrip_test(Arc::new(ValuesExec::try_new(
+            schema,
+            vec![
+                vec![lit(1i64), lit("foo")],
+                vec![lit(ScalarValue::Int64(None)), lit("bar")],
+            ],
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_memory() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(Int64Array::from(vec![Some(4), None, Some(6)])),
+            ],
+        )?;
+        roundtrip_test(Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()], vec![batch]],
+            schema.clone(),
+            Some(vec![1]),
+        )?))?;
+        roundtrip_test(Arc::new(MemoryExec::try_new(&[vec![]], schema, None)?))
+    }
+
+    #[test]
+    fn roundtrip_window() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let partition_by = vec![col("a", &schema)?];
+        let order_by = vec![PhysicalSortExpr {
+            expr: col("b", &schema)?,
+            options: SortOptions::default(),
+        }];
+
+        let sum = create_window_expr(
+            &WindowFunction::AggregateFunction(AggregateFunction::Sum),
+            "SUM(b)".to_string(),
+            &[col("b", &schema)?],
+            &partition_by,
+            &order_by,
+            Arc::new(WindowFrame::new(true)),
+            &schema,
+        )?;
+        let lag = create_window_expr(
+            &WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::Lag),
+            "LAG(b,2,0)".to_string(),
+            &[col("b", &schema)?, lit(2i64), lit(0i64)],
+            &partition_by,
+            &order_by,
+            Arc::new(WindowFrame::new(true)),
+            &schema,
+        )?;
+        let row_number = create_window_expr(
+            &WindowFunction::BuiltInWindowFunction(BuiltInWindowFunction::RowNumber),
+            "ROW_NUMBER()".to_string(),
+            &[],
+            &partition_by,
+            &order_by,
+            Arc::new(WindowFrame::new(true)),
+            &schema,
+        )?;
+        let window_expr = vec![sum, lag, row_number];
+
+        roundtrip_test(Arc::new(WindowAggExec::try_new(
+            window_expr.clone(),
+            Arc::new(EmptyExec::new(false, schema.clone())),
+            schema.clone(),
+            partition_by.clone(),
+            Some(order_by.clone()),
+        )?))?;
+        roundtrip_test(Arc::new(BoundedWindowAggExec::try_new(
+            window_expr,
+            Arc::new(EmptyExec::new(false, schema.clone())),
+            schema,
+            partition_by,
+            Some(order_by),
+        )?))
+    }
+
+    /// A scalar UDF that multiplies its argument by `factor`
+    fn scale_udf(factor: i64) -> ScalarUDF {
+        let fun = make_scalar_function(move |args: &[ArrayRef]| {
+            let array: Int64Array = as_int64_array(&args[0])?
+                .iter()
+                .map(|v| v.map(|v| v * factor))
+                .collect();
+            Ok(Arc::new(array) as ArrayRef)
+        });
+        create_udf(
+            &format!("scale_{factor}"),
+            vec![DataType::Int64],
+            Arc::new(DataType::Int64),
+            Volatility::Immutable,
+            fun,
+        )
+    }
+
+    #[derive(Debug)]
+    struct SumAccumulator {}
+
+    impl Accumulator for SumAccumulator {
+        fn state(&self) -> Result<Vec<ScalarValue>> {
+            Ok(vec![])
+        }
+
+        fn update_batch(&mut self, _values: &[ArrayRef]) -> Result<()> {
+            Ok(())
+        }
+
+        fn merge_batch(&mut self, _states: &[ArrayRef]) -> Result<()> {
+            Ok(())
+        }
+
+        fn evaluate(&self) -> Result<ScalarValue> {
+            Ok(ScalarValue::Int64(None))
+        }
+
+        fn size(&self) -> usize {
+            std::mem::size_of_val(self)
+        }
+    }
+
+    fn sum_udaf() -> AggregateUDF {
+        create_udaf(
+            "my_sum",
+            DataType::Int64,
+            Arc::new(DataType::Int64),
+            Volatility::Immutable,
+            Arc::new(|_| Ok(Box::new(SumAccumulator {}))),
+            Arc::new(vec![DataType::Int64]),
+        )
+    }
+
+    /// A physical expression that the protobuf format does not know about
+    #[derive(Debug)]
+    struct IsPositiveExpr {
+        arg: Arc<dyn PhysicalExpr>,
+    }
+
+    impl Display for IsPositiveExpr {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "{} > 0", self.arg)
+        }
+    }
+
+    impl PartialEq<dyn Any> for IsPositiveExpr {
+        fn eq(&self, other: &dyn Any) -> bool {
+            other
+                .downcast_ref::<Self>()
+                .map(|x| self.arg.eq(&x.arg))
+                .unwrap_or(false)
+        }
+    }
+
+    impl PhysicalExpr for IsPositiveExpr {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+            Ok(DataType::Boolean)
+        }
+
+        fn nullable(&self, input_schema: &Schema) -> Result<bool> {
+            self.arg.nullable(input_schema)
+        }
+
+        fn evaluate(&self, _batch: &RecordBatch) -> Result<ColumnarValue> {
+            Err(DataFusionError::NotImplemented(
+                "IsPositiveExpr is not evaluated in this test".to_string(),
+            ))
+        }
+
+        fn children(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+            vec![self.arg.clone()]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            children: Vec<Arc<dyn PhysicalExpr>>,
+        ) -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(Arc::new(IsPositiveExpr {
+                arg: children[0].clone(),
+            }))
+        }
+    }
+
+    /// A sink discarding its input, identified by its name
+    #[derive(Debug)]
+    struct TestSink {
+        name: String,
+    }
+
+    impl Display for TestSink {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "TestSink({})", self.name)
+        }
+    }
+
+    #[async_trait]
+    impl DataSink for TestSink {
+        async fn write_all(
+            &self,
+            _data: Vec<SendableRecordBatchStream>,
+            _context: &Arc<TaskContext>,
+        ) -> Result<u64> {
+            Ok(0)
+        }
+    }
+
+    /// Encodes the factor of `scale_udf`, the `sum_udaf`, `IsPositiveExpr`
+    /// and `TestSink`
+    #[derive(Debug)]
+    struct TestExtensionCodec {}
+
+    impl PhysicalExtensionCodec for TestExtensionCodec {
+        fn try_decode(
+            &self,
+            _buf: &[u8],
+            _inputs: &[Arc<dyn ExecutionPlan>],
+            _registry: &dyn FunctionRegistry,
+        ) -> Result<Arc<dyn ExecutionPlan>> {
+            Err(DataFusionError::NotImplemented("no plan nodes".to_string()))
+        }
+
+        fn try_encode(
+            &self,
+            _node: Arc<dyn ExecutionPlan>,
+            _buf: &mut Vec<u8>,
+        ) -> Result<()> {
+            Err(DataFusionError::NotImplemented("no plan nodes".to_string()))
+        }
+
+        fn try_decode_udf(&self, name: &str, buf: &[u8]) -> Result<Arc<ScalarUDF>> {
+            let factor = i64::from_le_bytes(buf.try_into().map_err(|_| {
+                DataFusionError::Internal(format!("invalid definition of {name}"))
+            })?);
+            Ok(Arc::new(scale_udf(factor)))
+        }
+
+        fn try_encode_udf(&self, node: &ScalarUDF, buf: &mut Vec<u8>) -> Result<()> {
+            let factor: i64 = node.name["scale_".len()..].parse().map_err(|_| {
+                DataFusionError::Internal(format!("unexpected udf {}", node.name))
+            })?;
+            buf.extend_from_slice(&factor.to_le_bytes());
+            Ok(())
+        }
+
+        fn try_decode_udaf(&self, name: &str, buf: &[u8]) -> Result<Arc<AggregateUDF>> {
+            assert_eq!(buf, name.as_bytes());
+            Ok(Arc::new(sum_udaf()))
+        }
+
+        fn try_encode_udaf(&self, node: &AggregateUDF, buf: &mut Vec<u8>) -> Result<()> {
+            buf.extend_from_slice(node.name.as_bytes());
+            Ok(())
+        }
+
+        fn try_decode_expr(
+            &self,
+            buf: &[u8],
+            inputs: &[Arc<dyn PhysicalExpr>],
+        ) -> Result<Arc<dyn PhysicalExpr>> {
+            assert_eq!(buf, b"is_positive");
+            Ok(Arc::new(IsPositiveExpr {
+                arg: inputs[0].clone(),
+            }))
+        }
+
+        fn try_encode_expr(
+            &self,
+            node: Arc<dyn PhysicalExpr>,
+            buf: &mut Vec<u8>,
+        ) -> Result<()> {
+            if node.as_any().downcast_ref::<IsPositiveExpr>().is_some() {
+                buf.extend_from_slice(b"is_positive");
+                Ok(())
+            } else {
+                Err(DataFusionError::NotImplemented(format!(
+                    "unexpected expression {node:?}"
+                )))
+            }
+        }
+
+        fn try_decode_sink(&self, buf: &[u8]) -> Result<Arc<dyn DataSink>> {
+            let name = String::from_utf8(buf.to_vec())
+                .map_err(|e| DataFusionError::Internal(e.to_string()))?;
+            Ok(Arc::new(TestSink { name }))
+        }
+
+        fn try_encode_sink(
+            &self,
+            sink: &Arc<dyn DataSink>,
+            buf: &mut Vec<u8>,
+        ) -> Result<()> {
+            let name = sink.to_string();
+            match name
+                .strip_prefix("TestSink(")
+                .and_then(|n| n.strip_suffix(')'))
+            {
+                Some(name) => {
+                    buf.extend_from_slice(name.as_bytes());
+                    Ok(())
+                }
+                None => Err(DataFusionError::NotImplemented(format!(
+                    "unexpected sink {name}"
+                ))),
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip_scalar_udf_extension_codec() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let input = Arc::new(EmptyExec::new(false, schema.clone()));
+        let expr =
+            udf::create_physical_expr(&scale_udf(3), &[col("a", &schema)?], &schema)?;
+        let plan = Arc::new(ProjectionExec::try_new(
+            vec![(expr, "scaled".to_string())],
+            input,
+        )?);
+
+        // the udf is not registered, so it can only be recreated by the codec
+        roundtrip_test_with_codec(plan.clone(), &TestExtensionCodec {})?;
+
+        let proto = protobuf::PhysicalPlanNode::try_from_physical_plan(
+            plan,
+            &DefaultPhysicalExtensionCodec {},
+        )?;
+        let ctx = SessionContext::new();
+        let runtime = ctx.runtime_env();
+        let err = proto
+            .try_into_physical_plan(
+                &ctx,
+                runtime.deref(),
+                &DefaultPhysicalExtensionCodec {},
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("scale_3"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_aggregate_udf_extension_codec() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let aggregates: Vec<Arc<dyn AggregateExpr>> = vec![udaf::create_aggregate_expr(
+            &sum_udaf(),
+            &[col("a", &schema)?],
+            &schema,
+            "my_sum(a)",
+        )?];
+
+        roundtrip_test_with_codec(
+            Arc::new(AggregateExec::try_new(
+                AggregateMode::Final,
+                PhysicalGroupBy::new_single(vec![]),
+                aggregates,
+                Arc::new(EmptyExec::new(false, schema.clone())),
+                schema,
+            )?),
+            &TestExtensionCodec {},
+        )
+    }
+
+    #[test]
+    fn roundtrip_extension_expr() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let predicate = Arc::new(IsPositiveExpr {
+            arg: col("a", &schema)?,
+        });
+        let plan = Arc::new(FilterExec::try_new(
+            predicate,
+            Arc::new(EmptyExec::new(false, schema)),
+        )?);
+        roundtrip_test_with_codec(plan.clone(), &TestExtensionCodec {})?;
+
+        assert!(protobuf::PhysicalPlanNode::try_from_physical_plan(
+            plan,
+            &DefaultPhysicalExtensionCodec {},
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_parquet_exec_with_extension_ordering() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: schema.clone(),
+            file_groups: vec![vec![PartitionedFile::new(
+                "/path/to/file.parquet".to_string(),
+                1024,
+            )]],
+            statistics: Statistics::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: Some(vec![PhysicalSortExpr {
+                expr: Arc::new(IsPositiveExpr {
+                    arg: col("a", &schema)?,
+                }),
+                options: SortOptions::default(),
+            }]),
+            infinite_source: false,
+        };
+        let plan = Arc::new(ParquetExec::new(scan_config, None, None));
+        roundtrip_test_with_codec(plan.clone(), &TestExtensionCodec {})?;
+
+        // the output ordering can only be encoded by the codec
+        assert!(protobuf::PhysicalPlanNode::try_from_physical_plan(
+            plan,
+            &DefaultPhysicalExtensionCodec {},
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_insert() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let plan = Arc::new(InsertExec::new(
+            Arc::new(EmptyExec::new(false, schema)),
+            Arc::new(TestSink {
+                name: "t".to_string(),
+            }),
+        ));
+        roundtrip_test_with_codec(plan.clone(), &TestExtensionCodec {})?;
+
+        // sinks can only be encoded by a codec
+        let err = protobuf::PhysicalPlanNode::try_from_physical_plan(
+            plan,
+            &DefaultPhysicalExtensionCodec {},
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("data sinks"), "{err}");
+        Ok(())
+    }
 }
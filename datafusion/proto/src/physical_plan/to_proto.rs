@@ -22,352 +22,581 @@ use std::{
     sync::Arc,
 };
 
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::physical_plan::expressions::{CastExpr, TryCastExpr};
-use datafusion::physical_plan::ColumnStatistics;
 use datafusion::physical_plan::{
     expressions::{
         CaseExpr, InListExpr, IsNotNullExpr, IsNullExpr, NegativeExpr, NotExpr,
     },
     Statistics,
 };
+use datafusion::physical_plan::{ColumnStatistics, WindowExpr};
 
 use datafusion::datasource::listing::{FileRange, PartitionedFile};
 use datafusion::physical_plan::file_format::FileScanConfig;
 
-use datafusion::physical_plan::expressions::{Count, DistinctCount, Literal};
+use datafusion::physical_plan::expressions::{
+    Count, CumeDist, DistinctCount, Literal, NthValue, Ntile, PhysicalSortExpr, Rank,
+    RankType, RowNumber, WindowShift,
+};
 
 use datafusion::physical_plan::expressions::{
     Avg, BinaryExpr, Column, LikeExpr, Max, Min, Sum,
 };
 use datafusion::physical_plan::{AggregateExpr, PhysicalExpr};
 
+use crate::physical_plan::PhysicalExtensionCodec;
 use crate::protobuf;
 use crate::protobuf::{PhysicalSortExprNode, ScalarValue};
 use datafusion::logical_expr::BuiltinScalarFunction;
 use datafusion::physical_expr::expressions::{DateTimeIntervalExpr, GetIndexedFieldExpr};
+use datafusion::physical_expr::window::{
+    BuiltInWindowExpr, NthValueKind, PlainAggregateWindowExpr, SlidingAggregateWindowExpr,
+};
 use datafusion::physical_expr::ScalarFunctionExpr;
-use datafusion::physical_plan::joins::utils::JoinSide;
+use datafusion::physical_plan::joins::utils::{JoinFilter, JoinSide};
+use datafusion::physical_plan::udaf::AggregateFunctionExpr;
+use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{DataFusionError, Result};
 
-impl TryFrom<Arc<dyn AggregateExpr>> for protobuf::PhysicalExprNode {
-    type Error = DataFusionError;
+/// Serializes an aggregate expression, using `codec` for user defined
+/// aggregate functions
+pub fn serialize_physical_aggr_expr(
+    a: Arc<dyn AggregateExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalExprNode> {
+    use datafusion::physical_plan::expressions;
+    use protobuf::physical_aggregate_expr_node::AggregateFunction as AggrFunctionType;
+    use protobuf::AggregateFunction;
 
-    fn try_from(a: Arc<dyn AggregateExpr>) -> Result<Self, Self::Error> {
-        use datafusion::physical_plan::expressions;
-        use protobuf::AggregateFunction;
-
-        let mut distinct = false;
-        let aggr_function = if a.as_any().downcast_ref::<Avg>().is_some() {
-            Ok(AggregateFunction::Avg.into())
-        } else if a.as_any().downcast_ref::<Sum>().is_some() {
-            Ok(AggregateFunction::Sum.into())
-        } else if a.as_any().downcast_ref::<Count>().is_some() {
-            Ok(AggregateFunction::Count.into())
-        } else if a.as_any().downcast_ref::<DistinctCount>().is_some() {
-            distinct = true;
-            Ok(AggregateFunction::Count.into())
-        } else if a.as_any().downcast_ref::<Min>().is_some() {
-            Ok(AggregateFunction::Min.into())
-        } else if a.as_any().downcast_ref::<Max>().is_some() {
-            Ok(AggregateFunction::Max.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxDistinct>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxDistinct.into())
-        } else if a.as_any().downcast_ref::<expressions::ArrayAgg>().is_some() {
-            Ok(AggregateFunction::ArrayAgg.into())
-        } else if a.as_any().downcast_ref::<expressions::Variance>().is_some() {
-            Ok(AggregateFunction::Variance.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::VariancePop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::VariancePop.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::Covariance>()
-            .is_some()
-        {
-            Ok(AggregateFunction::Covariance.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::CovariancePop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::CovariancePop.into())
-        } else if a.as_any().downcast_ref::<expressions::Stddev>().is_some() {
-            Ok(AggregateFunction::Stddev.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::StddevPop>()
-            .is_some()
-        {
-            Ok(AggregateFunction::StddevPop.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::Correlation>()
-            .is_some()
-        {
-            Ok(AggregateFunction::Correlation.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxPercentileCont>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxPercentileCont.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxPercentileContWithWeight>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxPercentileContWithWeight.into())
-        } else if a
-            .as_any()
-            .downcast_ref::<expressions::ApproxMedian>()
-            .is_some()
-        {
-            Ok(AggregateFunction::ApproxMedian.into())
-        } else {
-            Err(DataFusionError::NotImplemented(format!(
-                "Aggregate function not supported: {a:?}"
-            )))
-        }?;
-        let expressions: Vec<protobuf::PhysicalExprNode> = a
-            .expressions()
-            .iter()
-            .map(|e| e.clone().try_into())
-            .collect::<Result<Vec<_>>>()?;
-        Ok(protobuf::PhysicalExprNode {
+    let expressions: Vec<protobuf::PhysicalExprNode> = a
+        .expressions()
+        .iter()
+        .map(|e| serialize_physical_expr(e.clone(), codec))
+        .collect::<Result<Vec<_>>>()?;
+
+    if let Some(udaf) = a.as_any().downcast_ref::<AggregateFunctionExpr>() {
+        let mut fun_definition = vec![];
+        codec.try_encode_udaf(udaf.fun(), &mut fun_definition)?;
+        return Ok(protobuf::PhysicalExprNode {
             expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
                 protobuf::PhysicalAggregateExprNode {
-                    aggr_function,
+                    aggregate_function: Some(AggrFunctionType::UserDefinedAggrFunction(
+                        udaf.fun().name.clone(),
+                    )),
                     expr: expressions,
-                    distinct,
+                    distinct: false,
+                    fun_definition,
                 },
             )),
-        })
+        });
     }
+
+    let mut distinct = false;
+    let aggr_function = if a.as_any().downcast_ref::<Avg>().is_some() {
+        Ok(AggregateFunction::Avg.into())
+    } else if a.as_any().downcast_ref::<Sum>().is_some() {
+        Ok(AggregateFunction::Sum.into())
+    } else if a.as_any().downcast_ref::<Count>().is_some() {
+        Ok(AggregateFunction::Count.into())
+    } else if a.as_any().downcast_ref::<DistinctCount>().is_some() {
+        distinct = true;
+        Ok(AggregateFunction::Count.into())
+    } else if a.as_any().downcast_ref::<Min>().is_some() {
+        Ok(AggregateFunction::Min.into())
+    } else if a.as_any().downcast_ref::<Max>().is_some() {
+        Ok(AggregateFunction::Max.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::ApproxDistinct>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxDistinct.into())
+    } else if a.as_any().downcast_ref::<expressions::ArrayAgg>().is_some() {
+        Ok(AggregateFunction::ArrayAgg.into())
+    } else if a.as_any().downcast_ref::<expressions::Variance>().is_some() {
+        Ok(AggregateFunction::Variance.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::VariancePop>()
+        .is_some()
+    {
+        Ok(AggregateFunction::VariancePop.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::Covariance>()
+        .is_some()
+    {
+        Ok(AggregateFunction::Covariance.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::CovariancePop>()
+        .is_some()
+    {
+        Ok(AggregateFunction::CovariancePop.into())
+    } else if a.as_any().downcast_ref::<expressions::Stddev>().is_some() {
+        Ok(AggregateFunction::Stddev.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::StddevPop>()
+        .is_some()
+    {
+        Ok(AggregateFunction::StddevPop.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::Correlation>()
+        .is_some()
+    {
+        Ok(AggregateFunction::Correlation.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::ApproxPercentileCont>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxPercentileCont.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::ApproxPercentileContWithWeight>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxPercentileContWithWeight.into())
+    } else if a
+        .as_any()
+        .downcast_ref::<expressions::ApproxMedian>()
+        .is_some()
+    {
+        Ok(AggregateFunction::ApproxMedian.into())
+    } else {
+        Err(DataFusionError::NotImplemented(format!(
+            "Aggregate function not supported: {a:?}"
+        )))
+    }?;
+    Ok(protobuf::PhysicalExprNode {
+        expr_type: Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
+            protobuf::PhysicalAggregateExprNode {
+                aggregate_function: Some(AggrFunctionType::AggrFunction(aggr_function)),
+                expr: expressions,
+                distinct,
+                fun_definition: vec![],
+            },
+        )),
+    })
 }
 
-impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
-    type Error = DataFusionError;
+/// Serializes a window expression
+pub fn serialize_physical_window_expr(
+    window_expr: Arc<dyn WindowExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalExprNode> {
+    use protobuf::physical_window_expr_node::WindowFunction;
+    use protobuf::BuiltInWindowFunction;
 
-    fn try_from(value: Arc<dyn PhysicalExpr>) -> Result<Self, Self::Error> {
-        let expr = value.as_any();
+    let expr = window_expr.as_any();
+    let mut args = window_expr.expressions();
 
-        if let Some(expr) = expr.downcast_ref::<Column>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Column(
-                    protobuf::PhysicalColumn {
-                        name: expr.name().to_string(),
-                        index: expr.index() as u32,
-                    },
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<BinaryExpr>() {
-            let binary_expr = Box::new(protobuf::PhysicalBinaryExprNode {
-                l: Some(Box::new(expr.left().to_owned().try_into()?)),
-                r: Some(Box::new(expr.right().to_owned().try_into()?)),
-                op: format!("{:?}", expr.op()),
-            });
+    let window_function = if let Some(built_in) = expr.downcast_ref::<BuiltInWindowExpr>()
+    {
+        let built_in_fn_expr = built_in.get_built_in_func_expr();
+        let built_in_fn = built_in_fn_expr.as_any();
+        // Arguments that were folded into the function at planning time
+        // are added back as literals so the function can be recreated
+        let fun = if built_in_fn.downcast_ref::<RowNumber>().is_some() {
+            BuiltInWindowFunction::RowNumber
+        } else if let Some(rank) = built_in_fn.downcast_ref::<Rank>() {
+            match rank.get_type() {
+                RankType::Basic => BuiltInWindowFunction::Rank,
+                RankType::Dense => BuiltInWindowFunction::DenseRank,
+                RankType::Percent => BuiltInWindowFunction::PercentRank,
+            }
+        } else if built_in_fn.downcast_ref::<CumeDist>().is_some() {
+            BuiltInWindowFunction::CumeDist
+        } else if let Some(ntile) = built_in_fn.downcast_ref::<Ntile>() {
+            args.insert(
+                0,
+                Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
+                    ntile.get_n() as i64,
+                )))),
+            );
+            BuiltInWindowFunction::Ntile
+        } else if let Some(window_shift) = built_in_fn.downcast_ref::<WindowShift>() {
+            let shift_offset = window_shift.get_shift_offset();
+            args.insert(
+                1,
+                Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(Some(
+                    shift_offset.abs(),
+                )))),
+            );
+            if let Some(default_value) = window_shift.get_default_value() {
+                args.insert(2, Arc::new(Literal::new(default_value)));
+            }
+            if shift_offset >= 0 {
+                BuiltInWindowFunction::Lag
+            } else {
+                BuiltInWindowFunction::Lead
+            }
+        } else if let Some(nth_value) = built_in_fn.downcast_ref::<NthValue>() {
+            match nth_value.get_kind() {
+                NthValueKind::First => BuiltInWindowFunction::FirstValue,
+                NthValueKind::Last => BuiltInWindowFunction::LastValue,
+                NthValueKind::Nth(n) => {
+                    args.insert(
+                        1,
+                        Arc::new(Literal::new(datafusion_common::ScalarValue::Int64(
+                            Some(n as i64),
+                        ))),
+                    );
+                    BuiltInWindowFunction::NthValue
+                }
+            }
+        } else {
+            return Err(DataFusionError::NotImplemented(format!(
+                "BuiltIn window function not supported: {built_in_fn_expr:?}"
+            )));
+        };
+        WindowFunction::BuiltInFunction(fun as i32)
+    } else if let Some(plain) = expr.downcast_ref::<PlainAggregateWindowExpr>() {
+        WindowFunction::AggrFunction(aggr_function_of(plain.get_aggregate_expr(), codec)?)
+    } else if let Some(sliding) = expr.downcast_ref::<SlidingAggregateWindowExpr>() {
+        WindowFunction::AggrFunction(aggr_function_of(
+            sliding.get_aggregate_expr(),
+            codec,
+        )?)
+    } else {
+        return Err(DataFusionError::NotImplemented(format!(
+            "WindowExpr not supported: {window_expr:?}"
+        )));
+    };
+
+    let args = args
+        .into_iter()
+        .map(|e| serialize_physical_expr(e, codec))
+        .collect::<Result<Vec<_>>>()?;
+    let partition_by = window_expr
+        .partition_by()
+        .iter()
+        .map(|e| serialize_physical_expr(e.clone(), codec))
+        .collect::<Result<Vec<_>>>()?;
+    let order_by = window_expr
+        .order_by()
+        .iter()
+        .map(|e| serialize_physical_sort_expr(e, codec))
+        .collect::<Result<Vec<_>>>()?;
+    let window_frame: protobuf::WindowFrame =
+        window_expr.get_window_frame().as_ref().try_into()?;
+
+    Ok(protobuf::PhysicalExprNode {
+        expr_type: Some(protobuf::physical_expr_node::ExprType::WindowExpr(
+            Box::new(protobuf::PhysicalWindowExprNode {
+                expr: None,
+                args,
+                partition_by,
+                order_by,
+                window_frame: Some(window_frame),
+                window_function: Some(window_function),
+            }),
+        )),
+    })
+}
+
+/// Returns the protobuf aggregate function of an aggregate used as a window function
+fn aggr_function_of(
+    aggr_expr: &Arc<dyn AggregateExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<i32> {
+    use protobuf::physical_aggregate_expr_node::AggregateFunction;
+
+    let node = serialize_physical_aggr_expr(aggr_expr.clone(), codec)?;
+    match node.expr_type {
+        Some(protobuf::physical_expr_node::ExprType::AggregateExpr(
+            protobuf::PhysicalAggregateExprNode {
+                aggregate_function: Some(AggregateFunction::AggrFunction(aggr_function)),
+                distinct: false,
+                ..
+            },
+        )) => Ok(aggr_function),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Aggregate window function not supported: {aggr_expr:?}"
+        ))),
+    }
+}
+
+/// Serializes a physical expression, using `codec` for user defined
+/// functions and expressions this crate does not know about
+pub fn serialize_physical_expr(
+    value: Arc<dyn PhysicalExpr>,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalExprNode> {
+    let expr = value.as_any();
+
+    if let Some(expr) = expr.downcast_ref::<Column>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Column(
+                protobuf::PhysicalColumn {
+                    name: expr.name().to_string(),
+                    index: expr.index() as u32,
+                },
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<BinaryExpr>() {
+        let binary_expr = Box::new(protobuf::PhysicalBinaryExprNode {
+            l: Some(Box::new(serialize_physical_expr(
+                expr.left().to_owned(),
+                codec,
+            )?)),
+            r: Some(Box::new(serialize_physical_expr(
+                expr.right().to_owned(),
+                codec,
+            )?)),
+            op: format!("{:?}", expr.op()),
+        });
+
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::BinaryExpr(
+                binary_expr,
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<CaseExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Case(Box::new(
+                protobuf::PhysicalCaseNode {
+                    expr: expr
+                        .expr()
+                        .map(|exp| {
+                            serialize_physical_expr(exp.clone(), codec).map(Box::new)
+                        })
+                        .transpose()?,
+                    when_then_expr: expr
+                        .when_then_expr()
+                        .iter()
+                        .map(|(when_expr, then_expr)| {
+                            try_parse_when_then_expr(when_expr, then_expr, codec)
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    else_expr: expr
+                        .else_expr()
+                        .map(|a| serialize_physical_expr(a.clone(), codec).map(Box::new))
+                        .transpose()?,
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<NotExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::NotExpr(Box::new(
+                protobuf::PhysicalNot {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<IsNullExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::IsNullExpr(
+                Box::new(protobuf::PhysicalIsNull {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<IsNotNullExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::IsNotNullExpr(
+                Box::new(protobuf::PhysicalIsNotNull {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                }),
+            )),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<InListExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::InList(Box::new(
+                protobuf::PhysicalInListNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.expr().to_owned(),
+                        codec,
+                    )?)),
+                    list: expr
+                        .list()
+                        .iter()
+                        .map(|a| serialize_physical_expr(a.clone(), codec))
+                        .collect::<Result<Vec<_>>>()?,
+                    negated: expr.negated(),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<NegativeExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Negative(Box::new(
+                protobuf::PhysicalNegativeNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(lit) = expr.downcast_ref::<Literal>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Literal(
+                lit.value().try_into()?,
+            )),
+        })
+    } else if let Some(cast) = expr.downcast_ref::<CastExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Cast(Box::new(
+                protobuf::PhysicalCastNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        cast.expr().to_owned(),
+                        codec,
+                    )?)),
+                    arrow_type: Some(cast.cast_type().try_into()?),
+                },
+            ))),
+        })
+    } else if let Some(cast) = expr.downcast_ref::<TryCastExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::TryCast(Box::new(
+                protobuf::PhysicalTryCastNode {
+                    expr: Some(Box::new(serialize_physical_expr(
+                        cast.expr().to_owned(),
+                        codec,
+                    )?)),
+                    arrow_type: Some(cast.cast_type().try_into()?),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<ScalarFunctionExpr>() {
+        let args: Vec<protobuf::PhysicalExprNode> = expr
+            .args()
+            .iter()
+            .map(|e| serialize_physical_expr(e.to_owned(), codec))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Ok(fun) = BuiltinScalarFunction::from_str(expr.name()) {
+            let fun: protobuf::ScalarFunction = (&fun).try_into()?;
 
             Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::BinaryExpr(
-                    binary_expr,
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<CaseExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::Case(
-                        Box::new(
-                            protobuf::PhysicalCaseNode {
-                                expr: expr
-                                    .expr()
-                                    .map(|exp| exp.clone().try_into().map(Box::new))
-                                    .transpose()?,
-                                when_then_expr: expr
-                                    .when_then_expr()
-                                    .iter()
-                                    .map(|(when_expr, then_expr)| {
-                                        try_parse_when_then_expr(when_expr, then_expr)
-                                    })
-                                    .collect::<Result<
-                                        Vec<protobuf::PhysicalWhenThen>,
-                                        Self::Error,
-                                    >>()?,
-                                else_expr: expr
-                                    .else_expr()
-                                    .map(|a| a.clone().try_into().map(Box::new))
-                                    .transpose()?,
-                            },
-                        ),
-                    ),
-                ),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<NotExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::NotExpr(
-                    Box::new(protobuf::PhysicalNot {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<IsNullExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::IsNullExpr(
-                    Box::new(protobuf::PhysicalIsNull {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<IsNotNullExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::IsNotNullExpr(
-                    Box::new(protobuf::PhysicalIsNotNull {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<InListExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::InList(
-                        Box::new(
-                            protobuf::PhysicalInListNode {
-                                expr: Some(Box::new(expr.expr().to_owned().try_into()?)),
-                                list: expr
-                                    .list()
-                                    .iter()
-                                    .map(|a| a.clone().try_into())
-                                    .collect::<Result<
-                                    Vec<protobuf::PhysicalExprNode>,
-                                    Self::Error,
-                                >>()?,
-                                negated: expr.negated(),
-                            },
-                        ),
-                    ),
-                ),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<NegativeExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Negative(
-                    Box::new(protobuf::PhysicalNegativeNode {
-                        expr: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                    }),
-                )),
-            })
-        } else if let Some(lit) = expr.downcast_ref::<Literal>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Literal(
-                    lit.value().try_into()?,
-                )),
-            })
-        } else if let Some(cast) = expr.downcast_ref::<CastExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::Cast(Box::new(
-                    protobuf::PhysicalCastNode {
-                        expr: Some(Box::new(cast.expr().clone().try_into()?)),
-                        arrow_type: Some(cast.cast_type().try_into()?),
+                expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarFunction(
+                    protobuf::PhysicalScalarFunctionNode {
+                        name: expr.name().to_string(),
+                        fun: fun.into(),
+                        args,
+                        return_type: Some(expr.return_type().try_into()?),
                     },
-                ))),
-            })
-        } else if let Some(cast) = expr.downcast_ref::<TryCastExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::TryCast(
-                    Box::new(protobuf::PhysicalTryCastNode {
-                        expr: Some(Box::new(cast.expr().clone().try_into()?)),
-                        arrow_type: Some(cast.cast_type().try_into()?),
-                    }),
                 )),
             })
-        } else if let Some(expr) = expr.downcast_ref::<ScalarFunctionExpr>() {
-            let args: Vec<protobuf::PhysicalExprNode> = expr
-                .args()
-                .iter()
-                .map(|e| e.to_owned().try_into())
-                .collect::<Result<Vec<_>, _>>()?;
-            if let Ok(fun) = BuiltinScalarFunction::from_str(expr.name()) {
-                let fun: protobuf::ScalarFunction = (&fun).try_into()?;
-
-                Ok(protobuf::PhysicalExprNode {
-                    expr_type: Some(
-                        protobuf::physical_expr_node::ExprType::ScalarFunction(
-                            protobuf::PhysicalScalarFunctionNode {
-                                name: expr.name().to_string(),
-                                fun: fun.into(),
-                                args,
-                                return_type: Some(expr.return_type().try_into()?),
-                            },
-                        ),
-                    ),
-                })
-            } else {
-                Ok(protobuf::PhysicalExprNode {
-                    expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarUdf(
-                        protobuf::PhysicalScalarUdfNode {
-                            name: expr.name().to_string(),
-                            args,
-                            return_type: Some(expr.return_type().try_into()?),
-                        },
-                    )),
-                })
+        } else {
+            let mut fun_definition = vec![];
+            if let Some(udf) = expr.udf() {
+                codec.try_encode_udf(udf, &mut fun_definition)?;
             }
-        } else if let Some(expr) = expr.downcast_ref::<DateTimeIntervalExpr>() {
-            let dti_expr = Box::new(protobuf::PhysicalDateTimeIntervalExprNode {
-                l: Some(Box::new(expr.lhs().to_owned().try_into()?)),
-                r: Some(Box::new(expr.rhs().to_owned().try_into()?)),
-                op: format!("{:?}", expr.op()),
-            });
-
             Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::DateTimeIntervalExpr(
-                        dti_expr,
-                    ),
-                ),
-            })
-        } else if let Some(expr) = expr.downcast_ref::<LikeExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(protobuf::physical_expr_node::ExprType::LikeExpr(
-                    Box::new(protobuf::PhysicalLikeExprNode {
-                        negated: expr.negated(),
-                        case_insensitive: expr.case_insensitive(),
-                        expr: Some(Box::new(expr.expr().to_owned().try_into()?)),
-                        pattern: Some(Box::new(expr.pattern().to_owned().try_into()?)),
-                    }),
+                expr_type: Some(protobuf::physical_expr_node::ExprType::ScalarUdf(
+                    protobuf::PhysicalScalarUdfNode {
+                        name: expr.name().to_string(),
+                        args,
+                        return_type: Some(expr.return_type().try_into()?),
+                        fun_definition,
+                    },
                 )),
             })
-        } else if let Some(expr) = expr.downcast_ref::<GetIndexedFieldExpr>() {
-            Ok(protobuf::PhysicalExprNode {
-                expr_type: Some(
-                    protobuf::physical_expr_node::ExprType::GetIndexedFieldExpr(
-                        Box::new(protobuf::PhysicalGetIndexedFieldExprNode {
-                            arg: Some(Box::new(expr.arg().to_owned().try_into()?)),
-                            key: Some(ScalarValue::try_from(expr.key())?),
-                        }),
-                    ),
-                ),
-            })
-        } else {
-            Err(DataFusionError::Internal(format!(
-                "physical_plan::to_proto() unsupported expression {value:?}"
-            )))
         }
+    } else if let Some(expr) = expr.downcast_ref::<DateTimeIntervalExpr>() {
+        let dti_expr = Box::new(protobuf::PhysicalDateTimeIntervalExprNode {
+            l: Some(Box::new(serialize_physical_expr(
+                expr.lhs().to_owned(),
+                codec,
+            )?)),
+            r: Some(Box::new(serialize_physical_expr(
+                expr.rhs().to_owned(),
+                codec,
+            )?)),
+            op: format!("{:?}", expr.op()),
+        });
+
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(
+                protobuf::physical_expr_node::ExprType::DateTimeIntervalExpr(dti_expr),
+            ),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<LikeExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::LikeExpr(Box::new(
+                protobuf::PhysicalLikeExprNode {
+                    negated: expr.negated(),
+                    case_insensitive: expr.case_insensitive(),
+                    expr: Some(Box::new(serialize_physical_expr(
+                        expr.expr().to_owned(),
+                        codec,
+                    )?)),
+                    pattern: Some(Box::new(serialize_physical_expr(
+                        expr.pattern().to_owned(),
+                        codec,
+                    )?)),
+                },
+            ))),
+        })
+    } else if let Some(expr) = expr.downcast_ref::<GetIndexedFieldExpr>() {
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::GetIndexedFieldExpr(
+                Box::new(protobuf::PhysicalGetIndexedFieldExprNode {
+                    arg: Some(Box::new(serialize_physical_expr(
+                        expr.arg().to_owned(),
+                        codec,
+                    )?)),
+                    key: Some(ScalarValue::try_from(expr.key())?),
+                }),
+            )),
+        })
+    } else {
+        let mut buf = vec![];
+        codec.try_encode_expr(value.clone(), &mut buf).map_err(|e| {
+            DataFusionError::Internal(format!(
+                "Unsupported physical expression and extension codec failed with [{e}]. Expression: {value:?}"
+            ))
+        })?;
+        let inputs = value
+            .children()
+            .into_iter()
+            .map(|e| serialize_physical_expr(e, codec))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(protobuf::PhysicalExprNode {
+            expr_type: Some(protobuf::physical_expr_node::ExprType::Extension(
+                protobuf::PhysicalExtensionExprNode { expr: buf, inputs },
+            )),
+        })
     }
 }
 
+/// Serializes a physical sort expression
+pub fn serialize_physical_sort_expr(
+    sort_expr: &PhysicalSortExpr,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalSortExprNode> {
+    Ok(protobuf::PhysicalSortExprNode {
+        expr: Some(Box::new(serialize_physical_expr(
+            sort_expr.expr.clone(),
+            codec,
+        )?)),
+        asc: !sort_expr.options.descending,
+        nulls_first: sort_expr.options.nulls_first,
+    })
+}
+
 fn try_parse_when_then_expr(
     when_expr: &Arc<dyn PhysicalExpr>,
     then_expr: &Arc<dyn PhysicalExpr>,
+    codec: &dyn PhysicalExtensionCodec,
 ) -> Result<protobuf::PhysicalWhenThen> {
     Ok(protobuf::PhysicalWhenThen {
-        when_expr: Some(when_expr.clone().try_into()?),
-        then_expr: Some(then_expr.clone().try_into()?),
+        when_expr: Some(serialize_physical_expr(when_expr.clone(), codec)?),
+        then_expr: Some(serialize_physical_expr(then_expr.clone(), codec)?),
     })
 }
 
@@ -440,54 +669,47 @@ impl From<&Statistics> for protobuf::Statistics {
     }
 }
 
-impl TryFrom<&FileScanConfig> for protobuf::FileScanExecConf {
-    type Error = DataFusionError;
-    fn try_from(
-        conf: &FileScanConfig,
-    ) -> Result<protobuf::FileScanExecConf, Self::Error> {
-        let file_groups = conf
-            .file_groups
-            .iter()
-            .map(|p| p.as_slice().try_into())
-            .collect::<Result<Vec<_>, _>>()?;
+/// Serializes a file scan configuration, using `codec` for the expressions
+/// of its output ordering
+pub fn serialize_file_scan_config(
+    conf: &FileScanConfig,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::FileScanExecConf> {
+    let file_groups = conf
+        .file_groups
+        .iter()
+        .map(|p| p.as_slice().try_into())
+        .collect::<Result<Vec<_>, _>>()?;
 
-        let output_ordering = if let Some(output_ordering) = &conf.output_ordering {
-            output_ordering
-                .iter()
-                .map(|o| {
-                    let expr = o.expr.clone().try_into()?;
-                    Ok(PhysicalSortExprNode {
-                        expr: Some(Box::new(expr)),
-                        asc: !o.options.descending,
-                        nulls_first: o.options.nulls_first,
-                    })
-                })
-                .collect::<Result<Vec<PhysicalSortExprNode>>>()?
-        } else {
-            vec![]
-        };
+    let output_ordering = if let Some(output_ordering) = &conf.output_ordering {
+        output_ordering
+            .iter()
+            .map(|o| serialize_physical_sort_expr(o, codec))
+            .collect::<Result<Vec<PhysicalSortExprNode>>>()?
+    } else {
+        vec![]
+    };
 
-        Ok(protobuf::FileScanExecConf {
-            file_groups,
-            statistics: Some((&conf.statistics).into()),
-            limit: conf.limit.map(|l| protobuf::ScanLimit { limit: l as u32 }),
-            projection: conf
-                .projection
-                .as_ref()
-                .unwrap_or(&vec![])
-                .iter()
-                .map(|n| *n as u32)
-                .collect(),
-            schema: Some(conf.file_schema.as_ref().try_into()?),
-            table_partition_cols: conf
-                .table_partition_cols
-                .iter()
-                .map(|x| x.0.clone())
-                .collect::<Vec<_>>(),
-            object_store_url: conf.object_store_url.to_string(),
-            output_ordering,
-        })
-    }
+    Ok(protobuf::FileScanExecConf {
+        file_groups,
+        statistics: Some((&conf.statistics).into()),
+        limit: conf.limit.map(|l| protobuf::ScanLimit { limit: l as u32 }),
+        projection: conf
+            .projection
+            .as_ref()
+            .unwrap_or(&vec![])
+            .iter()
+            .map(|n| *n as u32)
+            .collect(),
+        schema: Some(conf.file_schema.as_ref().try_into()?),
+        table_partition_cols: conf
+            .table_partition_cols
+            .iter()
+            .map(|x| x.0.clone())
+            .collect::<Vec<_>>(),
+        object_store_url: conf.object_store_url.to_string(),
+        output_ordering,
+    })
 }
 
 impl From<JoinSide> for protobuf::JoinSide {
@@ -498,3 +720,57 @@ impl From<JoinSide> for protobuf::JoinSide {
         }
     }
 }
+
+/// Serializes a join filter
+pub fn serialize_join_filter(
+    filter: &JoinFilter,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::JoinFilter> {
+    let column_indices = filter
+        .column_indices()
+        .iter()
+        .map(|i| {
+            let side: protobuf::JoinSide = i.side.to_owned().into();
+            protobuf::ColumnIndex {
+                index: i.index as u32,
+                side: side.into(),
+            }
+        })
+        .collect();
+    Ok(protobuf::JoinFilter {
+        expression: Some(serialize_physical_expr(
+            filter.expression().to_owned(),
+            codec,
+        )?),
+        column_indices,
+        schema: Some(filter.schema().try_into()?),
+    })
+}
+
+impl From<&CompressionTypeVariant> for protobuf::CompressionTypeVariant {
+    fn from(value: &CompressionTypeVariant) -> Self {
+        match value {
+            CompressionTypeVariant::GZIP => Self::Gzip,
+            CompressionTypeVariant::BZIP2 => Self::Bzip2,
+            CompressionTypeVariant::XZ => Self::Xz,
+            CompressionTypeVariant::ZSTD => Self::Zstd,
+            CompressionTypeVariant::UNCOMPRESSED => Self::Uncompressed,
+        }
+    }
+}
+
+/// Encodes record batches as an Arrow IPC stream
+pub fn serialize_record_batches(
+    schema: &Schema,
+    batches: &[RecordBatch],
+) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    {
+        let mut writer = StreamWriter::try_new(&mut buf, schema)?;
+        for batch in batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+    Ok(buf)
+}
@@ -21,8 +21,8 @@ use datafusion_common::parsers::CompressionTypeVariant;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::{
     ast::{
-        ColumnDef, ColumnOptionDef, ObjectName, Statement as SQLStatement,
-        TableConstraint,
+        ColumnDef, ColumnOptionDef, ObjectName, Query, Statement as SQLStatement,
+        TableConstraint, Value,
     },
     dialect::{keywords::Keyword, Dialect, GenericDialect},
     parser::{Parser, ParserError},
@@ -88,6 +88,58 @@ pub struct DescribeTableStmt {
     pub table_name: ObjectName,
 }
 
+/// The source of a `COPY TO` statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyToSource {
+    /// `COPY table TO ...`
+    Relation(ObjectName),
+    /// `COPY (SELECT ...) TO ...`
+    Query(Query),
+}
+
+impl fmt::Display for CopyToSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopyToSource::Relation(r) => write!(f, "{r}"),
+            CopyToSource::Query(q) => write!(f, "({q})"),
+        }
+    }
+}
+
+/// DataFusion extension DML for `COPY (query) TO 'path' (option value, ...)`
+///
+/// Options are written as a key followed by a value, which is either a
+/// literal, an identifier or a parenthesized list of identifiers, e.g.
+/// `(FORMAT parquet, PARTITION_BY (a, b), COMPRESSION zstd)`. Keys are
+/// converted to lowercase and lists are joined with `,`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyToStatement {
+    /// The data to copy
+    pub source: CopyToSource,
+    /// The path or URL the data is written to
+    pub target: String,
+    /// Target specific options, in the order they were specified
+    pub options: Vec<(String, String)>,
+}
+
+impl fmt::Display for CopyToStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            source,
+            target,
+            options,
+        } = self;
+
+        write!(f, "COPY {source} TO {target}")?;
+        if !options.is_empty() {
+            let options: Vec<_> =
+                options.iter().map(|(k, v)| format!("{k} {v}")).collect();
+            write!(f, " ({})", options.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
 /// DataFusion Statement representations.
 ///
 /// Tokens parsed by [`DFParser`] are converted into these values.
@@ -99,6 +151,8 @@ pub enum Statement {
     CreateExternalTable(CreateExternalTable),
     /// Extension: `DESCRIBE TABLE`
     DescribeTableStmt(DescribeTableStmt),
+    /// Extension: `COPY TO`
+    CopyTo(CopyToStatement),
 }
 
 /// DataFusion SQL Parser based on [`sqlparser`]
@@ -193,6 +247,12 @@ impl<'a> DFParser<'a> {
                         // use custom parsing
                         self.parse_describe()
                     }
+                    Keyword::COPY => {
+                        // move one token forward
+                        self.parser.next_token();
+                        // use custom parsing
+                        self.parse_copy()
+                    }
                     _ => {
                         // use the native parser
                         Ok(Statement::Statement(Box::from(
@@ -218,6 +278,93 @@ impl<'a> DFParser<'a> {
         }))
     }
 
+    /// Parse a SQL `COPY TO` statement
+    pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
+        // parse as a query
+        let source = if self.parser.consume_token(&Token::LParen) {
+            let query = self.parser.parse_query()?;
+            self.parser.expect_token(&Token::RParen)?;
+            CopyToSource::Query(query)
+        } else {
+            // parse as table reference
+            let table_name = self.parser.parse_object_name()?;
+            CopyToSource::Relation(table_name)
+        };
+
+        self.parser.expect_keyword(Keyword::TO)?;
+
+        let target = self.parser.parse_literal_string()?;
+
+        // check for options in parens
+        let options = if self.parser.peek_token().token == Token::LParen {
+            self.parse_value_options()?
+        } else {
+            vec![]
+        };
+
+        Ok(Statement::CopyTo(CopyToStatement {
+            source,
+            target,
+            options,
+        }))
+    }
+
+    /// Parse a parenthesized list of `key value` options, where values
+    /// can be literals, identifiers or parenthesized lists of identifiers
+    fn parse_value_options(&mut self) -> Result<Vec<(String, String)>, ParserError> {
+        let mut options = vec![];
+        self.parser.expect_token(&Token::LParen)?;
+
+        loop {
+            let key = self.parse_option_key()?;
+            let value = if self.parser.peek_token().token == Token::LParen {
+                self.parse_partitions()?.join(",")
+            } else {
+                self.parse_option_value()?
+            };
+            options.push((key, value));
+            let comma = self.parser.consume_token(&Token::Comma);
+            if self.parser.consume_token(&Token::RParen) {
+                // allow a trailing comma, even though it's not in standard
+                break;
+            } else if !comma {
+                return self.expected(
+                    "',' or ')' after option definition",
+                    self.parser.peek_token(),
+                );
+            }
+        }
+        Ok(options)
+    }
+
+    /// Parse an option key, either an identifier or a string literal
+    fn parse_option_key(&mut self) -> Result<String, ParserError> {
+        let token = self.parser.next_token();
+        match token.token {
+            Token::Word(w) => Ok(w.value.to_lowercase()),
+            Token::SingleQuotedString(s) => Ok(s.to_lowercase()),
+            _ => self.expected("option key", token),
+        }
+    }
+
+    /// Parse an option value, either an identifier or a literal
+    fn parse_option_value(&mut self) -> Result<String, ParserError> {
+        let next_token = self.parser.peek_token();
+        match next_token.token {
+            Token::Word(w) => {
+                self.parser.next_token();
+                Ok(w.value)
+            }
+            _ => match self.parser.parse_value()? {
+                Value::SingleQuotedString(s) => Ok(s),
+                Value::DoubleQuotedString(s) => Ok(s),
+                Value::Number(n, _) => Ok(n),
+                Value::Boolean(b) => Ok(b.to_string()),
+                _ => self.expected("option value", next_token),
+            },
+        }
+    }
+
     /// Parse a SQL `CREATE` statement handling `CREATE EXTERNAL TABLE`
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
         if self.parser.parse_keyword(Keyword::EXTERNAL) {
@@ -944,4 +1091,75 @@ mod tests {
             "sql parser error: Unsupported file compression type ZZZ",
         )
     }
+
+    #[test]
+    fn copy_to_table_to_table() -> Result<(), ParserError> {
+        let sql = "COPY foo TO 'bar.parquet'";
+        let expected = Statement::CopyTo(CopyToStatement {
+            source: object_name("foo"),
+            target: "bar.parquet".to_string(),
+            options: vec![],
+        });
+        assert_eq!(verified_stmt(sql), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn copy_to_query_to_table() -> Result<(), ParserError> {
+        let sql = "COPY (SELECT 1) TO 'bar' (FORMAT parquet, PARTITION_BY (a, b), COMPRESSION zstd, row_group_size 10, 'delimiter' '|')";
+        let query = match DFParser::parse_sql("SELECT 1")?.pop_front().unwrap() {
+            Statement::Statement(statement) => match *statement {
+                SQLStatement::Query(query) => *query,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+        let expected = Statement::CopyTo(CopyToStatement {
+            source: CopyToSource::Query(query),
+            target: "bar".to_string(),
+            options: vec![
+                ("format".to_string(), "parquet".to_string()),
+                ("partition_by".to_string(), "a,b".to_string()),
+                ("compression".to_string(), "zstd".to_string()),
+                ("row_group_size".to_string(), "10".to_string()),
+                ("delimiter".to_string(), "|".to_string()),
+            ],
+        });
+        expect_parse_ok(sql, expected)?;
+
+        let sql = "COPY (SELECT 1) TO 'bar' (FORMAT)";
+        expect_parse_error(sql, "found: )");
+
+        let sql = "COPY (SELECT 1) TO 'bar' (FORMAT parquet COMPRESSION zstd)";
+        expect_parse_error(
+            sql,
+            "Expected ',' or ')' after option definition, found: COMPRESSION",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn copy_to_display() {
+        let statement = match verified_stmt(
+            "COPY (SELECT a FROM t) TO 'bar' (format parquet, partition_by a)",
+        ) {
+            Statement::CopyTo(statement) => statement,
+            other => panic!("Expected COPY TO, got {other:?}"),
+        };
+        assert_eq!(
+            statement.to_string(),
+            "COPY (SELECT a FROM t) TO bar (format parquet, partition_by a)"
+        );
+    }
+
+    fn object_name(name: &str) -> CopyToSource {
+        CopyToSource::Relation(ObjectName(vec![Ident::new(name)]))
+    }
+
+    /// Parses `sql` and returns the single resulting statement
+    fn verified_stmt(sql: &str) -> Statement {
+        let mut statements = DFParser::parse_sql(sql).unwrap();
+        assert_eq!(statements.len(), 1);
+        statements.pop_front().unwrap()
+    }
 }
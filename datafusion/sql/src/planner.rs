@@ -33,7 +33,7 @@ use datafusion_common::{OwnedTableReference, TableReference};
 use datafusion_expr::logical_plan::{LogicalPlan, LogicalPlanBuilder};
 use datafusion_expr::utils::find_column_exprs;
 use datafusion_expr::TableSource;
-use datafusion_expr::{col, AggregateUDF, Expr, ScalarUDF, SubqueryAlias};
+use datafusion_expr::{col, AggregateUDF, Expr, ScalarUDF, SubqueryAlias, WindowUDF};
 
 use crate::utils::make_decimal_type;
 
@@ -46,11 +46,29 @@ pub trait ContextProvider {
     fn get_function_meta(&self, name: &str) -> Option<Arc<ScalarUDF>>;
     /// Getter for a UDAF description
     fn get_aggregate_meta(&self, name: &str) -> Option<Arc<AggregateUDF>>;
+    /// Getter for a UDWF description. Providers without user-defined window
+    /// functions don't need to implement it.
+    fn get_window_meta(&self, _name: &str) -> Option<Arc<WindowUDF>> {
+        None
+    }
     /// Getter for system/user-defined variable type
     fn get_variable_type(&self, variable_names: &[String]) -> Option<DataType>;
 
     /// Get configuration options
     fn options(&self) -> &ConfigOptions;
+
+    /// Getter for a table source that reads the work table of the recursive
+    /// query (e.g. a `WITH RECURSIVE` CTE) named `name`, i.e. the rows the
+    /// recursive term produced in its previous iteration
+    fn create_cte_work_table(
+        &self,
+        _name: &str,
+        _schema: SchemaRef,
+    ) -> Result<Arc<dyn TableSource>> {
+        Err(DataFusionError::NotImplemented(
+            "Recursive CTE is not implemented".to_string(),
+        ))
+    }
 }
 
 /// SQL parser options
@@ -15,11 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use crate::utils::normalize_ident;
+use arrow_schema::Schema;
+use datafusion_common::tree_node::{TreeNode, VisitRecursion};
 use datafusion_common::{DataFusionError, Result, ScalarValue};
-use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder};
-use sqlparser::ast::{Expr as SQLExpr, Offset as SQLOffset, OrderByExpr, Query, Value};
+use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder, SubqueryAlias};
+use sqlparser::ast::{
+    Expr as SQLExpr, Offset as SQLOffset, OrderByExpr, Query, SetExpr, SetOperator,
+    SetQuantifier, TableAlias, Value,
+};
 
 use sqlparser::parser::ParserError::ParserError;
 
@@ -44,13 +51,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         let set_expr = query.body;
         if let Some(with) = query.with {
             // Process CTEs from top to bottom
-            // do not allow self-references
-            if with.recursive {
-                return Err(DataFusionError::NotImplemented(
-                    "Recursive CTEs are not supported".to_string(),
-                ));
-            }
-
+            let is_recursive = with.recursive;
             for cte in with.cte_tables {
                 // A `WITH` block can't use the same name more than once
                 let cte_name = normalize_ident(cte.alias.name.clone());
@@ -59,14 +60,24 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                         "WITH query name {cte_name:?} specified more than once"
                     ))));
                 }
-                // create logical plan & pass backreferencing CTEs
-                // CTE expr don't need extend outer_query_schema
-                let logical_plan =
-                    self.query_to_plan(*cte.query, &mut planner_context.clone())?;
 
-                // Each `WITH` block can change the column names in the last
-                // projection (e.g. "WITH table(t1, t2) AS SELECT 1, 2").
-                let logical_plan = self.apply_table_alias(logical_plan, cte.alias)?;
+                let logical_plan = if is_recursive {
+                    self.recursive_cte_to_plan(
+                        cte_name.clone(),
+                        *cte.query,
+                        cte.alias,
+                        &mut planner_context.clone(),
+                    )?
+                } else {
+                    // create logical plan & pass backreferencing CTEs
+                    // CTE expr don't need extend outer_query_schema
+                    let logical_plan =
+                        self.query_to_plan(*cte.query, &mut planner_context.clone())?;
+
+                    // Each `WITH` block can change the column names in the last
+                    // projection (e.g. "WITH table(t1, t2) AS SELECT 1, 2").
+                    self.apply_table_alias(logical_plan, cte.alias)?
+                };
 
                 planner_context.ctes.insert(cte_name, logical_plan);
             }
@@ -76,6 +87,81 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         self.limit(plan, query.offset, query.limit)
     }
 
+    /// Generate a logical plan for a CTE of a `WITH RECURSIVE` block.
+    ///
+    /// A recursive CTE is a `UNION [ALL]` of a static term, which must not
+    /// reference the CTE, and a recursive term that reads the rows produced
+    /// by its previous iteration through the CTE name. CTEs of the block
+    /// that are not of that form are planned like regular CTEs.
+    fn recursive_cte_to_plan(
+        &self,
+        cte_name: String,
+        cte_query: Query,
+        alias: TableAlias,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let is_plain_union = cte_query.with.is_none()
+            && cte_query.order_by.is_empty()
+            && cte_query.limit.is_none()
+            && cte_query.offset.is_none()
+            && cte_query.fetch.is_none();
+        let (left, right, set_quantifier) = match *cte_query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                left,
+                right,
+                set_quantifier,
+            } if is_plain_union => (left, right, set_quantifier),
+            body => {
+                let query = Query {
+                    body: Box::new(body),
+                    ..cte_query
+                };
+                let logical_plan = self.query_to_plan(query, planner_context)?;
+                return self.apply_table_alias(logical_plan, alias);
+            }
+        };
+
+        // The static term can't reference the CTE itself, and its (aliased)
+        // columns define the columns of the work table
+        let static_plan = self.set_expr_to_plan(*left, &mut planner_context.clone())?;
+        let static_plan = self.apply_table_alias(static_plan, alias)?;
+
+        let work_table_schema = Arc::new(Schema::from(static_plan.schema().as_ref()));
+        let work_table_source = self
+            .schema_provider
+            .create_cte_work_table(&cte_name, work_table_schema)?;
+        let work_table_plan =
+            LogicalPlanBuilder::scan(cte_name.clone(), work_table_source, None)?
+                .build()?;
+
+        let mut recursive_context = planner_context.clone();
+        recursive_context
+            .ctes
+            .insert(cte_name.clone(), work_table_plan);
+        let recursive_plan = self.set_expr_to_plan(*right, &mut recursive_context)?;
+
+        if !references_table(&recursive_plan, &cte_name) {
+            // Not actually recursive, plan it as a regular CTE
+            let static_plan = LogicalPlanBuilder::from(static_plan);
+            let plan = match set_quantifier {
+                SetQuantifier::All => static_plan.union(recursive_plan)?,
+                SetQuantifier::Distinct | SetQuantifier::None => {
+                    static_plan.union_distinct(recursive_plan)?
+                }
+            };
+            return Ok(LogicalPlan::SubqueryAlias(SubqueryAlias::try_new(
+                plan.build()?,
+                cte_name,
+            )?));
+        }
+
+        let is_distinct = !matches!(set_quantifier, SetQuantifier::All);
+        LogicalPlanBuilder::from(static_plan)
+            .to_recursive_query(cte_name, recursive_plan, is_distinct)?
+            .build()
+    }
+
     /// Wrap a plan in a limit
     fn limit(
         &self,
@@ -151,3 +237,21 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         LogicalPlanBuilder::from(plan).sort(order_by_rex)?.build()
     }
 }
+
+/// Returns true if `plan` scans the table `name`, e.g. the work table of a
+/// recursive CTE
+fn references_table(plan: &LogicalPlan, name: &str) -> bool {
+    let mut found = false;
+    plan.apply(&mut |plan| {
+        if let LogicalPlan::TableScan(scan) = plan {
+            if scan.table_name.table() == name && scan.table_name.schema().is_none() {
+                found = true;
+                return Ok(VisitRecursion::Stop);
+            }
+        }
+        Ok(VisitRecursion::Continue)
+    })
+    // closure always returns OK
+    .unwrap();
+    found
+}
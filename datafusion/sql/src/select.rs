@@ -52,9 +52,6 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         if !select.lateral_views.is_empty() {
             return Err(DataFusionError::NotImplemented("LATERAL VIEWS".to_string()));
         }
-        if select.qualify.is_some() {
-            return Err(DataFusionError::NotImplemented("QUALIFY".to_string()));
-        }
         if select.top.is_some() {
             return Err(DataFusionError::NotImplemented("TOP".to_string()));
         }
@@ -112,6 +109,24 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             })
             .transpose()?;
 
+        // Optionally the QUALIFY expression, which filters on the results of
+        // window functions. Like HAVING, it may refer to aliased columns, e.g.
+        //
+        //   SELECT c1, ROW_NUMBER() OVER (PARTITION BY c1) AS rn FROM t QUALIFY rn = 1;
+        //
+        let qualify_expr_opt = select
+            .qualify
+            .map::<Result<Expr>, _>(|qualify_expr| {
+                let qualify_expr = self.sql_expr_to_logical_expr(
+                    qualify_expr,
+                    &combined_schema,
+                    planner_context,
+                )?;
+                let qualify_expr = resolve_aliases_to_exprs(&qualify_expr, &alias_map)?;
+                normalize_col(qualify_expr, &projected_plan)
+            })
+            .transpose()?;
+
         // The outer expressions we will search through for
         // aggregates. Aggregates may be sourced from the SELECT...
         let mut aggr_expr_haystack = select_exprs.clone();
@@ -119,6 +134,10 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         if let Some(having_expr) = &having_expr_opt {
             aggr_expr_haystack.push(having_expr.clone());
         }
+        // ... or from the QUALIFY.
+        if let Some(qualify_expr) = &qualify_expr_opt {
+            aggr_expr_haystack.push(qualify_expr.clone());
+        }
 
         // All of the aggregate expressions (deduplicated).
         let aggr_exprs = find_aggregate_exprs(&aggr_expr_haystack);
@@ -149,14 +168,17 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             .collect::<Result<Vec<Expr>>>()?;
 
         // process group by, aggregation or having
-        let (plan, mut select_exprs_post_aggr, having_expr_post_aggr) = if !group_by_exprs
-            .is_empty()
-            || !aggr_exprs.is_empty()
-        {
+        let (
+            plan,
+            mut select_exprs_post_aggr,
+            having_expr_post_aggr,
+            qualify_expr_post_aggr,
+        ) = if !group_by_exprs.is_empty() || !aggr_exprs.is_empty() {
             self.aggregate(
                 plan,
                 &select_exprs,
                 having_expr_opt.as_ref(),
+                qualify_expr_opt.as_ref(),
                 group_by_exprs,
                 aggr_exprs,
             )?
@@ -164,7 +186,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             match having_expr_opt {
                 Some(having_expr) => return Err(DataFusionError::Plan(
                     format!("HAVING clause references: {having_expr} must appear in the GROUP BY clause or be used in an aggregate function"))),
-                None => (plan, select_exprs, having_expr_opt)
+                None => (plan, select_exprs, having_expr_opt, qualify_expr_opt)
             }
         };
 
@@ -176,8 +198,12 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             plan
         };
 
-        // process window function
-        let window_func_exprs = find_window_exprs(&select_exprs_post_aggr);
+        // process window function, which may be sourced from the SELECT or the QUALIFY
+        let mut window_expr_haystack = select_exprs_post_aggr.clone();
+        if let Some(qualify_expr_post_aggr) = &qualify_expr_post_aggr {
+            window_expr_haystack.push(qualify_expr_post_aggr.clone());
+        }
+        let window_func_exprs = find_window_exprs(&window_expr_haystack);
 
         let plan = if window_func_exprs.is_empty() {
             plan
@@ -193,6 +219,22 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             plan
         };
 
+        // process QUALIFY, which filters on the output of the window functions
+        let plan = if let Some(qualify_expr_post_aggr) = qualify_expr_post_aggr {
+            if window_func_exprs.is_empty() {
+                return Err(DataFusionError::Plan(
+                    "QUALIFY clause requires window functions in the SELECT list or QUALIFY clause".to_string(),
+                ));
+            }
+            let qualify_expr =
+                rebase_expr(&qualify_expr_post_aggr, &window_func_exprs, &plan)?;
+            LogicalPlanBuilder::from(plan)
+                .filter(qualify_expr)?
+                .build()?
+        } else {
+            plan
+        };
+
         // final projection
         let plan = project(plan, select_exprs_post_aggr)?;
 
@@ -413,9 +455,10 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         input: LogicalPlan,
         select_exprs: &[Expr],
         having_expr_opt: Option<&Expr>,
+        qualify_expr_opt: Option<&Expr>,
         group_by_exprs: Vec<Expr>,
         aggr_exprs: Vec<Expr>,
-    ) -> Result<(LogicalPlan, Vec<Expr>, Option<Expr>)> {
+    ) -> Result<(LogicalPlan, Vec<Expr>, Option<Expr>, Option<Expr>)> {
         // create the aggregate plan
         let plan = LogicalPlanBuilder::from(input.clone())
             .aggregate(group_by_exprs.clone(), aggr_exprs.clone())?
@@ -491,6 +534,28 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             None
         };
 
-        Ok((plan, select_exprs_post_aggr, having_expr_post_aggr))
+        // Rewrite the QUALIFY expression to use the columns produced by the
+        // aggregation.
+        let qualify_expr_post_aggr = if let Some(qualify_expr) = qualify_expr_opt {
+            let qualify_expr_post_aggr =
+                rebase_expr(qualify_expr, &aggr_projection_exprs, &input)?;
+
+            check_columns_satisfy_exprs(
+                &column_exprs_post_aggr,
+                &[qualify_expr_post_aggr.clone()],
+                "QUALIFY clause references non-aggregate values",
+            )?;
+
+            Some(qualify_expr_post_aggr)
+        } else {
+            None
+        };
+
+        Ok((
+            plan,
+            select_exprs_post_aggr,
+            having_expr_post_aggr,
+            qualify_expr_post_aggr,
+        ))
     }
 }
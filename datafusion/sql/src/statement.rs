@@ -16,7 +16,8 @@
 // under the License.
 
 use crate::parser::{
-    CreateExternalTable, DFParser, DescribeTableStmt, Statement as DFStatement,
+    CopyToSource, CopyToStatement, CreateExternalTable, DFParser, DescribeTableStmt,
+    Statement as DFStatement,
 };
 use crate::planner::{
     object_name_to_qualifier, ContextProvider, PlannerContext, SqlToRel,
@@ -33,7 +34,7 @@ use datafusion_expr::logical_plan::builder::project;
 use datafusion_expr::logical_plan::{Analyze, Prepare};
 use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
-    cast, col, CreateCatalog, CreateCatalogSchema,
+    cast, col, CopyTo, CreateCatalog, CreateCatalogSchema,
     CreateExternalTable as PlanCreateExternalTable, CreateMemoryTable, CreateView,
     DescribeTable, DmlStatement, DropTable, DropView, EmptyRelation, Explain,
     ExprSchemable, Filter, LogicalPlan, LogicalPlanBuilder, PlanType, SetVariable,
@@ -82,6 +83,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             DFStatement::CreateExternalTable(s) => self.external_table_to_plan(s),
             DFStatement::Statement(s) => self.sql_statement_to_plan(*s),
             DFStatement::DescribeTableStmt(s) => self.describe_table_to_plan(s),
+            DFStatement::CopyTo(s) => self.copy_to_plan(s),
         }
     }
 
@@ -445,6 +447,84 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         }))
     }
 
+    fn copy_to_plan(&self, statement: CopyToStatement) -> Result<LogicalPlan> {
+        let CopyToStatement {
+            source,
+            target,
+            options,
+        } = statement;
+
+        // determine the input plan
+        let input = match source {
+            CopyToSource::Relation(object_name) => {
+                let table_ref =
+                    self.object_name_to_table_reference(object_name.clone())?;
+                let provider = self.schema_provider.get_table_provider(table_ref)?;
+                LogicalPlanBuilder::scan(
+                    object_name_to_string(&object_name),
+                    provider,
+                    None,
+                )?
+                .build()?
+            }
+            CopyToSource::Query(query) => {
+                self.query_to_plan(query, &mut PlannerContext::new())?
+            }
+        };
+
+        // split the well known options from the format specific ones
+        let mut file_format = None;
+        let mut partition_by = vec![];
+        let mut format_options = vec![];
+        for (key, value) in options {
+            match key.as_str() {
+                "format" => file_format = Some(value.to_uppercase()),
+                "partition_by" => {
+                    partition_by = value
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                }
+                _ => format_options.push((key, value)),
+            }
+        }
+
+        // infer the format from the file extension if not specified
+        let file_format = match file_format {
+            Some(file_format) => file_format,
+            None => target
+                .trim_end_matches('/')
+                .rsplit_once('.')
+                .map(|(_, extension)| extension.to_uppercase())
+                .filter(|extension| {
+                    matches!(extension.as_str(), "PARQUET" | "CSV" | "JSON")
+                })
+                .ok_or_else(|| {
+                    DataFusionError::Plan(format!(
+                        "Format not explicitly set and unable to get file extension for COPY TO '{target}'. Use the FORMAT option"
+                    ))
+                })?,
+        };
+
+        let input_schema = input.schema();
+        for column in &partition_by {
+            if input_schema.field_with_unqualified_name(column).is_err() {
+                return Err(DataFusionError::Plan(format!(
+                    "Partition column '{column}' not found in the input of COPY TO"
+                )));
+            }
+        }
+
+        Ok(LogicalPlan::Copy(CopyTo::try_new(
+            Arc::new(input),
+            target,
+            file_format,
+            partition_by,
+            format_options,
+        )?))
+    }
+
     fn build_order_by(
         &self,
         order_exprs: Vec<OrderByExpr>,
@@ -283,6 +283,47 @@ fn update_column_does_not_exist(#[case] sql: &str) {
     assert_field_not_found(err, "doesnotexist");
 }
 
+#[test]
+fn plan_copy_to() {
+    let sql = "COPY test_decimal TO 'output.csv' (delimiter '|')";
+    let plan = r#"
+CopyTo: format=CSV output_url=output.csv partition_by=[] options: (delimiter |)
+  TableScan: test_decimal
+    "#
+    .trim();
+    quick_test(sql, plan);
+}
+
+#[test]
+fn plan_copy_query_to() {
+    let sql = "COPY (SELECT id, first_name FROM person WHERE id > 1) TO 'output/' (FORMAT parquet, PARTITION_BY (first_name), COMPRESSION zstd)";
+    let plan = r#"
+CopyTo: format=PARQUET output_url=output/ partition_by=[first_name] options: (compression zstd)
+  Projection: person.id, person.first_name
+    Filter: person.id > Int64(1)
+      TableScan: person
+    "#
+    .trim();
+    quick_test(sql, plan);
+}
+
+#[test]
+fn plan_copy_to_errors() {
+    let sql = "COPY person TO 'output'";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Plan(\"Format not explicitly set and unable to get file extension for COPY TO 'output'. Use the FORMAT option\")",
+        format!("{err:?}")
+    );
+
+    let sql = "COPY person TO 'output/' (FORMAT csv, PARTITION_BY (nope))";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Plan(\"Partition column 'nope' not found in the input of COPY TO\")",
+        format!("{err:?}")
+    );
+}
+
 #[test]
 fn plan_delete() {
     let sql = "delete from person where id=1";
@@ -1164,11 +1205,35 @@ fn recursive_ctes() {
               select n + 1 FROM numbers WHERE N < 10
         )
         select * from numbers;";
-    let err = logical_plan(sql).expect_err("query should have failed");
-    assert_eq!(
-        r#"NotImplemented("Recursive CTEs are not supported")"#,
-        format!("{err:?}")
-    );
+    let expected = "Projection: numbers.n\
+        \n  RecursiveQuery: name=numbers, is_distinct=false\
+        \n    SubqueryAlias: numbers\
+        \n      Projection: Int64(1) AS n\
+        \n        EmptyRelation\
+        \n    Projection: numbers.n + Int64(1)\
+        \n      Filter: numbers.n < Int64(10)\
+        \n        TableScan: numbers";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn recursive_ctes_without_self_reference() {
+    let sql = "
+        WITH RECURSIVE numbers AS (
+              select 1 as n
+            UNION ALL
+              select 2
+        )
+        select * from numbers;";
+    let expected = "Projection: numbers.n\
+        \n  SubqueryAlias: numbers\
+        \n    Union\
+        \n      SubqueryAlias: numbers\
+        \n        Projection: Int64(1) AS n\
+        \n          EmptyRelation\
+        \n      Projection: Int64(2) AS n\
+        \n        EmptyRelation";
+    quick_test(sql, expected);
 }
 
 #[test]
@@ -2224,6 +2289,54 @@ fn over_order_by_sort_keys_sorting_global_order_compacting() {
     quick_test(sql, expected);
 }
 
+#[test]
+fn qualify_with_alias() {
+    let sql =
+        "SELECT order_id, ROW_NUMBER() OVER (PARTITION BY order_id ORDER BY qty) AS rn \
+        FROM orders QUALIFY rn = 1";
+    let expected = "\
+        Projection: orders.order_id, ROW_NUMBER() PARTITION BY [orders.order_id] ORDER BY [orders.qty ASC NULLS LAST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW AS rn\
+        \n  Filter: ROW_NUMBER() PARTITION BY [orders.order_id] ORDER BY [orders.qty ASC NULLS LAST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW = Int64(1)\
+        \n    WindowAggr: windowExpr=[[ROW_NUMBER() PARTITION BY [orders.order_id] ORDER BY [orders.qty ASC NULLS LAST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW]]\
+        \n      TableScan: orders";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn qualify_window_function_not_in_projection() {
+    let sql = "SELECT order_id FROM orders \
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY customer_id ORDER BY qty) = 1";
+    let expected = "\
+        Projection: orders.order_id\
+        \n  Filter: ROW_NUMBER() PARTITION BY [orders.customer_id] ORDER BY [orders.qty ASC NULLS LAST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW = Int64(1)\
+        \n    WindowAggr: windowExpr=[[ROW_NUMBER() PARTITION BY [orders.customer_id] ORDER BY [orders.qty ASC NULLS LAST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW]]\
+        \n      TableScan: orders";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn qualify_with_aggregate() {
+    let sql = "SELECT customer_id, SUM(qty) FROM orders GROUP BY customer_id \
+        QUALIFY RANK() OVER (ORDER BY SUM(qty) DESC) <= 3";
+    let expected = "\
+        Projection: orders.customer_id, SUM(orders.qty)\
+        \n  Filter: RANK() ORDER BY [SUM(orders.qty) DESC NULLS FIRST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW <= Int64(3)\
+        \n    WindowAggr: windowExpr=[[RANK() ORDER BY [SUM(orders.qty) DESC NULLS FIRST] RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW]]\
+        \n      Aggregate: groupBy=[[orders.customer_id]], aggr=[[SUM(orders.qty)]]\
+        \n        TableScan: orders";
+    quick_test(sql, expected);
+}
+
+#[test]
+fn qualify_without_window_function() {
+    let sql = "SELECT order_id FROM orders QUALIFY order_id > 1";
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_eq!(
+        "Plan(\"QUALIFY clause requires window functions in the SELECT list or QUALIFY clause\")",
+        format!("{err:?}")
+    );
+}
+
 /// psql result
 /// ```text
 ///                               QUERY PLAN
@@ -2568,6 +2681,14 @@ impl ContextProvider for MockContextProvider {
     fn options(&self) -> &ConfigOptions {
         &self.options
     }
+
+    fn create_cte_work_table(
+        &self,
+        _name: &str,
+        schema: SchemaRef,
+    ) -> Result<Arc<dyn TableSource>> {
+        Ok(Arc::new(EmptyTable::new(schema)))
+    }
 }
 
 #[test]
@@ -16,19 +16,22 @@
 // under the License.
 
 use async_recursion::async_recursion;
-use datafusion::arrow::datatypes::DataType;
+use datafusion::arrow::datatypes::{DataType, Field, TimeUnit};
 use datafusion::common::{DFField, DFSchema, DFSchemaRef};
 use datafusion::logical_expr::{
     aggregate_function, window_function::find_df_window_func, BinaryExpr, Case, Expr,
     LogicalPlan, Operator,
 };
-use datafusion::logical_expr::{build_join_schema, LogicalPlanBuilder};
+use datafusion::logical_expr::{
+    build_join_schema, EmptyRelation, LogicalPlanBuilder, TableProviderFilterPushDown,
+    Values,
+};
 use datafusion::logical_expr::{expr, Cast, WindowFrameBound, WindowFrameUnits};
 use datafusion::prelude::JoinType;
 use datafusion::sql::TableReference;
 use datafusion::{
     error::{DataFusionError, Result},
-    optimizer::utils::split_conjunction,
+    optimizer::utils::{conjunction, split_conjunction},
     prelude::{Column, SessionContext},
     scalar::ScalarValue,
 };
@@ -38,7 +41,7 @@ use substrait::proto::{
         field_reference::ReferenceType::DirectReference, literal::LiteralType,
         reference_segment::ReferenceType::StructField,
         window_function::bound as SubstraitBound,
-        window_function::bound::Kind as BoundKind, window_function::Bound,
+        window_function::bound::Kind as BoundKind, window_function::Bound, Literal,
         MaskExpression, RexType,
     },
     extensions::simple_extension_declaration::MappingType,
@@ -46,8 +49,9 @@ use substrait::proto::{
     join_rel, plan_rel, r#type,
     read_rel::ReadType,
     rel::RelType,
+    set_rel,
     sort_field::{SortDirection, SortKind::*},
-    AggregateFunction, Expression, Plan, Rel, Type,
+    AggregateFunction, Expression, NamedStruct, Plan, Rel, Type,
 };
 use substrait::proto::{FunctionArgument, SortField};
 
@@ -121,7 +125,8 @@ pub async fn from_substrait_plan(
                         Ok(from_substrait_rel(ctx, rel, &function_extension).await?)
                     },
                     plan_rel::RelType::Root(root) => {
-                        Ok(from_substrait_rel(ctx, root.input.as_ref().unwrap(), &function_extension).await?)
+                        let plan = from_substrait_rel(ctx, root.input.as_ref().unwrap(), &function_extension).await?;
+                        rename_output_fields(plan, &root.names)
                     }
                 },
                 None => Err(DataFusionError::Internal("Cannot parse plan relation: None".to_string()))
@@ -134,6 +139,31 @@ pub async fn from_substrait_plan(
     }
 }
 
+/// Rename the output fields of `plan` to the names of the root relation,
+/// as aliases are not part of Substrait expressions
+fn rename_output_fields(plan: LogicalPlan, names: &[String]) -> Result<LogicalPlan> {
+    let fields = plan.schema().fields();
+    // names of nested fields are also listed, so only flat schemas are renamed
+    if fields.len() != names.len()
+        || fields.iter().zip(names).all(|(f, name)| f.name() == name)
+    {
+        return Ok(plan);
+    }
+    let exprs = fields
+        .iter()
+        .zip(names)
+        .map(|(f, name)| {
+            let expr = Expr::Column(f.qualified_column());
+            if f.name() == name {
+                expr
+            } else {
+                expr.alias(name)
+            }
+        })
+        .collect::<Vec<_>>();
+    LogicalPlanBuilder::from(plan).project(exprs)?.build()
+}
+
 /// Convert Substrait Rel to DataFusion DataFrame
 #[async_recursion]
 pub async fn from_substrait_rel(
@@ -144,27 +174,26 @@ pub async fn from_substrait_rel(
     match &rel.rel_type {
         Some(RelType::Project(p)) => {
             if let Some(input) = p.input.as_ref() {
-                let mut input = LogicalPlanBuilder::from(
-                    from_substrait_rel(ctx, input, extensions).await?,
-                );
+                let input = from_substrait_rel(ctx, input, extensions).await?;
                 let mut exprs: Vec<Expr> = vec![];
+                let mut window_exprs: Vec<Expr> = vec![];
                 for e in &p.expressions {
-                    let x =
-                        from_substrait_rex(e, input.clone().schema(), extensions).await?;
-                    // if the expression is WindowFunction, wrap in a Window relation
-                    //   before returning and do not add to list of this Projection's expression list
-                    // otherwise, add expression to the Projection's expression list
-                    match &*x {
-                        Expr::WindowFunction(_) => {
-                            input = input.window(vec![x.as_ref().clone()])?;
-                            exprs.push(x.as_ref().clone());
-                        }
-                        _ => {
-                            exprs.push(x.as_ref().clone());
-                        }
+                    let x = from_substrait_rex(e, input.schema(), extensions).await?;
+                    // WindowFunctions are computed by Window relations below the
+                    // Projection, which then refers to their output
+                    if let Expr::WindowFunction(_) = x.as_ref() {
+                        window_exprs.push(x.as_ref().clone());
                     }
+                    exprs.push(x.as_ref().clone());
                 }
-                input.project(exprs)?.build()
+                // window functions with the same window are computed by a
+                // single Window relation, as when planning SQL
+                let input = if window_exprs.is_empty() {
+                    input
+                } else {
+                    LogicalPlanBuilder::window_plan(input, window_exprs)?
+                };
+                LogicalPlanBuilder::from(input).project(exprs)?.build()
             } else {
                 Err(DataFusionError::NotImplemented(
                     "Projection without an input is not supported".to_string(),
@@ -293,9 +322,10 @@ pub async fn from_substrait_rel(
             let left = LogicalPlanBuilder::from(
                 from_substrait_rel(ctx, join.left.as_ref().unwrap(), extensions).await?,
             );
-            let right = LogicalPlanBuilder::from(
+            let right = LogicalPlanBuilder::from(disambiguate_join_input(
+                left.schema(),
                 from_substrait_rel(ctx, join.right.as_ref().unwrap(), extensions).await?,
-            );
+            )?);
             let join_type = from_substrait_jointype(join.r#type)?;
             let schema =
                 build_join_schema(left.schema(), right.schema(), &JoinType::Inner)?;
@@ -305,44 +335,56 @@ pub async fn from_substrait_rel(
                 extensions,
             )
             .await?;
-            let predicates = split_conjunction(&on);
-            // TODO: collect only one null_eq_null
-            let join_exprs: Vec<(Column, Column, bool)> = predicates
-                .iter()
-                .map(|p| match p {
-                    Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
-                        match (left.as_ref(), right.as_ref()) {
-                            (Expr::Column(l), Expr::Column(r)) => match op {
-                                Operator::Eq => Ok((l.clone(), r.clone(), false)),
-                                Operator::IsNotDistinctFrom => {
-                                    Ok((l.clone(), r.clone(), true))
-                                }
-                                _ => Err(DataFusionError::Internal(
-                                    "invalid join condition op".to_string(),
-                                )),
-                            },
-                            _ => Err(DataFusionError::Internal(
-                                "invalid join condition expresssion".to_string(),
-                            )),
-                        }
+            // equalities of a left and a right column are the join keys,
+            // the rest of the condition is applied as the join filter
+            let mut left_cols = vec![];
+            let mut right_cols = vec![];
+            let mut null_equals_null = false;
+            let mut filters = vec![];
+            for predicate in split_conjunction(&on) {
+                match join_key(predicate, left.schema(), right.schema()) {
+                    Some((l, r, null_eq_null))
+                        if left_cols.is_empty() || null_eq_null == null_equals_null =>
+                    {
+                        left_cols.push(l);
+                        right_cols.push(r);
+                        null_equals_null = null_eq_null;
                     }
-                    _ => Err(DataFusionError::Internal(
-                        "Non-binary expression is not supported in join condition"
-                            .to_string(),
-                    )),
-                })
-                .collect::<Result<Vec<_>>>()?;
-            let (left_cols, right_cols, null_eq_nulls): (Vec<_>, Vec<_>, Vec<_>) =
-                itertools::multiunzip(join_exprs);
+                    _ => filters.push(predicate.clone()),
+                }
+            }
+            if let Some(post_join_filter) = &join.post_join_filter {
+                let filter =
+                    from_substrait_rex(post_join_filter, &schema, extensions).await?;
+                filters.push(filter.as_ref().clone());
+            }
             left.join_detailed(
                 right.build()?,
                 join_type,
                 (left_cols, right_cols),
-                None,
-                null_eq_nulls[0],
+                conjunction(filters),
+                null_equals_null,
             )?
             .build()
         }
+        Some(RelType::Cross(cross)) => {
+            let left = LogicalPlanBuilder::from(
+                from_substrait_rel(ctx, cross.left.as_ref().unwrap(), extensions).await?,
+            );
+            let right = disambiguate_join_input(
+                left.schema(),
+                from_substrait_rel(ctx, cross.right.as_ref().unwrap(), extensions)
+                    .await?,
+            )?;
+            left.cross_join(right)?.build()
+        }
+        Some(RelType::Set(set)) => {
+            let mut inputs = vec![];
+            for input in &set.inputs {
+                inputs.push(from_substrait_rel(ctx, input, extensions).await?);
+            }
+            from_substrait_set_op(set.op, inputs)
+        }
         Some(RelType::Read(read)) => match &read.as_ref().read_type {
             Some(ReadType::NamedTable(nt)) => {
                 let table_reference = match nt.names.len() {
@@ -366,43 +408,132 @@ pub async fn from_substrait_rel(
                 };
                 let t = ctx.table(table_reference).await?;
                 let t = t.into_optimized_plan()?;
-                match &read.projection {
-                    Some(MaskExpression { select, .. }) => match &select.as_ref() {
-                        Some(projection) => {
-                            let column_indices: Vec<usize> = projection
-                                .struct_items
-                                .iter()
-                                .map(|item| item.field as usize)
-                                .collect();
-                            match &t {
-                                LogicalPlan::TableScan(scan) => {
-                                    let fields: Vec<DFField> = column_indices
-                                        .iter()
-                                        .map(|i| scan.projected_schema.field(*i).clone())
-                                        .collect();
-                                    // clippy thinks this clone is redundant but it is not
-                                    #[allow(clippy::redundant_clone)]
-                                    let mut scan = scan.clone();
-                                    scan.projection = Some(column_indices);
-                                    scan.projected_schema =
-                                        DFSchemaRef::new(DFSchema::new_with_metadata(
-                                            fields,
-                                            HashMap::new(),
-                                        )?);
-                                    Ok(LogicalPlan::TableScan(scan))
-                                }
-                                _ => Err(DataFusionError::Internal(
-                                    "unexpected plan for table".to_string(),
-                                )),
+                let projection = match &read.projection {
+                    Some(MaskExpression {
+                        select: Some(projection),
+                        ..
+                    }) => Some(
+                        projection
+                            .struct_items
+                            .iter()
+                            .map(|item| item.field as usize)
+                            .collect::<Vec<_>>(),
+                    ),
+                    _ => None,
+                };
+                if projection.is_none()
+                    && read.filter.is_none()
+                    && read.best_effort_filter.is_none()
+                {
+                    return Ok(t);
+                }
+                let scan = match t {
+                    LogicalPlan::TableScan(scan) => scan,
+                    _ => {
+                        return Err(DataFusionError::Internal(
+                            "unexpected plan for table".to_string(),
+                        ))
+                    }
+                };
+                // the filters refer to the columns of the table, before the
+                // projection is applied
+                let table_schema = DFSchema::try_from_qualified_schema(
+                    scan.table_name.clone(),
+                    &scan.source.schema(),
+                )?;
+                let mut filters = vec![];
+                if let Some(filter) = &read.best_effort_filter {
+                    let filter =
+                        from_substrait_rex(filter, &table_schema, extensions).await?;
+                    filters.extend(split_conjunction(&filter).into_iter().cloned());
+                }
+                let mut required = vec![];
+                if let Some(filter) = &read.filter {
+                    let filter =
+                        from_substrait_rex(filter, &table_schema, extensions).await?;
+                    required.extend(split_conjunction(&filter).into_iter().cloned());
+                }
+                // required filters the table does not handle exactly are
+                // applied by a Filter above the scan
+                let pushdown = scan
+                    .source
+                    .supports_filters_pushdown(&required.iter().collect::<Vec<_>>())?;
+                let mut unhandled = vec![];
+                for (filter, pushdown) in required.into_iter().zip(pushdown) {
+                    if pushdown != TableProviderFilterPushDown::Exact {
+                        unhandled.push(filter.clone());
+                    }
+                    filters.push(filter);
+                }
+                match conjunction(unhandled) {
+                    None => LogicalPlanBuilder::scan_with_filters(
+                        scan.table_name,
+                        scan.source,
+                        projection,
+                        filters,
+                    )?
+                    .build(),
+                    Some(predicate) => {
+                        let plan = LogicalPlanBuilder::scan_with_filters(
+                            scan.table_name,
+                            scan.source,
+                            None,
+                            filters,
+                        )?
+                        .filter(predicate)?;
+                        match projection {
+                            Some(indices) => {
+                                let exprs = indices
+                                    .iter()
+                                    .map(|i| {
+                                        Expr::Column(
+                                            table_schema.field(*i).qualified_column(),
+                                        )
+                                    })
+                                    .collect::<Vec<_>>();
+                                plan.project(exprs)?.build()
                             }
+                            None => plan.build(),
                         }
-                        _ => Ok(t),
-                    },
-                    _ => Ok(t),
+                    }
+                }
+            }
+            Some(ReadType::VirtualTable(vt)) => {
+                let schema = match &read.base_schema {
+                    Some(base_schema) => from_substrait_named_struct(base_schema)?,
+                    None => {
+                        return Err(DataFusionError::Substrait(
+                            "VirtualTable without a base schema is not valid".to_string(),
+                        ))
+                    }
+                };
+                if vt.values.is_empty() {
+                    return Ok(LogicalPlan::EmptyRelation(EmptyRelation {
+                        produce_one_row: false,
+                        schema,
+                    }));
                 }
+                let values = vt
+                    .values
+                    .iter()
+                    .map(|row| {
+                        if row.fields.len() != schema.fields().len() {
+                            return Err(DataFusionError::Substrait(format!(
+                                "VirtualTable row has {} values but the schema has {} fields",
+                                row.fields.len(),
+                                schema.fields().len()
+                            )));
+                        }
+                        row.fields
+                            .iter()
+                            .map(|lit| Ok(Expr::Literal(from_substrait_literal(lit)?)))
+                            .collect::<Result<Vec<_>>>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LogicalPlan::Values(Values { schema, values }))
             }
             _ => Err(DataFusionError::NotImplemented(
-                "Only NamedTable reads are supported".to_string(),
+                "Only NamedTable and VirtualTable reads are supported".to_string(),
             )),
         },
         _ => Err(DataFusionError::NotImplemented(format!(
@@ -412,6 +543,102 @@ pub async fn from_substrait_rel(
     }
 }
 
+/// Combine the inputs of a Substrait SetRel with the set operation `op`
+fn from_substrait_set_op(op: i32, inputs: Vec<LogicalPlan>) -> Result<LogicalPlan> {
+    let mut inputs = inputs.into_iter();
+    let primary = match inputs.next() {
+        Some(primary) => primary,
+        None => {
+            return Err(DataFusionError::Substrait(
+                "Set operation without inputs is not valid".to_string(),
+            ))
+        }
+    };
+    match set_rel::SetOp::from_i32(op) {
+        Some(set_rel::SetOp::UnionAll) => inputs
+            .try_fold(LogicalPlanBuilder::from(primary), |plan, input| {
+                plan.union(input)
+            })?
+            .build(),
+        Some(set_rel::SetOp::UnionDistinct) => inputs
+            .try_fold(LogicalPlanBuilder::from(primary), |plan, input| {
+                plan.union_distinct(input)
+            })?
+            .build(),
+        // the primary operations keep the duplicates of the primary input
+        Some(set_rel::SetOp::IntersectionPrimary) => inputs
+            .try_fold(primary, |plan, input| {
+                LogicalPlanBuilder::intersect(plan, input, true)
+            }),
+        Some(set_rel::SetOp::MinusPrimary) => inputs.try_fold(primary, |plan, input| {
+            LogicalPlanBuilder::except(plan, input, true)
+        }),
+        Some(set_op) => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported set operation: {set_op:?}"
+        ))),
+        None => Err(DataFusionError::Substrait(format!(
+            "Invalid set operation variant {op:?}"
+        ))),
+    }
+}
+
+/// Returns the columns of `predicate` if it is an equijoin key of the
+/// `left` and `right` inputs, and whether nulls compare equal
+fn join_key(
+    predicate: &Expr,
+    left: &DFSchema,
+    right: &DFSchema,
+) -> Option<(Column, Column, bool)> {
+    match predicate {
+        Expr::BinaryExpr(BinaryExpr {
+            left: l,
+            op,
+            right: r,
+        }) => {
+            let null_equals_null = match op {
+                Operator::Eq => false,
+                Operator::IsNotDistinctFrom => true,
+                _ => return None,
+            };
+            match (l.as_ref(), r.as_ref()) {
+                (Expr::Column(l), Expr::Column(r)) => {
+                    if left.index_of_column(l).is_ok() && right.index_of_column(r).is_ok()
+                    {
+                        Some((l.clone(), r.clone(), null_equals_null))
+                    } else if left.index_of_column(r).is_ok()
+                        && right.index_of_column(l).is_ok()
+                    {
+                        Some((r.clone(), l.clone(), null_equals_null))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Substrait relations have no aliases, so the inputs of a self join have
+/// the same qualified fields: the fields of `right` are then qualified with
+/// a fresh alias. This is safe as Substrait references fields by position.
+fn disambiguate_join_input(left: &DFSchema, right: LogicalPlan) -> Result<LogicalPlan> {
+    if left.join(right.schema()).is_ok() {
+        return Ok(right);
+    }
+    let alias = (0..)
+        .map(|i| format!("__substrait_join_input_{i}"))
+        .find(|alias| {
+            !left
+                .fields()
+                .iter()
+                .any(|f| matches!(f.qualifier(), Some(q) if q.table() == alias.as_str()))
+        })
+        .unwrap();
+    LogicalPlanBuilder::from(right).alias(alias)?.build()
+}
+
 fn from_substrait_jointype(join_type: i32) -> Result<JoinType> {
     if let Some(substrait_join_type) = join_rel::JoinType::from_i32(join_type) {
         match substrait_join_type {
@@ -682,109 +909,7 @@ pub async fn from_substrait_rex(
             }
         }
         Some(RexType::Literal(lit)) => {
-            match &lit.literal_type {
-                Some(LiteralType::I8(n)) => {
-                    if lit.type_variation_reference == 0 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::Int8(Some(*n as i8)))))
-                    } else if lit.type_variation_reference == 1 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::UInt8(Some(*n as u8)))))
-                    } else {
-                        Err(DataFusionError::Substrait(format!(
-                            "Unknown type variation reference {}",
-                            lit.type_variation_reference
-                        )))
-                    }
-                }
-                Some(LiteralType::I16(n)) => {
-                    if lit.type_variation_reference == 0 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::Int16(Some(*n as i16)))))
-                    } else if lit.type_variation_reference == 1 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::UInt16(Some(
-                            *n as u16,
-                        )))))
-                    } else {
-                        Err(DataFusionError::Substrait(format!(
-                            "Unknown type variation reference {}",
-                            lit.type_variation_reference
-                        )))
-                    }
-                }
-                Some(LiteralType::I32(n)) => {
-                    if lit.type_variation_reference == 0 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::Int32(Some(*n)))))
-                    } else if lit.type_variation_reference == 1 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::UInt32(Some(unsafe {
-                            std::mem::transmute_copy::<i32, u32>(n)
-                        })))))
-                    } else {
-                        Err(DataFusionError::Substrait(format!(
-                            "Unknown type variation reference {}",
-                            lit.type_variation_reference
-                        )))
-                    }
-                }
-                Some(LiteralType::I64(n)) => {
-                    if lit.type_variation_reference == 0 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::Int64(Some(*n)))))
-                    } else if lit.type_variation_reference == 1 {
-                        Ok(Arc::new(Expr::Literal(ScalarValue::UInt64(Some(unsafe {
-                            std::mem::transmute_copy::<i64, u64>(n)
-                        })))))
-                    } else {
-                        Err(DataFusionError::Substrait(format!(
-                            "Unknown type variation reference {}",
-                            lit.type_variation_reference
-                        )))
-                    }
-                }
-                Some(LiteralType::Boolean(b)) => {
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Boolean(Some(*b)))))
-                }
-                Some(LiteralType::Date(d)) => {
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Date32(Some(*d)))))
-                }
-                Some(LiteralType::Fp32(f)) => {
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Float32(Some(*f)))))
-                }
-                Some(LiteralType::Fp64(f)) => {
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Float64(Some(*f)))))
-                }
-                Some(LiteralType::Decimal(d)) => {
-                    let value: [u8; 16] = d.value.clone().try_into().or(Err(
-                        DataFusionError::Substrait(
-                            "Failed to parse decimal value".to_string(),
-                        ),
-                    ))?;
-                    let p = d.precision.try_into().map_err(|e| {
-                        DataFusionError::Substrait(format!(
-                            "Failed to parse decimal precision: {e}"
-                        ))
-                    })?;
-                    let s = d.scale.try_into().map_err(|e| {
-                        DataFusionError::Substrait(format!(
-                            "Failed to parse decimal scale: {e}"
-                        ))
-                    })?;
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Decimal128(
-                        Some(std::primitive::i128::from_le_bytes(value)),
-                        p,
-                        s,
-                    ))))
-                }
-                Some(LiteralType::String(s)) => {
-                    Ok(Arc::new(Expr::Literal(ScalarValue::Utf8(Some(s.clone())))))
-                }
-                Some(LiteralType::Binary(b)) => Ok(Arc::new(Expr::Literal(
-                    ScalarValue::Binary(Some(b.clone())),
-                ))),
-                Some(LiteralType::Null(ntype)) => {
-                    Ok(Arc::new(Expr::Literal(from_substrait_null(ntype)?)))
-                }
-                _ => Err(DataFusionError::NotImplemented(format!(
-                    "Unsupported literal_type: {:?}",
-                    lit.literal_type
-                ))),
-            }
+            Ok(Arc::new(Expr::Literal(from_substrait_literal(lit)?)))
         }
         Some(RexType::Cast(cast)) => match cast.as_ref().r#type.as_ref() {
             Some(output_type) => Ok(Arc::new(Expr::Cast(Cast::new(
@@ -851,14 +976,153 @@ pub async fn from_substrait_rex(
     }
 }
 
+fn from_substrait_literal(lit: &Literal) -> Result<ScalarValue> {
+    match &lit.literal_type {
+        Some(LiteralType::I8(n)) => {
+            if lit.type_variation_reference == 0 {
+                Ok(ScalarValue::Int8(Some(*n as i8)))
+            } else if lit.type_variation_reference == 1 {
+                Ok(ScalarValue::UInt8(Some(*n as u8)))
+            } else {
+                Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {}",
+                    lit.type_variation_reference
+                )))
+            }
+        }
+        Some(LiteralType::I16(n)) => {
+            if lit.type_variation_reference == 0 {
+                Ok(ScalarValue::Int16(Some(*n as i16)))
+            } else if lit.type_variation_reference == 1 {
+                Ok(ScalarValue::UInt16(Some(*n as u16)))
+            } else {
+                Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {}",
+                    lit.type_variation_reference
+                )))
+            }
+        }
+        Some(LiteralType::I32(n)) => {
+            if lit.type_variation_reference == 0 {
+                Ok(ScalarValue::Int32(Some(*n)))
+            } else if lit.type_variation_reference == 1 {
+                Ok(ScalarValue::UInt32(Some(unsafe {
+                    std::mem::transmute_copy::<i32, u32>(n)
+                })))
+            } else {
+                Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {}",
+                    lit.type_variation_reference
+                )))
+            }
+        }
+        Some(LiteralType::I64(n)) => {
+            if lit.type_variation_reference == 0 {
+                Ok(ScalarValue::Int64(Some(*n)))
+            } else if lit.type_variation_reference == 1 {
+                Ok(ScalarValue::UInt64(Some(unsafe {
+                    std::mem::transmute_copy::<i64, u64>(n)
+                })))
+            } else {
+                Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {}",
+                    lit.type_variation_reference
+                )))
+            }
+        }
+        Some(LiteralType::Boolean(b)) => Ok(ScalarValue::Boolean(Some(*b))),
+        Some(LiteralType::Date(d)) => Ok(ScalarValue::Date32(Some(*d))),
+        Some(LiteralType::Fp32(f)) => Ok(ScalarValue::Float32(Some(*f))),
+        Some(LiteralType::Fp64(f)) => Ok(ScalarValue::Float64(Some(*f))),
+        Some(LiteralType::Decimal(d)) => {
+            let value: [u8; 16] =
+                d.value
+                    .clone()
+                    .try_into()
+                    .or(Err(DataFusionError::Substrait(
+                        "Failed to parse decimal value".to_string(),
+                    )))?;
+            let p = d.precision.try_into().map_err(|e| {
+                DataFusionError::Substrait(format!(
+                    "Failed to parse decimal precision: {e}"
+                ))
+            })?;
+            let s = d.scale.try_into().map_err(|e| {
+                DataFusionError::Substrait(format!("Failed to parse decimal scale: {e}"))
+            })?;
+            Ok(ScalarValue::Decimal128(
+                Some(std::primitive::i128::from_le_bytes(value)),
+                p,
+                s,
+            ))
+        }
+        Some(LiteralType::String(s)) => Ok(ScalarValue::Utf8(Some(s.clone()))),
+        Some(LiteralType::Binary(b)) => Ok(ScalarValue::Binary(Some(b.clone()))),
+        Some(LiteralType::Null(ntype)) => Ok(from_substrait_null(ntype)?),
+        _ => Err(DataFusionError::NotImplemented(format!(
+            "Unsupported literal_type: {:?}",
+            lit.literal_type
+        ))),
+    }
+}
+
 fn from_substrait_type(dt: &substrait::proto::Type) -> Result<DataType> {
     match &dt.kind {
         Some(s_kind) => match s_kind {
             r#type::Kind::Bool(_) => Ok(DataType::Boolean),
-            r#type::Kind::I8(_) => Ok(DataType::Int8),
-            r#type::Kind::I16(_) => Ok(DataType::Int16),
-            r#type::Kind::I32(_) => Ok(DataType::Int32),
-            r#type::Kind::I64(_) => Ok(DataType::Int64),
+            r#type::Kind::I8(i) => from_integer_type_variation(
+                i.type_variation_reference,
+                DataType::Int8,
+                DataType::UInt8,
+            ),
+            r#type::Kind::I16(i) => from_integer_type_variation(
+                i.type_variation_reference,
+                DataType::Int16,
+                DataType::UInt16,
+            ),
+            r#type::Kind::I32(i) => from_integer_type_variation(
+                i.type_variation_reference,
+                DataType::Int32,
+                DataType::UInt32,
+            ),
+            r#type::Kind::I64(i) => from_integer_type_variation(
+                i.type_variation_reference,
+                DataType::Int64,
+                DataType::UInt64,
+            ),
+            r#type::Kind::Fp32(_) => Ok(DataType::Float32),
+            r#type::Kind::Fp64(_) => Ok(DataType::Float64),
+            r#type::Kind::Date(_) => Ok(DataType::Date32),
+            r#type::Kind::Timestamp(ts) => match ts.type_variation_reference {
+                0 => Ok(DataType::Timestamp(TimeUnit::Second, None)),
+                1 => Ok(DataType::Timestamp(TimeUnit::Millisecond, None)),
+                2 => Ok(DataType::Timestamp(TimeUnit::Microsecond, None)),
+                3 => Ok(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+                v => Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {v}"
+                ))),
+            },
+            r#type::Kind::String(s) => match s.type_variation_reference {
+                0 => Ok(DataType::Utf8),
+                1 => Ok(DataType::LargeUtf8),
+                v => Err(DataFusionError::Substrait(format!(
+                    "Unknown type variation reference {v}"
+                ))),
+            },
+            r#type::Kind::List(list) => {
+                let inner = list.r#type.as_ref().ok_or_else(|| {
+                    DataFusionError::Substrait(
+                        "List type without an element type".to_string(),
+                    )
+                })?;
+                let nullable = inner_nullable(inner);
+                Ok(DataType::List(Box::new(Field::new(
+                    "item",
+                    from_substrait_type(inner)?,
+                    nullable,
+                ))))
+            }
+            r#type::Kind::Binary(_) => Ok(DataType::Binary),
             r#type::Kind::Decimal(d) => {
                 Ok(DataType::Decimal128(d.precision as u8, d.scale as i8))
             }
@@ -872,6 +1136,84 @@ fn from_substrait_type(dt: &substrait::proto::Type) -> Result<DataType> {
     }
 }
 
+/// Returns true unless the Substrait type is declared as required, as
+/// for the element type of a list
+fn inner_nullable(dt: &Type) -> bool {
+    let nullability = match &dt.kind {
+        Some(r#type::Kind::Bool(t)) => t.nullability,
+        Some(r#type::Kind::I8(t)) => t.nullability,
+        Some(r#type::Kind::I16(t)) => t.nullability,
+        Some(r#type::Kind::I32(t)) => t.nullability,
+        Some(r#type::Kind::I64(t)) => t.nullability,
+        Some(r#type::Kind::Fp32(t)) => t.nullability,
+        Some(r#type::Kind::Fp64(t)) => t.nullability,
+        Some(r#type::Kind::Date(t)) => t.nullability,
+        Some(r#type::Kind::Timestamp(t)) => t.nullability,
+        Some(r#type::Kind::String(t)) => t.nullability,
+        Some(r#type::Kind::Binary(t)) => t.nullability,
+        Some(r#type::Kind::Decimal(t)) => t.nullability,
+        Some(r#type::Kind::List(t)) => t.nullability,
+        _ => r#type::Nullability::Nullable as i32,
+    };
+    nullability != r#type::Nullability::Required as i32
+}
+
+/// Returns the signed or unsigned integer type for the type variation
+/// reference of a Substrait integer type
+fn from_integer_type_variation(
+    type_variation_reference: u32,
+    signed: DataType,
+    unsigned: DataType,
+) -> Result<DataType> {
+    match type_variation_reference {
+        0 => Ok(signed),
+        1 => Ok(unsigned),
+        _ => Err(DataFusionError::Substrait(format!(
+            "Unknown type variation reference {type_variation_reference}"
+        ))),
+    }
+}
+
+/// Convert a Substrait NamedStruct to a DataFusion schema
+///
+/// Substrait types of the struct are required, and the fields are nullable,
+/// as the fields of VALUES lists planned by DataFusion.
+pub(crate) fn from_substrait_named_struct(
+    base_schema: &NamedStruct,
+) -> Result<DFSchemaRef> {
+    let types = match &base_schema.r#struct {
+        Some(s) => &s.types,
+        None => {
+            return Err(DataFusionError::Substrait(
+                "NamedStruct without a struct type is not supported".to_string(),
+            ))
+        }
+    };
+    if types.len() != base_schema.names.len() {
+        return Err(DataFusionError::Substrait(format!(
+            "NamedStruct has {} names but {} types, nested types are not supported",
+            base_schema.names.len(),
+            types.len()
+        )));
+    }
+    let fields = base_schema
+        .names
+        .iter()
+        .zip(types)
+        .map(|(name, dt)| {
+            Ok(DFField::new_unqualified(
+                name,
+                from_substrait_type(dt)?,
+                true,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DFSchemaRef::new(DFSchema::new_with_metadata(
+        fields,
+        HashMap::new(),
+    )?))
+}
+
 fn from_substrait_bound(
     bound: &Option<Bound>,
     is_lower: bool,
@@ -911,21 +1253,8 @@ fn from_substrait_bound(
 }
 
 fn from_substrait_null(null_type: &Type) -> Result<ScalarValue> {
-    if let Some(kind) = &null_type.kind {
-        match kind {
-            r#type::Kind::I8(_) => Ok(ScalarValue::Int8(None)),
-            r#type::Kind::I16(_) => Ok(ScalarValue::Int16(None)),
-            r#type::Kind::I32(_) => Ok(ScalarValue::Int32(None)),
-            r#type::Kind::I64(_) => Ok(ScalarValue::Int64(None)),
-            r#type::Kind::Decimal(d) => Ok(ScalarValue::Decimal128(
-                None,
-                d.precision as u8,
-                d.scale as i8,
-            )),
-            _ => Err(DataFusionError::NotImplemented(format!(
-                "Unsupported null kind: {kind:?}"
-            ))),
-        }
+    if null_type.kind.is_some() {
+        ScalarValue::try_from(&from_substrait_type(null_type)?)
     } else {
         Err(DataFusionError::NotImplemented(
             "Null type without kind is not supported".to_string(),
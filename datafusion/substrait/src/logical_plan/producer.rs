@@ -18,18 +18,22 @@
 use std::{collections::HashMap, mem, sync::Arc};
 
 use datafusion::{
-    arrow::datatypes::DataType,
+    arrow::datatypes::{DataType, TimeUnit},
     error::{DataFusionError, Result},
     logical_expr::{WindowFrame, WindowFrameBound},
     prelude::JoinType,
     scalar::ScalarValue,
 };
 
-use datafusion::common::DFSchemaRef;
+use datafusion::common::{DFSchema, DFSchemaRef};
 #[allow(unused_imports)]
 use datafusion::logical_expr::aggregate_function;
 use datafusion::logical_expr::expr::{BinaryExpr, Case, Cast, Sort, WindowFunction};
-use datafusion::logical_expr::{expr, Between, JoinConstraint, LogicalPlan, Operator};
+use datafusion::logical_expr::{
+    expr, Between, JoinConstraint, LogicalPlan, Operator, TableProviderFilterPushDown,
+    TableScan,
+};
+use datafusion::optimizer::utils::conjunction;
 use datafusion::prelude::{binary_expr, Expr};
 use substrait::{
     proto::{
@@ -38,7 +42,7 @@ use substrait::{
         expression::{
             field_reference::ReferenceType,
             if_then::IfClause,
-            literal::{Decimal, LiteralType},
+            literal::{Decimal, LiteralType, Struct},
             mask_expression::{StructItem, StructSelect},
             reference_segment,
             window_function::bound as SubstraitBound,
@@ -53,12 +57,13 @@ use substrait::{
         },
         function_argument::ArgType,
         join_rel, plan_rel, r#type,
-        read_rel::{NamedTable, ReadType},
+        read_rel::{NamedTable, ReadType, VirtualTable},
         rel::RelType,
+        set_rel,
         sort_field::{SortDirection, SortKind},
-        AggregateFunction, AggregateRel, AggregationPhase, Expression, FetchRel,
-        FilterRel, FunctionArgument, JoinRel, NamedStruct, Plan, PlanRel, ProjectRel,
-        ReadRel, Rel, RelRoot, SortField, SortRel,
+        AggregateFunction, AggregateRel, AggregationPhase, CrossRel, Expression,
+        FetchRel, FilterRel, FunctionArgument, JoinRel, NamedStruct, Plan, PlanRel,
+        ProjectRel, ReadRel, Rel, RelRoot, SetRel, SortField, SortRel,
     },
     version,
 };
@@ -75,7 +80,12 @@ pub fn to_substrait_plan(plan: &LogicalPlan) -> Result<Box<Plan>> {
     let plan_rels = vec![PlanRel {
         rel_type: Some(plan_rel::RelType::Root(RelRoot {
             input: Some(*to_substrait_rel(plan, &mut extension_info)?),
-            names: plan.schema().field_names(),
+            names: plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().to_owned())
+                .collect(),
         })),
     }];
 
@@ -102,47 +112,50 @@ pub fn to_substrait_rel(
 ) -> Result<Box<Rel>> {
     match plan {
         LogicalPlan::TableScan(scan) => {
-            let projection = scan.projection.as_ref().map(|p| {
-                p.iter()
-                    .map(|i| StructItem {
-                        field: *i as i32,
-                        child: None,
-                    })
-                    .collect()
+            let projection = scan.projection.as_ref().map(|p| MaskExpression {
+                select: Some(StructSelect {
+                    struct_items: p
+                        .iter()
+                        .map(|i| StructItem {
+                            field: *i as i32,
+                            child: None,
+                        })
+                        .collect(),
+                }),
+                maintain_singular_struct: false,
             });
+            // the filters of the scan refer to the columns of the table,
+            // before the projection is applied
+            let table_schema = Arc::new(DFSchema::try_from_qualified_schema(
+                scan.table_name.clone(),
+                &scan.source.schema(),
+            )?);
+            let (filter, best_effort_filter) =
+                to_substrait_scan_filters(scan, &table_schema, extension_info)?;
 
-            if let Some(struct_items) = projection {
-                Ok(Box::new(Rel {
-                    rel_type: Some(RelType::Read(Box::new(ReadRel {
-                        common: None,
-                        base_schema: Some(NamedStruct {
-                            names: scan
-                                .source
-                                .schema()
-                                .fields()
-                                .iter()
-                                .map(|f| f.name().to_owned())
-                                .collect(),
-                            r#struct: None,
-                        }),
-                        filter: None,
-                        best_effort_filter: None,
-                        projection: Some(MaskExpression {
-                            select: Some(StructSelect { struct_items }),
-                            maintain_singular_struct: false,
-                        }),
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Read(Box::new(ReadRel {
+                    common: None,
+                    base_schema: Some(NamedStruct {
+                        names: scan
+                            .source
+                            .schema()
+                            .fields()
+                            .iter()
+                            .map(|f| f.name().to_owned())
+                            .collect(),
+                        r#struct: None,
+                    }),
+                    filter,
+                    best_effort_filter,
+                    projection,
+                    advanced_extension: None,
+                    read_type: Some(ReadType::NamedTable(NamedTable {
+                        names: scan.table_name.to_vec(),
                         advanced_extension: None,
-                        read_type: Some(ReadType::NamedTable(NamedTable {
-                            names: scan.table_name.to_vec(),
-                            advanced_extension: None,
-                        })),
-                    }))),
-                }))
-            } else {
-                Err(DataFusionError::NotImplemented(
-                    "TableScan without projection is not supported".to_string(),
-                ))
-            }
+                    })),
+                }))),
+            }))
         }
         LogicalPlan::Projection(p) => {
             let expressions = p
@@ -254,10 +267,6 @@ pub fn to_substrait_rel(
             let left = to_substrait_rel(join.left.as_ref(), extension_info)?;
             let right = to_substrait_rel(join.right.as_ref(), extension_info)?;
             let join_type = to_substrait_jointype(join.join_type);
-            // we only support basic joins so return an error for anything not yet supported
-            if join.filter.is_some() {
-                return Err(DataFusionError::NotImplemented("join filter".to_string()));
-            }
             match join.join_constraint {
                 JoinConstraint::On => {}
                 _ => {
@@ -273,7 +282,7 @@ pub fn to_substrait_rel(
             } else {
                 Operator::Eq
             };
-            let join_expression = join
+            let join_on = join
                 .on
                 .iter()
                 .map(|(l, r)| binary_expr(l.clone(), eq_op, r.clone()))
@@ -281,28 +290,102 @@ pub fn to_substrait_rel(
             // join schema from left and right to maintain all nececesary columns from inputs
             // note that we cannot simple use join.schema here since we discard some input columns
             // when performing semi and anti joins
-            let join_schema = join.left.schema().join(join.right.schema());
-            if let Some(e) = join_expression {
-                Ok(Box::new(Rel {
-                    rel_type: Some(RelType::Join(Box::new(JoinRel {
-                        common: None,
-                        left: Some(left),
-                        right: Some(right),
-                        r#type: join_type as i32,
-                        expression: Some(Box::new(to_substrait_rex(
-                            &e,
-                            &Arc::new(join_schema?),
-                            extension_info,
-                        )?)),
-                        post_join_filter: None,
-                        advanced_extension: None,
-                    }))),
-                }))
-            } else {
-                Err(DataFusionError::NotImplemented(
-                    "Empty join condition".to_string(),
-                ))
-            }
+            let join_schema = Arc::new(join.left.schema().join(join.right.schema())?);
+            // the non-equi part of the join condition is the post join filter,
+            // unless there are no equijoin keys to use as the join expression
+            let (join_expression, join_filter) = match (join_on, &join.filter) {
+                (Some(on), filter) => (on, filter.as_ref()),
+                (None, Some(filter)) => (filter.clone(), None),
+                (None, None) => {
+                    return Err(DataFusionError::NotImplemented(
+                        "Empty join condition".to_string(),
+                    ))
+                }
+            };
+            let expression =
+                to_substrait_rex(&join_expression, &join_schema, extension_info)?;
+            let post_join_filter = match join_filter {
+                Some(filter) => Some(Box::new(to_substrait_rex(
+                    filter,
+                    &join_schema,
+                    extension_info,
+                )?)),
+                None => None,
+            };
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Join(Box::new(JoinRel {
+                    common: None,
+                    left: Some(left),
+                    right: Some(right),
+                    r#type: join_type as i32,
+                    expression: Some(Box::new(expression)),
+                    post_join_filter,
+                    advanced_extension: None,
+                }))),
+            }))
+        }
+        LogicalPlan::CrossJoin(cross_join) => {
+            let left = to_substrait_rel(cross_join.left.as_ref(), extension_info)?;
+            let right = to_substrait_rel(cross_join.right.as_ref(), extension_info)?;
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Cross(Box::new(CrossRel {
+                    common: None,
+                    left: Some(left),
+                    right: Some(right),
+                    advanced_extension: None,
+                }))),
+            }))
+        }
+        LogicalPlan::Union(union) => {
+            let inputs = union
+                .inputs
+                .iter()
+                .map(|input| Ok(*to_substrait_rel(input.as_ref(), extension_info)?))
+                .collect::<Result<Vec<_>>>()?;
+            // `UNION DISTINCT` is planned as an aggregate over a `UNION ALL`
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Set(SetRel {
+                    common: None,
+                    inputs,
+                    op: set_rel::SetOp::UnionAll as i32,
+                    advanced_extension: None,
+                })),
+            }))
+        }
+        LogicalPlan::Values(values) => {
+            let values = values
+                .values
+                .iter()
+                .map(|row| {
+                    let fields = row
+                        .iter()
+                        .map(|e| match e {
+                            Expr::Literal(value) => to_substrait_literal(value),
+                            Expr::Alias(expr, _) => match expr.as_ref() {
+                                Expr::Literal(value) => to_substrait_literal(value),
+                                _ => Err(DataFusionError::NotImplemented(format!(
+                                    "Unsupported value in VALUES: {e:?}"
+                                ))),
+                            },
+                            _ => Err(DataFusionError::NotImplemented(format!(
+                                "Unsupported value in VALUES: {e:?}"
+                            ))),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(Struct { fields })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Box::new(Rel {
+                rel_type: Some(RelType::Read(Box::new(ReadRel {
+                    common: None,
+                    base_schema: Some(to_substrait_named_struct(plan.schema())?),
+                    filter: None,
+                    best_effort_filter: None,
+                    projection: None,
+                    advanced_extension: None,
+                    read_type: Some(ReadType::VirtualTable(VirtualTable { values })),
+                }))),
+            }))
         }
         LogicalPlan::SubqueryAlias(alias) => {
             // Do nothing if encounters SubqueryAlias
@@ -351,6 +434,71 @@ pub fn to_substrait_rel(
     }
 }
 
+/// Convert the schema of a DataFusion plan to a Substrait NamedStruct
+pub(crate) fn to_substrait_named_struct(schema: &DFSchemaRef) -> Result<NamedStruct> {
+    let types = schema
+        .fields()
+        .iter()
+        .map(|f| to_substrait_type(f.data_type(), f.is_nullable()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(NamedStruct {
+        names: schema
+            .fields()
+            .iter()
+            .map(|f| f.name().to_owned())
+            .collect(),
+        r#struct: Some(r#type::Struct {
+            types,
+            type_variation_reference: 0,
+            nullability: r#type::Nullability::Required as i32,
+        }),
+    })
+}
+
+/// Convert the filters of `scan` to the filter and best effort filter
+/// of a Substrait ReadRel.
+///
+/// Filters the table handles exactly must be applied by the consumer,
+/// while the other filters are only hints, as DataFusion keeps a
+/// `Filter` above the scan for them
+fn to_substrait_scan_filters(
+    scan: &TableScan,
+    table_schema: &DFSchemaRef,
+    extension_info: &mut (
+        Vec<extensions::SimpleExtensionDeclaration>,
+        HashMap<String, u32>,
+    ),
+) -> Result<(Option<Box<Expression>>, Option<Box<Expression>>)> {
+    let filters = scan.filters.iter().collect::<Vec<_>>();
+    let pushdown = scan.source.supports_filters_pushdown(&filters)?;
+    let mut exact = vec![];
+    let mut inexact = vec![];
+    for (filter, pushdown) in filters.into_iter().zip(pushdown) {
+        if pushdown == TableProviderFilterPushDown::Exact {
+            exact.push(filter.clone());
+        } else {
+            inexact.push(filter.clone());
+        }
+    }
+    let filter = match conjunction(exact) {
+        Some(e) => Some(Box::new(to_substrait_rex(
+            &e,
+            table_schema,
+            extension_info,
+        )?)),
+        None => None,
+    };
+    let best_effort_filter = match conjunction(inexact) {
+        Some(e) => Some(Box::new(to_substrait_rex(
+            &e,
+            table_schema,
+            extension_info,
+        )?)),
+        None => None,
+    };
+    Ok((filter, best_effort_filter))
+}
+
 fn to_substrait_jointype(join_type: JoinType) -> join_rel::JoinType {
     match join_type {
         JoinType::Inner => join_rel::JoinType::Inner,
@@ -372,7 +520,7 @@ pub fn operator_to_name(op: Operator) -> &'static str {
         Operator::Gt => "gt",
         Operator::GtEq => "gte",
         Operator::Plus => "add",
-        Operator::Minus => "substract",
+        Operator::Minus => "subtract",
         Operator::Multiply => "multiply",
         Operator::Divide => "divide",
         Operator::Modulo => "mod",
@@ -626,7 +774,7 @@ pub fn to_substrait_rex(
             Ok(Expression {
                 rex_type: Some(RexType::Cast(Box::new(
                     substrait::proto::expression::Cast {
-                        r#type: Some(to_substrait_type(data_type)?),
+                        r#type: Some(to_substrait_type(data_type, false)?),
                         input: Some(Box::new(to_substrait_rex(
                             expr,
                             schema,
@@ -637,48 +785,9 @@ pub fn to_substrait_rex(
                 ))),
             })
         }
-        Expr::Literal(value) => {
-            let literal_type = match value {
-                ScalarValue::Int8(Some(n)) => Some(LiteralType::I8(*n as i32)),
-                ScalarValue::UInt8(Some(n)) => Some(LiteralType::I8(*n as i32)),
-                ScalarValue::Int16(Some(n)) => Some(LiteralType::I16(*n as i32)),
-                ScalarValue::UInt16(Some(n)) => Some(LiteralType::I16(*n as i32)),
-                ScalarValue::Int32(Some(n)) => Some(LiteralType::I32(*n)),
-                ScalarValue::UInt32(Some(n)) => Some(LiteralType::I32(unsafe {
-                    mem::transmute_copy::<u32, i32>(n)
-                })),
-                ScalarValue::Int64(Some(n)) => Some(LiteralType::I64(*n)),
-                ScalarValue::UInt64(Some(n)) => Some(LiteralType::I64(unsafe {
-                    mem::transmute_copy::<u64, i64>(n)
-                })),
-                ScalarValue::Boolean(Some(b)) => Some(LiteralType::Boolean(*b)),
-                ScalarValue::Float32(Some(f)) => Some(LiteralType::Fp32(*f)),
-                ScalarValue::Float64(Some(f)) => Some(LiteralType::Fp64(*f)),
-                ScalarValue::Decimal128(v, p, s) if v.is_some() => {
-                    Some(LiteralType::Decimal(Decimal {
-                        value: v.unwrap().to_le_bytes().to_vec(),
-                        precision: *p as i32,
-                        scale: *s as i32,
-                    }))
-                }
-                ScalarValue::Utf8(Some(s)) => Some(LiteralType::String(s.clone())),
-                ScalarValue::LargeUtf8(Some(s)) => Some(LiteralType::String(s.clone())),
-                ScalarValue::Binary(Some(b)) => Some(LiteralType::Binary(b.clone())),
-                ScalarValue::LargeBinary(Some(b)) => Some(LiteralType::Binary(b.clone())),
-                ScalarValue::Date32(Some(d)) => Some(LiteralType::Date(*d)),
-                _ => Some(try_to_substrait_null(value)?),
-            };
-
-            let type_variation_reference = if value.is_unsigned() { 1 } else { 0 };
-
-            Ok(Expression {
-                rex_type: Some(RexType::Literal(Literal {
-                    nullable: true,
-                    type_variation_reference,
-                    literal_type,
-                })),
-            })
-        }
+        Expr::Literal(value) => Ok(Expression {
+            rex_type: Some(RexType::Literal(to_substrait_literal(value)?)),
+        }),
         Expr::Alias(expr, _alias) => to_substrait_rex(expr, schema, extension_info),
         Expr::WindowFunction(WindowFunction {
             fun,
@@ -727,9 +836,65 @@ pub fn to_substrait_rex(
     }
 }
 
-fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
+fn to_substrait_literal(value: &ScalarValue) -> Result<Literal> {
+    let literal_type = match value {
+        ScalarValue::Int8(Some(n)) => Some(LiteralType::I8(*n as i32)),
+        ScalarValue::UInt8(Some(n)) => Some(LiteralType::I8(*n as i32)),
+        ScalarValue::Int16(Some(n)) => Some(LiteralType::I16(*n as i32)),
+        ScalarValue::UInt16(Some(n)) => Some(LiteralType::I16(*n as i32)),
+        ScalarValue::Int32(Some(n)) => Some(LiteralType::I32(*n)),
+        ScalarValue::UInt32(Some(n)) => Some(LiteralType::I32(unsafe {
+            mem::transmute_copy::<u32, i32>(n)
+        })),
+        ScalarValue::Int64(Some(n)) => Some(LiteralType::I64(*n)),
+        ScalarValue::UInt64(Some(n)) => Some(LiteralType::I64(unsafe {
+            mem::transmute_copy::<u64, i64>(n)
+        })),
+        ScalarValue::Boolean(Some(b)) => Some(LiteralType::Boolean(*b)),
+        ScalarValue::Float32(Some(f)) => Some(LiteralType::Fp32(*f)),
+        ScalarValue::Float64(Some(f)) => Some(LiteralType::Fp64(*f)),
+        ScalarValue::Decimal128(v, p, s) if v.is_some() => {
+            Some(LiteralType::Decimal(Decimal {
+                value: v.unwrap().to_le_bytes().to_vec(),
+                precision: *p as i32,
+                scale: *s as i32,
+            }))
+        }
+        ScalarValue::Utf8(Some(s)) => Some(LiteralType::String(s.clone())),
+        ScalarValue::LargeUtf8(Some(s)) => Some(LiteralType::String(s.clone())),
+        ScalarValue::Binary(Some(b)) => Some(LiteralType::Binary(b.clone())),
+        ScalarValue::LargeBinary(Some(b)) => Some(LiteralType::Binary(b.clone())),
+        ScalarValue::Date32(Some(d)) => Some(LiteralType::Date(*d)),
+        _ => Some(try_to_substrait_null(value)?),
+    };
+
+    let type_variation_reference = if value.is_unsigned() { 1 } else { 0 };
+
+    Ok(Literal {
+        nullable: true,
+        type_variation_reference,
+        literal_type,
+    })
+}
+
+fn to_substrait_type(dt: &DataType, nullable: bool) -> Result<substrait::proto::Type> {
     let default_type_ref = 0;
-    let default_nullability = r#type::Nullability::Required as i32;
+    // unsigned integers are encoded as the signed types of the same width,
+    // with type variation reference 1, as for literals
+    let integer_type_ref = match dt {
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => 1,
+        _ => default_type_ref,
+    };
+    // large strings use type variation reference 1 of the string type
+    let container_type_ref = match dt {
+        DataType::LargeUtf8 => 1,
+        _ => default_type_ref,
+    };
+    let default_nullability = if nullable {
+        r#type::Nullability::Nullable as i32
+    } else {
+        r#type::Nullability::Required as i32
+    };
     match dt {
         DataType::Null => Err(DataFusionError::Internal(
             "Null cast is not valid".to_string(),
@@ -740,26 +905,72 @@ fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
                 nullability: default_nullability,
             })),
         }),
-        DataType::Int8 => Ok(substrait::proto::Type {
+        DataType::Int8 | DataType::UInt8 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I8(r#type::I8 {
-                type_variation_reference: default_type_ref,
+                type_variation_reference: integer_type_ref,
                 nullability: default_nullability,
             })),
         }),
-        DataType::Int16 => Ok(substrait::proto::Type {
+        DataType::Int16 | DataType::UInt16 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I16(r#type::I16 {
-                type_variation_reference: default_type_ref,
+                type_variation_reference: integer_type_ref,
                 nullability: default_nullability,
             })),
         }),
-        DataType::Int32 => Ok(substrait::proto::Type {
+        DataType::Int32 | DataType::UInt32 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I32(r#type::I32 {
-                type_variation_reference: default_type_ref,
+                type_variation_reference: integer_type_ref,
                 nullability: default_nullability,
             })),
         }),
-        DataType::Int64 => Ok(substrait::proto::Type {
+        DataType::Int64 | DataType::UInt64 => Ok(substrait::proto::Type {
             kind: Some(r#type::Kind::I64(r#type::I64 {
+                type_variation_reference: integer_type_ref,
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::Float32 => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::Fp32(r#type::Fp32 {
+                type_variation_reference: default_type_ref,
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::Float64 => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::Fp64(r#type::Fp64 {
+                type_variation_reference: default_type_ref,
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::Date32 => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::Date(r#type::Date {
+                type_variation_reference: default_type_ref,
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::Timestamp(unit, None) => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::Timestamp(r#type::Timestamp {
+                type_variation_reference: timestamp_type_variation(unit),
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::Utf8 | DataType::LargeUtf8 => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::String(r#type::String {
+                type_variation_reference: container_type_ref,
+                nullability: default_nullability,
+            })),
+        }),
+        DataType::List(field) => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::List(Box::new(r#type::List {
+                r#type: Some(Box::new(to_substrait_type(
+                    field.data_type(),
+                    field.is_nullable(),
+                )?)),
+                type_variation_reference: default_type_ref,
+                nullability: default_nullability,
+            }))),
+        }),
+        DataType::Binary => Ok(substrait::proto::Type {
+            kind: Some(r#type::Kind::Binary(r#type::Binary {
                 type_variation_reference: default_type_ref,
                 nullability: default_nullability,
             })),
@@ -778,6 +989,17 @@ fn to_substrait_type(dt: &DataType) -> Result<substrait::proto::Type> {
     }
 }
 
+/// Substrait timestamps have no unit, so the unit is encoded as the type
+/// variation reference of the timestamp type
+fn timestamp_type_variation(unit: &TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    }
+}
+
 #[allow(deprecated)]
 fn make_substrait_window_function(
     function_reference: u32,
@@ -909,42 +1131,9 @@ fn to_substrait_bounds(window_frame: &WindowFrame) -> Result<(Bound, Bound)> {
 }
 
 fn try_to_substrait_null(v: &ScalarValue) -> Result<LiteralType> {
-    let default_type_ref = 0;
-    let default_nullability = r#type::Nullability::Nullable as i32;
-    match v {
-        ScalarValue::Int8(None) => Ok(LiteralType::Null(substrait::proto::Type {
-            kind: Some(r#type::Kind::I8(r#type::I8 {
-                type_variation_reference: default_type_ref,
-                nullability: default_nullability,
-            })),
-        })),
-        ScalarValue::Int16(None) => Ok(LiteralType::Null(substrait::proto::Type {
-            kind: Some(r#type::Kind::I16(r#type::I16 {
-                type_variation_reference: default_type_ref,
-                nullability: default_nullability,
-            })),
-        })),
-        ScalarValue::Int32(None) => Ok(LiteralType::Null(substrait::proto::Type {
-            kind: Some(r#type::Kind::I32(r#type::I32 {
-                type_variation_reference: default_type_ref,
-                nullability: default_nullability,
-            })),
-        })),
-        ScalarValue::Int64(None) => Ok(LiteralType::Null(substrait::proto::Type {
-            kind: Some(r#type::Kind::I64(r#type::I64 {
-                type_variation_reference: default_type_ref,
-                nullability: default_nullability,
-            })),
-        })),
-        ScalarValue::Decimal128(None, p, s) => {
-            Ok(LiteralType::Null(substrait::proto::Type {
-                kind: Some(r#type::Kind::Decimal(r#type::Decimal {
-                    scale: *s as i32,
-                    precision: *p as i32,
-                    type_variation_reference: default_type_ref,
-                    nullability: default_nullability,
-                })),
-            }))
+    match v.get_datatype() {
+        dt if v.is_null() && dt != DataType::Null => {
+            Ok(LiteralType::Null(to_substrait_type(&dt, true)?))
         }
         // TODO: Extend support for remaining data types
         _ => Err(DataFusionError::NotImplemented(format!(
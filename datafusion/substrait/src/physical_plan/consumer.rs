@@ -15,13 +15,21 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::logical_plan::consumer::{from_substrait_named_struct, from_substrait_rex};
 use async_recursion::async_recursion;
 use chrono::DateTime;
 use datafusion::arrow::datatypes::Schema;
+use datafusion::common::{DFSchema, DFSchemaRef};
 use datafusion::datasource::listing::PartitionedFile;
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::error::{DataFusionError, Result};
+use datafusion::optimizer::utils::conjunction;
+use datafusion::physical_expr::execution_props::ExecutionProps;
+use datafusion::physical_expr::expressions::Column;
+use datafusion::physical_expr::{create_physical_expr, PhysicalExpr};
 use datafusion::physical_plan::file_format::{FileScanConfig, ParquetExec};
+use datafusion::physical_plan::filter::FilterExec;
+use datafusion::physical_plan::projection::ProjectionExec;
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::prelude::SessionContext;
 use object_store::ObjectMeta;
@@ -37,20 +45,10 @@ use substrait::proto::{
 pub async fn from_substrait_rel(
     _ctx: &mut SessionContext,
     rel: &Rel,
-    _extensions: &HashMap<u32, &String>,
+    extensions: &HashMap<u32, &String>,
 ) -> Result<Arc<dyn ExecutionPlan>> {
     match &rel.rel_type {
         Some(RelType::Read(read)) => {
-            if read.filter.is_some() || read.best_effort_filter.is_some() {
-                return Err(DataFusionError::NotImplemented(
-                    "Read with filter is not supported".to_string(),
-                ));
-            }
-            if read.base_schema.is_some() {
-                return Err(DataFusionError::NotImplemented(
-                    "Read with schema is not supported".to_string(),
-                ));
-            }
             if read.advanced_extension.is_some() {
                 return Err(DataFusionError::NotImplemented(
                     "Read with AdvancedExtension is not supported".to_string(),
@@ -104,9 +102,48 @@ pub async fn from_substrait_rel(
                         file_groups[part_index].push(partitioned_file)
                     }
 
+                    let schema = match &read.base_schema {
+                        Some(base_schema) => from_substrait_named_struct(base_schema)?,
+                        None => DFSchemaRef::new(DFSchema::empty()),
+                    };
+                    let file_schema = Arc::new(Schema::from(schema.as_ref()));
+
+                    // filters refer to the columns of the files, before the
+                    // projection is applied
+                    let filter = match &read.filter {
+                        Some(filter) => Some(
+                            from_substrait_rex(filter, &schema, extensions)
+                                .await?
+                                .as_ref()
+                                .clone(),
+                        ),
+                        None => None,
+                    };
+                    let best_effort_filter = match &read.best_effort_filter {
+                        Some(filter) => Some(
+                            from_substrait_rex(filter, &schema, extensions)
+                                .await?
+                                .as_ref()
+                                .clone(),
+                        ),
+                        None => None,
+                    };
+                    // both filters are used to prune the files
+                    let predicate = match conjunction(
+                        filter.iter().chain(best_effort_filter.iter()).cloned(),
+                    ) {
+                        Some(predicate) => Some(create_physical_expr(
+                            &predicate,
+                            &schema,
+                            &file_schema,
+                            &ExecutionProps::new(),
+                        )?),
+                        None => None,
+                    };
+
                     let mut base_config = FileScanConfig {
                         object_store_url: ObjectStoreUrl::local_filesystem(),
-                        file_schema: Arc::new(Schema::empty()),
+                        file_schema: file_schema.clone(),
                         file_groups,
                         statistics: Default::default(),
                         projection: None,
@@ -116,19 +153,56 @@ pub async fn from_substrait_rel(
                         infinite_source: false,
                     };
 
+                    let mut projection = None;
                     if let Some(MaskExpression { select, .. }) = &read.projection {
-                        if let Some(projection) = &select.as_ref() {
-                            let column_indices: Vec<usize> = projection
+                        if let Some(projection_select) = &select.as_ref() {
+                            let column_indices: Vec<usize> = projection_select
                                 .struct_items
                                 .iter()
                                 .map(|item| item.field as usize)
                                 .collect();
-                            base_config.projection = Some(column_indices);
+                            projection = Some(column_indices);
                         }
                     }
 
-                    Ok(Arc::new(ParquetExec::new(base_config, None, None))
-                        as Arc<dyn ExecutionPlan>)
+                    match filter {
+                        None => {
+                            base_config.projection = projection;
+                            Ok(Arc::new(ParquetExec::new(base_config, predicate, None))
+                                as Arc<dyn ExecutionPlan>)
+                        }
+                        // the filter must be applied, so the files are read
+                        // in full, filtered and then projected
+                        Some(filter) => {
+                            let scan =
+                                Arc::new(ParquetExec::new(base_config, predicate, None));
+                            let filter = create_physical_expr(
+                                &filter,
+                                &schema,
+                                &file_schema,
+                                &ExecutionProps::new(),
+                            )?;
+                            let plan = Arc::new(FilterExec::try_new(filter, scan)?);
+                            match projection {
+                                Some(indices) => {
+                                    let exprs = indices
+                                        .iter()
+                                        .map(|i| {
+                                            let name = file_schema.field(*i).name();
+                                            (
+                                                Arc::new(Column::new(name, *i))
+                                                    as Arc<dyn PhysicalExpr>,
+                                                name.to_string(),
+                                            )
+                                        })
+                                        .collect();
+                                    Ok(Arc::new(ProjectionExec::try_new(exprs, plan)?)
+                                        as Arc<dyn ExecutionPlan>)
+                                }
+                                None => Ok(plan as Arc<dyn ExecutionPlan>),
+                            }
+                        }
+                    }
                 }
                 _ => Err(DataFusionError::NotImplemented(
                     "Only LocalFile reads are supported when parsing physical"
@@ -15,10 +15,17 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::logical_plan::producer::{to_substrait_named_struct, to_substrait_rex};
+use datafusion::common::{Column as DFColumn, DFSchema, DFSchemaRef};
 use datafusion::error::{DataFusionError, Result};
+use datafusion::physical_expr::expressions::{BinaryExpr, Column, Literal};
+use datafusion::physical_expr::PhysicalExpr;
 use datafusion::physical_plan::file_format::ParquetExec;
 use datafusion::physical_plan::{displayable, ExecutionPlan};
+use datafusion::prelude::{binary_expr, lit, Expr};
 use std::collections::HashMap;
+use std::sync::Arc;
+use substrait::proto::expression::mask_expression::{StructItem, StructSelect};
 use substrait::proto::expression::MaskExpression;
 use substrait::proto::extensions;
 use substrait::proto::read_rel::local_files::file_or_files::ParquetReadOptions;
@@ -33,7 +40,7 @@ use substrait::proto::Rel;
 /// Convert DataFusion ExecutionPlan to Substrait Rel
 pub fn to_substrait_rel(
     plan: &dyn ExecutionPlan,
-    _extension_info: &mut (
+    extension_info: &mut (
         Vec<extensions::SimpleExtensionDeclaration>,
         HashMap<String, u32>,
     ),
@@ -55,14 +62,39 @@ pub fn to_substrait_rel(
             }
         }
 
+        let schema = DFSchemaRef::new(DFSchema::try_from(
+            base_config.file_schema.as_ref().clone(),
+        )?);
+        let projection = base_config.projection.as_ref().map(|p| StructSelect {
+            struct_items: p
+                .iter()
+                .map(|i| StructItem {
+                    field: *i as i32,
+                    child: None,
+                })
+                .collect(),
+        });
+        // the schema is omitted if it has types Substrait can't represent
+        let base_schema = to_substrait_named_struct(&schema).ok();
+        // the predicate is only used to prune the files, so it may be
+        // applied by the consumer on a best effort basis, and is skipped
+        // if it can't be converted, or refers to an omitted schema
+        let best_effort_filter = match (scan.predicate(), &base_schema) {
+            (Some(predicate), Some(_)) => to_logical_expr(predicate)
+                .and_then(|expr| to_substrait_rex(&expr, &schema, extension_info))
+                .ok()
+                .map(Box::new),
+            _ => None,
+        };
+
         Ok(Box::new(Rel {
             rel_type: Some(RelType::Read(Box::new(ReadRel {
                 common: None,
-                base_schema: None,
+                base_schema,
                 filter: None,
-                best_effort_filter: None,
+                best_effort_filter,
                 projection: Some(MaskExpression {
-                    select: None,
+                    select: projection,
                     maintain_singular_struct: false,
                 }),
                 advanced_extension: None,
@@ -79,3 +111,24 @@ pub fn to_substrait_rel(
         )))
     }
 }
+
+/// Convert the predicate of a scan to a logical expression over the
+/// columns of the scanned files
+fn to_logical_expr(expr: &Arc<dyn PhysicalExpr>) -> Result<Expr> {
+    let any = expr.as_any();
+    if let Some(column) = any.downcast_ref::<Column>() {
+        Ok(Expr::Column(DFColumn::from_name(column.name())))
+    } else if let Some(literal) = any.downcast_ref::<Literal>() {
+        Ok(lit(literal.value().clone()))
+    } else if let Some(binary) = any.downcast_ref::<BinaryExpr>() {
+        Ok(binary_expr(
+            to_logical_expr(binary.left())?,
+            *binary.op(),
+            to_logical_expr(binary.right())?,
+        ))
+    } else {
+        Err(DataFusionError::NotImplemented(format!(
+            "Unsupported predicate in Substrait physical plan producer: {expr}"
+        )))
+    }
+}
@@ -22,9 +22,12 @@ mod tests {
 
     use crate::{consumer::from_substrait_plan, producer::to_substrait_plan};
     use datafusion::arrow::datatypes::{DataType, Field, Schema};
-    use datafusion::error::Result;
+    use datafusion::arrow::util::pretty::pretty_format_batches;
+    use datafusion::error::{DataFusionError, Result};
+    use datafusion::logical_expr::LogicalPlan;
     use datafusion::prelude::*;
     use substrait::proto::extensions::simple_extension_declaration::MappingType;
+    use substrait::proto::{plan_rel, rel::RelType, set_rel::SetOp, Plan, ReadRel};
 
     #[tokio::test]
     async fn simple_select() -> Result<()> {
@@ -237,6 +240,67 @@ mod tests {
             .await
     }
 
+    #[tokio::test]
+    async fn roundtrip_join_with_filter() -> Result<()> {
+        roundtrip(
+            "SELECT data.a FROM data JOIN data2 ON data.a = data2.a AND data.e > data2.a",
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_non_equi_join() -> Result<()> {
+        roundtrip("SELECT data.a FROM data LEFT JOIN data2 ON data.a > data2.a").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_table_scan_without_projection() -> Result<()> {
+        let mut ctx = create_context().await?;
+        let plan = ctx.table("data").await?.into_unoptimized_plan();
+        let proto = to_substrait_plan(&plan)?;
+        let plan2 = from_substrait_plan(&mut ctx, &proto).await?;
+        assert_eq!(format!("{plan:?}"), format!("{plan2:?}"));
+        assert_eq!(format!("{plan2:?}"), "TableScan: data");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn table_scan_filters() -> Result<()> {
+        let mut ctx = create_context().await?;
+        let plan = ctx
+            .sql("SELECT b FROM data WHERE a > 1")
+            .await?
+            .into_optimized_plan()?;
+        let mut proto = to_substrait_plan(&plan)?;
+
+        // filters the table only uses to prune files are best effort filters
+        let read = read_rel(&mut proto);
+        assert!(read.filter.is_none());
+        assert!(read.best_effort_filter.is_some());
+        let plan2 = from_substrait_plan(&mut ctx, &proto).await?;
+        assert_eq!(
+            format!("{plan2:?}"),
+            "Projection: data.b\
+            \n  Filter: data.a > Int64(1)\
+            \n    TableScan: data projection=[a, b], partial_filters=[data.a > Int64(1)]"
+        );
+
+        // a required filter is applied above the scan, as the table does
+        // not handle it exactly
+        let read = read_rel(&mut proto);
+        read.filter = read.best_effort_filter.take();
+        let plan2 = from_substrait_plan(&mut ctx, &proto).await?;
+        assert_eq!(
+            format!("{plan2:?}"),
+            "Projection: data.b\
+            \n  Filter: data.a > Int64(1)\
+            \n    Projection: data.a, data.b\
+            \n      Filter: data.a > Int64(1)\
+            \n        TableScan: data, partial_filters=[data.a > Int64(1)]"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn simple_intersect() -> Result<()> {
         assert_expected_plan(
@@ -265,6 +329,312 @@ mod tests {
         roundtrip("SELECT * FROM datafusion.public.data;").await
     }
 
+    #[tokio::test]
+    async fn roundtrip_alias() -> Result<()> {
+        roundtrip("SELECT a AS x, b + 1 AS y FROM data").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_union() -> Result<()> {
+        roundtrip("SELECT a, e FROM data UNION ALL SELECT a, e FROM data").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_union_distinct() -> Result<()> {
+        roundtrip("SELECT a, e FROM data UNION SELECT a, e FROM data").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_union_three_inputs() -> Result<()> {
+        roundtrip(
+            "SELECT a FROM data UNION ALL SELECT a FROM data WHERE a > 1 \
+             UNION ALL SELECT a FROM data WHERE a < 10",
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_cross_join() -> Result<()> {
+        roundtrip("SELECT data.a, data2.a FROM data CROSS JOIN data2").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_values() -> Result<()> {
+        roundtrip("SELECT * FROM (VALUES (1, 'a', 2.5), (2, 'b', NULL))").await
+    }
+
+    #[tokio::test]
+    async fn roundtrip_values_union() -> Result<()> {
+        roundtrip("SELECT a FROM data UNION ALL SELECT column1 FROM (VALUES (1), (2))")
+            .await
+    }
+
+    #[tokio::test]
+    async fn window_functions_with_same_window() -> Result<()> {
+        roundtrip(
+            "SELECT a, SUM(b) OVER (PARTITION BY a), COUNT(b) OVER (PARTITION BY a) FROM data",
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn set_operations() -> Result<()> {
+        let mut ctx = create_context().await?;
+        let plan = ctx
+            .sql("SELECT a FROM data UNION ALL SELECT a FROM data2")
+            .await?
+            .into_optimized_plan()?;
+        let mut proto = to_substrait_plan(&plan)?;
+
+        for (op, expected) in [
+            (
+                SetOp::UnionDistinct,
+                "Aggregate: groupBy=[[data.a]], aggr=[[]]",
+            ),
+            (
+                SetOp::IntersectionPrimary,
+                "LeftSemi Join: data.a = data2.a",
+            ),
+            (SetOp::MinusPrimary, "LeftAnti Join: data.a = data2.a"),
+        ] {
+            set_root_set_op(&mut proto, op);
+            let plan2 = from_substrait_plan(&mut ctx, &proto).await?;
+            let plan2 = ctx.state().optimize(&plan2)?;
+            let plan2str = format!("{plan2:?}");
+            assert!(
+                plan2str.contains(expected),
+                "expected {expected} in {plan2str}"
+            );
+        }
+
+        set_root_set_op(&mut proto, SetOp::IntersectionMultiset);
+        let err = from_substrait_plan(&mut ctx, &proto).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unsupported set operation: IntersectionMultiset"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tpch_q1() -> Result<()> {
+        roundtrip_tpch_query(1).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q2() -> Result<()> {
+        assert_tpch_query_not_implemented(2, &["LIKE"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q3() -> Result<()> {
+        roundtrip_tpch_query(3).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q4() -> Result<()> {
+        roundtrip_tpch_query(4).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q5() -> Result<()> {
+        roundtrip_tpch_query(5).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q6() -> Result<()> {
+        roundtrip_tpch_query(6).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q7() -> Result<()> {
+        assert_tpch_query_not_implemented(7, &["datepart"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q8() -> Result<()> {
+        assert_tpch_query_not_implemented(8, &["datepart"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q9() -> Result<()> {
+        assert_tpch_query_not_implemented(9, &["LIKE", "datepart"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q10() -> Result<()> {
+        roundtrip_tpch_query(10).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q11() -> Result<()> {
+        roundtrip_tpch_query(11).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q12() -> Result<()> {
+        roundtrip_tpch_query(12).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q13() -> Result<()> {
+        assert_tpch_query_not_implemented(13, &["LIKE"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q14() -> Result<()> {
+        assert_tpch_query_not_implemented(14, &["LIKE"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q15() -> Result<()> {
+        roundtrip_tpch_query(15).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q16() -> Result<()> {
+        assert_tpch_query_not_implemented(16, &["LIKE", " IN ("]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q17() -> Result<()> {
+        roundtrip_tpch_query(17).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q18() -> Result<()> {
+        roundtrip_tpch_query(18).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q19() -> Result<()> {
+        assert_tpch_query_not_implemented(19, &[" IN ("]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q20() -> Result<()> {
+        assert_tpch_query_not_implemented(20, &["LIKE"]).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q21() -> Result<()> {
+        roundtrip_tpch_query(21).await
+    }
+
+    #[tokio::test]
+    async fn tpch_q22() -> Result<()> {
+        assert_tpch_query_not_implemented(22, &["substr", " IN ("]).await
+    }
+
+    /// Replaces the operation of the SetRel below the root of `proto`
+    fn set_root_set_op(proto: &mut Plan, op: SetOp) {
+        let set = match proto.relations[0].rel_type.as_mut() {
+            Some(plan_rel::RelType::Root(root)) => {
+                match root.input.as_mut().and_then(|rel| rel.rel_type.as_mut()) {
+                    Some(RelType::Set(set)) => set,
+                    rel => panic!("expected SetRel, got {rel:?}"),
+                }
+            }
+            rel => panic!("expected RelRoot, got {rel:?}"),
+        };
+        set.op = op as i32;
+    }
+
+    /// Returns the ReadRel below the Filter below the root Projection of `proto`
+    fn read_rel(proto: &mut Plan) -> &mut ReadRel {
+        let project = match &mut proto.relations[0].rel_type {
+            Some(plan_rel::RelType::Root(root)) => root.input.as_mut().unwrap(),
+            _ => panic!("expected a root relation"),
+        };
+        let filter = match &mut project.rel_type {
+            Some(RelType::Project(project)) => project.input.as_mut().unwrap(),
+            _ => panic!("expected a ProjectRel"),
+        };
+        let read = match &mut filter.rel_type {
+            Some(RelType::Filter(filter)) => filter.input.as_mut().unwrap(),
+            _ => panic!("expected a FilterRel"),
+        };
+        match &mut read.rel_type {
+            Some(RelType::Read(read)) => read.as_mut(),
+            _ => panic!("expected a ReadRel"),
+        }
+    }
+
+    /// Checks that the round trip of TPC-H query `query_no` through Substrait
+    /// produces the same output fields and results as the original plan.
+    ///
+    /// Plans are not compared, as the producer rewrites some expressions,
+    /// such as `BETWEEN`.
+    async fn roundtrip_tpch_query(query_no: usize) -> Result<()> {
+        let mut ctx = create_tpch_context().await?;
+        // statements other than the query itself, such as the creation of the
+        // view of q15, are executed as they are
+        for sql in tpch_query(query_no)?
+            .split(';')
+            .map(str::trim)
+            .filter(|sql| !sql.is_empty())
+        {
+            if sql.to_lowercase().starts_with("select") {
+                roundtrip_tpch_statement(&mut ctx, sql).await?;
+            } else {
+                ctx.sql(sql).await?.collect().await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn roundtrip_tpch_statement(ctx: &mut SessionContext, sql: &str) -> Result<()> {
+        let plan = ctx.sql(sql).await?.into_optimized_plan()?;
+        let proto = to_substrait_plan(&plan)?;
+        let plan2 = from_substrait_plan(ctx, &proto).await?;
+        let plan2 = ctx.state().optimize(&plan2)?;
+
+        let names = |plan: &LogicalPlan| {
+            plan.schema()
+                .fields()
+                .iter()
+                .map(|f| f.name().clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&plan), names(&plan2));
+
+        let expected = DataFrame::new(ctx.state(), plan).collect().await?;
+        let actual = DataFrame::new(ctx.state(), plan2).collect().await?;
+        assert_eq!(
+            pretty_format_batches(&expected)?.to_string(),
+            pretty_format_batches(&actual)?.to_string()
+        );
+        Ok(())
+    }
+
+    /// Checks that the plan of TPC-H query `query_no` cannot be converted to
+    /// Substrait, as it uses one of the expressions shown as `unsupported`
+    /// which the producer does not handle yet
+    async fn assert_tpch_query_not_implemented(
+        query_no: usize,
+        unsupported: &[&str],
+    ) -> Result<()> {
+        let ctx = create_tpch_context().await?;
+        let plan = ctx
+            .sql(&tpch_query(query_no)?)
+            .await?
+            .into_optimized_plan()?;
+        match to_substrait_plan(&plan) {
+            Err(DataFusionError::NotImplemented(msg)) => assert!(
+                unsupported.iter().any(|expr| msg.contains(expr)),
+                "unexpected error for q{query_no}: {msg}"
+            ),
+            Err(e) => panic!("expected a NotImplemented error for q{query_no}, got {e}"),
+            Ok(_) => panic!("expected q{query_no} not to be supported"),
+        }
+        Ok(())
+    }
+
+    fn tpch_query(query_no: usize) -> Result<String> {
+        Ok(std::fs::read_to_string(format!(
+            "../../benchmarks/queries/q{query_no}.sql"
+        ))?)
+    }
+
     async fn assert_expected_plan(sql: &str, expected_plan_str: &str) -> Result<()> {
         let mut ctx = create_context().await?;
         let df = ctx.sql(sql).await?;
@@ -356,6 +726,25 @@ mod tests {
         Ok((function_names, function_anchors))
     }
 
+    /// Creates a context with the TPC-H tables, using a single partition
+    /// so that the results of both plans are computed in the same order
+    async fn create_tpch_context() -> Result<SessionContext> {
+        let ctx =
+            SessionContext::with_config(SessionConfig::new().with_target_partitions(1));
+        for table in [
+            "customer", "lineitem", "nation", "orders", "part", "partsupp", "region",
+            "supplier",
+        ] {
+            ctx.register_csv(
+                table,
+                &format!("../core/tests/tpch-csv/{table}.csv"),
+                CsvReadOptions::new(),
+            )
+            .await?;
+        }
+        Ok(ctx)
+    }
+
     async fn create_context() -> Result<SessionContext> {
         let ctx = SessionContext::new();
         let mut explicit_options = CsvReadOptions::new();
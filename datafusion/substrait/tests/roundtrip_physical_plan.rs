@@ -17,10 +17,13 @@
 
 #[cfg(test)]
 mod tests {
-    use datafusion::arrow::datatypes::Schema;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
     use datafusion::datasource::listing::PartitionedFile;
     use datafusion::datasource::object_store::ObjectStoreUrl;
     use datafusion::error::Result;
+    use datafusion::logical_expr::Operator;
+    use datafusion::physical_expr::expressions::{binary, col, is_null, lit};
+    use datafusion::physical_expr::PhysicalExpr;
     use datafusion::physical_plan::file_format::{FileScanConfig, ParquetExec};
     use datafusion::physical_plan::{displayable, ExecutionPlan};
     use datafusion::prelude::SessionContext;
@@ -28,6 +31,9 @@ mod tests {
     use std::collections::HashMap;
     use std::sync::Arc;
     use substrait::proto::extensions;
+    use substrait::proto::extensions::simple_extension_declaration::MappingType;
+    use substrait::proto::rel::RelType;
+    use substrait::proto::{ReadRel, Rel};
 
     #[tokio::test]
     async fn parquet_exec() -> Result<()> {
@@ -77,4 +83,174 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn parquet_exec_with_projection_and_predicate() -> Result<()> {
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema: file_schema.clone(),
+            file_groups: vec![vec![PartitionedFile::new(
+                "file://foo/part-0.parquet".to_string(),
+                123,
+            )]],
+            statistics: Default::default(),
+            projection: Some(vec![1]),
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        };
+        let predicate = binary(
+            col("a", &file_schema)?,
+            Operator::Gt,
+            lit(1i64),
+            &file_schema,
+        )?;
+        let parquet_exec: Arc<dyn ExecutionPlan> =
+            Arc::new(ParquetExec::new(scan_config, Some(predicate), None));
+
+        let mut extension_info: (
+            Vec<extensions::SimpleExtensionDeclaration>,
+            HashMap<String, u32>,
+        ) = (vec![], HashMap::new());
+
+        let substrait_rel =
+            producer::to_substrait_rel(parquet_exec.as_ref(), &mut extension_info)?;
+
+        // the scan is described by the schema of the files, and its
+        // predicate is only a hint for the consumer
+        let read = match &substrait_rel.rel_type {
+            Some(RelType::Read(read)) => read,
+            _ => panic!("expected a ReadRel"),
+        };
+        assert!(read.base_schema.is_some());
+        assert!(read.filter.is_none());
+        assert!(read.best_effort_filter.is_some());
+
+        let extensions = extension_info
+            .0
+            .iter()
+            .map(|e| match e.mapping_type.as_ref().unwrap() {
+                MappingType::ExtensionFunction(f) => (f.function_anchor, &f.name),
+                _ => unreachable!("Producer does not generate a non-function extension"),
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut ctx = SessionContext::new();
+        let parquet_exec_roundtrip =
+            consumer::from_substrait_rel(&mut ctx, substrait_rel.as_ref(), &extensions)
+                .await?;
+
+        let expected = format!("{}", displayable(parquet_exec.as_ref()).indent());
+        let actual = format!("{}", displayable(parquet_exec_roundtrip.as_ref()).indent());
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_timestamp_large_utf8_and_list() -> Result<()> {
+        let file_schema = Arc::new(Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+            Field::new("s", DataType::LargeUtf8, true),
+            Field::new(
+                "l",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+        ]));
+        let parquet_exec = create_parquet_exec(file_schema.clone(), None);
+
+        let mut extension_info = (vec![], HashMap::new());
+        let substrait_rel =
+            producer::to_substrait_rel(parquet_exec.as_ref(), &mut extension_info)?;
+        let read = read_rel(&substrait_rel);
+        assert!(read.base_schema.is_some());
+
+        let mut ctx = SessionContext::new();
+        let parquet_exec_roundtrip = consumer::from_substrait_rel(
+            &mut ctx,
+            substrait_rel.as_ref(),
+            &HashMap::new(),
+        )
+        .await?;
+
+        assert_eq!(parquet_exec_roundtrip.schema(), file_schema);
+        let expected = format!("{}", displayable(parquet_exec.as_ref()).indent());
+        let actual = format!("{}", displayable(parquet_exec_roundtrip.as_ref()).indent());
+        assert_eq!(expected, actual);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parquet_exec_with_unsupported_predicate_and_type() -> Result<()> {
+        // the predicate is skipped if it can't be converted
+        let file_schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let predicate = is_null(col("a", &file_schema)?)?;
+        let parquet_exec = create_parquet_exec(file_schema, Some(predicate));
+
+        let mut extension_info = (vec![], HashMap::new());
+        let substrait_rel =
+            producer::to_substrait_rel(parquet_exec.as_ref(), &mut extension_info)?;
+        let read = read_rel(&substrait_rel);
+        assert!(read.base_schema.is_some());
+        assert!(read.best_effort_filter.is_none());
+
+        // the schema, and the predicate referring to it, are omitted if the
+        // schema has types Substrait can't represent
+        let file_schema = Arc::new(Schema::new(vec![Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+            true,
+        )]));
+        let predicate = binary(
+            col("ts", &file_schema)?,
+            Operator::Eq,
+            col("ts", &file_schema)?,
+            &file_schema,
+        )?;
+        let parquet_exec = create_parquet_exec(file_schema, Some(predicate));
+
+        let substrait_rel =
+            producer::to_substrait_rel(parquet_exec.as_ref(), &mut extension_info)?;
+        let read = read_rel(&substrait_rel);
+        assert!(read.base_schema.is_none());
+        assert!(read.best_effort_filter.is_none());
+
+        Ok(())
+    }
+
+    fn create_parquet_exec(
+        file_schema: SchemaRef,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Arc<dyn ExecutionPlan> {
+        let scan_config = FileScanConfig {
+            object_store_url: ObjectStoreUrl::local_filesystem(),
+            file_schema,
+            file_groups: vec![vec![PartitionedFile::new(
+                "file://foo/part-0.parquet".to_string(),
+                123,
+            )]],
+            statistics: Default::default(),
+            projection: None,
+            limit: None,
+            table_partition_cols: vec![],
+            output_ordering: None,
+            infinite_source: false,
+        };
+        Arc::new(ParquetExec::new(scan_config, predicate, None))
+    }
+
+    fn read_rel(rel: &Rel) -> &ReadRel {
+        match &rel.rel_type {
+            Some(RelType::Read(read)) => read,
+            _ => panic!("expected a ReadRel"),
+        }
+    }
 }